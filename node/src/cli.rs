@@ -20,7 +20,8 @@
 mod cli_parachain;
 
 use super::service::{
-    AdditionalRuntimeApiCollection, FullBackend, FullClient, IdentifyVariant, RuntimeApiCollection,
+    pool_analytics::PoolAnalyticsConfig, AdditionalRuntimeApiCollection, FullBackend, FullClient,
+    IdentifyVariant, RuntimeApiCollection,
 };
 use clap::Parser;
 #[cfg(feature = "parachain")]
@@ -196,6 +197,21 @@ pub struct Cli {
     #[clap(long)]
     pub no_hardware_benchmarks: bool,
 
+    /// Index the volume, TVL and spot prices of all liquidity pools in the local database and
+    /// serve them using the `poolAnalytics` RPC namespace. Backfilling past blocks requires
+    /// `--state-pruning archive`.
+    #[clap(long)]
+    pub pool_analytics: bool,
+
+    /// The length in blocks of the candles computed by the pool analytics indexer.
+    #[clap(long, default_value = "300")]
+    pub pool_analytics_candle_period: BlockNumber,
+
+    /// The block at which the pool analytics indexer starts when it runs for the first time.
+    /// Defaults to the last finalized block.
+    #[clap(long)]
+    pub pool_analytics_start_block: Option<BlockNumber>,
+
     /// Relaychain arguments
     #[cfg(feature = "parachain")]
     #[clap(raw = true)]
@@ -207,6 +223,16 @@ pub struct Cli {
     pub parachain_id: Option<u32>,
 }
 
+impl Cli {
+    /// The configuration of the pool analytics indexer, or `None` if it's disabled.
+    pub fn pool_analytics_config(&self) -> Option<PoolAnalyticsConfig> {
+        self.pool_analytics.then_some(PoolAnalyticsConfig {
+            candle_period: self.pool_analytics_candle_period.max(1),
+            start_block: self.pool_analytics_start_block,
+        })
+    }
+}
+
 impl SubstrateCli for Cli {
     fn author() -> String {
         env!("CARGO_PKG_AUTHORS").into()
@@ -526,6 +526,7 @@ fn none_command(cli: &Cli) -> sc_cli::Result<()> {
                 parachain_id,
                 polkadot_config,
                 hwbench,
+                cli.pool_analytics_config(),
             )
             .await
             .map(|r| r.0)
@@ -536,6 +537,7 @@ fn none_command(cli: &Cli) -> sc_cli::Result<()> {
                 parachain_id,
                 polkadot_config,
                 hwbench,
+                cli.pool_analytics_config(),
             )
             .await
             .map(|r| r.0)
@@ -555,12 +557,14 @@ fn none_command(cli: &Cli) -> sc_cli::Result<()> {
             spec if spec.is_zeitgeist() => new_full::<ZeitgeistRuntimeApi, ZeitgeistExecutor>(
                 config,
                 cli.no_hardware_benchmarks,
+                cli.pool_analytics_config(),
             )
             .map_err(sc_cli::Error::Service),
             #[cfg(feature = "with-battery-station-runtime")]
             _ => new_full::<BatteryStationRuntimeApi, BatteryStationExecutor>(
                 config,
                 cli.no_hardware_benchmarks,
+                cli.pool_analytics_config(),
             )
             .map_err(sc_cli::Error::Service),
             #[cfg(all(
@@ -570,6 +574,7 @@ fn none_command(cli: &Cli) -> sc_cli::Result<()> {
             _ => new_full::<ZeitgeistRuntimeApi, ZeitgeistExecutor>(
                 config,
                 cli.no_hardware_benchmarks,
+                cli.pool_analytics_config(),
             )
             .map_err(sc_cli::Error::Service),
             #[cfg(all(
@@ -20,6 +20,7 @@
 //! used by Substrate nodes. This file extends those RPC definitions with
 //! capabilities that are specific to this project's runtime configuration.
 
+use crate::service::pool_analytics::PoolAnalyticsConfig;
 use jsonrpsee::RpcModule;
 use sc_client_api::AuxStore;
 use sc_rpc_api::DenyUnsafe;
@@ -38,6 +39,8 @@ pub struct FullDeps<C, P> {
     pub deny_unsafe: DenyUnsafe,
     /// Transaction pool instance.
     pub pool: Arc<P>,
+    /// Configuration of the pool analytics indexer, if enabled.
+    pub pool_analytics: Option<PoolAnalyticsConfig>,
 }
 
 /// Instantiate all full RPC extensions.
@@ -54,19 +57,26 @@ where
         + 'static,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+    C::Api: zrml_global_disputes_rpc::GlobalDisputesRuntimeApi<Block, AccountId, Balance, MarketId>,
     C::Api: zrml_swaps_rpc::SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + Sync + Send + 'static,
 {
+    use crate::service::pool_analytics::rpc::{PoolAnalytics, PoolAnalyticsApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
+    use zrml_global_disputes_rpc::{GlobalDisputes, GlobalDisputesApiServer};
     use zrml_swaps_rpc::{Swaps, SwapsApiServer};
 
     let mut io = RpcModule::new(());
-    let FullDeps { client, pool, deny_unsafe } = deps;
+    let FullDeps { client, pool, deny_unsafe, pool_analytics } = deps;
 
     io.merge(System::new(Arc::clone(&client), Arc::clone(&pool), deny_unsafe).into_rpc())?;
     io.merge(TransactionPayment::new(Arc::clone(&client)).into_rpc())?;
+    io.merge(GlobalDisputes::new(Arc::clone(&client)).into_rpc())?;
+    if let Some(pool_analytics_config) = pool_analytics {
+        io.merge(PoolAnalytics::new(Arc::clone(&client), pool_analytics_config).into_rpc())?;
+    }
     io.merge(Swaps::new(client).into_rpc())?;
 
     Ok(io)
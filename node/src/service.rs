@@ -15,6 +15,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod pool_analytics;
 #[cfg(feature = "parachain")]
 mod service_parachain;
 #[cfg(not(feature = "parachain"))]
@@ -76,6 +77,12 @@ impl sc_executor::NativeExecutionDispatch for ZeitgeistExecutor {
     }
 }
 
+#[cfg(feature = "with-battery-station-runtime")]
+pool_analytics::impl_decode_pool_activity!(BatteryStationExecutor, battery_station_runtime);
+
+#[cfg(feature = "with-zeitgeist-runtime")]
+pool_analytics::impl_decode_pool_activity!(ZeitgeistExecutor, zeitgeist_runtime);
+
 /// Can be called for a `Configuration` to check if it is a configuration for
 /// the `Zeitgeist` network.
 pub trait IdentifyVariant {
@@ -106,6 +113,7 @@ pub trait RuntimeApiCollection:
     + sp_api::Metadata<Block>
     + sp_offchain::OffchainWorkerApi<Block>
     + sp_session::SessionKeys<Block>
+    + zrml_global_disputes_rpc::GlobalDisputesRuntimeApi<Block, AccountId, Balance, MarketId>
     + zrml_swaps_rpc::SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>
 where
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
@@ -122,6 +130,7 @@ where
         + sp_api::Metadata<Block>
         + sp_offchain::OffchainWorkerApi<Block>
         + sp_session::SessionKeys<Block>
+        + zrml_global_disputes_rpc::GlobalDisputesRuntimeApi<Block, AccountId, Balance, MarketId>
         + zrml_swaps_rpc::SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>,
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
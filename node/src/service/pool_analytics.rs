@@ -0,0 +1,486 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! Optional indexer which keeps historical data of liquidity pools in the auxiliary storage of
+//! the node's database.
+//!
+//! The indexer follows finalized blocks, decodes the events of the swaps pallet and updates the
+//! cumulative volume, the total value locked (TVL) and the OHLC candles of the spot prices of
+//! every pool touched by the block. The data is served by the `poolAnalytics` RPC namespace
+//! defined in [`rpc`].
+
+pub mod rpc;
+
+use futures::StreamExt;
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::{AuxStore, Backend, BlockchainEvents, StorageProvider};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::{Error as BlockChainError, HeaderBackend};
+use sp_core::{hashing::twox_128, storage::StorageKey};
+use sp_runtime::{generic::BlockId, traits::Header as HeaderT};
+use std::{collections::BTreeMap, marker::PhantomData, sync::Arc};
+use zeitgeist_primitives::{
+    constants::BASE,
+    types::{AccountId, Asset, Balance, Block, BlockNumber, Hash, MarketId, PoolId, SerdeWrapper},
+};
+use zrml_swaps_rpc::SwapsRuntimeApi;
+
+const LOG_TARGET: &str = "pool-analytics";
+
+const LAST_BLOCK_KEY: &[u8] = b"pool_analytics:last_block";
+const STATS_PREFIX: &[u8] = b"pool_analytics:stats";
+const CANDLE_PREFIX: &[u8] = b"pool_analytics:candle";
+const TVL_PREFIX: &[u8] = b"pool_analytics:tvl";
+
+/// Configuration of the pool analytics indexer.
+#[derive(Clone, Copy, Debug)]
+pub struct PoolAnalyticsConfig {
+    /// The length of a candle in blocks.
+    pub candle_period: BlockNumber,
+    /// The block at which the indexer starts if it has never run before. If `None`, the indexer
+    /// starts at the last finalized block.
+    pub start_block: Option<BlockNumber>,
+}
+
+/// A change of a pool's state that was emitted by the swaps pallet.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PoolActivity {
+    /// Assets were swapped on the pool.
+    Swap {
+        pool_id: PoolId,
+        asset_in: Asset<MarketId>,
+        asset_out: Asset<MarketId>,
+        amount_in: Balance,
+        amount_out: Balance,
+    },
+    /// The balances of the pool changed without a swap (pool creation, joins, exits, arbitrage).
+    BalancesChanged { pool_id: PoolId },
+}
+
+impl PoolActivity {
+    fn pool_id(&self) -> PoolId {
+        match self {
+            PoolActivity::Swap { pool_id, .. } | PoolActivity::BalancesChanged { pool_id } => {
+                *pool_id
+            }
+        }
+    }
+}
+
+/// Decodes the pool activity from the SCALE encoded `System::Events` of a block.
+///
+/// Implemented for the executor of each runtime using [`impl_decode_pool_activity`], as the
+/// event type depends on the runtime.
+pub trait DecodePoolActivity {
+    fn decode_pool_activity(
+        encoded_events: &[u8],
+    ) -> Result<Vec<PoolActivity>, parity_scale_codec::Error>;
+}
+
+macro_rules! impl_decode_pool_activity {
+    ($executor:ty, $runtime:ident) => {
+        impl $crate::service::pool_analytics::DecodePoolActivity for $executor {
+            fn decode_pool_activity(
+                encoded_events: &[u8],
+            ) -> Result<
+                Vec<$crate::service::pool_analytics::PoolActivity>,
+                parity_scale_codec::Error,
+            > {
+                use $crate::service::pool_analytics::PoolActivity;
+                use $runtime::Event;
+                use parity_scale_codec::Decode;
+                use zrml_swaps::Event as SwapsEvent;
+
+                let records = Vec::<
+                    frame_system::EventRecord<Event, zeitgeist_primitives::types::Hash>,
+                >::decode(&mut &encoded_events[..])?;
+                Ok(records
+                    .into_iter()
+                    .filter_map(|record| {
+                        let event = match record.event {
+                            Event::Swaps(event) => event,
+                            _ => return None,
+                        };
+                        // `SwapExactAmountInRouted` is ignored, as the swaps along the route emit
+                        // their own events.
+                        let pool_id = match event {
+                            SwapsEvent::SwapExactAmountIn(e)
+                            | SwapsEvent::SwapExactAmountOut(e) => {
+                                return Some(PoolActivity::Swap {
+                                    pool_id: e.cpep.pool_id,
+                                    asset_in: e.asset_in,
+                                    asset_out: e.asset_out,
+                                    amount_in: e.asset_amount_in,
+                                    amount_out: e.asset_amount_out,
+                                });
+                            }
+                            SwapsEvent::PoolCreate(cpep, ..) => cpep.pool_id,
+                            SwapsEvent::PoolJoin(e) | SwapsEvent::PoolExit(e) => e.cpep.pool_id,
+                            SwapsEvent::PoolJoinWithExactAssetAmount(e)
+                            | SwapsEvent::PoolJoinWithExactPoolAmount(e)
+                            | SwapsEvent::PoolExitWithExactAssetAmount(e)
+                            | SwapsEvent::PoolExitWithExactPoolAmount(e) => e.cpep.pool_id,
+                            SwapsEvent::ArbitrageBuyBurn(pool_id, _)
+                            | SwapsEvent::ArbitrageMintSell(pool_id, _) => pool_id,
+                            _ => return None,
+                        };
+                        Some(PoolActivity::BalancesChanged { pool_id })
+                    })
+                    .collect())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_decode_pool_activity;
+
+/// The current state of a pool.
+#[derive(Clone, Debug, Decode, Default, Deserialize, Encode, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolStats {
+    /// The cumulative amount of each asset swapped into or out of the pool.
+    pub volume: Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>,
+    /// The value of all assets in the pool in units of the base asset.
+    pub tvl: SerdeWrapper<Balance>,
+    /// The number of the block in which the pool was last updated.
+    pub updated_at: BlockNumber,
+}
+
+impl PoolStats {
+    fn add_volume(&mut self, asset: Asset<MarketId>, amount: Balance) {
+        match self.volume.iter_mut().find(|(a, _)| *a == asset) {
+            Some((_, volume)) => volume.0 = volume.0.saturating_add(amount),
+            None => self.volume.push((asset, SerdeWrapper(amount))),
+        }
+    }
+}
+
+/// The spot prices (in units of the base asset, without fees) and the volume of an asset in a
+/// pool over a period of blocks.
+#[derive(Clone, Debug, Decode, Deserialize, Encode, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Candle {
+    /// The number of the first block of the period.
+    pub start: BlockNumber,
+    pub open: SerdeWrapper<Balance>,
+    pub high: SerdeWrapper<Balance>,
+    pub low: SerdeWrapper<Balance>,
+    pub close: SerdeWrapper<Balance>,
+    /// The amount of the asset swapped into or out of the pool during the period.
+    pub volume: SerdeWrapper<Balance>,
+}
+
+impl Candle {
+    fn new(start: BlockNumber, price: Balance) -> Self {
+        let price = SerdeWrapper(price);
+        Candle {
+            start,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: SerdeWrapper(0),
+        }
+    }
+
+    fn update(&mut self, price: Balance, volume: Balance) {
+        self.high.0 = self.high.0.max(price);
+        self.low.0 = self.low.0.min(price);
+        self.close.0 = price;
+        self.volume.0 = self.volume.0.saturating_add(volume);
+    }
+}
+
+pub(crate) fn stats_key(pool_id: PoolId) -> Vec<u8> {
+    (STATS_PREFIX, pool_id).encode()
+}
+
+pub(crate) fn candle_key(pool_id: PoolId, asset: Asset<MarketId>, index: BlockNumber) -> Vec<u8> {
+    (CANDLE_PREFIX, pool_id, asset, index).encode()
+}
+
+pub(crate) fn tvl_key(pool_id: PoolId, index: BlockNumber) -> Vec<u8> {
+    (TVL_PREFIX, pool_id, index).encode()
+}
+
+pub(crate) fn read_aux<T: Decode>(
+    store: &impl AuxStore,
+    key: &[u8],
+) -> sp_blockchain::Result<Option<T>> {
+    store
+        .get_aux(key)?
+        .map(|bytes| T::decode(&mut &bytes[..]))
+        .transpose()
+        .map_err(|e| BlockChainError::Application(Box::new(e)))
+}
+
+/// Follow finalized blocks and index the activity of all pools.
+///
+/// Only finalized blocks are indexed so that the data isn't polluted by forks. Blocks are indexed
+/// in order, starting after the last indexed block or, if the indexer has never run before, at
+/// the configured start block or the last finalized block. If a block can't be indexed, the
+/// indexer stops and retries it once the next block is finalized. Blocks whose events can't be
+/// decoded and blocks of runtimes without the required version of the swaps runtime API are
+/// skipped. Backfilling requires the state of the historical blocks, i.e. an archive node.
+pub async fn run<C, B, Executor>(client: Arc<C>, config: PoolAnalyticsConfig)
+where
+    B: Backend<Block>,
+    C: AuxStore
+        + BlockchainEvents<Block>
+        + HeaderBackend<Block>
+        + ProvideRuntimeApi<Block>
+        + StorageProvider<Block, B>,
+    C::Api: SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>,
+    Executor: DecodePoolActivity,
+{
+    let indexer = Indexer::<C, B, Executor> { client, config, _marker: PhantomData };
+    // Subscribe before backfilling so that no finality notification is missed.
+    let mut finality_notifications = indexer.client.finality_notification_stream();
+    indexer.catch_up(indexer.client.info().finalized_number);
+    while let Some(notification) = finality_notifications.next().await {
+        indexer.catch_up(*notification.header.number());
+    }
+}
+
+struct Indexer<C, B, Executor> {
+    client: Arc<C>,
+    config: PoolAnalyticsConfig,
+    _marker: PhantomData<(B, Executor)>,
+}
+
+impl<C, B, Executor> Indexer<C, B, Executor>
+where
+    B: Backend<Block>,
+    C: AuxStore + HeaderBackend<Block> + ProvideRuntimeApi<Block> + StorageProvider<Block, B>,
+    C::Api: SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>,
+    Executor: DecodePoolActivity,
+{
+    /// Index all blocks from the block after the last indexed block up to and including
+    /// `finalized`. Stops at the first block that can't be indexed.
+    fn catch_up(&self, finalized: BlockNumber) {
+        let next = match read_aux::<BlockNumber>(&*self.client, LAST_BLOCK_KEY) {
+            Ok(Some(last_block)) => last_block.saturating_add(1),
+            Ok(None) => self.config.start_block.unwrap_or(finalized),
+            Err(e) => {
+                log::warn!(target: LOG_TARGET, "Failed to read the last indexed block: {}", e);
+                return;
+            }
+        };
+        for number in next..=finalized {
+            let result = self.client.hash(number).and_then(|hash| {
+                let hash = hash.ok_or_else(|| BlockChainError::UnknownBlock(number.to_string()))?;
+                self.index_block(hash)
+            });
+            if let Err(e) = result {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Failed to index block #{}, retrying on the next finalized block: {}",
+                    number,
+                    e
+                );
+                return;
+            }
+        }
+    }
+
+    fn index_block(&self, hash: Hash) -> sp_blockchain::Result<()> {
+        let number = self
+            .client
+            .number(hash)?
+            .ok_or_else(|| BlockChainError::UnknownBlock(format!("{:?}", hash)))?;
+        let last_block = read_aux::<BlockNumber>(&*self.client, LAST_BLOCK_KEY)?;
+        if last_block.map_or(false, |last_block| number <= last_block) {
+            return Ok(());
+        }
+
+        let at = BlockId::hash(hash);
+        let events_key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+        let activities = match self.client.storage(&at, &events_key)? {
+            // Blocks produced by runtimes with an older event layout can't be decoded.
+            Some(encoded_events) => Executor::decode_pool_activity(&encoded_events.0)
+                .unwrap_or_else(|e| {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Skipping the events of block #{}, which can't be decoded: {}",
+                        number,
+                        e
+                    );
+                    Vec::new()
+                }),
+            None => Vec::new(),
+        };
+        let mut volumes = calc_volumes(activities);
+
+        let api = self.client.runtime_api();
+        // Spot prices and balances of all assets of a pool are only available from version 2 of
+        // the swaps runtime API on.
+        let has_pool_api = api
+            .has_api_with::<dyn SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>, _>(
+                &at,
+                |version| version >= 2,
+            )?;
+        if !has_pool_api && !volumes.is_empty() {
+            log::warn!(
+                target: LOG_TARGET,
+                "Skipping the pools of block #{}, whose runtime doesn't provide their spot prices",
+                number
+            );
+            volumes.clear();
+        }
+        let candle_period = self.config.candle_period.max(1);
+        let index = number / candle_period;
+        let mut insert = Vec::new();
+        for (pool_id, pool_volumes) in volumes {
+            // The pool may have been destroyed in this block.
+            let prices = match api.get_all_spot_prices(&at, &pool_id, false)? {
+                Some(prices) => prices,
+                None => continue,
+            };
+            let balances = api.get_pool_balances(&at, &pool_id)?.unwrap_or_default();
+            let tvl = calc_tvl(&prices, &balances);
+
+            let mut stats =
+                read_aux::<PoolStats>(&*self.client, &stats_key(pool_id))?.unwrap_or_default();
+            for (asset, amount) in pool_volumes.iter() {
+                stats.add_volume(*asset, *amount);
+            }
+            stats.tvl = SerdeWrapper(tvl);
+            stats.updated_at = number;
+            insert.push((stats_key(pool_id), stats.encode()));
+            insert.push((tvl_key(pool_id, index), tvl.encode()));
+
+            for (asset, SerdeWrapper(price)) in prices {
+                let key = candle_key(pool_id, asset, index);
+                let mut candle = read_aux::<Candle>(&*self.client, &key)?
+                    .unwrap_or_else(|| Candle::new(index.saturating_mul(candle_period), price));
+                candle.update(price, pool_volumes.get(&asset).copied().unwrap_or_default());
+                insert.push((key, candle.encode()));
+            }
+        }
+        insert.push((LAST_BLOCK_KEY.to_vec(), number.encode()));
+
+        let insert = insert.iter().map(|(k, v)| (&k[..], &v[..])).collect::<Vec<_>>();
+        self.client.insert_aux(insert.iter(), &[])
+    }
+}
+
+/// Sum up the volume of each asset of each pool that had activity. Pools without swaps have no
+/// volume, but are still included.
+fn calc_volumes(
+    activities: Vec<PoolActivity>,
+) -> BTreeMap<PoolId, BTreeMap<Asset<MarketId>, Balance>> {
+    let mut volumes = BTreeMap::<PoolId, BTreeMap<Asset<MarketId>, Balance>>::new();
+    for activity in activities {
+        let pool_volumes = volumes.entry(activity.pool_id()).or_default();
+        if let PoolActivity::Swap { asset_in, asset_out, amount_in, amount_out, .. } = activity {
+            let volume_in = pool_volumes.entry(asset_in).or_default();
+            *volume_in = volume_in.saturating_add(amount_in);
+            let volume_out = pool_volumes.entry(asset_out).or_default();
+            *volume_out = volume_out.saturating_add(amount_out);
+        }
+    }
+    volumes
+}
+
+/// Calculate the value of the pool's assets in units of the base asset. Assets without a spot
+/// price are assumed to be the base asset.
+fn calc_tvl(
+    prices: &[(Asset<MarketId>, SerdeWrapper<Balance>)],
+    balances: &[(Asset<MarketId>, SerdeWrapper<Balance>)],
+) -> Balance {
+    balances.iter().fold(0, |tvl, (asset, balance)| {
+        let value = match prices.iter().find(|(a, _)| a == asset) {
+            Some((_, price)) => balance.0.saturating_mul(price.0) / BASE,
+            None => balance.0,
+        };
+        tvl.saturating_add(value)
+    })
+}
+
+#[cfg(all(test, feature = "with-zeitgeist-runtime"))]
+mod tests {
+    use super::*;
+    use crate::service::ZeitgeistExecutor;
+    use frame_system::{EventRecord, Phase};
+    use zeitgeist_runtime::Event;
+    use zrml_swaps::{
+        events::{CommonPoolEventParams, SwapEvent, SwapRoute, SwapRoutedEvent},
+        Event as SwapsEvent,
+    };
+
+    const POOL_ID: PoolId = 0;
+    const ASSET_A: Asset<MarketId> = Asset::CategoricalOutcome(0, 0);
+    const ASSET_B: Asset<MarketId> = Asset::CategoricalOutcome(0, 1);
+    const ASSET_C: Asset<MarketId> = Asset::CategoricalOutcome(0, 2);
+
+    fn swap_event(
+        asset_in: Asset<MarketId>,
+        asset_out: Asset<MarketId>,
+        asset_amount_in: Balance,
+        asset_amount_out: Balance,
+    ) -> Event {
+        Event::Swaps(SwapsEvent::SwapExactAmountIn(SwapEvent {
+            asset_amount_in,
+            asset_amount_out,
+            asset_bound: None,
+            asset_in,
+            asset_out,
+            cpep: CommonPoolEventParams { pool_id: POOL_ID, who: AccountId::from([0; 32]) },
+            max_price: None,
+        }))
+    }
+
+    #[test]
+    fn routed_swaps_are_counted_once() {
+        // Complete sets are bought with 10 units of the base asset and the other outcomes are
+        // swapped for `ASSET_A`, which emits one event per inner swap and one for the route.
+        let events = vec![
+            swap_event(ASSET_B, ASSET_A, 10 * BASE, 4 * BASE),
+            swap_event(ASSET_C, ASSET_A, 10 * BASE, 5 * BASE),
+            Event::Swaps(SwapsEvent::SwapExactAmountInRouted(SwapRoutedEvent {
+                asset_amount_in: 10 * BASE,
+                asset_amount_out: 19 * BASE,
+                asset_in: Asset::Ztg,
+                asset_out: ASSET_A,
+                cpep: CommonPoolEventParams { pool_id: POOL_ID, who: AccountId::from([0; 32]) },
+                route: SwapRoute::MintSell,
+            })),
+        ];
+        let records = events
+            .into_iter()
+            .map(|event| EventRecord::<Event, Hash> {
+                phase: Phase::ApplyExtrinsic(1),
+                event,
+                topics: vec![],
+            })
+            .collect::<Vec<_>>();
+
+        let activities =
+            <ZeitgeistExecutor as DecodePoolActivity>::decode_pool_activity(&records.encode())
+                .unwrap();
+        assert_eq!(activities.len(), 2);
+        let volumes = calc_volumes(activities);
+        assert_eq!(
+            volumes,
+            BTreeMap::from([(
+                POOL_ID,
+                BTreeMap::from([(ASSET_A, 9 * BASE), (ASSET_B, 10 * BASE), (ASSET_C, 10 * BASE)])
+            )])
+        );
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC methods serving the data collected by the pool analytics indexer.
+
+use super::{candle_key, read_aux, stats_key, tvl_key, Candle, PoolAnalyticsConfig, PoolStats};
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sc_client_api::AuxStore;
+use std::sync::Arc;
+use zeitgeist_primitives::types::{Asset, Balance, BlockNumber, MarketId, PoolId, SerdeWrapper};
+
+/// The maximum number of candles returned by a single call.
+const MAX_CANDLES: BlockNumber = 1_000;
+
+#[rpc(client, server)]
+pub trait PoolAnalyticsApi {
+    #[method(name = "poolAnalytics_getPoolStats")]
+    fn pool_stats(&self, pool_id: PoolId) -> RpcResult<Option<PoolStats>>;
+
+    /// Returns the candles of `asset` for all periods which intersect `[from, to]`. Periods
+    /// without any pool activity are omitted.
+    #[method(name = "poolAnalytics_getCandles")]
+    fn candles(
+        &self,
+        pool_id: PoolId,
+        asset: Asset<MarketId>,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> RpcResult<Vec<Candle>>;
+
+    /// Returns the TVL at the end of all periods which intersect `[from, to]`, keyed by the
+    /// number of the first block of the period. Periods without any pool activity are omitted.
+    #[method(name = "poolAnalytics_getTvl")]
+    fn tvl(
+        &self,
+        pool_id: PoolId,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> RpcResult<Vec<(BlockNumber, SerdeWrapper<Balance>)>>;
+}
+
+/// A struct that implements the [`PoolAnalyticsApi`].
+pub struct PoolAnalytics<C> {
+    client: Arc<C>,
+    config: PoolAnalyticsConfig,
+}
+
+impl<C> PoolAnalytics<C> {
+    /// Create a new `PoolAnalytics` with the given reference to the client.
+    pub fn new(client: Arc<C>, config: PoolAnalyticsConfig) -> Self {
+        PoolAnalytics { client, config }
+    }
+
+    fn candle_indices(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> RpcResult<(BlockNumber, BlockNumber)> {
+        let candle_period = self.config.candle_period.max(1);
+        let (first, last) = (from / candle_period, to / candle_period);
+        if last < first || last - first >= MAX_CANDLES {
+            return Err(CallError::Custom(ErrorObject::owned(
+                Error::InvalidRange.into(),
+                "Invalid block range.",
+                Some(format!("The range must cover between 1 and {} candles.", MAX_CANDLES)),
+            ))
+            .into());
+        }
+        Ok((first, last))
+    }
+}
+
+pub enum Error {
+    /// Reading from the database failed.
+    DatabaseError,
+    /// The requested range of blocks is empty or too large.
+    InvalidRange,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::DatabaseError => 1,
+            Error::InvalidRange => 2,
+        }
+    }
+}
+
+fn database_error(e: sp_blockchain::Error) -> CallError {
+    CallError::Custom(ErrorObject::owned(
+        Error::DatabaseError.into(),
+        "Unable to read pool analytics.",
+        Some(e.to_string()),
+    ))
+}
+
+impl<C> PoolAnalyticsApiServer for PoolAnalytics<C>
+where
+    C: AuxStore + Send + Sync + 'static,
+{
+    fn pool_stats(&self, pool_id: PoolId) -> RpcResult<Option<PoolStats>> {
+        Ok(read_aux(&*self.client, &stats_key(pool_id)).map_err(database_error)?)
+    }
+
+    fn candles(
+        &self,
+        pool_id: PoolId,
+        asset: Asset<MarketId>,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> RpcResult<Vec<Candle>> {
+        let (first, last) = self.candle_indices(from, to)?;
+        let mut candles = Vec::new();
+        for index in first..=last {
+            let key = candle_key(pool_id, asset, index);
+            if let Some(candle) = read_aux::<Candle>(&*self.client, &key).map_err(database_error)? {
+                candles.push(candle);
+            }
+        }
+        Ok(candles)
+    }
+
+    fn tvl(
+        &self,
+        pool_id: PoolId,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> RpcResult<Vec<(BlockNumber, SerdeWrapper<Balance>)>> {
+        let (first, last) = self.candle_indices(from, to)?;
+        let candle_period = self.config.candle_period.max(1);
+        let mut tvl = Vec::new();
+        for index in first..=last {
+            let key = tvl_key(pool_id, index);
+            if let Some(value) = read_aux::<Balance>(&*self.client, &key).map_err(database_error)? {
+                tvl.push((index.saturating_mul(candle_period), SerdeWrapper(value)));
+            }
+        }
+        Ok(tvl)
+    }
+}
@@ -16,7 +16,10 @@
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    service::{AdditionalRuntimeApiCollection, RuntimeApiCollection},
+    service::{
+        pool_analytics::{self, DecodePoolActivity, PoolAnalyticsConfig},
+        AdditionalRuntimeApiCollection, RuntimeApiCollection,
+    },
     KUSAMA_BLOCK_DURATION, SOFT_DEADLINE_PERCENT,
 };
 use cumulus_client_cli::CollatorOptions;
@@ -59,6 +62,7 @@ pub async fn new_full<RuntimeApi, Executor>(
     parachain_id: ParaId,
     polkadot_config: Configuration,
     hwbench: Option<sc_sysinfo::HwBench>,
+    pool_analytics: Option<PoolAnalyticsConfig>,
 ) -> sc_service::error::Result<(TaskManager, Arc<FullClient<RuntimeApi, Executor>>)>
 where
     RuntimeApi:
@@ -67,7 +71,7 @@ where
         + AdditionalRuntimeApiCollection<
             StateBackend = sc_client_api::StateBackendFor<FullBackend, Block>,
         >,
-    Executor: NativeExecutionDispatch + 'static,
+    Executor: NativeExecutionDispatch + DecodePoolActivity + 'static,
 {
     do_new_full(
         parachain_config,
@@ -144,6 +148,7 @@ where
             }))
         },
         hwbench,
+        pool_analytics,
     )
     .await
 }
@@ -241,6 +246,7 @@ async fn do_new_full<RuntimeApi, Executor, BIC>(
     id: polkadot_primitives::v2::Id,
     build_consensus: BIC,
     hwbench: Option<sc_sysinfo::HwBench>,
+    pool_analytics: Option<PoolAnalyticsConfig>,
 ) -> sc_service::error::Result<(TaskManager, Arc<FullClient<RuntimeApi, Executor>>)>
 where
     RuntimeApi:
@@ -249,7 +255,7 @@ where
         + AdditionalRuntimeApiCollection<
             StateBackend = sc_client_api::StateBackendFor<FullBackend, Block>,
         >,
-    Executor: NativeExecutionDispatch + 'static,
+    Executor: NativeExecutionDispatch + DecodePoolActivity + 'static,
     BIC: FnOnce(
         Arc<FullClient<RuntimeApi, Executor>>,
         Option<&Registry>,
@@ -308,8 +314,12 @@ where
         let pool = transaction_pool.clone();
 
         Box::new(move |deny_unsafe, _| {
-            let deps =
-                crate::rpc::FullDeps { client: client.clone(), pool: pool.clone(), deny_unsafe };
+            let deps = crate::rpc::FullDeps {
+                client: client.clone(),
+                pool: pool.clone(),
+                deny_unsafe,
+                pool_analytics,
+            };
 
             crate::rpc::create_full(deps).map_err(Into::into)
         })
@@ -328,6 +338,14 @@ where
         transaction_pool: transaction_pool.clone(),
     })?;
 
+    if let Some(pool_analytics_config) = pool_analytics {
+        task_manager.spawn_handle().spawn(
+            "pool-analytics",
+            None,
+            pool_analytics::run::<_, _, Executor>(client.clone(), pool_analytics_config),
+        );
+    }
+
     if let Some(hwbench) = hwbench {
         sc_sysinfo::print_hwbench(&hwbench);
 
@@ -17,7 +17,10 @@
 
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
-use crate::service::{AdditionalRuntimeApiCollection, RuntimeApiCollection};
+use crate::service::{
+    pool_analytics::{self, DecodePoolActivity, PoolAnalyticsConfig},
+    AdditionalRuntimeApiCollection, RuntimeApiCollection,
+};
 use sc_client_api::{BlockBackend, ExecutorProvider};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_executor::{NativeElseWasmExecutor, NativeExecutionDispatch};
@@ -41,6 +44,7 @@ type FullSelectChain = sc_consensus::LongestChain<FullBackend, Block>;
 pub fn new_full<RuntimeApi, Executor>(
     mut config: Configuration,
     disable_hardware_benchmarks: bool,
+    pool_analytics: Option<PoolAnalyticsConfig>,
 ) -> Result<TaskManager, ServiceError>
 where
     RuntimeApi:
@@ -49,7 +53,7 @@ where
         + AdditionalRuntimeApiCollection<
             StateBackend = sc_client_api::StateBackendFor<FullBackend, Block>,
         >,
-    Executor: NativeExecutionDispatch + 'static,
+    Executor: NativeExecutionDispatch + DecodePoolActivity + 'static,
 {
     let hwbench = if !disable_hardware_benchmarks {
         config.database.path().map(|database_path| {
@@ -127,8 +131,12 @@ where
         let pool = transaction_pool.clone();
 
         Box::new(move |deny_unsafe, _| {
-            let deps =
-                crate::rpc::FullDeps { client: client.clone(), pool: pool.clone(), deny_unsafe };
+            let deps = crate::rpc::FullDeps {
+                client: client.clone(),
+                pool: pool.clone(),
+                deny_unsafe,
+                pool_analytics,
+            };
 
             crate::rpc::create_full(deps).map_err(Into::into)
         })
@@ -147,6 +155,14 @@ where
         telemetry: telemetry.as_mut(),
     })?;
 
+    if let Some(pool_analytics_config) = pool_analytics {
+        task_manager.spawn_handle().spawn(
+            "pool-analytics",
+            None,
+            pool_analytics::run::<_, _, Executor>(client.clone(), pool_analytics_config),
+        );
+    }
+
     if let Some(hwbench) = hwbench {
         sc_sysinfo::print_hwbench(&hwbench);
 
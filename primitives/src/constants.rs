@@ -102,6 +102,8 @@ pub const PM_PALLET_ID: PalletId = PalletId(*b"zge/pred");
 pub const SD_PALLET_ID: PalletId = PalletId(*b"zge/sedp");
 
 // Swaps
+/// Pallet identifier, used to derive the account which pays the rewards of arbitrage keepers.
+pub const SWAPS_ARBITRAGE_BOUNTY_PALLET_ID: PalletId = PalletId(*b"zge/arbt");
 /// Max. assets in a swap pool.
 pub const MAX_ASSETS: u16 = MAX_CATEGORIES + 1;
 /// Pallet identifier, mainly used for named balance reserves.
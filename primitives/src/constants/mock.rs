@@ -7,34 +7,51 @@ use crate::{
 };
 use frame_support::{parameter_types, traits::LockIdentifier, PalletId};
 use orml_traits::parameter_type_with_key;
+use sp_runtime::Perbill;
 
 // Authorized
 parameter_types! {
+    pub const AuthorityAppealBond: Balance = 5 * BASE;
+    pub const AuthorityAppealPeriod: BlockNumber = 10;
+    pub const AuthorityReportPeriod: BlockNumber = 10;
     pub const AuthorizedPalletId: PalletId = PalletId(*b"zge/atzd");
     pub const CorrectionPeriod: BlockNumber = 4;
 }
 
 // Court
 parameter_types! {
+    pub const CourtAppealBond: Balance = 5 * BASE;
+    pub const CourtAppealDuration: u64 = BLOCKS_PER_DAY;
     pub const CourtCaseDuration: u64 = BLOCKS_PER_DAY;
     pub const CourtPalletId: PalletId = PalletId(*b"zge/cout");
-    pub const StakeWeight: u128 = 2 * BASE;
+    pub const CourtRevealDuration: u64 = BLOCKS_PER_DAY;
+    pub const CourtMaxAppeals: u32 = 3;
+    pub const CourtMaxCasesPerJuror: u32 = 2;
+    pub const CourtMaxDelegators: u32 = 2;
+    pub const CourtMaxJurors: u32 = 31;
+    pub const JurorUnbondingPeriod: u64 = BLOCKS_PER_DAY;
+    pub const MinJurorStake: Balance = 2 * BASE;
 }
 
 // Global disputes parameters
 parameter_types! {
+    pub const GlobalDisputeConvictionLockPeriod: BlockNumber = 10;
     pub const GlobalDisputeLockId: LockIdentifier = *b"zge/vote";
     pub const GlobalDisputesPalletId: PalletId = PalletId(*b"zge/gldp");
     pub const MaxGlobalDisputeVotes: u32 = 50;
     pub const MaxOwners: u32 = 10;
     pub const MinOutcomeVoteAmount: Balance = 10 * CENT;
     pub const RemoveKeysLimit: u32 = 250;
+    pub const VoterRewardShare: Perbill = Perbill::from_percent(50);
     pub const VotingOutcomeFee: Balance = 100 * CENT;
 }
 
 // Liquidity Mining parameters
 parameter_types! {
+    pub const IncentiveProgramClaimPeriod: BlockNumber = 10;
+    pub const IncentiveProgramDeposit: Balance = 5 * BASE;
     pub const LiquidityMiningPalletId: PalletId = PalletId(*b"zge/lymg");
+    pub const MaxIncentivePrograms: u32 = 4;
 }
 
 // Prediction Market parameters
@@ -70,6 +87,9 @@ parameter_types! {
 
 // Swaps parameters
 parameter_types! {
+    pub const ArbitrageBountyPalletId: PalletId = PalletId(*b"zge/arbt");
+    pub const ArbitrageKeeperShare: Perbill = Perbill::from_percent(10);
+    pub const CreatorSwapFeeShare: Perbill = Perbill::zero();
     pub const ExitFee: Balance = 3 * BASE / 1000; // 0.3%
     pub const MinAssets: u16 = 2;
     pub const MaxAssets: u16 = MaxCategories::get() + 1;
@@ -82,6 +102,7 @@ parameter_types! {
     pub const MinSubsidy: Balance = MinLiquidity::get();
     pub const MinSubsidyPerAccount: Balance = MinSubsidy::get();
     pub const MinWeight: Balance = BASE;
+    pub const ProtocolSwapFeeShare: Perbill = Perbill::zero();
     pub const SwapsPalletId: PalletId = PalletId(*b"zge/swap");
 }
 
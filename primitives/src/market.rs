@@ -15,7 +15,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{pool::ScoringRule, types::OutcomeReport};
+use crate::{
+    pool::ScoringRule,
+    types::{MultiHash, OutcomeReport},
+};
 use alloc::vec::Vec;
 use core::ops::{Range, RangeInclusive};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -273,6 +276,33 @@ pub struct Report<AccountId, BlockNumber> {
 pub struct AuthorityReport<BlockNumber> {
     pub resolve_at: BlockNumber,
     pub outcome: OutcomeReport,
+    /// A hash pointer to the reasoning or evidence the authority provided for its report.
+    pub evidence: Option<MultiHash>,
+}
+
+/// The authority which resolves a disputed market with the `Authorized` dispute mechanism.
+///
+/// * `AI`: Account id
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum MarketAuthority<AI> {
+    /// A regular or multisig account.
+    Account(AI),
+    /// A collective, identified by the id that the collective's origin is mapped to.
+    Collective(u8),
+}
+
+/// An appeal against the report of an authority.
+///
+/// * `AI`: Account id
+/// * `BA`: Balance type for bonds
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AuthorityAppeal<AI, BA> {
+    /// The account which filed the appeal.
+    pub appellant: AI,
+    /// The amount reserved from the appellant.
+    pub bond: BA,
+    /// The authority's outcome at the time of the appeal.
+    pub outcome: OutcomeReport,
 }
 
 /// Contains a market id and the market period.
@@ -30,6 +30,7 @@ use crate::types::CategoryIndex;
     PartialEq,
     PartialOrd,
 )]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub enum OutcomeReport {
     Categorical(CategoryIndex),
     Scalar(u128),
@@ -17,7 +17,7 @@
 
 use crate::{
     constants::MAX_ASSETS,
-    types::{Asset, PoolStatus},
+    types::{Asset, PoolStatus, SerdeWrapper},
 };
 use alloc::{collections::BTreeMap, vec::Vec};
 use parity_scale_codec::{Compact, Decode, Encode, MaxEncodedLen};
@@ -79,8 +79,74 @@ where
     }
 }
 
+/// A schedule which linearly moves the weights of a CPMM pool from the weights set at pool
+/// creation to `end_weights` between the blocks `start` and `end`.
+#[derive(TypeInfo, Clone, Encode, Eq, Decode, PartialEq, RuntimeDebug)]
+pub struct WeightSchedule<BlockNumber, MarketId>
+where
+    MarketId: MaxEncodedLen,
+{
+    pub start: BlockNumber,
+    pub end: BlockNumber,
+    pub end_weights: BTreeMap<Asset<MarketId>, u128>,
+}
+
+impl<BlockNumber, MarketId> MaxEncodedLen for WeightSchedule<BlockNumber, MarketId>
+where
+    BlockNumber: MaxEncodedLen,
+    MarketId: MaxEncodedLen,
+{
+    fn max_encoded_len() -> usize {
+        let b_tree_map_size = 1usize
+            .saturating_add(MAX_ASSETS.saturated_into::<usize>().saturating_mul(
+                <Asset<MarketId>>::max_encoded_len().saturating_add(u128::max_encoded_len()),
+            ))
+            .saturating_add(<Compact<u64>>::max_encoded_len());
+
+        BlockNumber::max_encoded_len().saturating_mul(2).saturating_add(b_tree_map_size)
+    }
+}
+
+/// Per-pool parameters of the bisection method used for on-chain arbitrage of CPMM pools.
+#[derive(TypeInfo, Clone, Copy, Encode, Eq, Decode, MaxEncodedLen, PartialEq, RuntimeDebug)]
+pub struct ArbitrageConfig {
+    /// The maximum allowed distance of the total spot price from `1` after arbitrage.
+    pub tolerance: u128,
+    /// The maximum number of iterations of the bisection method.
+    pub max_iterations: u16,
+}
+
 #[derive(TypeInfo, Clone, Copy, Encode, Eq, Decode, MaxEncodedLen, PartialEq, RuntimeDebug)]
 pub enum ScoringRule {
     CPMM,
     RikiddoSigmoidFeeMarketEma,
+    /// Logarithmic market scoring rule with the fixed liquidity parameter `b`, specified as
+    /// fixed-point number.
+    LMSR {
+        liquidity_parameter: u128,
+    },
+}
+
+/// The simulated result of a trade on a pool, as returned by the swaps runtime API.
+#[derive(
+    TypeInfo, Clone, Copy, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug,
+)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "std",
+    serde(bound(
+        serialize = "Balance: std::fmt::Display",
+        deserialize = "Balance: std::str::FromStr"
+    ))
+)]
+pub struct TradeQuote<Balance: MaxEncodedLen> {
+    /// The amount the trade is solved for (the amount out if the amount in is given and vice
+    /// versa).
+    pub amount: SerdeWrapper<Balance>,
+    /// The part of `amount` which is due to fees, i.e. the difference between `amount` and the
+    /// amount of the same trade without fees.
+    pub fee: SerdeWrapper<Balance>,
+    /// The spot price of the traded pair after the trade, including fees.
+    pub spot_price_after: SerdeWrapper<Balance>,
 }
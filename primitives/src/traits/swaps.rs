@@ -78,6 +78,25 @@ pub trait Swaps<AccountId> {
 
     fn open_pool(pool_id: PoolId) -> Result<Weight, DispatchError>;
 
+    /// Pool - Exit
+    ///
+    /// Retrieves a given set of assets from `pool_id` to `who`.
+    ///
+    /// # Arguments
+    ///
+    /// * `who`: Liquidity Provider (LP). The account whose assets should be received.
+    /// * `pool_id`: Unique pool identifier.
+    /// * `pool_amount`: The amount of LP shares of this pool being burned based on the
+    /// retrieved assets.
+    /// * `min_assets_out`: List of asset lower bounds. No asset should be lower than the
+    /// provided values.
+    fn pool_exit(
+        who: AccountId,
+        pool_id: PoolId,
+        pool_amount: Self::Balance,
+        min_assets_out: Vec<Self::Balance>,
+    ) -> Result<Weight, DispatchError>;
+
     /// Pool - Exit with exact pool amount
     ///
     /// Takes an asset from `pool_id` and transfers to `origin`. Differently from `pool_exit`,
@@ -99,6 +118,25 @@ pub trait Swaps<AccountId> {
         max_pool_amount: Self::Balance,
     ) -> Result<Weight, DispatchError>;
 
+    /// Pool - Join
+    ///
+    /// Joins a given set of assets provided from `who` to `pool_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `who`: Liquidity Provider (LP). The account whose assets should be transferred.
+    /// * `pool_id`: Unique pool identifier.
+    /// * `pool_amount`: The amount of LP shares for this pool that should be minted to the
+    /// provider.
+    /// * `max_assets_in`: List of asset upper bounds. No asset should be greater than the
+    /// provided values.
+    fn pool_join(
+        who: AccountId,
+        pool_id: PoolId,
+        pool_amount: Self::Balance,
+        max_assets_in: Vec<Self::Balance>,
+    ) -> Result<Weight, DispatchError>;
+
     /// Pool - Join with exact asset amount
     ///
     /// Joins an asset provided from `origin` to `pool_id`. Differently from `pool_join`,
@@ -123,6 +161,23 @@ pub trait Swaps<AccountId> {
     /// Returns the pool instance of a corresponding `pool_id`.
     fn pool(pool_id: PoolId) -> Result<Pool<Self::Balance, Self::MarketId>, DispatchError>;
 
+    /// Returns the account which holds the assets of the pool with id `pool_id`.
+    fn pool_account_id(pool_id: &PoolId) -> AccountId;
+
+    /// Returns the assets of the pool with id `pool_id` together with their balances in the pool
+    /// account, in the order of `Pool::assets`.
+    fn pool_balances(
+        pool_id: PoolId,
+    ) -> Result<Vec<(Asset<Self::MarketId>, Self::Balance)>, DispatchError>;
+
+    /// Returns the assets of the pool with id `pool_id` together with the amounts which
+    /// `pool_join` transfers into the pool in exchange for `pool_amount` pool shares, in the order
+    /// of `Pool::assets`.
+    fn pool_join_amounts(
+        pool_id: PoolId,
+        pool_amount: Self::Balance,
+    ) -> Result<Vec<(Asset<Self::MarketId>, Self::Balance)>, DispatchError>;
+
     /// If the market is categorical, removes everything that is not ZTG or winning assets from the
     /// selected pool. Additionally, it distributes the rewards to all pool share holders.
     ///
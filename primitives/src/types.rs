@@ -57,7 +57,7 @@ pub type CategoryIndex = u16;
 /// The multicodec encoding the hash algorithm uses only 1 byte,
 /// effecitvely limiting the number of available hash types.
 /// HashType (1B) + DigestSize (1B) + Hash (48B).
-#[derive(TypeInfo, Clone, Debug, Decode, Encode, Eq, PartialEq)]
+#[derive(TypeInfo, Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq)]
 pub enum MultiHash {
     Sha3_384([u8; 50]),
 }
@@ -104,7 +104,8 @@ pub struct IsCallable;
 impl Contains<Call> for IsCallable {
     fn contains(call: &Call) -> bool {
         use zeitgeist_primitives::types::{
-            MarketDisputeMechanism::Court, ScoringRule::RikiddoSigmoidFeeMarketEma,
+            MarketDisputeMechanism::Court,
+            ScoringRule::{RikiddoSigmoidFeeMarketEma, LMSR},
         };
         use zrml_prediction_markets::Call::{
             create_cpmm_market_and_deploy_assets, create_market, edit_market,
@@ -116,9 +117,11 @@ impl Contains<Call> for IsCallable {
             Call::LiquidityMining(_) => false,
             Call::PredictionMarkets(inner_call) => {
                 match inner_call {
-                    // Disable Rikiddo markets
+                    // Disable Rikiddo and LMSR markets
                     create_market { scoring_rule: RikiddoSigmoidFeeMarketEma, .. } => false,
                     edit_market { scoring_rule: RikiddoSigmoidFeeMarketEma, .. } => false,
+                    create_market { scoring_rule: LMSR { .. }, .. } => false,
+                    edit_market { scoring_rule: LMSR { .. }, .. } => false,
                     // Disable Court dispute resolution mechanism
                     create_market { dispute_mechanism: Court, .. } => false,
                     create_cpmm_market_and_deploy_assets { dispute_mechanism: Court, .. } => false,
@@ -133,13 +136,6 @@ impl Contains<Call> for IsCallable {
 
 decl_common_types!();
 
-#[cfg(feature = "with-global-disputes")]
-create_runtime_with_additional_pallets!(
-    GlobalDisputes: zrml_global_disputes::{Call, Event<T>, Pallet, Storage} = 59,
-    Sudo: pallet_sudo::{Call, Config<T>, Event<T>, Pallet, Storage} = 150,
-);
-
-#[cfg(not(feature = "with-global-disputes"))]
 create_runtime_with_additional_pallets!(
     Sudo: pallet_sudo::{Call, Config<T>, Event<T>, Pallet, Storage} = 150,
 );
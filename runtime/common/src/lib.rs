@@ -45,7 +45,7 @@ pub mod weights;
 macro_rules! decl_common_types {
     {} => {
         use sp_runtime::generic;
-        use frame_support::traits::{Currency, Imbalance, OnUnbalanced, NeverEnsureOrigin, TryStateSelect};
+        use frame_support::traits::{Currency, EnsureOrigin, Imbalance, OnUnbalanced, NeverEnsureOrigin, TryStateSelect};
 
         pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 
@@ -62,6 +62,9 @@ macro_rules! decl_common_types {
                 pallet_parachain_staking::migrations::MigrateAtStakeAutoCompound<Runtime>,
                 zrml_prediction_markets::migrations::UpdateMarketsForBaseAssetAndRecordBonds<Runtime>,
                 zrml_prediction_markets::migrations::AddFieldToAuthorityReport<Runtime>,
+                zrml_authorized::migrations::AddEvidenceToAuthorityReport<Runtime>,
+                zrml_court::migrations::ClearVotesAndRequestedJurors<Runtime>,
+                zrml_liquidity_mining::migrations::MigrateOwnedValuesToRewardsPerShare<Runtime>,
             ),
         >;
 
@@ -75,6 +78,9 @@ macro_rules! decl_common_types {
             (
                 zrml_prediction_markets::migrations::UpdateMarketsForBaseAssetAndRecordBonds<Runtime>,
                 zrml_prediction_markets::migrations::AddFieldToAuthorityReport<Runtime>,
+                zrml_authorized::migrations::AddEvidenceToAuthorityReport<Runtime>,
+                zrml_court::migrations::ClearVotesAndRequestedJurors<Runtime>,
+                zrml_liquidity_mining::migrations::MigrateOwnedValuesToRewardsPerShare<Runtime>,
             ),
         >;
 
@@ -159,6 +165,33 @@ macro_rules! decl_common_types {
             EnsureProportionAtLeast<AccountId, AdvisoryCommitteeInstance, 1, 1>,
         >;
 
+        // Collectives which can be assigned as market authority. At least 50% of the members of
+        // the collective have to agree. Advisory committee = 0, council = 1,
+        // technical committee = 2.
+        pub struct EnsureHalfAuthorityCollective;
+
+        impl EnsureOrigin<Origin> for EnsureHalfAuthorityCollective {
+            type Success = u8;
+
+            fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+                EnsureProportionAtLeast::<AccountId, AdvisoryCommitteeInstance, 1, 2>::try_origin(o)
+                    .map(|_| 0)
+                    .or_else(|o| {
+                        EnsureProportionAtLeast::<AccountId, CouncilInstance, 1, 2>::try_origin(o)
+                            .map(|_| 1)
+                    })
+                    .or_else(|o| {
+                        EnsureProportionAtLeast::<AccountId, TechnicalCommitteeInstance, 1, 2>::try_origin(o)
+                            .map(|_| 2)
+                    })
+            }
+
+            #[cfg(feature = "runtime-benchmarks")]
+            fn successful_origin() -> Origin {
+                EnsureProportionAtLeast::<AccountId, AdvisoryCommitteeInstance, 1, 2>::successful_origin()
+            }
+        }
+
         #[cfg(feature = "std")]
         pub fn native_version() -> NativeVersion {
             NativeVersion { runtime_version: VERSION, can_author_with: Default::default() }
@@ -172,9 +205,11 @@ macro_rules! decl_common_types {
             frame_support::PalletId: AccountIdConversion<AccountId>,
         {
             fn contains(ai: &AccountId) -> bool {
-                let mut pallets = vec![
+                let pallets = vec![
+                    ArbitrageBountyPalletId::get(),
                     AuthorizedPalletId::get(),
                     CourtPalletId::get(),
+                    GlobalDisputesPalletId::get(),
                     LiquidityMiningPalletId::get(),
                     PmPalletId::get(),
                     SimpleDisputesPalletId::get(),
@@ -182,9 +217,6 @@ macro_rules! decl_common_types {
                     TreasuryPalletId::get(),
                 ];
 
-                #[cfg(feature = "with-global-disputes")]
-                pallets.push(GlobalDisputesPalletId::get());
-
                 if let Some(pallet_id) = frame_support::PalletId::try_from_sub_account::<u128>(ai) {
                     return pallets.contains(&pallet_id.0);
                 }
@@ -220,6 +252,71 @@ macro_rules! decl_common_types {
             }
         }
 
+        // Randomness used for juror draws. On parachains, the subject is mixed with the VRF
+        // output of the block author, which is verified against the VRF key the author has
+        // registered in `AuthorMapping`. The VRF input of block `n` is the hash of block `n - 2`,
+        // so the output cannot be chosen by the author. Blocks without a valid VRF pre-digest
+        // have no randomness, so juror draws fail and have to be retried in a later block.
+        // Non-parachain (development) builds and benchmarks use `RandomnessCollectiveFlip`.
+        pub struct VrfRandomness;
+
+        impl VrfRandomness {
+            /// The VRF input the author of block `block_number` has to sign.
+            #[cfg(feature = "parachain")]
+            pub fn vrf_input(block_number: BlockNumber) -> Hash {
+                System::block_hash(block_number.saturating_sub(2))
+            }
+
+            #[cfg(all(feature = "parachain", not(feature = "runtime-benchmarks")))]
+            fn verified_vrf_output() -> Option<[u8; 32]> {
+                use nimbus_primitives::{NimbusId, NIMBUS_ENGINE_ID};
+                use parity_scale_codec::Decode;
+                use session_keys_primitives::{
+                    KeysLookup, PreDigest, VRF_ENGINE_ID, VRF_INOUT_CONTEXT,
+                };
+
+                let digest = System::digest();
+                let mut nimbus_id = None;
+                let mut pre_digest = None;
+                for (engine_id, mut data) in digest.logs.iter().filter_map(|log| log.as_pre_runtime())
+                {
+                    if engine_id == NIMBUS_ENGINE_ID {
+                        nimbus_id = NimbusId::decode(&mut data).ok();
+                    } else if engine_id == VRF_ENGINE_ID {
+                        pre_digest = PreDigest::decode(&mut data).ok();
+                    }
+                }
+
+                let vrf_id = AuthorMapping::lookup_keys(&nimbus_id?)?;
+                let PreDigest { vrf_output, vrf_proof } = pre_digest?;
+                let public_key = schnorrkel::PublicKey::from_bytes(vrf_id.as_ref()).ok()?;
+                let transcript =
+                    session_keys_primitives::make_transcript(Self::vrf_input(System::block_number()));
+                let (in_out, _) =
+                    public_key.vrf_verify(transcript, &vrf_output.0, &vrf_proof.0).ok()?;
+                Some(in_out.make_bytes(VRF_INOUT_CONTEXT))
+            }
+        }
+
+        impl frame_support::traits::Randomness<Option<Hash>, BlockNumber> for VrfRandomness {
+            #[cfg(all(feature = "parachain", not(feature = "runtime-benchmarks")))]
+            fn random(subject: &[u8]) -> (Option<Hash>, BlockNumber) {
+                use sp_runtime::traits::Hash as _;
+                let random_hash = Self::verified_vrf_output()
+                    .map(|vrf_output| BlakeTwo256::hash_of(&(vrf_output, subject)));
+                (random_hash, System::block_number())
+            }
+
+            #[cfg(any(not(feature = "parachain"), feature = "runtime-benchmarks"))]
+            fn random(subject: &[u8]) -> (Option<Hash>, BlockNumber) {
+                let (random_hash, block_number) =
+                    <RandomnessCollectiveFlip as frame_support::traits::Randomness<_, _>>::random(
+                        subject,
+                    );
+                (Some(random_hash), block_number)
+            }
+        }
+
         pub mod opaque {
             //! Opaque types. These are used by the CLI to instantiate machinery that don't need to know
             //! the specifics of the runtime. They can then be made to be agnostic over specific formats
@@ -310,6 +407,7 @@ macro_rules! create_runtime {
                 Swaps: zrml_swaps::{Call, Event<T>, Pallet, Storage} = 56,
                 PredictionMarkets: zrml_prediction_markets::{Call, Event<T>, Pallet, Storage} = 57,
                 Styx: zrml_styx::{Call, Event<T>, Pallet, Storage} = 58,
+                GlobalDisputes: zrml_global_disputes::{Call, Event<T>, Pallet, Storage} = 59,
 
                 $($additional_pallets)*
             }
@@ -926,31 +1024,48 @@ macro_rules! impl_config_traits {
         impl parachain_info::Config for Runtime {}
 
         impl zrml_authorized::Config for Runtime {
+            type AppealBond = AuthorityAppealBond;
+            type AppealPeriod = AuthorityAppealPeriod;
             type AuthorizedDisputeResolutionOrigin = EnsureRootOrHalfAdvisoryCommittee;
+            type CollectiveAuthorityOrigin = EnsureHalfAuthorityCollective;
             type CorrectionPeriod = CorrectionPeriod;
             type DisputeResolution = zrml_prediction_markets::Pallet<Runtime>;
             type Event = Event;
             type MarketCommons = MarketCommons;
             type PalletId = AuthorizedPalletId;
+            type ReportPeriod = AuthorityReportPeriod;
+            type Slash = Treasury;
             type WeightInfo = zrml_authorized::weights::WeightInfo<Runtime>;
         }
 
         impl zrml_court::Config for Runtime {
+            type AppealBond = CourtAppealBond;
+            type CourtAppealDuration = CourtAppealDuration;
             type CourtCaseDuration = CourtCaseDuration;
+            type CourtRevealDuration = CourtRevealDuration;
             type DisputeResolution = zrml_prediction_markets::Pallet<Runtime>;
             type Event = Event;
+            type JurorUnbondingPeriod = JurorUnbondingPeriod;
             type MarketCommons = MarketCommons;
+            type MaxAppeals = CourtMaxAppeals;
+            type MaxCasesPerJuror = CourtMaxCasesPerJuror;
+            type MaxDelegators = CourtMaxDelegators;
+            type MaxJurors = CourtMaxJurors;
+            type MinJurorStake = MinJurorStake;
             type PalletId = CourtPalletId;
-            type Random = RandomnessCollectiveFlip;
-            type StakeWeight = StakeWeight;
+            type Random = VrfRandomness;
             type TreasuryPalletId = TreasuryPalletId;
             type WeightInfo = zrml_court::weights::WeightInfo<Runtime>;
         }
 
         impl zrml_liquidity_mining::Config for Runtime {
+            type AssetManager = AssetManager;
             type Event = Event;
+            type IncentiveProgramClaimPeriod = IncentiveProgramClaimPeriod;
+            type IncentiveProgramDeposit = IncentiveProgramDeposit;
             type MarketCommons = MarketCommons;
             type MarketId = MarketId;
+            type MaxIncentivePrograms = MaxIncentivePrograms;
             type PalletId = LiquidityMiningPalletId;
             type WeightInfo = zrml_liquidity_mining::weights::WeightInfo<Runtime>;
         }
@@ -974,7 +1089,7 @@ macro_rules! impl_config_traits {
 
             fn add_shares(_: Self::AccountId, _: Self::MarketId, _: Self::Balance) {}
 
-            fn distribute_market_incentives(
+            fn close_market_incentives(
                 _: &Self::MarketId,
             ) -> frame_support::pallet_prelude::DispatchResult {
                 Ok(())
@@ -997,9 +1112,7 @@ macro_rules! impl_config_traits {
             type DisputeBond = DisputeBond;
             type DisputeFactor = DisputeFactor;
             type Event = Event;
-            #[cfg(feature = "with-global-disputes")]
             type GlobalDisputes = GlobalDisputes;
-            #[cfg(feature = "with-global-disputes")]
             type GlobalDisputePeriod = GlobalDisputePeriod;
             // LiquidityMining is currently unstable.
             // NoopLiquidityMining will be applied only to mainnet once runtimes are separated.
@@ -1058,8 +1171,8 @@ macro_rules! impl_config_traits {
             type PalletId = SimpleDisputesPalletId;
         }
 
-        #[cfg(feature = "with-global-disputes")]
         impl zrml_global_disputes::Config for Runtime {
+            type ConvictionLockPeriod = GlobalDisputeConvictionLockPeriod;
             type Currency = Balances;
             type Event = Event;
             type GlobalDisputeLockId = GlobalDisputeLockId;
@@ -1069,11 +1182,17 @@ macro_rules! impl_config_traits {
             type MaxOwners = MaxOwners;
             type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
             type RemoveKeysLimit = RemoveKeysLimit;
+            type SetEnabledOrigin = EnsureRootOrTwoThirdsCouncil;
+            type TallyMode = GlobalDisputeTallyMode;
+            type VoterRewardShare = VoterRewardShare;
             type VotingOutcomeFee = VotingOutcomeFee;
             type WeightInfo = zrml_global_disputes::weights::WeightInfo<Runtime>;
         }
 
         impl zrml_swaps::Config for Runtime {
+            type ArbitrageBountyPalletId = ArbitrageBountyPalletId;
+            type ArbitrageKeeperShare = ArbitrageKeeperShare;
+            type CreatorSwapFeeShare = CreatorSwapFeeShare;
             type Event = Event;
             type ExitFee = ExitFee;
             type FixedTypeU = FixedU128<U33>;
@@ -1095,7 +1214,9 @@ macro_rules! impl_config_traits {
             type MinSubsidyPerAccount = MinSubsidyPerAccount;
             type MinWeight = MinWeight;
             type PalletId = SwapsPalletId;
+            type ProtocolSwapFeeShare = ProtocolSwapFeeShare;
             type RikiddoSigmoidFeeMarketEma = RikiddoSigmoidFeeMarketEma;
+            type TreasuryPalletId = TreasuryPalletId;
             type AssetManager = AssetManager;
             type WeightInfo = zrml_swaps::weights::WeightInfo<Runtime>;
         }
@@ -1207,7 +1328,6 @@ macro_rules! create_runtime_api {
                     list_benchmark!(list, extra, zrml_swaps, Swaps);
                     list_benchmark!(list, extra, zrml_authorized, Authorized);
                     list_benchmark!(list, extra, zrml_court, Court);
-                    #[cfg(feature = "with-global-disputes")]
                     list_benchmark!(list, extra, zrml_global_disputes, GlobalDisputes);
                     #[cfg(not(feature = "parachain"))]
                     list_benchmark!(list, extra, zrml_prediction_markets, PredictionMarkets);
@@ -1285,7 +1405,6 @@ macro_rules! create_runtime_api {
                     add_benchmark!(params, batches, zrml_swaps, Swaps);
                     add_benchmark!(params, batches, zrml_authorized, Authorized);
                     add_benchmark!(params, batches, zrml_court, Court);
-                    #[cfg(feature = "with-global-disputes")]
                     add_benchmark!(params, batches, zrml_global_disputes, GlobalDisputes);
                     #[cfg(not(feature = "parachain"))]
                     add_benchmark!(params, batches, zrml_prediction_markets, PredictionMarkets);
@@ -1355,7 +1474,8 @@ macro_rules! create_runtime_api {
             #[cfg(feature = "parachain")]
             impl session_keys_primitives::VrfApi<Block> for Runtime {
                 fn get_last_vrf_output() -> Option<<Block as BlockT>::Hash> {
-                    None
+                    // Called on the state of the parent of the block that is being authored.
+                    Some(VrfRandomness::vrf_input(System::block_number().saturating_add(1)))
                 }
                 fn vrf_key_lookup(
                     nimbus_id: nimbus_primitives::NimbusId
@@ -1477,6 +1597,29 @@ macro_rules! create_runtime_api {
                 }
             }
 
+            impl zrml_global_disputes_runtime_api::GlobalDisputesApi<Block, AccountId, Balance, MarketId>
+            for Runtime
+            {
+                fn winner(
+                    market_id: MarketId,
+                ) -> Option<zrml_global_disputes::types::WinnerInfo<Balance, Vec<AccountId>>> {
+                    GlobalDisputes::winner(&market_id)
+                }
+
+                fn outcomes(
+                    market_id: MarketId,
+                ) -> Vec<(OutcomeReport, zrml_global_disputes::types::OutcomeInfo<Balance, Vec<AccountId>>)> {
+                    GlobalDisputes::outcomes(&market_id)
+                }
+
+                fn voter_locks(
+                    voter: AccountId,
+                    market_id: MarketId,
+                ) -> Option<zrml_global_disputes::types::VoterLockInfo<Balance>> {
+                    GlobalDisputes::voter_locks(&voter, &market_id)
+                }
+            }
+
             impl zrml_swaps_runtime_api::SwapsApi<Block, PoolId, AccountId, Balance, MarketId>
             for Runtime
             {
@@ -1493,6 +1636,73 @@ macro_rules! create_runtime_api {
                     Swaps::pool_account_id(pool_id)
                 }
 
+                fn get_all_spot_prices(
+                    pool_id: &PoolId,
+                    with_fees: bool,
+                ) -> Option<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>> {
+                    Swaps::get_all_spot_prices(pool_id, with_fees)
+                        .ok()
+                        .map(|prices| prices.into_iter().map(|(asset, price)| (asset, SerdeWrapper(price))).collect())
+                }
+
+                fn get_pool_balances(
+                    pool_id: &PoolId,
+                ) -> Option<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>> {
+                    Swaps::get_pool_balances(pool_id)
+                        .ok()
+                        .map(|balances| balances.into_iter().map(|(asset, balance)| (asset, SerdeWrapper(balance))).collect())
+                }
+
+                fn quote_swap_exact_amount_in(
+                    pool_id: &PoolId,
+                    asset_in: &Asset<MarketId>,
+                    asset_amount_in: Balance,
+                    asset_out: &Asset<MarketId>,
+                ) -> Result<TradeQuote<Balance>, sp_runtime::DispatchError> {
+                    Swaps::quote_swap_exact_amount_in(*pool_id, *asset_in, asset_amount_in, *asset_out)
+                }
+
+                fn quote_swap_exact_amount_out(
+                    pool_id: &PoolId,
+                    asset_in: &Asset<MarketId>,
+                    asset_out: &Asset<MarketId>,
+                    asset_amount_out: Balance,
+                ) -> Result<TradeQuote<Balance>, sp_runtime::DispatchError> {
+                    Swaps::quote_swap_exact_amount_out(*pool_id, *asset_in, *asset_out, asset_amount_out)
+                }
+
+                fn quote_pool_join_with_exact_asset_amount(
+                    pool_id: &PoolId,
+                    asset_in: &Asset<MarketId>,
+                    asset_amount: Balance,
+                ) -> Result<TradeQuote<Balance>, sp_runtime::DispatchError> {
+                    Swaps::quote_pool_join_with_exact_asset_amount(*pool_id, *asset_in, asset_amount)
+                }
+
+                fn quote_pool_join_with_exact_pool_amount(
+                    pool_id: &PoolId,
+                    asset: &Asset<MarketId>,
+                    pool_amount: Balance,
+                ) -> Result<TradeQuote<Balance>, sp_runtime::DispatchError> {
+                    Swaps::quote_pool_join_with_exact_pool_amount(*pool_id, *asset, pool_amount)
+                }
+
+                fn quote_pool_exit_with_exact_asset_amount(
+                    pool_id: &PoolId,
+                    asset: &Asset<MarketId>,
+                    asset_amount: Balance,
+                ) -> Result<TradeQuote<Balance>, sp_runtime::DispatchError> {
+                    Swaps::quote_pool_exit_with_exact_asset_amount(*pool_id, *asset, asset_amount)
+                }
+
+                fn quote_pool_exit_with_exact_pool_amount(
+                    pool_id: &PoolId,
+                    asset: &Asset<MarketId>,
+                    pool_amount: Balance,
+                ) -> Result<TradeQuote<Balance>, sp_runtime::DispatchError> {
+                    Swaps::quote_pool_exit_with_exact_pool_amount(*pool_id, *asset, pool_amount)
+                }
+
                 fn pool_shares_id(pool_id: PoolId) -> Asset<SerdeWrapper<MarketId>> {
                     Asset::PoolShare(SerdeWrapper(pool_id))
                 }
@@ -1859,6 +2069,7 @@ macro_rules! create_common_tests {
                 use frame_support::PalletId;
                 use test_case::test_case;
 
+                #[test_case(ArbitrageBountyPalletId::get(); "arbitrage_bounty")]
                 #[test_case(AuthorizedPalletId::get(); "authorized")]
                 #[test_case(CourtPalletId::get(); "court")]
                 #[test_case(LiquidityMiningPalletId::get(); "liquidity_mining")]
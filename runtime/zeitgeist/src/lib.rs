@@ -115,7 +115,7 @@ impl Contains<Call> for IsCallable {
 
         use zeitgeist_primitives::types::{
             MarketDisputeMechanism::{Court, SimpleDisputes},
-            ScoringRule::RikiddoSigmoidFeeMarketEma,
+            ScoringRule::{RikiddoSigmoidFeeMarketEma, LMSR},
         };
         use zrml_prediction_markets::Call::{
             create_cpmm_market_and_deploy_assets, create_market, edit_market,
@@ -149,9 +149,11 @@ impl Contains<Call> for IsCallable {
             Call::LiquidityMining(_) => false,
             Call::PredictionMarkets(inner_call) => {
                 match inner_call {
-                    // Disable Rikiddo markets
+                    // Disable Rikiddo and LMSR markets
                     create_market { scoring_rule: RikiddoSigmoidFeeMarketEma, .. } => false,
                     edit_market { scoring_rule: RikiddoSigmoidFeeMarketEma, .. } => false,
+                    create_market { scoring_rule: LMSR { .. }, .. } => false,
+                    edit_market { scoring_rule: LMSR { .. }, .. } => false,
                     // Disable Court & SimpleDisputes dispute resolution mechanism
                     create_market { dispute_mechanism: Court | SimpleDisputes, .. } => false,
                     edit_market { dispute_mechanism: Court | SimpleDisputes, .. } => false,
@@ -193,12 +195,6 @@ impl Contains<Call> for IsCallable {
 
 decl_common_types!();
 
-#[cfg(feature = "with-global-disputes")]
-create_runtime_with_additional_pallets!(
-    GlobalDisputes: zrml_global_disputes::{Call, Event<T>, Pallet, Storage} = 59,
-);
-
-#[cfg(not(feature = "with-global-disputes"))]
 create_runtime_with_additional_pallets!();
 
 impl_config_traits!();
@@ -40,8 +40,8 @@ use sp_runtime::{
 use sp_version::RuntimeVersion;
 use zeitgeist_primitives::{constants::*, types::*};
 
-#[cfg(feature = "with-global-disputes")]
 use frame_support::traits::LockIdentifier;
+use zrml_global_disputes::types::TallyMode;
 
 pub(crate) const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(10);
 pub(crate) const MAXIMUM_BLOCK_WEIGHT: Weight =
@@ -52,6 +52,12 @@ pub(crate) const FEES_AND_TIPS_BURN_PERCENTAGE: u32 = 0;
 
 parameter_types! {
     // Authorized
+    /// The amount which has to be bonded to appeal against an authority's report.
+    pub const AuthorityAppealBond: Balance = 2_000 * BASE;
+    /// The period after an appeal, in which the dispute can be escalated.
+    pub const AuthorityAppealPeriod: BlockNumber = 3 * BLOCKS_PER_DAY;
+    /// The period after a dispute, in which the market's authority has to report.
+    pub const AuthorityReportPeriod: BlockNumber = 7 * BLOCKS_PER_DAY;
     pub const AuthorizedPalletId: PalletId = AUTHORIZED_PALLET_ID;
     pub const CorrectionPeriod: BlockNumber = BLOCKS_PER_DAY;
 
@@ -77,13 +83,28 @@ parameter_types! {
     pub const TechnicalCommitteeMotionDuration: BlockNumber = 7 * BLOCKS_PER_DAY;
 
     // Court
+    /// The bond required to appeal the first round of a court case. Doubles with every round.
+    pub const CourtAppealBond: Balance = 2_000 * BASE;
+    /// Duration in which the outcome of a round of a court case can be appealed.
+    pub const CourtAppealDuration: u64 = BLOCKS_PER_DAY;
     /// Duration of a single court case.
     pub const CourtCaseDuration: u64 = BLOCKS_PER_DAY;
     /// Pallet identifier, mainly used for named balance reserves. DO NOT CHANGE.
     pub const CourtPalletId: PalletId = COURT_PALLET_ID;
-    /// This value is multiplied by the current number of jurors to determine the stake
-    /// the juror has to pay.
-    pub const StakeWeight: u128 = 2 * BASE;
+    /// Duration in which jurors have to reveal their votes after the end of a court case.
+    pub const CourtRevealDuration: u64 = BLOCKS_PER_DAY;
+    /// The maximum number of appeals of a court case.
+    pub const CourtMaxAppeals: u32 = 4;
+    /// The maximum number of unsettled court cases a juror can be selected for.
+    pub const CourtMaxCasesPerJuror: u32 = 16;
+    /// The maximum number of delegators of a juror.
+    pub const CourtMaxDelegators: u32 = 32;
+    /// The maximum number of jurors of a round of a court case (the last possible round).
+    pub const CourtMaxJurors: u32 = 63;
+    /// Duration a juror has to wait after announcing to leave the court.
+    pub const JurorUnbondingPeriod: u64 = 7 * BLOCKS_PER_DAY;
+    /// The minimum stake required to join the court as a juror.
+    pub const MinJurorStake: Balance = 1_000 * BASE;
 
     // Democracy
     /// How often (in blocks) new public referenda are launched.
@@ -132,6 +153,13 @@ parameter_types! {
     pub const SubAccountDeposit: Balance = 20 * BASE;
 
     // Liquidity Mining parameters
+    /// The number of blocks after the end of an incentive program in which its incentives can
+    /// still be claimed.
+    pub const IncentiveProgramClaimPeriod: BlockNumber = 30 * BLOCKS_PER_DAY;
+    /// The amount that the sponsor of an incentive program reserves until the program is closed.
+    pub const IncentiveProgramDeposit: Balance = 100 * BASE;
+    /// Maximum number of incentive programs per market.
+    pub const MaxIncentivePrograms: u32 = 8;
     /// Pallet identifier, mainly used for named balance reserves. DO NOT CHANGE.
     pub const LiquidityMiningPalletId: PalletId = LM_PALLET_ID;
 
@@ -222,6 +250,14 @@ parameter_types! {
     pub const SimpleDisputesPalletId: PalletId = SD_PALLET_ID;
 
     // Swaps parameters
+    /// Pallet identifier, used to derive the account which pays the rewards of arbitrage
+    /// keepers. DO NOT CHANGE.
+    pub const ArbitrageBountyPalletId: PalletId = SWAPS_ARBITRAGE_BOUNTY_PALLET_ID;
+    /// The share of the estimated profit of arbitrage which is paid to the keeper who executes
+    /// it.
+    pub const ArbitrageKeeperShare: Perbill = Perbill::from_percent(10);
+    /// The share of the swap fees of CPMM pools which is paid to the market creator.
+    pub const CreatorSwapFeeShare: Perbill = Perbill::zero();
     /// A precentage from the withdrawal amount a liquidity provider wants to withdraw
     /// from a pool before the pool is closed.
     pub const ExitFee: Balance = BASE / 10_000; // 0.01%
@@ -247,6 +283,8 @@ parameter_types! {
     pub const MinSubsidyPerAccount: Balance = MinSubsidy::get();
     /// Minimum weight a single asset can have.
     pub const MinWeight: Balance = BASE;
+    /// The share of the swap fees of CPMM pools which is paid to the treasury.
+    pub const ProtocolSwapFeeShare: Perbill = Perbill::zero();
     /// Pallet identifier, mainly used for named balance reserves. DO NOT CHANGE.
     pub const SwapsPalletId: PalletId = SWAPS_PALLET_ID;
 
@@ -350,9 +388,10 @@ parameter_types! {
     pub const MinVestedTransfer: Balance = ExistentialDeposit::get();
 }
 
-#[cfg(feature = "with-global-disputes")]
 parameter_types! {
     // Global Disputes
+    /// The number of blocks of one lock period for votes with conviction.
+    pub const GlobalDisputeConvictionLockPeriod: BlockNumber = GlobalDisputePeriod::get();
     /// Vote lock identifier, mainly used for the LockableCurrency on the native token.
     pub const GlobalDisputeLockId: LockIdentifier = GLOBAL_DISPUTES_LOCK_ID;
     /// Pallet identifier
@@ -365,10 +404,14 @@ parameter_types! {
     pub const MaxGlobalDisputeVotes: u32 = 50;
     /// The minimum required amount to vote on an outcome.
     pub const MinOutcomeVoteAmount: Balance = 10 * BASE;
+    /// The share of the collected fees, which is distributed among the voters of the winning outcome.
+    pub const VoterRewardShare: Perbill = Perbill::from_percent(50);
     /// The fee required to add a voting outcome.
     pub const VotingOutcomeFee: Balance = 200 * BASE;
     /// The remove limit for the Outcomes storage double map.
     pub const RemoveKeysLimit: u32 = 250;
+    /// The way the votes on voting outcomes are tallied.
+    pub const GlobalDisputeTallyMode: TallyMode = TallyMode::Linear;
 }
 
 parameter_type_with_key! {
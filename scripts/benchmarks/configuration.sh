@@ -41,4 +41,4 @@ else
 fi
 export EXECUTION="${EXECUTION:-wasm}"
 export ADDITIONAL="${ADDITIONAL:-}"
-export ADDITIONAL_FEATURES="${ADDITIONAL_FEATURES:-with-global-disputes}"
+export ADDITIONAL_FEATURES="${ADDITIONAL_FEATURES:-}"
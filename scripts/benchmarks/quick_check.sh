@@ -25,7 +25,5 @@ export PROFILE=release
 export PROFILE_DIR=release
 export ADDITIONAL=--detailed-log-output
 export EXECUTION=native
-# TODO(#848) Delete this, when global disputes is on main-net
-export ADDITIONAL_FEATURES=with-global-disputes
 
 source ./scripts/benchmarks/run_benchmarks.sh
@@ -20,8 +20,7 @@ no_runtime_benchmarks=('court' 'market-commons' 'rikiddo')
 
 cargo test --package zeitgeist-runtime --lib -- --nocapture
 
-# TODO(#848): Delete when feature "with-global-dispute" is removed
-cargo test -p zrml-prediction-markets --features with-global-disputes,parachain
+cargo test -p zrml-prediction-markets --features parachain
 
 
 for package in zrml/*
@@ -15,6 +15,14 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use zeitgeist_primitives::traits::DisputeApi;
+use frame_support::dispatch::DispatchResult;
+use zeitgeist_primitives::{traits::DisputeApi, types::OutcomeReport};
 
-pub trait AuthorizedPalletApi: DisputeApi {}
+pub trait AuthorizedPalletApi: DisputeApi {
+    /// Clean up after the market was resolved and settle an outstanding appeal.
+    ///
+    /// **Should** be called for every resolved market with the `Authorized` dispute mechanism,
+    /// regardless of whether the outcome was determined by the authority or by another
+    /// mechanism (for example a global dispute).
+    fn on_resolved(market_id: &Self::MarketId, resolved_outcome: &OutcomeReport) -> DispatchResult;
+}
@@ -23,16 +23,20 @@
 
 #[cfg(test)]
 use crate::Pallet as Authorized;
-use crate::{market_mock, AuthorizedOutcomeReports, Call, Config, Pallet};
-use frame_benchmarking::benchmarks;
+use crate::{
+    market_mock, Appeals, AuthorizedOutcomeReports, BalanceOf, Call, Config, CurrencyOf,
+    MarketAuthorities, Pallet,
+};
+use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_support::{
     dispatch::UnfilteredDispatchable,
-    traits::{EnsureOrigin, Get},
+    traits::{Currency, EnsureOrigin, Get},
 };
-use sp_runtime::traits::Saturating;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Saturating};
 use zeitgeist_primitives::{
     traits::DisputeResolutionApi,
-    types::{AuthorityReport, OutcomeReport},
+    types::{AuthorityReport, MarketAuthority, MarketStatus, OutcomeReport},
 };
 use zrml_market_commons::MarketCommonsPalletApi;
 
@@ -56,13 +60,15 @@ benchmarks! {
         let call = Call::<T>::authorize_market_outcome {
             market_id,
             outcome: OutcomeReport::Scalar(1),
+            evidence: None,
         };
     }: {
         call.dispatch_bypass_filter(origin)?
     } verify {
         let report = AuthorityReport {
             resolve_at: correction_period_ends_at,
-            outcome: OutcomeReport::Scalar(1)
+            outcome: OutcomeReport::Scalar(1),
+            evidence: None,
         };
         assert_eq!(AuthorizedOutcomeReports::<T>::get(market_id).unwrap(), report);
     }
@@ -78,7 +84,8 @@ benchmarks! {
         let now = frame_system::Pallet::<T>::block_number();
         let resolve_at = now.saturating_add(T::CorrectionPeriod::get());
 
-        let report = AuthorityReport { resolve_at, outcome: OutcomeReport::Scalar(0) };
+        let report =
+            AuthorityReport { resolve_at, outcome: OutcomeReport::Scalar(0), evidence: None };
         AuthorizedOutcomeReports::<T>::insert(market_id, report);
 
         let now = frame_system::Pallet::<T>::block_number();
@@ -87,14 +94,60 @@ benchmarks! {
         let call = Call::<T>::authorize_market_outcome {
             market_id,
             outcome: OutcomeReport::Scalar(1),
+            evidence: None,
         };
     }: {
         call.dispatch_bypass_filter(origin)?
     } verify {
-        let report = AuthorityReport { resolve_at, outcome: OutcomeReport::Scalar(1) };
+        let report =
+            AuthorityReport { resolve_at, outcome: OutcomeReport::Scalar(1), evidence: None };
         assert_eq!(AuthorizedOutcomeReports::<T>::get(market_id).unwrap(), report);
     }
 
+    set_market_authority {
+        let market_id = 0u32.into();
+        let mut market = market_mock::<T>();
+        market.status = MarketStatus::Active;
+        let creator = market.creator.clone();
+        T::MarketCommons::push_market(market).unwrap();
+        let authority = MarketAuthority::Account(whitelisted_caller());
+
+        let call = Call::<T>::set_market_authority { market_id, authority: authority.clone() };
+    }: {
+        call.dispatch_bypass_filter(RawOrigin::Signed(creator).into())?
+    } verify {
+        assert_eq!(MarketAuthorities::<T>::get(market_id), Some(authority));
+    }
+
+    appeal {
+        let m in 1..63;
+
+        let market_id = 0u32.into();
+        let market = market_mock::<T>();
+        T::MarketCommons::push_market(market).unwrap();
+
+        frame_system::Pallet::<T>::set_block_number(42u32.into());
+        let now = frame_system::Pallet::<T>::block_number();
+        let resolve_at = now.saturating_add(T::CorrectionPeriod::get());
+        let report = AuthorityReport { resolve_at, outcome: OutcomeReport::Scalar(0), evidence: None };
+        AuthorizedOutcomeReports::<T>::insert(market_id, report);
+        T::DisputeResolution::add_auto_resolve(&market_id, resolve_at).unwrap();
+
+        let appeal_ends_at = now.saturating_add(T::AppealPeriod::get());
+        for _ in 1..=m {
+            let id = T::MarketCommons::push_market(market_mock::<T>()).unwrap();
+            T::DisputeResolution::add_auto_resolve(&id, appeal_ends_at).unwrap();
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        let _ = CurrencyOf::<T>::deposit_creating(&caller, BalanceOf::<T>::max_value());
+        let call = Call::<T>::appeal { market_id };
+    }: {
+        call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())?
+    } verify {
+        assert_eq!(Appeals::<T>::get(market_id).unwrap().appellant, caller);
+    }
+
     impl_benchmark_test_suite!(
         Authorized,
         crate::mock::ExtBuilder::default().build(),
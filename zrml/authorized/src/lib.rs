@@ -39,22 +39,24 @@ mod pallet {
         dispatch::{DispatchResult, DispatchResultWithPostInfo},
         ensure,
         pallet_prelude::{ConstU32, EnsureOrigin, OptionQuery, StorageMap},
-        traits::{Currency, Get, Hooks, IsType, StorageVersion},
+        traits::{
+            Currency, Get, Hooks, IsType, NamedReservableCurrency, OnUnbalanced, StorageVersion,
+        },
         PalletId, Twox64Concat,
     };
-    use frame_system::pallet_prelude::OriginFor;
+    use frame_system::{ensure_signed, pallet_prelude::OriginFor};
     use sp_runtime::{traits::Saturating, DispatchError};
     use zeitgeist_primitives::{
         traits::{DisputeApi, DisputeResolutionApi},
         types::{
-            Asset, AuthorityReport, Market, MarketDispute, MarketDisputeMechanism, MarketStatus,
-            OutcomeReport,
+            Asset, AuthorityAppeal, AuthorityReport, Market, MarketAuthority, MarketDispute,
+            MarketDisputeMechanism, MarketStatus, MultiHash, OutcomeReport,
         },
     };
     use zrml_market_commons::MarketCommonsPalletApi;
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
     pub(crate) type BalanceOf<T> =
         <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -63,6 +65,11 @@ mod pallet {
     pub(crate) type MarketIdOf<T> =
         <<T as Config>::MarketCommons as MarketCommonsPalletApi>::MarketId;
     pub(crate) type MomentOf<T> = <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Moment;
+    pub(crate) type NegativeImbalanceOf<T> =
+        <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+    pub(crate) type AuthorityAppealOf<T> =
+        AuthorityAppeal<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+    pub(crate) type MarketAuthorityOf<T> = MarketAuthority<<T as frame_system::Config>::AccountId>;
     pub type CacheSize = ConstU32<64>;
     pub(crate) type MarketOf<T> = Market<
         <T as frame_system::Config>::AccountId,
@@ -75,6 +82,17 @@ mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Overwrites already provided outcomes for the same market and account.
+        ///
+        /// If the market has an authority assigned (see [`Pallet::set_market_authority`]), only
+        /// that account or collective may report, and only within `ReportPeriod` after the
+        /// dispute. Otherwise, the report must come from `AuthorizedDisputeResolutionOrigin`. The
+        /// report can no longer be changed once it was appealed.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The id of the market.
+        /// - `outcome`: The outcome reported by the authority.
+        /// - `evidence`: An optional hash pointer to the reasoning or evidence for the outcome.
         #[frame_support::transactional]
         #[pallet::weight(
             T::WeightInfo::authorize_market_outcome_first_report(CacheSize::get()).max(
@@ -85,29 +103,42 @@ mod pallet {
             origin: OriginFor<T>,
             market_id: MarketIdOf<T>,
             outcome: OutcomeReport,
+            evidence: Option<MultiHash>,
         ) -> DispatchResultWithPostInfo {
-            T::AuthorizedDisputeResolutionOrigin::ensure_origin(origin)?;
             let market = T::MarketCommons::market(&market_id)?;
+            Self::ensure_market_authority(origin, &market_id)?;
             ensure!(market.status == MarketStatus::Disputed, Error::<T>::MarketIsNotDisputed);
             ensure!(market.matches_outcome_report(&outcome), Error::<T>::OutcomeMismatch);
             ensure!(
                 market.dispute_mechanism == MarketDisputeMechanism::Authorized,
                 Error::<T>::MarketDoesNotHaveDisputeMechanismAuthorized
             );
+            ensure!(!Appeals::<T>::contains_key(market_id), Error::<T>::ReportAppealed);
 
             let now = frame_system::Pallet::<T>::block_number();
+            let disputes = T::DisputeResolution::get_disputes(&market_id);
+            if let Some(report_deadline) = Self::report_deadline(&market_id, &disputes) {
+                ensure!(now < report_deadline, Error::<T>::ReportPeriodExpired);
+            }
 
             let report_opt = AuthorizedOutcomeReports::<T>::get(market_id);
             let (report, ids_len) = match &report_opt {
-                Some(report) => (AuthorityReport { resolve_at: report.resolve_at, outcome }, 0u32),
+                Some(report) => {
+                    (AuthorityReport { resolve_at: report.resolve_at, outcome, evidence }, 0u32)
+                }
                 None => {
                     let resolve_at = now.saturating_add(T::CorrectionPeriod::get());
                     let ids_len = T::DisputeResolution::add_auto_resolve(&market_id, resolve_at)?;
-                    (AuthorityReport { resolve_at, outcome }, ids_len)
+                    (AuthorityReport { resolve_at, outcome, evidence }, ids_len)
                 }
             };
 
-            AuthorizedOutcomeReports::<T>::insert(market_id, report);
+            AuthorizedOutcomeReports::<T>::insert(market_id, report.clone());
+            Self::deposit_event(Event::AuthorityReported {
+                market_id,
+                outcome: report.outcome,
+                evidence: report.evidence,
+            });
 
             if report_opt.is_none() {
                 Ok(Some(T::WeightInfo::authorize_market_outcome_first_report(ids_len)).into())
@@ -115,10 +146,112 @@ mod pallet {
                 Ok(Some(T::WeightInfo::authorize_market_outcome_existing_report()).into())
             }
         }
+
+        /// Assigns the authority which resolves the market if it gets disputed.
+        ///
+        /// The authority may be a regular account, a multisig account or one of the collectives
+        /// accepted by `CollectiveAuthorityOrigin`. Markets without an assigned authority are
+        /// resolved by `AuthorizedDisputeResolutionOrigin`. The authority can only be assigned
+        /// once by the market creator and only before the market closes, so it is best set in the
+        /// same batch which creates the market.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The id of the market.
+        /// - `authority`: The account or collective which is allowed to report the outcome.
+        #[frame_support::transactional]
+        #[pallet::weight(T::WeightInfo::set_market_authority())]
+        pub fn set_market_authority(
+            origin: OriginFor<T>,
+            market_id: MarketIdOf<T>,
+            authority: MarketAuthorityOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.creator == who, Error::<T>::CallerNotMarketCreator);
+            ensure!(
+                market.dispute_mechanism == MarketDisputeMechanism::Authorized,
+                Error::<T>::MarketDoesNotHaveDisputeMechanismAuthorized
+            );
+            ensure!(
+                matches!(market.status, MarketStatus::Proposed | MarketStatus::Active),
+                Error::<T>::MarketIsNotOpen
+            );
+            ensure!(
+                !MarketAuthorities::<T>::contains_key(market_id),
+                Error::<T>::MarketAuthorityAlreadySet
+            );
+
+            MarketAuthorities::<T>::insert(market_id, authority.clone());
+            Self::deposit_event(Event::MarketAuthoritySet { market_id, authority });
+
+            Ok(())
+        }
+
+        /// Appeal against the report of the authority in exchange for the `AppealBond`.
+        ///
+        /// An appeal can only be filed during the correction period of the report. It freezes
+        /// the report and postpones the resolution of the market by `AppealPeriod`, in which the
+        /// dispute may be escalated to a global dispute. The bond is returned if the market
+        /// resolves to an outcome which differs from the appealed report, and slashed otherwise.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The id of the market.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of markets which resolve at the end of
+        /// the appeal period.
+        #[frame_support::transactional]
+        #[pallet::weight(T::WeightInfo::appeal(CacheSize::get()))]
+        pub fn appeal(
+            origin: OriginFor<T>,
+            market_id: MarketIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Disputed, Error::<T>::MarketIsNotDisputed);
+            ensure!(
+                market.dispute_mechanism == MarketDisputeMechanism::Authorized,
+                Error::<T>::MarketDoesNotHaveDisputeMechanismAuthorized
+            );
+            ensure!(!Appeals::<T>::contains_key(market_id), Error::<T>::ReportAppealed);
+            let report =
+                AuthorizedOutcomeReports::<T>::get(market_id).ok_or(Error::<T>::ReportNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now < report.resolve_at, Error::<T>::CorrectionPeriodExpired);
+
+            let bond = T::AppealBond::get();
+            CurrencyOf::<T>::reserve_named(&Self::reserve_id(), &who, bond)?;
+
+            T::DisputeResolution::remove_auto_resolve(&market_id, report.resolve_at);
+            let resolve_at = now.saturating_add(T::AppealPeriod::get());
+            let ids_len = T::DisputeResolution::add_auto_resolve(&market_id, resolve_at)?;
+
+            let appeal = AuthorityAppeal { appellant: who.clone(), bond, outcome: report.outcome };
+            AuthorizedOutcomeReports::<T>::insert(
+                market_id,
+                AuthorityReport { resolve_at, outcome: appeal.outcome.clone(), ..report },
+            );
+            Appeals::<T>::insert(market_id, appeal);
+            Self::deposit_event(Event::ReportAppealed { market_id, appellant: who });
+
+            Ok(Some(T::WeightInfo::appeal(ids_len)).into())
+        }
     }
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        /// The amount which is reserved from the account which appeals against a report.
+        #[pallet::constant]
+        type AppealBond: Get<BalanceOf<Self>>;
+
+        /// The period after an appeal, in which the dispute can be escalated before the market
+        /// resolves with the appealed report.
+        #[pallet::constant]
+        type AppealPeriod: Get<Self::BlockNumber>;
+
         /// Event
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
@@ -140,8 +273,21 @@ mod pallet {
         >;
 
         /// The origin that is allowed to resolved disupute in Authorized dispute mechanism.
+        /// Only used for markets without an assigned authority.
         type AuthorizedDisputeResolutionOrigin: EnsureOrigin<Self::Origin>;
 
+        /// The origin of the collectives which can be assigned as authority of a market. Yields
+        /// the id of the collective.
+        type CollectiveAuthorityOrigin: EnsureOrigin<Self::Origin, Success = u8>;
+
+        /// The period after the dispute, in which the authority has to report. If no report was
+        /// submitted in time, the dispute mechanism is considered to have failed.
+        #[pallet::constant]
+        type ReportPeriod: Get<Self::BlockNumber>;
+
+        /// Handler for slashed appeal bonds.
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
         /// Identifier of this pallet
         #[pallet::constant]
         type PalletId: Get<PalletId>;
@@ -152,20 +298,53 @@ mod pallet {
 
     #[pallet::error]
     pub enum Error<T> {
+        /// Only the creator of the market is allowed to assign its authority.
+        CallerNotMarketCreator,
+        /// The caller is not the authority assigned to the market.
+        CallerNotMarketAuthority,
+        /// The correction period of the report is over.
+        CorrectionPeriodExpired,
+        /// The authority of the market was already assigned.
+        MarketAuthorityAlreadySet,
         /// The market unexpectedly has the incorrect dispute mechanism.
         MarketDoesNotHaveDisputeMechanismAuthorized,
         /// An account attempts to submit a report to an undisputed market.
         MarketIsNotDisputed,
+        /// The authority can only be assigned to proposed or active markets.
+        MarketIsNotOpen,
         /// Only one dispute is allowed.
         OnlyOneDisputeAllowed,
         /// The report does not match the market's type.
         OutcomeMismatch,
+        /// The report was already appealed.
+        ReportAppealed,
+        /// The authority has not reported yet.
+        ReportNotFound,
+        /// The authority did not report within the report period.
+        ReportPeriodExpired,
     }
 
     #[pallet::event]
+    #[pallet::generate_deposit(fn deposit_event)]
     pub enum Event<T>
     where
-        T: Config, {}
+        T: Config,
+    {
+        /// The appeal was successful and the bond was returned to the appellant.
+        AppealAccepted { market_id: MarketIdOf<T>, appellant: T::AccountId },
+        /// The appeal was unsuccessful and the bond was slashed.
+        AppealRejected { market_id: MarketIdOf<T>, appellant: T::AccountId },
+        /// The authority of a market has reported an outcome.
+        AuthorityReported {
+            market_id: MarketIdOf<T>,
+            outcome: OutcomeReport,
+            evidence: Option<MultiHash>,
+        },
+        /// An authority was assigned to a market.
+        MarketAuthoritySet { market_id: MarketIdOf<T>, authority: MarketAuthorityOf<T> },
+        /// The report of the authority was appealed.
+        ReportAppealed { market_id: MarketIdOf<T>, appellant: T::AccountId },
+    }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
@@ -178,10 +357,53 @@ mod pallet {
     where
         T: Config,
     {
+        /// The reserve ID of the authorized pallet.
+        #[inline]
+        pub fn reserve_id() -> [u8; 8] {
+            T::PalletId::get().0
+        }
+
         /// Return the resolution block number for the given market.
         fn get_auto_resolve(market_id: &MarketIdOf<T>) -> Option<T::BlockNumber> {
             AuthorizedOutcomeReports::<T>::get(market_id).map(|report| report.resolve_at)
         }
+
+        /// Ensure that `origin` is allowed to report the outcome of the given market.
+        fn ensure_market_authority(
+            origin: OriginFor<T>,
+            market_id: &MarketIdOf<T>,
+        ) -> DispatchResult {
+            match MarketAuthorities::<T>::get(market_id) {
+                Some(MarketAuthority::Account(authority)) => {
+                    let who = ensure_signed(origin)?;
+                    ensure!(who == authority, Error::<T>::CallerNotMarketAuthority);
+                }
+                Some(MarketAuthority::Collective(authority)) => {
+                    let collective = T::CollectiveAuthorityOrigin::ensure_origin(origin)?;
+                    ensure!(collective == authority, Error::<T>::CallerNotMarketAuthority);
+                }
+                None => {
+                    T::AuthorizedDisputeResolutionOrigin::ensure_origin(origin)?;
+                }
+            }
+            Ok(())
+        }
+
+        /// Return the block until which the authority has to report, if the market was
+        /// disputed and has an assigned authority.
+        ///
+        /// Markets without an assigned authority are resolved by
+        /// `AuthorizedDisputeResolutionOrigin` without deadline, as they were before market
+        /// authorities were introduced.
+        fn report_deadline(
+            market_id: &MarketIdOf<T>,
+            disputes: &[MarketDispute<T::AccountId, T::BlockNumber>],
+        ) -> Option<T::BlockNumber> {
+            if !MarketAuthorities::<T>::contains_key(market_id) {
+                return None;
+            }
+            disputes.first().map(|dispute| dispute.at.saturating_add(T::ReportPeriod::get()))
+        }
     }
 
     impl<T> DisputeApi for Pallet<T>
@@ -234,8 +456,8 @@ mod pallet {
         }
 
         fn has_failed(
-            _: &[MarketDispute<Self::AccountId, Self::BlockNumber>],
-            _: &Self::MarketId,
+            disputes: &[MarketDispute<Self::AccountId, Self::BlockNumber>],
+            market_id: &Self::MarketId,
             market: &MarketOf<T>,
         ) -> Result<bool, DispatchError> {
             ensure!(
@@ -243,17 +465,66 @@ mod pallet {
                 Error::<T>::MarketDoesNotHaveDisputeMechanismAuthorized
             );
 
-            Ok(false)
+            if Appeals::<T>::contains_key(market_id) {
+                return Ok(true);
+            }
+            if AuthorizedOutcomeReports::<T>::contains_key(market_id) {
+                return Ok(false);
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            Ok(Self::report_deadline(market_id, disputes).map_or(false, |deadline| now >= deadline))
         }
     }
 
-    impl<T> AuthorizedPalletApi for Pallet<T> where T: Config {}
+    impl<T> AuthorizedPalletApi for Pallet<T>
+    where
+        T: Config,
+    {
+        fn on_resolved(
+            market_id: &Self::MarketId,
+            resolved_outcome: &OutcomeReport,
+        ) -> DispatchResult {
+            AuthorizedOutcomeReports::<T>::remove(market_id);
+            MarketAuthorities::<T>::remove(market_id);
+
+            if let Some(appeal) = Appeals::<T>::take(market_id) {
+                let AuthorityAppeal { appellant, bond, outcome } = appeal;
+                if outcome == *resolved_outcome {
+                    let (imbalance, _) = CurrencyOf::<T>::slash_reserved_named(
+                        &Self::reserve_id(),
+                        &appellant,
+                        bond,
+                    );
+                    T::Slash::on_unbalanced(imbalance);
+                    Self::deposit_event(Event::AppealRejected { market_id: *market_id, appellant });
+                } else {
+                    CurrencyOf::<T>::unreserve_named(&Self::reserve_id(), &appellant, bond);
+                    Self::deposit_event(Event::AppealAccepted { market_id: *market_id, appellant });
+                }
+            }
+
+            Ok(())
+        }
+    }
 
     /// Maps the market id to the outcome reported by the authorized account.    
     #[pallet::storage]
     #[pallet::getter(fn outcomes)]
     pub type AuthorizedOutcomeReports<T: Config> =
         StorageMap<_, Twox64Concat, MarketIdOf<T>, AuthorityReport<T::BlockNumber>, OptionQuery>;
+
+    /// Maps the market id to the account or collective which is allowed to resolve the market.
+    /// Markets without an entry are resolved by `AuthorizedDisputeResolutionOrigin`.
+    #[pallet::storage]
+    #[pallet::getter(fn market_authority)]
+    pub type MarketAuthorities<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, MarketAuthorityOf<T>, OptionQuery>;
+
+    /// Maps the market id to the appeal against the authority's report.
+    #[pallet::storage]
+    #[pallet::getter(fn appeals)]
+    pub type Appeals<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, AuthorityAppealOf<T>, OptionQuery>;
 }
 
 #[cfg(any(feature = "runtime-benchmarks", test))]
@@ -15,6 +15,170 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{AuthorizedOutcomeReports, Config, Pallet};
+#[cfg(feature = "try-runtime")]
+use alloc::{format, string::ToString};
+use frame_support::{
+    dispatch::Weight,
+    log,
+    pallet_prelude::PhantomData,
+    traits::{Get, OnRuntimeUpgrade, StorageVersion},
+    RuntimeDebug,
+};
+#[cfg(feature = "try-runtime")]
+use frame_support::{
+    migration::storage_key_iter, traits::OnRuntimeUpgradeHelpersExt, Twox64Concat,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use zeitgeist_primitives::types::{AuthorityReport, OutcomeReport};
+
+const AUTHORIZED_REQUIRED_STORAGE_VERSION: u16 = 3;
+const AUTHORIZED_NEXT_STORAGE_VERSION: u16 = 4;
+
+/// The layout of `AuthorityReport` before the `evidence` field was added.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OldAuthorityReport<BlockNumber> {
+    pub resolve_at: BlockNumber,
+    pub outcome: OutcomeReport,
+}
+
+pub struct AddEvidenceToAuthorityReport<T>(PhantomData<T>);
+
+// Add the `evidence` field to the values of the `AuthorizedOutcomeReports` map.
+impl<T: Config> OnRuntimeUpgrade for AddEvidenceToAuthorityReport<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut total_weight = T::DbWeight::get().reads(1);
+        let authorized_version = StorageVersion::get::<Pallet<T>>();
+        if authorized_version != AUTHORIZED_REQUIRED_STORAGE_VERSION {
+            log::info!(
+                "AddEvidenceToAuthorityReport: authorized version is {:?}, require {:?};",
+                authorized_version,
+                AUTHORIZED_REQUIRED_STORAGE_VERSION,
+            );
+            return total_weight;
+        }
+        log::info!("AddEvidenceToAuthorityReport: Starting...");
+
+        let mut translated = 0u64;
+        AuthorizedOutcomeReports::<T>::translate::<OldAuthorityReport<T::BlockNumber>, _>(
+            |_, old_report| {
+                translated = translated.saturating_add(1);
+                Some(AuthorityReport {
+                    resolve_at: old_report.resolve_at,
+                    outcome: old_report.outcome,
+                    evidence: None,
+                })
+            },
+        );
+        log::info!("AddEvidenceToAuthorityReport: Upgraded {} reports.", translated);
+        total_weight =
+            total_weight.saturating_add(T::DbWeight::get().reads_writes(translated, translated));
+
+        StorageVersion::new(AUTHORIZED_NEXT_STORAGE_VERSION).put::<Pallet<T>>();
+        total_weight = total_weight.saturating_add(T::DbWeight::get().writes(1));
+        log::info!("AddEvidenceToAuthorityReport: Done!");
+        total_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<(), &'static str> {
+        let mut counter = 0_u32;
+        for (key, value) in storage_key_iter::<
+            crate::MarketIdOf<T>,
+            OldAuthorityReport<T::BlockNumber>,
+            Twox64Concat,
+        >(b"Authorized", b"AuthorizedOutcomeReports")
+        {
+            Self::set_temp_storage(value, &format!("{:?}", key));
+            counter = counter.saturating_add(1_u32);
+        }
+        let counter_key = "counter_key".to_string();
+        Self::set_temp_storage(counter, &counter_key);
+        Ok(())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        let mut reports_count = 0_u32;
+        for (key, new_value) in AuthorizedOutcomeReports::<T>::iter() {
+            let key_str = format!("{:?}", key);
+            let old_value: OldAuthorityReport<T::BlockNumber> = Self::get_temp_storage(&key_str)
+                .unwrap_or_else(|| panic!("old value not found for market id {:?}", key_str));
+            assert_eq!(old_value.resolve_at, new_value.resolve_at);
+            assert_eq!(old_value.outcome, new_value.outcome);
+            assert_eq!(new_value.evidence, None);
+            reports_count = reports_count.saturating_add(1_u32);
+        }
+        let old_reports_count: u32 = Self::get_temp_storage(&"counter_key".to_string())
+            .expect("old counter key storage not found");
+        assert_eq!(reports_count, old_reports_count);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{ExtBuilder, Runtime};
+    use frame_support::{migration::put_storage_value, Twox64Concat};
+
+    const AUTHORIZED: &[u8] = b"Authorized";
+    const AUTHORIZED_OUTCOME_REPORTS: &[u8] = b"AuthorizedOutcomeReports";
+
+    #[test]
+    fn on_runtime_upgrade_increments_the_storage_version() {
+        ExtBuilder::default().build().execute_with(|| {
+            set_up_chain();
+            AddEvidenceToAuthorityReport::<Runtime>::on_runtime_upgrade();
+            assert_eq!(StorageVersion::get::<Pallet<Runtime>>(), AUTHORIZED_NEXT_STORAGE_VERSION);
+        });
+    }
+
+    #[test]
+    fn on_runtime_upgrade_adds_empty_evidence() {
+        ExtBuilder::default().build().execute_with(|| {
+            set_up_chain();
+            let hash = utility::key_to_hash::<Twox64Concat, u128>(0);
+            let old_report =
+                OldAuthorityReport { resolve_at: 42, outcome: OutcomeReport::Scalar(1) };
+            put_storage_value(AUTHORIZED, AUTHORIZED_OUTCOME_REPORTS, &hash, old_report);
+
+            AddEvidenceToAuthorityReport::<Runtime>::on_runtime_upgrade();
+
+            assert_eq!(
+                AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(),
+                AuthorityReport {
+                    resolve_at: 42,
+                    outcome: OutcomeReport::Scalar(1),
+                    evidence: None
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn on_runtime_upgrade_is_noop_if_versions_are_not_correct() {
+        ExtBuilder::default().build().execute_with(|| {
+            StorageVersion::new(AUTHORIZED_NEXT_STORAGE_VERSION).put::<Pallet<Runtime>>();
+            let report = AuthorityReport {
+                resolve_at: 42,
+                outcome: OutcomeReport::Scalar(1),
+                evidence: None,
+            };
+            AuthorizedOutcomeReports::<Runtime>::insert(0, report.clone());
+
+            AddEvidenceToAuthorityReport::<Runtime>::on_runtime_upgrade();
+
+            assert_eq!(AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(), report);
+        });
+    }
+
+    fn set_up_chain() {
+        StorageVersion::new(AUTHORIZED_REQUIRED_STORAGE_VERSION).put::<Pallet<Runtime>>();
+    }
+}
+
 // We use these utilities to prevent having to make the swaps pallet a dependency of
 // prediciton-markets. The calls are based on the implementation of `StorageVersion`, found here:
 // https://github.com/paritytech/substrate/blob/bc7a1e6c19aec92bfa247d8ca68ec63e07061032/frame/support/src/traits/metadata.rs#L168-L230
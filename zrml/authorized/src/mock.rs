@@ -24,18 +24,18 @@ use alloc::{vec, vec::Vec};
 use frame_support::{
     construct_runtime, ord_parameter_types,
     pallet_prelude::{DispatchError, Weight},
-    traits::Everything,
+    traits::{EnsureOrigin, Everything},
     BoundedVec,
 };
-use frame_system::EnsureSignedBy;
+use frame_system::{EnsureSignedBy, RawOrigin};
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
 };
 use zeitgeist_primitives::{
     constants::mock::{
-        AuthorizedPalletId, BlockHashCount, CorrectionPeriod, MaxReserves, MinimumPeriod,
-        PmPalletId, BASE,
+        AuthorityAppealBond, AuthorityAppealPeriod, AuthorityReportPeriod, AuthorizedPalletId,
+        BlockHashCount, CorrectionPeriod, MaxReserves, MinimumPeriod, PmPalletId, BASE,
     },
     traits::DisputeResolutionApi,
     types::{
@@ -47,6 +47,8 @@ use zeitgeist_primitives::{
 pub const ALICE: AccountIdTest = 0;
 pub const BOB: AccountIdTest = 1;
 pub const CHARLIE: AccountIdTest = 2;
+pub const COLLECTIVE_0: AccountIdTest = 100;
+pub const COLLECTIVE_1: AccountIdTest = 101;
 
 construct_runtime!(
     pub enum Runtime
@@ -70,6 +72,27 @@ ord_parameter_types! {
     pub const MaxDisputes: u32 = 64;
 }
 
+// MockCollectiveOrigin accepts the signed origins of `COLLECTIVE_0` and `COLLECTIVE_1` as the
+// collectives `0` and `1`.
+pub struct MockCollectiveOrigin;
+
+impl EnsureOrigin<Origin> for MockCollectiveOrigin {
+    type Success = u8;
+
+    fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+        Into::<Result<RawOrigin<AccountIdTest>, Origin>>::into(o).and_then(|o| match o {
+            RawOrigin::Signed(COLLECTIVE_0) => Ok(0),
+            RawOrigin::Signed(COLLECTIVE_1) => Ok(1),
+            r => Err(Origin::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn successful_origin() -> Origin {
+        Origin::from(RawOrigin::Signed(COLLECTIVE_0))
+    }
+}
+
 // MockResolution implements DisputeResolutionApi with no-ops.
 pub struct MockResolution;
 
@@ -132,13 +155,18 @@ impl DisputeResolutionApi for MockResolution {
 }
 
 impl crate::Config for Runtime {
+    type AppealBond = AuthorityAppealBond;
+    type AppealPeriod = AuthorityAppealPeriod;
     type Event = ();
     type CorrectionPeriod = CorrectionPeriod;
     type DisputeResolution = MockResolution;
     type MarketCommons = MarketCommons;
     type PalletId = AuthorizedPalletId;
+    type ReportPeriod = AuthorityReportPeriod;
+    type Slash = ();
     type AuthorizedDisputeResolutionOrigin =
         EnsureSignedBy<AuthorizedDisputeResolutionUser, AccountIdTest>;
+    type CollectiveAuthorityOrigin = MockCollectiveOrigin;
     type WeightInfo = crate::weights::WeightInfo<Runtime>;
 }
 
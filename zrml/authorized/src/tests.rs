@@ -19,14 +19,25 @@
 
 use crate::{
     market_mock,
-    mock::{Authorized, AuthorizedDisputeResolutionUser, ExtBuilder, Origin, Runtime, BOB},
+    mock::{
+        Authorized, AuthorizedDisputeResolutionUser, Balances, ExtBuilder, Origin, Runtime, BOB,
+        CHARLIE, COLLECTIVE_0, COLLECTIVE_1,
+    },
     mock_storage::pallet as mock_storage,
-    AuthorizedOutcomeReports, Error,
+    Appeals, AuthorizedOutcomeReports, AuthorizedPalletApi, Error, MarketAuthorities,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::DispatchError,
+    traits::{Currency, NamedReservableCurrency, ReservableCurrency},
 };
-use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
 use zeitgeist_primitives::{
+    constants::mock::{AuthorityAppealBond, AuthorityAppealPeriod, AuthorityReportPeriod},
     traits::DisputeApi,
-    types::{AuthorityReport, MarketDispute, MarketDisputeMechanism, MarketStatus, OutcomeReport},
+    types::{
+        AuthorityReport, MarketAuthority, MarketDispute, MarketDisputeMechanism, MarketStatus,
+        MultiHash, OutcomeReport,
+    },
 };
 use zrml_market_commons::Markets;
 
@@ -37,13 +48,14 @@ fn authorize_market_outcome_inserts_a_new_outcome() {
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
-            OutcomeReport::Scalar(1)
+            OutcomeReport::Scalar(1),
+            None
         ));
         let now = frame_system::Pallet::<Runtime>::block_number();
         let resolve_at = now + <Runtime as crate::Config>::CorrectionPeriod::get();
         assert_eq!(
             AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(),
-            AuthorityReport { outcome: OutcomeReport::Scalar(1), resolve_at }
+            AuthorityReport { outcome: OutcomeReport::Scalar(1), resolve_at, evidence: None }
         );
     });
 }
@@ -57,12 +69,17 @@ fn authorize_market_outcome_does_not_reset_dispute_resolution() {
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
             OutcomeReport::Scalar(1),
+            None,
         ));
         let now = frame_system::Pallet::<Runtime>::block_number();
         let resolve_at_0 = now + <Runtime as crate::Config>::CorrectionPeriod::get();
         assert_eq!(
             AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(),
-            AuthorityReport { outcome: OutcomeReport::Scalar(1), resolve_at: resolve_at_0 }
+            AuthorityReport {
+                outcome: OutcomeReport::Scalar(1),
+                resolve_at: resolve_at_0,
+                evidence: None
+            }
         );
 
         assert_eq!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at_0), vec![0]);
@@ -70,12 +87,17 @@ fn authorize_market_outcome_does_not_reset_dispute_resolution() {
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
-            OutcomeReport::Scalar(2)
+            OutcomeReport::Scalar(2),
+            None
         ));
 
         assert_eq!(
             AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(),
-            AuthorityReport { outcome: OutcomeReport::Scalar(2), resolve_at: resolve_at_0 }
+            AuthorityReport {
+                outcome: OutcomeReport::Scalar(2),
+                resolve_at: resolve_at_0,
+                evidence: None
+            }
         );
 
         assert_eq!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at_0), vec![0]);
@@ -89,7 +111,8 @@ fn authorize_market_outcome_fails_if_market_does_not_exist() {
             Authorized::authorize_market_outcome(
                 Origin::signed(AuthorizedDisputeResolutionUser::get()),
                 0,
-                OutcomeReport::Scalar(1)
+                OutcomeReport::Scalar(1),
+                None
             ),
             zrml_market_commons::Error::<Runtime>::MarketDoesNotExist
         );
@@ -106,7 +129,8 @@ fn authorize_market_outcome_fails_on_non_authorized_market() {
             Authorized::authorize_market_outcome(
                 Origin::signed(AuthorizedDisputeResolutionUser::get()),
                 0,
-                OutcomeReport::Scalar(1)
+                OutcomeReport::Scalar(1),
+                None
             ),
             Error::<Runtime>::MarketDoesNotHaveDisputeMechanismAuthorized
         );
@@ -123,7 +147,8 @@ fn authorize_market_outcome_fails_on_undisputed_market() {
             Authorized::authorize_market_outcome(
                 Origin::signed(AuthorizedDisputeResolutionUser::get()),
                 0,
-                OutcomeReport::Scalar(1)
+                OutcomeReport::Scalar(1),
+                None
             ),
             Error::<Runtime>::MarketIsNotDisputed
         );
@@ -138,7 +163,8 @@ fn authorize_market_outcome_fails_on_invalid_report() {
             Authorized::authorize_market_outcome(
                 Origin::signed(AuthorizedDisputeResolutionUser::get()),
                 0,
-                OutcomeReport::Categorical(123)
+                OutcomeReport::Categorical(123),
+                None
             ),
             Error::<Runtime>::OutcomeMismatch
         );
@@ -150,7 +176,12 @@ fn authorize_market_outcome_fails_on_unauthorized_account() {
     ExtBuilder::default().build().execute_with(|| {
         Markets::<Runtime>::insert(0, market_mock::<Runtime>());
         assert_noop!(
-            Authorized::authorize_market_outcome(Origin::signed(BOB), 0, OutcomeReport::Scalar(1)),
+            Authorized::authorize_market_outcome(
+                Origin::signed(BOB),
+                0,
+                OutcomeReport::Scalar(1),
+                None
+            ),
             DispatchError::BadOrigin,
         );
     });
@@ -184,7 +215,8 @@ fn on_resolution_removes_stored_outcomes() {
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
-            OutcomeReport::Scalar(2)
+            OutcomeReport::Scalar(2),
+            None
         ));
         assert_ok!(Authorized::on_resolution(&[], &0, &market));
         assert_eq!(AuthorizedOutcomeReports::<Runtime>::get(0), None);
@@ -200,12 +232,14 @@ fn on_resolution_returns_the_reported_outcome() {
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
-            OutcomeReport::Scalar(1)
+            OutcomeReport::Scalar(1),
+            None
         ));
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
-            OutcomeReport::Scalar(2)
+            OutcomeReport::Scalar(2),
+            None
         ));
         assert_eq!(
             Authorized::on_resolution(&[], &0, &market).unwrap(),
@@ -222,22 +256,24 @@ fn authorized_market_outcome_can_handle_multiple_markets() {
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
-            OutcomeReport::Scalar(123)
+            OutcomeReport::Scalar(123),
+            None
         ));
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             1,
-            OutcomeReport::Scalar(456)
+            OutcomeReport::Scalar(456),
+            None
         ));
         let now = frame_system::Pallet::<Runtime>::block_number();
         let resolve_at = now + <Runtime as crate::Config>::CorrectionPeriod::get();
         assert_eq!(
             AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(),
-            AuthorityReport { outcome: OutcomeReport::Scalar(123), resolve_at }
+            AuthorityReport { outcome: OutcomeReport::Scalar(123), resolve_at, evidence: None }
         );
         assert_eq!(
             AuthorizedOutcomeReports::<Runtime>::get(1).unwrap(),
-            AuthorityReport { outcome: OutcomeReport::Scalar(456), resolve_at }
+            AuthorityReport { outcome: OutcomeReport::Scalar(456), resolve_at, evidence: None }
         );
     });
 }
@@ -251,7 +287,8 @@ fn get_auto_resolve_works() {
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
-            OutcomeReport::Scalar(1)
+            OutcomeReport::Scalar(1),
+            None
         ));
         let now = frame_system::Pallet::<Runtime>::block_number();
         let resolve_at = now + <Runtime as crate::Config>::CorrectionPeriod::get();
@@ -266,3 +303,370 @@ fn get_auto_resolve_returns_none_without_market_storage() {
         assert_eq!(Authorized::get_auto_resolve(&[], &0, &market).unwrap(), None,);
     });
 }
+
+#[test]
+fn set_market_authority_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut market = market_mock::<Runtime>();
+        market.status = MarketStatus::Active;
+        let creator = market.creator;
+        Markets::<Runtime>::insert(0, market);
+        assert_ok!(Authorized::set_market_authority(
+            Origin::signed(creator),
+            0,
+            MarketAuthority::Account(CHARLIE)
+        ));
+        assert_eq!(MarketAuthorities::<Runtime>::get(0), Some(MarketAuthority::Account(CHARLIE)));
+    });
+}
+
+#[test]
+fn set_market_authority_fails_if_caller_is_not_the_creator() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut market = market_mock::<Runtime>();
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(0, market);
+        assert_noop!(
+            Authorized::set_market_authority(
+                Origin::signed(BOB),
+                0,
+                MarketAuthority::Account(CHARLIE)
+            ),
+            Error::<Runtime>::CallerNotMarketCreator
+        );
+    });
+}
+
+#[test]
+fn set_market_authority_fails_if_authority_is_already_set() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut market = market_mock::<Runtime>();
+        market.status = MarketStatus::Active;
+        let creator = market.creator;
+        Markets::<Runtime>::insert(0, market);
+        assert_ok!(Authorized::set_market_authority(
+            Origin::signed(creator),
+            0,
+            MarketAuthority::Account(CHARLIE)
+        ));
+        assert_noop!(
+            Authorized::set_market_authority(
+                Origin::signed(creator),
+                0,
+                MarketAuthority::Account(BOB)
+            ),
+            Error::<Runtime>::MarketAuthorityAlreadySet
+        );
+    });
+}
+
+#[test]
+fn set_market_authority_fails_on_disputed_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market = market_mock::<Runtime>();
+        let creator = market.creator;
+        Markets::<Runtime>::insert(0, market);
+        assert_noop!(
+            Authorized::set_market_authority(
+                Origin::signed(creator),
+                0,
+                MarketAuthority::Account(CHARLIE)
+            ),
+            Error::<Runtime>::MarketIsNotOpen
+        );
+    });
+}
+
+#[test]
+fn authorize_market_outcome_accepts_only_the_market_authority() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        MarketAuthorities::<Runtime>::insert(0, MarketAuthority::Account(CHARLIE));
+        assert_noop!(
+            Authorized::authorize_market_outcome(
+                Origin::signed(AuthorizedDisputeResolutionUser::get()),
+                0,
+                OutcomeReport::Scalar(1),
+                None
+            ),
+            Error::<Runtime>::CallerNotMarketAuthority
+        );
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(CHARLIE),
+            0,
+            OutcomeReport::Scalar(1),
+            None
+        ));
+        assert_eq!(
+            AuthorizedOutcomeReports::<Runtime>::get(0).unwrap().outcome,
+            OutcomeReport::Scalar(1)
+        );
+    });
+}
+
+#[test]
+fn authorize_market_outcome_fails_after_the_report_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        MarketAuthorities::<Runtime>::insert(0, MarketAuthority::Account(CHARLIE));
+        // The mocked dispute happened at block 42.
+        frame_system::Pallet::<Runtime>::set_block_number(42 + AuthorityReportPeriod::get());
+        assert_noop!(
+            Authorized::authorize_market_outcome(
+                Origin::signed(CHARLIE),
+                0,
+                OutcomeReport::Scalar(1),
+                None
+            ),
+            Error::<Runtime>::ReportPeriodExpired
+        );
+    });
+}
+
+#[test]
+fn authorize_market_outcome_has_no_deadline_without_market_authority() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(0, &market);
+        // The mocked dispute happened at block 42.
+        frame_system::Pallet::<Runtime>::set_block_number(42 + AuthorityReportPeriod::get());
+        let dispute = MarketDispute { by: BOB, at: 42, outcome: OutcomeReport::Scalar(1) };
+        assert!(!Authorized::has_failed(&[dispute], &0, &market).unwrap());
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            None
+        ));
+    });
+}
+
+#[test]
+fn authorize_market_outcome_accepts_only_the_market_authority_collective() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        MarketAuthorities::<Runtime>::insert(0, MarketAuthority::Collective(1));
+        assert_noop!(
+            Authorized::authorize_market_outcome(
+                Origin::signed(COLLECTIVE_0),
+                0,
+                OutcomeReport::Scalar(1),
+                None
+            ),
+            Error::<Runtime>::CallerNotMarketAuthority
+        );
+        assert_noop!(
+            Authorized::authorize_market_outcome(
+                Origin::signed(AuthorizedDisputeResolutionUser::get()),
+                0,
+                OutcomeReport::Scalar(1),
+                None
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(COLLECTIVE_1),
+            0,
+            OutcomeReport::Scalar(1),
+            None
+        ));
+    });
+}
+
+#[test]
+fn has_failed_returns_true_if_the_authority_did_not_report_in_time() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market = market_mock::<Runtime>();
+        MarketAuthorities::<Runtime>::insert(0, MarketAuthority::Account(CHARLIE));
+        let dispute = MarketDispute { by: BOB, at: 42, outcome: OutcomeReport::Scalar(1) };
+        frame_system::Pallet::<Runtime>::set_block_number(42 + AuthorityReportPeriod::get() - 1);
+        assert!(!Authorized::has_failed(&[dispute.clone()], &0, &market).unwrap());
+        frame_system::Pallet::<Runtime>::set_block_number(42 + AuthorityReportPeriod::get());
+        assert!(Authorized::has_failed(&[dispute], &0, &market).unwrap());
+    });
+}
+
+#[test]
+fn has_failed_returns_false_if_the_authority_reported() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(0, &market);
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            None
+        ));
+        let dispute = MarketDispute { by: BOB, at: 42, outcome: OutcomeReport::Scalar(1) };
+        frame_system::Pallet::<Runtime>::set_block_number(42 + AuthorityReportPeriod::get());
+        assert!(!Authorized::has_failed(&[dispute], &0, &market).unwrap());
+    });
+}
+
+#[test]
+fn authorize_market_outcome_stores_the_evidence() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        let evidence = MultiHash::Sha3_384([1; 50]);
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            Some(evidence.clone()),
+        ));
+        assert_eq!(AuthorizedOutcomeReports::<Runtime>::get(0).unwrap().evidence, Some(evidence));
+    });
+}
+
+#[test]
+fn appeal_reserves_the_bond_and_postpones_the_resolution() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            None,
+        ));
+        let now = frame_system::Pallet::<Runtime>::block_number();
+        let resolve_at_0 = now + <Runtime as crate::Config>::CorrectionPeriod::get();
+
+        frame_system::Pallet::<Runtime>::set_block_number(now + 1);
+        assert_ok!(Authorized::appeal(Origin::signed(BOB), 0));
+
+        let resolve_at_1 = now + 1 + AuthorityAppealPeriod::get();
+        assert_eq!(
+            Balances::reserved_balance_named(&Authorized::reserve_id(), &BOB),
+            AuthorityAppealBond::get()
+        );
+        assert!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at_0).is_empty());
+        assert_eq!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at_1), vec![0]);
+        assert_eq!(AuthorizedOutcomeReports::<Runtime>::get(0).unwrap().resolve_at, resolve_at_1);
+        let appeal = Appeals::<Runtime>::get(0).unwrap();
+        assert_eq!(appeal.appellant, BOB);
+        assert_eq!(appeal.outcome, OutcomeReport::Scalar(1));
+    });
+}
+
+#[test]
+fn appeal_fails_without_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        assert_noop!(Authorized::appeal(Origin::signed(BOB), 0), Error::<Runtime>::ReportNotFound);
+    });
+}
+
+#[test]
+fn appeal_fails_after_the_correction_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            None,
+        ));
+        let resolve_at = AuthorizedOutcomeReports::<Runtime>::get(0).unwrap().resolve_at;
+        frame_system::Pallet::<Runtime>::set_block_number(resolve_at);
+        assert_noop!(
+            Authorized::appeal(Origin::signed(BOB), 0),
+            Error::<Runtime>::CorrectionPeriodExpired
+        );
+    });
+}
+
+#[test]
+fn appeal_fails_if_already_appealed() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            None,
+        ));
+        assert_ok!(Authorized::appeal(Origin::signed(BOB), 0));
+        assert_noop!(
+            Authorized::appeal(Origin::signed(CHARLIE), 0),
+            Error::<Runtime>::ReportAppealed
+        );
+    });
+}
+
+#[test]
+fn authorize_market_outcome_fails_on_appealed_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            None,
+        ));
+        assert_ok!(Authorized::appeal(Origin::signed(BOB), 0));
+        assert_noop!(
+            Authorized::authorize_market_outcome(
+                Origin::signed(AuthorizedDisputeResolutionUser::get()),
+                0,
+                OutcomeReport::Scalar(2),
+                None,
+            ),
+            Error::<Runtime>::ReportAppealed
+        );
+    });
+}
+
+#[test]
+fn has_failed_returns_true_if_the_report_was_appealed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(0, &market);
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            None,
+        ));
+        assert_ok!(Authorized::appeal(Origin::signed(BOB), 0));
+        assert!(Authorized::has_failed(&[], &0, &market).unwrap());
+    });
+}
+
+#[test]
+fn on_resolved_returns_the_bond_of_a_successful_appeal() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            None,
+        ));
+        let free_before = Balances::free_balance(&BOB);
+        assert_ok!(Authorized::appeal(Origin::signed(BOB), 0));
+        assert_ok!(Authorized::on_resolved(&0, &OutcomeReport::Scalar(2)));
+        assert_eq!(Balances::free_balance(&BOB), free_before);
+        assert_eq!(Balances::reserved_balance(&BOB), 0);
+        assert!(Appeals::<Runtime>::get(0).is_none());
+        assert!(AuthorizedOutcomeReports::<Runtime>::get(0).is_none());
+    });
+}
+
+#[test]
+fn on_resolved_slashes_the_bond_of_an_unsuccessful_appeal() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1),
+            None,
+        ));
+        let free_before = Balances::free_balance(&BOB);
+        assert_ok!(Authorized::appeal(Origin::signed(BOB), 0));
+        assert_ok!(Authorized::on_resolved(&0, &OutcomeReport::Scalar(1)));
+        assert_eq!(Balances::free_balance(&BOB), free_before - AuthorityAppealBond::get());
+        assert_eq!(Balances::reserved_balance(&BOB), 0);
+        assert!(Appeals::<Runtime>::get(0).is_none());
+    });
+}
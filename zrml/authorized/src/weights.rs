@@ -47,26 +47,51 @@ use frame_support::{traits::Get, weights::Weight};
 pub trait WeightInfoZeitgeist {
     fn authorize_market_outcome_first_report(m: u32) -> Weight;
     fn authorize_market_outcome_existing_report() -> Weight;
+    fn set_market_authority() -> Weight;
+    fn appeal(m: u32) -> Weight;
 }
 
 /// Weight functions for zrml_authorized (automatically generated)
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: Authorized MarketAuthorities (r:1 w:0)
+    // Storage: PredictionMarkets Disputes (r:1 w:0)
     // Storage: Authorized AuthorizedOutcomeReports (r:1 w:1)
     // Storage: PredictionMarkets MarketIdsPerDisputeBlock (r:1 w:1)
     fn authorize_market_outcome_first_report(m: u32) -> Weight {
-        Weight::from_ref_time(38_444_000)
+        Weight::from_ref_time(42_716_000)
             // Standard Error: 1_000
             .saturating_add(Weight::from_ref_time(173_000).saturating_mul(m.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(5))
             .saturating_add(T::DbWeight::get().writes(2))
     }
     // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: Authorized MarketAuthorities (r:1 w:0)
+    // Storage: PredictionMarkets Disputes (r:1 w:0)
     // Storage: Authorized AuthorizedOutcomeReports (r:1 w:1)
     fn authorize_market_outcome_existing_report() -> Weight {
-        Weight::from_ref_time(30_850_000)
+        Weight::from_ref_time(35_122_000)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: Authorized MarketAuthorities (r:1 w:1)
+    fn set_market_authority() -> Weight {
+        Weight::from_ref_time(27_430_000)
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+    // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: Authorized Appeals (r:1 w:1)
+    // Storage: Authorized AuthorizedOutcomeReports (r:1 w:1)
+    // Storage: Balances Reserves (r:1 w:1)
+    // Storage: PredictionMarkets MarketIdsPerDisputeBlock (r:2 w:2)
+    fn appeal(m: u32) -> Weight {
+        Weight::from_ref_time(61_583_000)
+            // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(186_000).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
 }
@@ -23,12 +23,19 @@
 
 #[cfg(test)]
 use crate::Pallet as Court;
-use crate::{BalanceOf, Call, Config, CurrencyOf, Pallet};
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_support::{dispatch::UnfilteredDispatchable, traits::Currency};
+use crate::{
+    market_mock, Appeals, BalanceOf, Call, Config, Courts, CurrencyOf, Delegations, MarketIdOf,
+    Pallet, Rewards, Vote, Votes,
+};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+    dispatch::UnfilteredDispatchable,
+    traits::{Currency, Get},
+};
 use frame_system::RawOrigin;
-use sp_runtime::traits::Bounded;
-use zeitgeist_primitives::types::OutcomeReport;
+use sp_runtime::traits::{Bounded, Hash, One};
+use zeitgeist_primitives::{traits::DisputeApi, types::OutcomeReport};
+use zrml_market_commons::MarketCommonsPalletApi;
 
 fn deposit<T>(caller: &T::AccountId)
 where
@@ -42,28 +49,126 @@ where
     T: Config,
 {
     deposit::<T>(caller);
-    Call::<T>::join_court {}
+    Call::<T>::join_court { stake: T::MinJurorStake::get() }
         .dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())
         .unwrap();
 }
 
+// Lets `juror` join the court and disputes a new market, whose court case consists of `juror`.
+fn setup_court_case<T>(juror: &T::AccountId) -> MarketIdOf<T>
+where
+    T: Config,
+{
+    deposit_and_join_court::<T>(juror);
+    let market_id = T::MarketCommons::push_market(market_mock::<T>()).unwrap();
+    let market = T::MarketCommons::market(&market_id).unwrap();
+    Pallet::<T>::on_dispute(&[], &market_id, &market).unwrap();
+    market_id
+}
+
 benchmarks! {
+    appeal {
+        let caller: T::AccountId = whitelisted_caller();
+        deposit::<T>(&caller);
+        let juror: T::AccountId = account("juror", 0, 0);
+        let market_id = setup_court_case::<T>(&juror);
+        let outcome = OutcomeReport::Scalar(u128::MAX);
+        let salt = T::Hashing::hash_of(&0u8);
+        let commitment = T::Hashing::hash_of(&(&juror, &outcome, &salt));
+        Call::<T>::commit_vote { market_id, commitment }
+            .dispatch_bypass_filter(RawOrigin::Signed(juror.clone()).into())
+            .unwrap();
+        let court = Courts::<T>::get(market_id).unwrap();
+        <frame_system::Pallet<T>>::set_block_number(court.voting_ends + One::one());
+        Call::<T>::reveal_vote { market_id, outcome, salt }
+            .dispatch_bypass_filter(RawOrigin::Signed(juror).into())
+            .unwrap();
+        <frame_system::Pallet<T>>::set_block_number(court.reveal_ends + One::one());
+    }: _(RawOrigin::Signed(caller), market_id)
+    verify {
+        assert_eq!(Appeals::<T>::get(market_id).len(), 1);
+    }
+
+    claim_rewards {
+        let caller: T::AccountId = whitelisted_caller();
+        let _ = CurrencyOf::<T>::deposit_creating(&caller, T::MinJurorStake::get());
+        Rewards::<T>::insert(&caller, T::MinJurorStake::get());
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!Rewards::<T>::contains_key(caller));
+    }
+
+    delegate {
+        let caller: T::AccountId = whitelisted_caller();
+        deposit::<T>(&caller);
+        let juror: T::AccountId = account("juror", 0, 0);
+        deposit_and_join_court::<T>(&juror);
+        let amount = T::MinJurorStake::get();
+    }: _(RawOrigin::Signed(caller.clone()), juror.clone(), amount)
+    verify {
+        assert_eq!(Delegations::<T>::get(juror, caller), amount);
+    }
+
     exit_court {
+        let d in 0..T::MaxDelegators::get();
         let caller: T::AccountId = whitelisted_caller();
         deposit_and_join_court::<T>(&caller);
-    }: _(RawOrigin::Signed(caller))
+        for i in 0..d {
+            let delegator: T::AccountId = account("delegator", i, 0);
+            deposit::<T>(&delegator);
+            Call::<T>::delegate { juror: caller.clone(), amount: T::MinJurorStake::get() }
+                .dispatch_bypass_filter(RawOrigin::Signed(delegator).into())
+                .unwrap();
+        }
+        Call::<T>::prepare_exit_court {}
+            .dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())
+            .unwrap();
+        let now = <frame_system::Pallet<T>>::block_number();
+        <frame_system::Pallet<T>>::set_block_number(now + T::JurorUnbondingPeriod::get());
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_eq!(Delegations::<T>::iter_prefix(caller).count(), 0);
+    }
 
     join_court {
         let caller: T::AccountId = whitelisted_caller();
         deposit::<T>(&caller);
+    }: _(RawOrigin::Signed(caller), T::MinJurorStake::get())
+
+    commit_vote {
+        let caller: T::AccountId = whitelisted_caller();
+        let market_id = setup_court_case::<T>(&caller);
+        let commitment = T::Hashing::hash_of(&(&caller, OutcomeReport::Scalar(u128::MAX)));
+    }: _(RawOrigin::Signed(caller), market_id, commitment)
+
+    prepare_exit_court {
+        let caller: T::AccountId = whitelisted_caller();
+        deposit_and_join_court::<T>(&caller);
     }: _(RawOrigin::Signed(caller))
 
-    vote {
+    reveal_vote {
         let caller: T::AccountId = whitelisted_caller();
-        let market_id = Default::default();
+        let market_id = setup_court_case::<T>(&caller);
         let outcome = OutcomeReport::Scalar(u128::MAX);
-        deposit_and_join_court::<T>(&caller);
-    }: _(RawOrigin::Signed(caller), market_id, outcome)
+        let salt = T::Hashing::hash_of(&0u8);
+        let commitment = T::Hashing::hash_of(&(&caller, &outcome, &salt));
+        Votes::<T>::insert(market_id, &caller, Vote::Secret { commitment });
+        let voting_ends = Courts::<T>::get(market_id).unwrap().voting_ends;
+        <frame_system::Pallet<T>>::set_block_number(voting_ends + One::one());
+    }: _(RawOrigin::Signed(caller), market_id, outcome, salt)
+
+    undelegate {
+        let caller: T::AccountId = whitelisted_caller();
+        deposit::<T>(&caller);
+        let juror: T::AccountId = account("juror", 0, 0);
+        deposit_and_join_court::<T>(&juror);
+        Call::<T>::delegate { juror: juror.clone(), amount: T::MinJurorStake::get() }
+            .dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())
+            .unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), juror.clone())
+    verify {
+        assert!(!Delegations::<T>::contains_key(juror, caller));
+    }
 
     impl_benchmark_test_suite!(
         Court,
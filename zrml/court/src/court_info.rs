@@ -0,0 +1,43 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+/// The state of the court case of a market. Only unsettled court cases are stored.
+#[derive(
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    parity_scale_codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+)]
+pub struct CourtInfo<Balance, BlockNumber, Jurors> {
+    /// The index of the current round. Starts at zero and is incremented with every appeal.
+    pub round: u32,
+    /// The jurors selected for the current round and their stakes (including delegations) at
+    /// the time they were drawn.
+    pub jurors: Jurors,
+    /// The sum of the stakes of `jurors`.
+    pub total_stake: Balance,
+    /// The block until which the jurors of the current round can commit to a vote.
+    pub voting_ends: BlockNumber,
+    /// The block until which the jurors of the current round can reveal their vote.
+    pub reveal_ends: BlockNumber,
+    /// The block at which the market is resolved if the current round is not appealed.
+    pub appeal_ends: BlockNumber,
+}
@@ -25,59 +25,67 @@
 
 extern crate alloc;
 
+mod appeal;
 mod benchmarks;
+mod court_info;
 mod court_pallet_api;
 mod juror;
 mod juror_status;
 pub mod migrations;
 mod mock;
 mod tests;
+mod vote;
 pub mod weights;
 
+pub use appeal::Appeal;
+pub use court_info::CourtInfo;
 pub use court_pallet_api::CourtPalletApi;
 pub use juror::Juror;
 pub use juror_status::JurorStatus;
 pub use pallet::*;
+pub use vote::Vote;
 
 #[frame_support::pallet]
 mod pallet {
-    use crate::{weights::WeightInfoZeitgeist, CourtPalletApi, Juror, JurorStatus};
-    use alloc::{
-        collections::{BTreeMap, BTreeSet},
-        vec::Vec,
+    use crate::{
+        weights::WeightInfoZeitgeist, Appeal, CourtInfo, CourtPalletApi, Juror, JurorStatus, Vote,
     };
-    use arrayvec::ArrayVec;
+    use alloc::{collections::BTreeMap, vec::Vec};
     use core::marker::PhantomData;
     use frame_support::{
         dispatch::DispatchResult,
         ensure,
-        pallet_prelude::{CountedStorageMap, StorageDoubleMap, StorageValue, ValueQuery},
+        pallet_prelude::{
+            CountedStorageMap, StorageDoubleMap, StorageMap, StorageValue, ValueQuery,
+        },
         traits::{
             BalanceStatus, Currency, Get, Hooks, IsType, NamedReservableCurrency, Randomness,
-            StorageVersion,
+            ReservableCurrency, StorageVersion,
         },
-        Blake2_128Concat, PalletId,
+        transactional, Blake2_128Concat, BoundedVec, PalletId,
     };
     use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-    use rand::{rngs::StdRng, seq::SliceRandom, RngCore, SeedableRng};
+    use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
     use sp_runtime::{
-        traits::{AccountIdConversion, CheckedDiv, Saturating},
-        ArithmeticError, DispatchError, SaturatedConversion,
+        traits::{AccountIdConversion, CheckedDiv, Hash, Saturating, Zero},
+        DispatchError, PerThing, Perbill, SaturatedConversion,
     };
     use zeitgeist_primitives::{
         traits::{DisputeApi, DisputeResolutionApi},
-        types::{Asset, Market, MarketDispute, MarketDisputeMechanism, OutcomeReport},
+        types::{
+            Asset, Market, MarketDispute, MarketDisputeMechanism, MarketStatus, OutcomeReport,
+        },
     };
     use zrml_market_commons::MarketCommonsPalletApi;
 
-    // Number of jurors for an initial market dispute
+    // The reserve ID of delegations, which are kept apart from juror stakes and from funds the
+    // delegator has reserved for anything else, so that slashing a delegation can't touch them.
+    const DELEGATION_RESERVE_ID: [u8; 8] = *b"zge/cdlg";
+    // Number of jurors for the first round of a court case. Each appeal round doubles the number
+    // of jurors plus one, i.e. 3, 7, 15, 31, ...
     const INITIAL_JURORS_NUM: usize = 3;
-    const MAX_RANDOM_JURORS: usize = 13;
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
-    // Weight used to increase the number of jurors for subsequent disputes
-    // of the same market
-    const SUBSEQUENT_JURORS_FACTOR: usize = 2;
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
     // Divides the reserved juror balance to calculate the slash amount. `5` here
     // means that the output value will be 20% of the dividend.
     const TARDY_PUNISHMENT_DIVISOR: u8 = 5;
@@ -89,6 +97,15 @@ mod pallet {
     pub(crate) type MarketIdOf<T> =
         <<T as Config>::MarketCommons as MarketCommonsPalletApi>::MarketId;
     pub(crate) type MomentOf<T> = <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Moment;
+    pub(crate) type AppealOf<T> = Appeal<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+    pub(crate) type CourtOf<T> = CourtInfo<
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+        BoundedVec<
+            (<T as frame_system::Config>::AccountId, BalanceOf<T>),
+            <T as Config>::MaxJurors,
+        >,
+    >;
     pub(crate) type MarketOf<T> = Market<
         <T as frame_system::Config>::AccountId,
         BalanceOf<T>,
@@ -99,26 +116,180 @@ mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Claim the rewards the caller earned as juror or delegator.
+        //
+        // MARK(non-transactional): Once `deposit_into_existing` is successful, `remove` won't
+        // fail.
+        #[pallet::weight(T::WeightInfo::claim_rewards())]
+        pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let reward = Rewards::<T>::get(&who);
+            ensure!(!reward.is_zero(), Error::<T>::NoRewards);
+            let _ = CurrencyOf::<T>::deposit_into_existing(&who, reward)?;
+            Rewards::<T>::remove(&who);
+            Self::deposit_event(Event::RewardsClaimed(who, reward));
+            Ok(())
+        }
+
+        /// Back `juror` with `amount` of the caller's funds.
+        ///
+        /// The delegated funds increase the probability of the juror being selected for court
+        /// cases. Delegators share the rewards and slashes of the juror in proportion to their
+        /// delegation. Delegating to a juror again increases the delegation. A juror can have
+        /// at most `MaxDelegators` delegators.
+        ///
+        /// Fails if the juror was selected for a court case which is not yet settled, as rewards
+        /// and slashes are split by the delegations at the time the court case is settled.
+        //
+        // MARK(non-transactional): Once `reserve_named` is successful, the storage writes won't
+        // fail.
+        #[pallet::weight(T::WeightInfo::delegate())]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            juror: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who != juror, Error::<T>::CannotDelegateToSelf);
+            ensure!(!amount.is_zero(), Error::<T>::ZeroDelegation);
+            let _ = Self::juror(&juror)?;
+            ensure!(!ExitRequests::<T>::contains_key(&juror), Error::<T>::JurorIsExiting);
+            ensure!(!Self::has_active_cases(&juror), Error::<T>::JurorHasActiveCases);
+            let is_new_delegator = !Delegations::<T>::contains_key(&juror, &who);
+            let delegators = DelegatorCounts::<T>::get(&juror);
+            ensure!(
+                !is_new_delegator || delegators < T::MaxDelegators::get(),
+                Error::<T>::MaxDelegatorsReached
+            );
+            CurrencyOf::<T>::reserve_named(&Self::delegation_reserve_id(), &who, amount)?;
+            if is_new_delegator {
+                DelegatorCounts::<T>::insert(&juror, delegators.saturating_add(1));
+            }
+            Delegations::<T>::mutate(&juror, &who, |delegation| {
+                *delegation = delegation.saturating_add(amount);
+            });
+            DelegatedStakes::<T>::mutate(&juror, |stake| *stake = stake.saturating_add(amount));
+            Self::deposit_event(Event::Delegated(who, juror, amount));
+            Ok(())
+        }
+
+        /// Leave the court after the unbonding period which was started using
+        /// `prepare_exit_court` is over.
+        ///
+        /// Fails if the juror was selected for a court case which is not yet settled. All
+        /// delegations to the juror are returned to the delegators.
+        //
         // MARK(non-transactional): `remove_juror_from_all_courts_of_all_markets` is infallible.
-        #[pallet::weight(T::WeightInfo::exit_court())]
+        #[pallet::weight(T::WeightInfo::exit_court(T::MaxDelegators::get()))]
         pub fn exit_court(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
             let juror = Self::juror(&who)?;
+            let unbonded_at =
+                ExitRequests::<T>::get(&who).ok_or(Error::<T>::JurorHasNotPreparedExit)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(now >= unbonded_at, Error::<T>::UnbondingPeriodNotOver);
+            ensure!(!Self::has_active_cases(&who), Error::<T>::JurorHasActiveCases);
             Self::remove_juror_from_all_courts_of_all_markets(&who);
             Self::deposit_event(Event::ExitedJuror(who, juror));
             Ok(())
         }
 
+        /// Appeal the outcome of the current round of a court case.
+        ///
+        /// The current round is settled and a new round with more jurors is started. The
+        /// appellant has to reserve a bond which doubles with every round. The bond is returned
+        /// if the market resolves to an outcome other than the one appealed against and slashed
+        /// otherwise.
+        ///
+        /// Only possible within `CourtAppealDuration` blocks after the end of the reveal period.
+        #[pallet::weight(T::WeightInfo::appeal())]
+        #[transactional]
+        pub fn appeal(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(
+                market.dispute_mechanism == MarketDisputeMechanism::Court,
+                Error::<T>::MarketDoesNotHaveCourtMechanism
+            );
+            ensure!(market.status == MarketStatus::Disputed, Error::<T>::MarketIsNotDisputed);
+            let court = Courts::<T>::get(&market_id).ok_or(Error::<T>::CourtCaseNotFound)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(now > court.reveal_ends, Error::<T>::AppealPeriodNotStarted);
+            ensure!(now < court.appeal_ends, Error::<T>::AppealPeriodExpired);
+            ensure!(court.round < T::MaxAppeals::get(), Error::<T>::MaxAppealsReached);
+
+            let bond = Self::appeal_bond(court.round);
+            // Appeal bonds aren't reserved using the named reserve of the court, which only holds
+            // juror stakes.
+            CurrencyOf::<T>::reserve(&who, bond)?;
+            let outcome = Self::settle_round(&market_id, &court)?;
+            Appeals::<T>::try_mutate(&market_id, |appeals| {
+                appeals
+                    .try_push(Appeal { appellant: who.clone(), bond, outcome })
+                    .map_err(|_| Error::<T>::MaxAppealsReached)
+            })?;
+
+            T::DisputeResolution::remove_auto_resolve(&market_id, court.appeal_ends);
+            let next_round = court.round.saturating_add(1);
+            Self::select_jurors(&market_id, next_round)?;
+
+            Self::deposit_event(Event::Appealed(who, market_id, next_round));
+            Ok(())
+        }
+
+        /// Announce leaving the court.
+        ///
+        /// The juror won't be selected for new court cases, but remains on the court cases the
+        /// juror is already selected for. The juror can leave the court using `exit_court` after
+        /// `JurorUnbondingPeriod` blocks.
+        //
+        // MARK(non-transactional): No fallible storage operation is performed.
+        #[pallet::weight(T::WeightInfo::prepare_exit_court())]
+        pub fn prepare_exit_court(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let _ = Self::juror(&who)?;
+            ensure!(!ExitRequests::<T>::contains_key(&who), Error::<T>::JurorIsExiting);
+            let now = <frame_system::Pallet<T>>::block_number();
+            let unbonded_at = now.saturating_add(T::JurorUnbondingPeriod::get());
+            ExitRequests::<T>::insert(&who, unbonded_at);
+            Self::deposit_event(Event::JurorPreparedExit(who, unbonded_at));
+            Ok(())
+        }
+
+        /// Remove the caller's delegation to `juror`.
+        ///
+        /// Fails if the juror was selected for a court case which is not yet settled.
+        //
+        // MARK(non-transactional): No fallible storage operation is performed.
+        #[pallet::weight(T::WeightInfo::undelegate())]
+        pub fn undelegate(origin: OriginFor<T>, juror: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Delegations::<T>::contains_key(&juror, &who), Error::<T>::DelegationNotFound);
+            let amount = Delegations::<T>::get(&juror, &who);
+            ensure!(!Self::has_active_cases(&juror), Error::<T>::JurorHasActiveCases);
+            CurrencyOf::<T>::unreserve_named(&Self::delegation_reserve_id(), &who, amount);
+            Delegations::<T>::remove(&juror, &who);
+            DelegatorCounts::<T>::mutate(&juror, |count| *count = count.saturating_sub(1));
+            DelegatedStakes::<T>::mutate(&juror, |stake| *stake = stake.saturating_sub(amount));
+            Self::deposit_event(Event::Undelegated(who, juror, amount));
+            Ok(())
+        }
+
+        /// Join the court as a juror by staking `stake`.
+        ///
+        /// The probability of being selected for a court case is proportional to the stake.
+        //
         // MARK(non-transactional): Once `reserve_named` is successful, `insert` won't fail.
         #[pallet::weight(T::WeightInfo::join_court())]
-        pub fn join_court(origin: OriginFor<T>) -> DispatchResult {
+        pub fn join_court(origin: OriginFor<T>, stake: BalanceOf<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
             if Jurors::<T>::get(&who).is_some() {
                 return Err(Error::<T>::JurorAlreadyExists.into());
             }
-            let jurors_num = Jurors::<T>::count() as usize;
-            let jurors_num_plus_one = jurors_num.checked_add(1).ok_or(ArithmeticError::Overflow)?;
-            let stake = Self::current_required_stake(jurors_num_plus_one);
+            ensure!(stake >= T::MinJurorStake::get(), Error::<T>::BelowMinJurorStake);
             CurrencyOf::<T>::reserve_named(&Self::reserve_id(), &who, stake)?;
             let juror = Juror { status: JurorStatus::Ok };
             Jurors::<T>::insert(&who, juror.clone());
@@ -126,32 +297,88 @@ mod pallet {
             Ok(())
         }
 
+        /// Commit to a vote on the outcome of a disputed market.
+        ///
+        /// The commitment is the hash of `(juror, outcome, salt)` and must be revealed using
+        /// `reveal_vote` after the voting period has ended. Committing again before the end of
+        /// the voting period replaces the previous commitment.
+        //
         // MARK(non-transactional): No fallible storage operation is performed.
-        #[pallet::weight(T::WeightInfo::vote())]
-        pub fn vote(
+        #[pallet::weight(T::WeightInfo::commit_vote())]
+        pub fn commit_vote(
             origin: OriginFor<T>,
             #[pallet::compact] market_id: MarketIdOf<T>,
-            outcome: OutcomeReport,
+            commitment: T::Hash,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             if Jurors::<T>::get(&who).is_none() {
                 return Err(Error::<T>::OnlyJurorsCanVote.into());
             }
+            let court = Self::court_of_requested_juror(&market_id, &who)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(now <= court.voting_ends, Error::<T>::VotingPeriodExpired);
+            Votes::<T>::insert(&market_id, &who, Vote::Secret { commitment });
+            Self::deposit_event(Event::JurorCommittedVote(who, market_id));
+            Ok(())
+        }
+
+        /// Reveal a vote previously committed using `commit_vote`.
+        ///
+        /// Only possible within `CourtRevealDuration` blocks after the end of the voting period.
+        /// Votes which are not revealed are not counted and the juror is treated as tardy.
+        //
+        // MARK(non-transactional): No fallible storage operation is performed.
+        #[pallet::weight(T::WeightInfo::reveal_vote())]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            outcome: OutcomeReport,
+            salt: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let court = Self::court_of_requested_juror(&market_id, &who)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(now > court.voting_ends, Error::<T>::RevealPeriodNotStarted);
+            ensure!(now <= court.reveal_ends, Error::<T>::RevealPeriodExpired);
+            let commitment = match Votes::<T>::get(&market_id, &who) {
+                Some(Vote::Secret { commitment }) => commitment,
+                Some(Vote::Revealed { .. }) => return Err(Error::<T>::VoteAlreadyRevealed.into()),
+                None => return Err(Error::<T>::NoCommittedVote.into()),
+            };
+            ensure!(
+                T::Hashing::hash_of(&(&who, &outcome, &salt)) == commitment,
+                Error::<T>::CommitmentMismatch
+            );
             Votes::<T>::insert(
-                market_id,
-                who,
-                (<frame_system::Pallet<T>>::block_number(), outcome),
+                &market_id,
+                &who,
+                Vote::Revealed { commitment, outcome: outcome.clone(), salt },
             );
+            Self::deposit_event(Event::JurorRevealedVote(who, market_id, outcome));
             Ok(())
         }
     }
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        /// The bond required to appeal the first round of a court case. The bond doubles with
+        /// every subsequent round.
+        #[pallet::constant]
+        type AppealBond: Get<BalanceOf<Self>>;
+
+        /// Block duration in which the outcome of a round can be appealed after the end of the
+        /// reveal period.
+        #[pallet::constant]
+        type CourtAppealDuration: Get<Self::BlockNumber>;
+
         /// Block duration to cast a vote on an outcome.
         #[pallet::constant]
         type CourtCaseDuration: Get<Self::BlockNumber>;
 
+        /// Block duration to reveal a committed vote after the end of the court case.
+        #[pallet::constant]
+        type CourtRevealDuration: Get<Self::BlockNumber>;
+
         type DisputeResolution: DisputeResolutionApi<
             AccountId = Self::AccountId,
             BlockNumber = Self::BlockNumber,
@@ -168,16 +395,41 @@ mod pallet {
             BlockNumber = Self::BlockNumber,
         >;
 
-        /// Identifier of this pallet
+        /// Block duration a juror has to wait after `prepare_exit_court` to leave the court.
         #[pallet::constant]
-        type PalletId: Get<PalletId>;
+        type JurorUnbondingPeriod: Get<Self::BlockNumber>;
 
-        /// Randomness source
-        type Random: Randomness<Self::Hash, Self::BlockNumber>;
+        /// The maximum number of appeals of a court case.
+        #[pallet::constant]
+        type MaxAppeals: Get<u32>;
 
-        /// Weight used to calculate the necessary staking amount to become a juror
+        /// The maximum number of unsettled court cases a juror can be selected for. Jurors who
+        /// reached the limit are not drawn for new court cases.
+        #[pallet::constant]
+        type MaxCasesPerJuror: Get<u32>;
+
+        /// The maximum number of delegators of a juror.
+        #[pallet::constant]
+        type MaxDelegators: Get<u32>;
+
+        /// The maximum number of jurors of a round. Should be at least `2^(MaxAppeals + 2) - 1`,
+        /// the number of jurors of the last possible round.
+        #[pallet::constant]
+        type MaxJurors: Get<u32>;
+
+        /// The minimum stake a juror has to reserve to join the court.
+        #[pallet::constant]
+        type MinJurorStake: Get<BalanceOf<Self>>;
+
+        /// Identifier of this pallet
         #[pallet::constant]
-        type StakeWeight: Get<BalanceOf<Self>>;
+        type PalletId: Get<PalletId>;
+
+        /// Randomness source used to draw jurors. Must not be biasable by block producers,
+        /// which is why runtimes should use a VRF-based source. Returns `None` if no such
+        /// randomness is available in the current block, in which case drawing jurors fails with
+        /// `RandomnessUnavailable` and has to be retried in a later block.
+        type Random: Randomness<Option<Self::Hash>, Self::BlockNumber>;
 
         /// Slashed funds are send to the treasury
         #[pallet::constant]
@@ -189,16 +441,64 @@ mod pallet {
 
     #[pallet::error]
     pub enum Error<T> {
+        /// The appeal period of the current round has not started yet.
+        AppealPeriodNotStarted,
+        /// The appeal period of the current round is over.
+        AppealPeriodExpired,
+        /// The stake is below `MinJurorStake`.
+        BelowMinJurorStake,
+        /// Jurors can't delegate to themselves.
+        CannotDelegateToSelf,
+        /// The revealed outcome and salt don't match the committed vote.
+        CommitmentMismatch,
+        /// The market already has a court case. Use `appeal` to start a new round.
+        CourtCaseAlreadyExists,
+        /// The market does not have a court case.
+        CourtCaseNotFound,
+        /// The caller did not delegate to the juror.
+        DelegationNotFound,
         /// It is not possible to insert a Juror that is already stored
         JurorAlreadyExists,
         /// An account id does not exist on the jurors storage.
         JurorDoesNotExists,
+        /// The juror was selected for a court case which is not yet settled.
+        JurorHasActiveCases,
+        /// The juror has to call `prepare_exit_court` before leaving the court.
+        JurorHasNotPreparedExit,
+        /// The juror is about to leave the court.
+        JurorIsExiting,
+        /// The juror was not requested to vote on this market.
+        JurorNotRequested,
         /// On dispute or resolution, someone tried to pass a non-court market type
         MarketDoesNotHaveCourtMechanism,
+        /// The market is not disputed.
+        MarketIsNotDisputed,
+        /// The maximum number of appeals of a court case has been reached.
+        MaxAppealsReached,
+        /// The juror already has `MaxDelegators` delegators.
+        MaxDelegatorsReached,
+        /// The juror did not commit to a vote on this market.
+        NoCommittedVote,
+        /// The caller has no rewards to claim.
+        NoRewards,
         /// No-one voted on an outcome to resolve a market
         NoVotes,
         /// Forbids voting of unknown accounts
         OnlyJurorsCanVote,
+        /// There is no randomness to draw jurors in this block. Try again in a later block.
+        RandomnessUnavailable,
+        /// The reveal period of the court case is over.
+        RevealPeriodExpired,
+        /// Votes can only be revealed after the end of the voting period.
+        RevealPeriodNotStarted,
+        /// The juror already revealed the vote.
+        VoteAlreadyRevealed,
+        /// The unbonding period of the juror is not over yet.
+        UnbondingPeriodNotOver,
+        /// The voting period of the court case is over.
+        VotingPeriodExpired,
+        /// The delegated amount must not be zero.
+        ZeroDelegation,
     }
 
     #[pallet::event]
@@ -207,8 +507,22 @@ mod pallet {
     where
         T: Config,
     {
+        /// The current round of a court case was appealed. \[appellant, market_id, new_round\]
+        Appealed(T::AccountId, MarketIdOf<T>, u32),
+        /// Funds were delegated to a juror. \[delegator, juror, amount\]
+        Delegated(T::AccountId, T::AccountId, BalanceOf<T>),
         ExitedJuror(T::AccountId, Juror),
         JoinedJuror(T::AccountId, Juror),
+        /// A juror committed to a vote on a market. \[juror, market_id\]
+        JurorCommittedVote(T::AccountId, MarketIdOf<T>),
+        /// A juror announced leaving the court. \[juror, unbonded_at\]
+        JurorPreparedExit(T::AccountId, T::BlockNumber),
+        /// A juror revealed a vote on a market. \[juror, market_id, outcome\]
+        JurorRevealedVote(T::AccountId, MarketIdOf<T>, OutcomeReport),
+        /// Rewards were claimed. \[who, amount\]
+        RewardsClaimed(T::AccountId, BalanceOf<T>),
+        /// A delegation to a juror was removed. \[delegator, juror, amount\]
+        Undelegated(T::AccountId, T::AccountId, BalanceOf<T>),
     }
 
     #[pallet::hooks]
@@ -222,20 +536,53 @@ mod pallet {
     where
         T: Config,
     {
-        // Returns an unique random subset of `jurors` with length `len`.
+        // The bond required to appeal the round with index `round`.
+        fn appeal_bond(round: u32) -> BalanceOf<T> {
+            let factor: BalanceOf<T> = 2u128.saturating_pow(round).saturated_into();
+            T::AppealBond::get().saturating_mul(factor)
+        }
+
+        // Returns an unique random subset of `jurors` with length `len`. Each juror is drawn
+        // without replacement with a probability proportional to its stake (including
+        // delegations). Jurors without stake are never drawn.
         //
-        // If `len` is greater than the length of `jurors`, then `len` will be capped.
-        pub(crate) fn random_jurors<'a, 'b, R>(
-            jurors: &'a [(T::AccountId, Juror)],
+        // If `len` is greater than the number of jurors with stake, then `len` will be capped.
+        pub(crate) fn random_jurors<'a, R>(
+            jurors: &'a [(T::AccountId, Juror, BalanceOf<T>)],
             len: usize,
             rng: &mut R,
-        ) -> ArrayVec<&'b (T::AccountId, Juror), MAX_RANDOM_JURORS>
+        ) -> Vec<&'a (T::AccountId, Juror, BalanceOf<T>)>
         where
-            'a: 'b,
             R: RngCore,
         {
-            let actual_len = jurors.len().min(len);
-            jurors.choose_multiple(rng, actual_len).collect()
+            let mut remaining: Vec<_> = jurors.iter().filter(|el| !el.2.is_zero()).collect();
+            let mut total_stake =
+                remaining.iter().fold(0u128, |acc, el| acc.saturating_add(el.2.saturated_into()));
+            let actual_len = remaining.len().min(len);
+            let mut selected = Vec::with_capacity(actual_len);
+            for _ in 0..actual_len {
+                if total_stake == 0 {
+                    break;
+                }
+                let mut threshold = rng.gen_range(0..total_stake);
+                let idx = remaining.iter().position(|el| {
+                    let stake: u128 = el.2.saturated_into();
+                    if threshold < stake {
+                        true
+                    } else {
+                        threshold = threshold.saturating_sub(stake);
+                        false
+                    }
+                });
+                let juror = if let Some(idx) = idx {
+                    remaining.swap_remove(idx)
+                } else {
+                    break;
+                };
+                total_stake = total_stake.saturating_sub(juror.2.saturated_into());
+                selected.push(juror);
+            }
+            selected
         }
 
         /// The reserve ID of the court pallet.
@@ -244,20 +591,26 @@ mod pallet {
             T::PalletId::get().0
         }
 
+        /// The reserve ID of the delegations to jurors.
+        #[inline]
+        pub fn delegation_reserve_id() -> [u8; 8] {
+            DELEGATION_RESERVE_ID
+        }
+
         // Returns a pseudo random number generator implementation based on the seed
         // provided by the `Config::Random` type and the `JurorsSelectionNonce` storage.
-        pub(crate) fn rng() -> impl RngCore {
-            let nonce = <JurorsSelectionNonce<T>>::mutate(|n| {
-                let rslt = *n;
-                *n = n.wrapping_add(1);
-                rslt
-            });
+        //
+        // Fails if `Config::Random` has no randomness in the current block.
+        pub(crate) fn rng() -> Result<impl RngCore, DispatchError> {
+            let nonce = <JurorsSelectionNonce<T>>::get();
+            let (random_hash_opt, _) = T::Random::random(&nonce.to_le_bytes());
+            let random_hash = random_hash_opt.ok_or(Error::<T>::RandomnessUnavailable)?;
+            <JurorsSelectionNonce<T>>::put(nonce.wrapping_add(1));
             let mut seed = [0; 32];
-            let (random_hash, _) = T::Random::random(&nonce.to_le_bytes());
             for (byte, el) in random_hash.as_ref().iter().copied().zip(seed.iter_mut()) {
                 *el = byte
             }
-            StdRng::from_seed(seed)
+            Ok(StdRng::from_seed(seed))
         }
 
         // Used to avoid code duplications.
@@ -273,44 +626,41 @@ mod pallet {
             T::TreasuryPalletId::get().into_account_truncating()
         }
 
-        // No-one can stake more than BalanceOf::<T>::max(), therefore, this function saturates
-        // arithmetic operations.
-        fn current_required_stake(jurors_num: usize) -> BalanceOf<T> {
-            let jurors_len: BalanceOf<T> = jurors_num.saturated_into();
-            T::StakeWeight::get().saturating_mul(jurors_len)
-        }
-
         // Retrieves a juror from the storage
         fn juror(account_id: &T::AccountId) -> Result<Juror, DispatchError> {
             Jurors::<T>::get(account_id).ok_or_else(|| Error::<T>::JurorDoesNotExists.into())
         }
 
+        // Retrieves the court case of `market_id` if `juror` was selected for its current round.
+        fn court_of_requested_juror(
+            market_id: &MarketIdOf<T>,
+            juror: &T::AccountId,
+        ) -> Result<CourtOf<T>, DispatchError> {
+            Courts::<T>::get(market_id)
+                .filter(|court| court.jurors.iter().any(|(ai, _)| ai == juror))
+                .ok_or_else(|| Error::<T>::JurorNotRequested.into())
+        }
+
         // # Manages tardy jurors and returns valid winners and valid losers.
         //
         // ## Management
         //
-        // * Jurors that didn't vote within `CourtCaseDuration` or didn't vote at all are
-        // placed as tardy.
+        // * Jurors that didn't commit to a vote within `CourtCaseDuration` or didn't reveal their
+        // vote within `CourtRevealDuration` are placed as tardy.
         //
-        // * Slashes 20% of staked funds and removes tardy jurors that didn't vote or voted
-        // after the maximum allowed block.
+        // * Slashes 20% of staked funds and removes tardy jurors that didn't vote or didn't
+        // reveal their vote.
         //
         // ## Returned list of accounts
         //
-        // All new and old tardy jurors, excluding the ones that voted within `CourtCaseDuration`,
-        // are removed from the list of accounts that will be slashed to reward winners. Already
-        // tardy jurors that voted again on the second most voted outcome are also removed from the
-        // same list.
+        // All new and old tardy jurors, excluding the ones that revealed their vote, are removed
+        // from the list of accounts that will be slashed to reward winners. Already tardy jurors
+        // that voted again on the second most voted outcome are also removed from the same list.
         //
         // In other words, does not yield slashed accounts, winners of the losing side,
-        // accounts that didn't vote or accounts that voted after the maximum allowed block
+        // accounts that didn't vote or accounts that didn't reveal their vote
         fn manage_tardy_jurors<'a, 'b, F>(
-            requested_jurors: &'a [(
-                T::AccountId,
-                Juror,
-                T::BlockNumber,
-                Option<&(T::BlockNumber, OutcomeReport)>,
-            )],
+            requested_jurors: &'a [(T::AccountId, Juror, Option<&'a Vote<T::Hash>>)],
             mut cb: F,
         ) -> Result<Vec<(&'b T::AccountId, &'b OutcomeReport)>, DispatchError>
         where
@@ -333,38 +683,31 @@ mod pallet {
                     slash,
                     BalanceStatus::Free,
                 )?;
+                let _ = Self::slash_delegations(
+                    ai,
+                    TARDY_PUNISHMENT_DIVISOR,
+                    Some(&treasury_account_id),
+                )?;
                 Self::remove_juror_from_all_courts_of_all_markets(ai);
                 Ok::<_, DispatchError>(())
             };
 
-            for (ai, juror, max_block, vote_opt) in requested_jurors {
-                if let Some((block, outcome)) = vote_opt {
-                    let vote_is_expired = block > max_block;
-                    if vote_is_expired {
-                        // Tardy juror voted after maximum allowed block. Slash
-                        if let JurorStatus::Tardy = juror.status {
-                            slash_and_remove_juror(ai)?;
-                        }
-                        // Ordinary juror voted after maximum allowed block. Set as tardy
-                        else {
+            for (ai, juror, vote_opt) in requested_jurors {
+                if let Some(Vote::Revealed { outcome, .. }) = vote_opt {
+                    let has_voted_on_the_second_most_outcome = cb(outcome);
+                    if has_voted_on_the_second_most_outcome {
+                        // Don't set already tardy juror as tardy again
+                        if JurorStatus::Tardy != juror.status {
                             Self::set_stored_juror_as_tardy(ai)?;
                         }
                     } else {
-                        let has_voted_on_the_second_most_outcome = cb(outcome);
-                        if has_voted_on_the_second_most_outcome {
-                            // Don't set already tardy juror as tardy again
-                            if JurorStatus::Tardy != juror.status {
-                                Self::set_stored_juror_as_tardy(ai)?;
-                            }
-                        } else {
-                            valid_winners_and_losers.push((ai, outcome));
-                        }
+                        valid_winners_and_losers.push((ai, outcome));
                     }
-                // Tardy juror didn't vote. Slash
+                // Tardy juror didn't vote or didn't reveal the vote. Slash
                 } else if let JurorStatus::Tardy = juror.status {
                     slash_and_remove_juror(ai)?;
                 }
-                // Ordinary juror didn't vote. Set as tardy
+                // Ordinary juror didn't vote or didn't reveal the vote. Set as tardy
                 else {
                     Self::set_stored_juror_as_tardy(ai)?;
                 }
@@ -388,13 +731,116 @@ mod pallet {
             })
         }
 
-        // Calculates the necessary number of jurors depending on the number of market disputes.
+        // Calculates the necessary number of jurors of the round with index `round`.
         //
         // Result is capped to `usize::MAX` or in other words, capped to a very, very, very
         // high number of jurors.
-        fn necessary_jurors_num(disputes: &[MarketDispute<T::AccountId, T::BlockNumber>]) -> usize {
-            let len = disputes.len();
-            INITIAL_JURORS_NUM.saturating_add(SUBSEQUENT_JURORS_FACTOR.saturating_mul(len))
+        pub(crate) fn necessary_jurors_num(round: u32) -> usize {
+            let factor = 2usize.saturating_pow(round);
+            INITIAL_JURORS_NUM.saturating_add(1).saturating_mul(factor).saturating_sub(1)
+        }
+
+        // Draws the jurors of round `round` of the court case of `market_id`, stores the new
+        // state of the court case, adds the court case to the cases of the drawn jurors and
+        // schedules the resolution of the market.
+        fn select_jurors(market_id: &MarketIdOf<T>, round: u32) -> DispatchResult {
+            let mut rng = Self::rng()?;
+            let max_cases: usize = T::MaxCasesPerJuror::get().saturated_into();
+            let jurors: Vec<_> = Jurors::<T>::iter()
+                .filter(|(ai, _)| {
+                    !ExitRequests::<T>::contains_key(ai)
+                        && JurorCases::<T>::decode_len(ai).unwrap_or(0) < max_cases
+                })
+                .map(|(ai, juror)| {
+                    let stake = Self::total_stake(&ai);
+                    (ai, juror, stake)
+                })
+                .collect();
+            let jurors_num =
+                Self::necessary_jurors_num(round).min(T::MaxJurors::get().saturated_into());
+            let random_jurors: Vec<_> = Self::random_jurors(&jurors, jurors_num, &mut rng)
+                .into_iter()
+                .map(|(ai, _, stake)| (ai.clone(), *stake))
+                .collect();
+            let total_stake = random_jurors
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |acc, (_, stake)| acc.saturating_add(*stake));
+            for (ai, _) in &random_jurors {
+                JurorCases::<T>::try_mutate(ai, |cases_opt| {
+                    cases_opt
+                        .get_or_insert_with(Default::default)
+                        .try_push(*market_id)
+                        .map_err(|_| DispatchError::Other("Too many cases"))
+                })?;
+            }
+            let random_jurors = BoundedVec::try_from(random_jurors)
+                .map_err(|_| DispatchError::Other("Too many jurors"))?;
+            let curr_block_num = <frame_system::Pallet<T>>::block_number();
+            let voting_ends = curr_block_num.saturating_add(T::CourtCaseDuration::get());
+            let reveal_ends = voting_ends.saturating_add(T::CourtRevealDuration::get());
+            let appeal_ends = reveal_ends.saturating_add(T::CourtAppealDuration::get());
+            let court = CourtInfo {
+                round,
+                jurors: random_jurors,
+                total_stake,
+                voting_ends,
+                reveal_ends,
+                appeal_ends,
+            };
+            Courts::<T>::insert(market_id, court);
+            T::DisputeResolution::add_auto_resolve(market_id, appeal_ends)?;
+            Ok(())
+        }
+
+        // Rewards and slashes the jurors of the current round of `court`, the court case of
+        // `market_id`, removes the round's votes and the court case from the cases of the round's
+        // jurors and returns the winning outcome of the round.
+        fn settle_round(
+            market_id: &MarketIdOf<T>,
+            court: &CourtOf<T>,
+        ) -> Result<OutcomeReport, DispatchError> {
+            let votes: Vec<_> = Votes::<T>::iter_prefix(market_id).collect();
+            let requested_jurors: Vec<_> = court
+                .jurors
+                .iter()
+                .map(|(juror_id, _)| {
+                    let juror = Self::juror(juror_id)?;
+                    let juror_id = juror_id.clone();
+                    let vote_opt = votes.iter().find(|el| el.0 == juror_id).map(|el| &el.1);
+                    Ok((juror_id, juror, vote_opt))
+                })
+                .collect::<Result<_, DispatchError>>()?;
+            let (first, second_opt) = Self::two_best_outcomes(&votes)?;
+            let valid_winners_and_losers = if let Some(second) = second_opt {
+                Self::manage_tardy_jurors(&requested_jurors, |outcome| outcome == &second)?
+            } else {
+                Self::manage_tardy_jurors(&requested_jurors, |_| false)?
+            };
+            Self::slash_losers_to_award_winners(&valid_winners_and_losers, &first)?;
+            let _ = Votes::<T>::clear_prefix(market_id, u32::max_value(), None);
+            for (ai, _) in court.jurors.iter() {
+                Self::remove_case_of_juror(ai, market_id);
+            }
+            Ok(first)
+        }
+
+        // Returns the bonds of appeals against the final outcome of a court case and slashes the
+        // bonds of appeals against rounds which decided on the final outcome.
+        fn settle_appeals(market_id: &MarketIdOf<T>, outcome: &OutcomeReport) -> DispatchResult {
+            let treasury_account_id = Self::treasury_account_id();
+            for appeal in Appeals::<T>::take(market_id) {
+                if &appeal.outcome == outcome {
+                    let _ = CurrencyOf::<T>::repatriate_reserved(
+                        &appeal.appellant,
+                        &treasury_account_id,
+                        appeal.bond,
+                        BalanceStatus::Free,
+                    )?;
+                } else {
+                    CurrencyOf::<T>::unreserve(&appeal.appellant, appeal.bond);
+                }
+            }
+            Ok(())
         }
 
         // Every juror that not voted on the first or second most voted outcome are slashed.
@@ -416,7 +862,9 @@ mod pallet {
                         .checked_div(&BalanceOf::<T>::from(2u8))
                         .ok_or(DispatchError::Other("Zero division"))?;
                     CurrencyOf::<T>::slash_reserved_named(&Self::reserve_id(), jai, slash);
-                    total_incentives = total_incentives.saturating_add(slash);
+                    let delegations_slash = Self::slash_delegations(jai, 2, None)?;
+                    total_incentives =
+                        total_incentives.saturating_add(slash).saturating_add(delegations_slash);
                 }
             }
 
@@ -430,20 +878,100 @@ mod pallet {
 
             for (jai, outcome) in valid_winners_and_losers {
                 if outcome == &winner_outcome {
-                    CurrencyOf::<T>::deposit_into_existing(jai, individual_winner_incentive)?;
+                    Self::reward_juror_and_delegators(jai, individual_winner_incentive);
                 }
             }
 
             Ok(())
         }
 
-        // For market resolution based on the votes of a market
+        // Slashes `1 / divisor` of every delegation to `juror`. The slashed funds are transferred
+        // to `beneficiary` or burnt if there is none. Returns the total slashed amount.
+        fn slash_delegations(
+            juror: &T::AccountId,
+            divisor: u8,
+            beneficiary: Option<&T::AccountId>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let mut total_slash = BalanceOf::<T>::zero();
+            let delegations: Vec<_> = Delegations::<T>::iter_prefix(juror).collect();
+            for (delegator, amount) in delegations {
+                // Unsigned division will never overflow
+                let slash = amount
+                    .checked_div(&BalanceOf::<T>::from(divisor))
+                    .ok_or(DispatchError::Other("Zero division"))?;
+                if let Some(beneficiary) = beneficiary {
+                    let _ = CurrencyOf::<T>::repatriate_reserved_named(
+                        &Self::delegation_reserve_id(),
+                        &delegator,
+                        beneficiary,
+                        slash,
+                        BalanceStatus::Free,
+                    )?;
+                } else {
+                    let _ = CurrencyOf::<T>::slash_reserved_named(
+                        &Self::delegation_reserve_id(),
+                        &delegator,
+                        slash,
+                    );
+                }
+                Delegations::<T>::insert(juror, &delegator, amount.saturating_sub(slash));
+                total_slash = total_slash.saturating_add(slash);
+            }
+            DelegatedStakes::<T>::mutate(juror, |stake| *stake = stake.saturating_sub(total_slash));
+            Ok(total_slash)
+        }
+
+        // Splits `reward` between `juror` and the delegators of `juror` in proportion to their
+        // stakes. The rewards can be claimed using `claim_rewards`.
+        fn reward_juror_and_delegators(juror: &T::AccountId, reward: BalanceOf<T>) {
+            let total_stake = Self::total_stake(juror);
+            let mut remainder = reward;
+            for (delegator, amount) in Delegations::<T>::iter_prefix(juror) {
+                let share = Perbill::from_rational(amount, total_stake).mul_floor(reward);
+                Rewards::<T>::mutate(&delegator, |rewards| {
+                    *rewards = rewards.saturating_add(share)
+                });
+                remainder = remainder.saturating_sub(share);
+            }
+            Rewards::<T>::mutate(juror, |rewards| *rewards = rewards.saturating_add(remainder));
+        }
+
+        // The stake of `juror` including all delegations to `juror`.
+        pub(crate) fn total_stake(juror: &T::AccountId) -> BalanceOf<T> {
+            CurrencyOf::<T>::reserved_balance_named(&Self::reserve_id(), juror)
+                .saturating_add(DelegatedStakes::<T>::get(juror))
+        }
+
+        // Returns `true` if `juror` was selected for a court case which is not yet settled.
+        fn has_active_cases(juror: &T::AccountId) -> bool {
+            JurorCases::<T>::contains_key(juror)
+        }
+
+        // Removes `market_id` from the unsettled court cases of `juror`.
+        fn remove_case_of_juror(juror: &T::AccountId, market_id: &MarketIdOf<T>) {
+            JurorCases::<T>::mutate_exists(juror, |cases_opt| {
+                if let Some(cases) = cases_opt {
+                    cases.retain(|el| el != market_id);
+                    if cases.is_empty() {
+                        *cases_opt = None;
+                    }
+                }
+            });
+        }
+
+        // For market resolution based on the votes of a market. Votes which weren't revealed are
+        // ignored.
         fn two_best_outcomes(
-            votes: &[(T::AccountId, (T::BlockNumber, OutcomeReport))],
+            votes: &[(T::AccountId, Vote<T::Hash>)],
         ) -> Result<(OutcomeReport, Option<OutcomeReport>), DispatchError> {
             let mut scores = BTreeMap::<OutcomeReport, u32>::new();
 
-            for (_, (_, outcome_report)) in votes {
+            for (_, vote) in votes {
+                let outcome_report = if let Vote::Revealed { outcome, .. } = vote {
+                    outcome
+                } else {
+                    continue;
+                };
                 if let Some(el) = scores.get_mut(outcome_report) {
                     *el = el.saturating_add(1);
                 } else {
@@ -485,15 +1013,30 @@ mod pallet {
         // Obliterates all stored references of a juror un-reserving balances.
         fn remove_juror_from_all_courts_of_all_markets(ai: &T::AccountId) {
             CurrencyOf::<T>::unreserve_all_named(&Self::reserve_id(), ai);
-            Jurors::<T>::remove(ai);
-            let mut market_ids = BTreeSet::new();
-            market_ids.extend(RequestedJurors::<T>::iter().map(|el| el.0));
-            for market_id in &market_ids {
-                RequestedJurors::<T>::remove(market_id, ai);
+            for (delegator, amount) in Delegations::<T>::drain_prefix(ai) {
+                CurrencyOf::<T>::unreserve_named(
+                    &Self::delegation_reserve_id(),
+                    &delegator,
+                    amount,
+                );
             }
-            market_ids.clear();
-            market_ids.extend(Votes::<T>::iter().map(|el| el.0));
-            for market_id in &market_ids {
+            DelegatedStakes::<T>::remove(ai);
+            DelegatorCounts::<T>::remove(ai);
+            ExitRequests::<T>::remove(ai);
+            Jurors::<T>::remove(ai);
+            for market_id in JurorCases::<T>::take(ai).unwrap_or_default() {
+                Courts::<T>::mutate(market_id, |court_opt| {
+                    if let Some(court) = court_opt {
+                        court.jurors.retain(|(el, stake)| {
+                            if el == ai {
+                                court.total_stake = court.total_stake.saturating_sub(*stake);
+                                false
+                            } else {
+                                true
+                            }
+                        });
+                    }
+                });
                 Votes::<T>::remove(market_id, ai);
             }
         }
@@ -511,7 +1054,7 @@ mod pallet {
         type Origin = T::Origin;
 
         fn on_dispute(
-            disputes: &[MarketDispute<Self::AccountId, Self::BlockNumber>],
+            _: &[MarketDispute<Self::AccountId, Self::BlockNumber>],
             market_id: &Self::MarketId,
             market: &MarketOf<T>,
         ) -> DispatchResult {
@@ -519,16 +1062,8 @@ mod pallet {
                 market.dispute_mechanism == MarketDisputeMechanism::Court,
                 Error::<T>::MarketDoesNotHaveCourtMechanism
             );
-            let jurors: Vec<_> = Jurors::<T>::iter().collect();
-            let necessary_jurors_num = Self::necessary_jurors_num(disputes);
-            let mut rng = Self::rng();
-            let random_jurors = Self::random_jurors(&jurors, necessary_jurors_num, &mut rng);
-            let curr_block_num = <frame_system::Pallet<T>>::block_number();
-            let block_limit = curr_block_num.saturating_add(T::CourtCaseDuration::get());
-            for (ai, _) in random_jurors {
-                RequestedJurors::<T>::insert(market_id, ai, block_limit);
-            }
-            Ok(())
+            ensure!(!Courts::<T>::contains_key(market_id), Error::<T>::CourtCaseAlreadyExists);
+            Self::select_jurors(market_id, 0)
         }
 
         // Set jurors that sided on the second most voted outcome as tardy. Jurors are only
@@ -544,36 +1079,25 @@ mod pallet {
                 market.dispute_mechanism == MarketDisputeMechanism::Court,
                 Error::<T>::MarketDoesNotHaveCourtMechanism
             );
-            let votes: Vec<_> = Votes::<T>::iter_prefix(market_id).collect();
-            let requested_jurors: Vec<_> = RequestedJurors::<T>::iter_prefix(market_id)
-                .map(|(juror_id, max_allowed_block)| {
-                    let juror = Self::juror(&juror_id)?;
-                    let vote_opt = votes.iter().find(|el| el.0 == juror_id).map(|el| &el.1);
-                    Ok((juror_id, juror, max_allowed_block, vote_opt))
-                })
-                .collect::<Result<_, DispatchError>>()?;
-            let (first, second_opt) = Self::two_best_outcomes(&votes)?;
-            let valid_winners_and_losers = if let Some(second) = second_opt {
-                Self::manage_tardy_jurors(&requested_jurors, |outcome| outcome == &second)?
-            } else {
-                Self::manage_tardy_jurors(&requested_jurors, |_| false)?
-            };
-            Self::slash_losers_to_award_winners(&valid_winners_and_losers, &first)?;
-            let _ = Votes::<T>::clear_prefix(market_id, u32::max_value(), None);
-            let _ = RequestedJurors::<T>::clear_prefix(market_id, u32::max_value(), None);
+            let court = Courts::<T>::get(market_id).ok_or(Error::<T>::CourtCaseNotFound)?;
+            let first = Self::settle_round(market_id, &court)?;
+            Self::settle_appeals(market_id, &first)?;
+            // The rewards of the court case were added to `Rewards`, so the court case is no
+            // longer needed.
+            Courts::<T>::remove(market_id);
             Ok(Some(first))
         }
 
         fn get_auto_resolve(
             _: &[MarketDispute<Self::AccountId, Self::BlockNumber>],
-            _: &Self::MarketId,
+            market_id: &Self::MarketId,
             market: &MarketOf<T>,
         ) -> Result<Option<Self::BlockNumber>, DispatchError> {
             ensure!(
                 market.dispute_mechanism == MarketDisputeMechanism::Court,
                 Error::<T>::MarketDoesNotHaveCourtMechanism
             );
-            Ok(None)
+            Ok(Courts::<T>::get(market_id).map(|court| court.appeal_ends))
         }
 
         fn has_failed(
@@ -591,28 +1115,73 @@ mod pallet {
 
     impl<T> CourtPalletApi for Pallet<T> where T: Config {}
 
-    /// Accounts that stake funds to decide outcomes.
+    /// Appeals of the rounds of a court case.
     #[pallet::storage]
-    pub type Jurors<T: Config> = CountedStorageMap<_, Blake2_128Concat, T::AccountId, Juror>;
+    pub type Appeals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketIdOf<T>,
+        BoundedVec<AppealOf<T>, T::MaxAppeals>,
+        ValueQuery,
+    >;
 
-    /// An extra layer of pseudo randomness.
+    /// The unsettled court cases of markets which were disputed using the court. Court cases are
+    /// removed once the market is resolved.
     #[pallet::storage]
-    pub type JurorsSelectionNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+    pub type Courts<T: Config> = StorageMap<_, Blake2_128Concat, MarketIdOf<T>, CourtOf<T>>;
+
+    /// The sum of all delegations to a juror.
+    #[pallet::storage]
+    pub type DelegatedStakes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// The number of delegators of a juror.
+    #[pallet::storage]
+    pub type DelegatorCounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
-    /// Selected jurors that should vote a market outcome until a certain block number
+    /// Funds delegated to a juror by a delegator.
     #[pallet::storage]
-    pub type RequestedJurors<T: Config> = StorageDoubleMap<
+    pub type Delegations<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
-        MarketIdOf<T>,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Jurors about to leave the court and the block at which they can leave.
+    #[pallet::storage]
+    pub type ExitRequests<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+    /// The unsettled court cases a juror was selected for.
+    #[pallet::storage]
+    pub type JurorCases<T: Config> = StorageMap<
+        _,
         Blake2_128Concat,
         T::AccountId,
-        T::BlockNumber,
+        BoundedVec<MarketIdOf<T>, T::MaxCasesPerJuror>,
     >;
 
+    /// Accounts that stake funds to decide outcomes.
+    #[pallet::storage]
+    pub type Jurors<T: Config> = CountedStorageMap<_, Blake2_128Concat, T::AccountId, Juror>;
+
+    /// An extra layer of pseudo randomness.
+    #[pallet::storage]
+    pub type JurorsSelectionNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Rewards of jurors and delegators which can be claimed using `claim_rewards`.
+    #[pallet::storage]
+    pub type Rewards<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
     /// Votes of market outcomes for disputes
     ///
-    /// Stores the vote block number and the submitted outcome.
+    /// Stores the commitment of a juror and, once revealed, the submitted outcome.
     #[pallet::storage]
     pub type Votes<T: Config> = StorageDoubleMap<
         _,
@@ -620,6 +1189,38 @@ mod pallet {
         MarketIdOf<T>,
         Blake2_128Concat,
         T::AccountId,
-        (T::BlockNumber, OutcomeReport),
+        Vote<T::Hash>,
     >;
 }
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+pub(crate) fn market_mock<T>() -> MarketOf<T>
+where
+    T: crate::Config,
+{
+    use frame_support::traits::Get;
+    use sp_runtime::traits::AccountIdConversion;
+    use zeitgeist_primitives::types::{Asset, MarketBonds, ScoringRule};
+
+    zeitgeist_primitives::types::Market {
+        base_asset: Asset::Ztg,
+        creation: zeitgeist_primitives::types::MarketCreation::Permissionless,
+        creator_fee: 0,
+        creator: T::PalletId::get().into_account_truncating(),
+        market_type: zeitgeist_primitives::types::MarketType::Scalar(0..=100),
+        dispute_mechanism: zeitgeist_primitives::types::MarketDisputeMechanism::Court,
+        metadata: Default::default(),
+        oracle: T::PalletId::get().into_account_truncating(),
+        period: zeitgeist_primitives::types::MarketPeriod::Block(Default::default()),
+        deadlines: zeitgeist_primitives::types::Deadlines {
+            grace_period: 1_u32.into(),
+            oracle_duration: 1_u32.into(),
+            dispute_duration: 1_u32.into(),
+        },
+        report: None,
+        resolved_outcome: None,
+        scoring_rule: ScoringRule::CPMM,
+        status: zeitgeist_primitives::types::MarketStatus::Disputed,
+        bonds: MarketBonds::default(),
+    }
+}
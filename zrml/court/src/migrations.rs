@@ -14,3 +14,139 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, Pallet, Votes};
+use frame_support::{
+    dispatch::Weight,
+    log,
+    migration::clear_storage_prefix,
+    pallet_prelude::PhantomData,
+    traits::{Get, OnRuntimeUpgrade, StorageVersion},
+};
+
+const COURT: &[u8] = b"Court";
+const REQUESTED_JURORS: &[u8] = b"RequestedJurors";
+
+const COURT_REQUIRED_STORAGE_VERSION: u16 = 2;
+const COURT_NEXT_STORAGE_VERSION: u16 = 3;
+
+pub struct ClearVotesAndRequestedJurors<T>(PhantomData<T>);
+
+// Votes used to be stored as `(BlockNumber, OutcomeReport)` and can't be converted into commitments
+// of the commit-reveal scheme, so they are removed together with the `RequestedJurors` map, which
+// no longer exists.
+impl<T: Config> OnRuntimeUpgrade for ClearVotesAndRequestedJurors<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut total_weight = T::DbWeight::get().reads(1);
+        let court_version = StorageVersion::get::<Pallet<T>>();
+        if court_version != COURT_REQUIRED_STORAGE_VERSION {
+            log::info!(
+                "ClearVotesAndRequestedJurors: court version is {:?}, require {:?};",
+                court_version,
+                COURT_REQUIRED_STORAGE_VERSION,
+            );
+            return total_weight;
+        }
+        log::info!("ClearVotesAndRequestedJurors: Starting...");
+
+        let votes_removed = Votes::<T>::clear(u32::MAX, None).unique;
+        log::info!("ClearVotesAndRequestedJurors: Removed {} votes.", votes_removed);
+        let requested_jurors_removed =
+            clear_storage_prefix(COURT, REQUESTED_JURORS, b"", None, None).unique;
+        log::info!(
+            "ClearVotesAndRequestedJurors: Removed {} requested jurors.",
+            requested_jurors_removed
+        );
+        total_weight = total_weight.saturating_add(
+            T::DbWeight::get()
+                .writes(votes_removed.saturating_add(requested_jurors_removed).into()),
+        );
+
+        StorageVersion::new(COURT_NEXT_STORAGE_VERSION).put::<Pallet<T>>();
+        total_weight = total_weight.saturating_add(T::DbWeight::get().writes(1));
+        log::info!("ClearVotesAndRequestedJurors: Done!");
+        total_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        use frame_support::migration::storage_iter;
+        assert_eq!(Votes::<T>::iter_keys().count(), 0);
+        assert_eq!(storage_iter::<T::BlockNumber>(COURT, REQUESTED_JURORS).count(), 0);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock::{ExtBuilder, Runtime, ALICE, BOB},
+        Vote,
+    };
+    use frame_support::{
+        migration::{get_storage_value, put_storage_value},
+        Blake2_128Concat, StorageHasher,
+    };
+    use parity_scale_codec::Encode;
+    use zeitgeist_primitives::types::{AccountIdTest, BlockNumber, MarketId, OutcomeReport};
+
+    const VOTES: &[u8] = b"Votes";
+
+    #[test]
+    fn on_runtime_upgrade_increments_the_storage_version() {
+        ExtBuilder::default().build().execute_with(|| {
+            set_up_chain();
+            ClearVotesAndRequestedJurors::<Runtime>::on_runtime_upgrade();
+            assert_eq!(StorageVersion::get::<Pallet<Runtime>>(), COURT_NEXT_STORAGE_VERSION);
+        });
+    }
+
+    #[test]
+    fn on_runtime_upgrade_removes_old_votes_and_requested_jurors() {
+        ExtBuilder::default().build().execute_with(|| {
+            set_up_chain();
+            let hash = double_map_hash(0, ALICE);
+            put_storage_value::<(BlockNumber, OutcomeReport)>(
+                COURT,
+                VOTES,
+                &hash,
+                (42, OutcomeReport::Scalar(1)),
+            );
+            put_storage_value::<BlockNumber>(COURT, REQUESTED_JURORS, &double_map_hash(0, BOB), 42);
+
+            ClearVotesAndRequestedJurors::<Runtime>::on_runtime_upgrade();
+
+            assert!(
+                get_storage_value::<(BlockNumber, OutcomeReport)>(COURT, VOTES, &hash).is_none()
+            );
+            assert!(
+                get_storage_value::<BlockNumber>(COURT, REQUESTED_JURORS, &double_map_hash(0, BOB))
+                    .is_none()
+            );
+        });
+    }
+
+    #[test]
+    fn on_runtime_upgrade_is_noop_if_versions_are_not_correct() {
+        ExtBuilder::default().build().execute_with(|| {
+            StorageVersion::new(COURT_NEXT_STORAGE_VERSION).put::<Pallet<Runtime>>();
+            let vote = Vote::Secret { commitment: Default::default() };
+            Votes::<Runtime>::insert(0, ALICE, vote.clone());
+
+            ClearVotesAndRequestedJurors::<Runtime>::on_runtime_upgrade();
+
+            assert_eq!(Votes::<Runtime>::get(0, ALICE).unwrap(), vote);
+        });
+    }
+
+    fn double_map_hash(market_id: MarketId, account: AccountIdTest) -> Vec<u8> {
+        let mut hash = market_id.using_encoded(Blake2_128Concat::hash).as_ref().to_vec();
+        hash.extend(account.using_encoded(Blake2_128Concat::hash).as_ref());
+        hash
+    }
+
+    fn set_up_chain() {
+        StorageVersion::new(COURT_REQUIRED_STORAGE_VERSION).put::<Pallet<Runtime>>();
+    }
+}
@@ -22,17 +22,19 @@ use frame_support::{
     construct_runtime,
     pallet_prelude::{DispatchError, Weight},
     parameter_types,
-    traits::Everything,
+    traits::{Everything, Randomness},
     BoundedVec, PalletId,
 };
 use sp_runtime::{
     testing::Header,
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Hash as HashT, IdentityLookup},
 };
 use zeitgeist_primitives::{
     constants::mock::{
-        BlockHashCount, CourtCaseDuration, CourtPalletId, MaxReserves, MinimumPeriod, PmPalletId,
-        StakeWeight, BASE,
+        BlockHashCount, CourtAppealBond, CourtAppealDuration, CourtCaseDuration, CourtMaxAppeals,
+        CourtMaxCasesPerJuror, CourtMaxDelegators, CourtMaxJurors, CourtPalletId,
+        CourtRevealDuration, JurorUnbondingPeriod, MaxReserves, MinJurorStake, MinimumPeriod,
+        PmPalletId, BASE,
     },
     traits::DisputeResolutionApi,
     types::{
@@ -48,6 +50,7 @@ pub const INITIAL_BALANCE: u128 = 1000 * BASE;
 
 parameter_types! {
     pub const TreasuryPalletId: PalletId = PalletId(*b"3.141592");
+    pub storage RandomnessAvailable: bool = true;
 }
 
 construct_runtime!(
@@ -60,7 +63,6 @@ construct_runtime!(
         Balances: pallet_balances::{Call, Config<T>, Event<T>, Pallet, Storage},
         Court: zrml_court::{Event<T>, Pallet, Storage},
         MarketCommons: zrml_market_commons::{Pallet, Storage},
-        RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage},
         System: frame_system::{Call, Config, Event<T>, Pallet, Storage},
         Timestamp: pallet_timestamp::{Pallet},
     }
@@ -112,14 +114,35 @@ impl DisputeResolutionApi for NoopResolution {
     }
 }
 
+// Deterministic randomness that only depends on the subject and the current block number.
+// Unavailable if `RandomnessAvailable` is set to `false`.
+pub struct MockRandomness;
+
+impl Randomness<Option<Hash>, BlockNumber> for MockRandomness {
+    fn random(subject: &[u8]) -> (Option<Hash>, BlockNumber) {
+        let block_number = System::block_number();
+        let random_hash =
+            RandomnessAvailable::get().then(|| BlakeTwo256::hash_of(&(subject, block_number)));
+        (random_hash, block_number)
+    }
+}
+
 impl crate::Config for Runtime {
+    type AppealBond = CourtAppealBond;
+    type CourtAppealDuration = CourtAppealDuration;
     type CourtCaseDuration = CourtCaseDuration;
+    type CourtRevealDuration = CourtRevealDuration;
     type DisputeResolution = NoopResolution;
     type Event = ();
+    type JurorUnbondingPeriod = JurorUnbondingPeriod;
     type MarketCommons = MarketCommons;
+    type MaxAppeals = CourtMaxAppeals;
+    type MaxCasesPerJuror = CourtMaxCasesPerJuror;
+    type MaxDelegators = CourtMaxDelegators;
+    type MaxJurors = CourtMaxJurors;
+    type MinJurorStake = MinJurorStake;
     type PalletId = CourtPalletId;
-    type Random = RandomnessCollectiveFlip;
-    type StakeWeight = StakeWeight;
+    type Random = MockRandomness;
     type TreasuryPalletId = TreasuryPalletId;
     type WeightInfo = crate::weights::WeightInfo<Runtime>;
 }
@@ -163,8 +186,6 @@ impl pallet_balances::Config for Runtime {
     type WeightInfo = ();
 }
 
-impl pallet_randomness_collective_flip::Config for Runtime {}
-
 impl zrml_market_commons::Config for Runtime {
     type Currency = Balances;
     type MarketId = MarketId;
@@ -19,23 +19,33 @@
 
 use crate::{
     mock::{
-        Balances, Court, ExtBuilder, Origin, RandomnessCollectiveFlip, Runtime, System, ALICE, BOB,
-        CHARLIE, INITIAL_BALANCE,
+        Balances, Court, ExtBuilder, MarketCommons, Origin, RandomnessAvailable, Runtime, System,
+        ALICE, BOB, CHARLIE, INITIAL_BALANCE,
     },
-    Error, Juror, JurorStatus, Jurors, MarketOf, RequestedJurors, Votes,
+    Appeal, Appeals, Courts, DelegatedStakes, Delegations, DelegatorCounts, Error, ExitRequests,
+    Juror, JurorCases, JurorStatus, Jurors, MarketOf, Rewards, Vote, Votes,
 };
 use frame_support::{
     assert_noop, assert_ok,
-    traits::{Hooks, NamedReservableCurrency},
+    traits::{Currency, Get, NamedReservableCurrency, ReservableCurrency},
 };
+use sp_runtime::traits::{BlakeTwo256, Hash};
 use zeitgeist_primitives::{
-    constants::BASE,
+    constants::{
+        mock::{
+            CourtAppealBond, CourtAppealDuration, CourtCaseDuration, CourtMaxAppeals,
+            CourtMaxCasesPerJuror, CourtMaxDelegators, CourtRevealDuration, JurorUnbondingPeriod,
+            MinJurorStake,
+        },
+        BASE,
+    },
     traits::DisputeApi,
     types::{
-        Asset, Deadlines, Market, MarketBonds, MarketCreation, MarketDisputeMechanism,
-        MarketPeriod, MarketStatus, MarketType, OutcomeReport, ScoringRule,
+        AccountIdTest, Asset, Deadlines, Hash as HashTest, Market, MarketBonds, MarketCreation,
+        MarketDisputeMechanism, MarketPeriod, MarketStatus, MarketType, OutcomeReport, ScoringRule,
     },
 };
+use zrml_market_commons::MarketCommonsPalletApi;
 
 const DEFAULT_MARKET: MarketOf<Runtime> = Market {
     base_asset: Asset::Ztg,
@@ -54,27 +64,342 @@ const DEFAULT_MARKET: MarketOf<Runtime> = Market {
     scoring_rule: ScoringRule::CPMM,
     bonds: MarketBonds { creation: None, oracle: None },
 };
-const DEFAULT_SET_OF_JURORS: &[(u128, Juror)] = &[
-    (7, Juror { status: JurorStatus::Ok }),
-    (6, Juror { status: JurorStatus::Tardy }),
-    (5, Juror { status: JurorStatus::Ok }),
-    (4, Juror { status: JurorStatus::Tardy }),
-    (3, Juror { status: JurorStatus::Ok }),
-    (2, Juror { status: JurorStatus::Ok }),
-    (1, Juror { status: JurorStatus::Ok }),
+const DEFAULT_SET_OF_JURORS: &[(u128, Juror, u128)] = &[
+    (7, Juror { status: JurorStatus::Ok }, 2 * BASE),
+    (6, Juror { status: JurorStatus::Tardy }, 2 * BASE),
+    (5, Juror { status: JurorStatus::Ok }, 2 * BASE),
+    (4, Juror { status: JurorStatus::Tardy }, 2 * BASE),
+    (3, Juror { status: JurorStatus::Ok }, 2 * BASE),
+    (2, Juror { status: JurorStatus::Ok }, 2 * BASE),
+    (1, Juror { status: JurorStatus::Ok }, 2 * BASE),
 ];
 
+const APPELLANT: AccountIdTest = 42;
+const DELEGATOR: AccountIdTest = 43;
+
+#[test]
+fn appeal_starts_a_new_round_with_more_jurors() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        setup_disputed_market(&(0..10).collect::<Vec<_>>());
+        assert_eq!(requested_jurors().len(), 3);
+        vote_and_move_to_appeal_period(OutcomeReport::Scalar(1));
+        assert_ok!(Court::appeal(Origin::signed(APPELLANT), 0));
+        assert_eq!(
+            Appeals::<Runtime>::get(0).into_inner(),
+            vec![Appeal {
+                appellant: APPELLANT,
+                bond: CourtAppealBond::get(),
+                outcome: OutcomeReport::Scalar(1)
+            }]
+        );
+        assert_eq!(Balances::reserved_balance(&APPELLANT), CourtAppealBond::get());
+        assert_eq!(requested_jurors().len(), 7);
+        assert_eq!(Votes::<Runtime>::iter_prefix(0).count(), 0);
+        let court = Courts::<Runtime>::get(0).unwrap();
+        assert_eq!(court.round, 1);
+        assert_eq!(court.voting_ends, System::block_number() + CourtCaseDuration::get());
+    });
+}
+
+#[test]
+fn appeal_doubles_the_bond_with_every_round() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        setup_disputed_market(&[ALICE, BOB, CHARLIE]);
+        for _ in 0..CourtMaxAppeals::get() {
+            vote_and_move_to_appeal_period(OutcomeReport::Scalar(1));
+            assert_ok!(Court::appeal(Origin::signed(APPELLANT), 0));
+        }
+        let bonds: Vec<_> = Appeals::<Runtime>::get(0).iter().map(|el| el.bond).collect();
+        let bond = CourtAppealBond::get();
+        assert_eq!(bonds, vec![bond, 2 * bond, 4 * bond]);
+        assert_eq!(Balances::reserved_balance(&APPELLANT), 7 * bond);
+    });
+}
+
+#[test]
+fn appeal_fails_if_the_maximum_number_of_appeals_is_reached() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        setup_disputed_market(&[ALICE, BOB, CHARLIE]);
+        for _ in 0..CourtMaxAppeals::get() {
+            vote_and_move_to_appeal_period(OutcomeReport::Scalar(1));
+            assert_ok!(Court::appeal(Origin::signed(APPELLANT), 0));
+        }
+        vote_and_move_to_appeal_period(OutcomeReport::Scalar(1));
+        assert_noop!(
+            Court::appeal(Origin::signed(APPELLANT), 0),
+            Error::<Runtime>::MaxAppealsReached
+        );
+    });
+}
+
+#[test]
+fn appeal_fails_before_the_end_of_the_reveal_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        setup_disputed_market(&[ALICE, BOB, CHARLIE]);
+        commit_and_reveal_votes(&[(ALICE, OutcomeReport::Scalar(1))]);
+        assert_noop!(
+            Court::appeal(Origin::signed(APPELLANT), 0),
+            Error::<Runtime>::AppealPeriodNotStarted
+        );
+    });
+}
+
+#[test]
+fn appeal_fails_after_the_appeal_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        setup_disputed_market(&[ALICE, BOB, CHARLIE]);
+        vote_and_move_to_appeal_period(OutcomeReport::Scalar(1));
+        System::set_block_number(System::block_number() + CourtAppealDuration::get());
+        assert_noop!(
+            Court::appeal(Origin::signed(APPELLANT), 0),
+            Error::<Runtime>::AppealPeriodExpired
+        );
+    });
+}
+
+#[test]
+fn appeal_fails_without_randomness() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        setup_disputed_market(&[ALICE, BOB, CHARLIE]);
+        vote_and_move_to_appeal_period(OutcomeReport::Scalar(1));
+        RandomnessAvailable::set(&false);
+        assert_noop!(
+            Court::appeal(Origin::signed(APPELLANT), 0),
+            Error::<Runtime>::RandomnessUnavailable
+        );
+        RandomnessAvailable::set(&true);
+        assert_ok!(Court::appeal(Origin::signed(APPELLANT), 0));
+    });
+}
+
+#[test]
+fn appeal_fails_if_the_market_is_not_disputed() {
+    ExtBuilder::default().build().execute_with(|| {
+        MarketCommons::push_market(DEFAULT_MARKET).unwrap();
+        assert_noop!(
+            Court::appeal(Origin::signed(APPELLANT), 0),
+            Error::<Runtime>::MarketIsNotDisputed
+        );
+    });
+}
+
+#[test]
+fn claim_rewards_deposits_the_rewards_of_the_caller() {
+    ExtBuilder::default().build().execute_with(|| {
+        Rewards::<Runtime>::insert(ALICE, 3 * BASE);
+        assert_ok!(Court::claim_rewards(Origin::signed(ALICE)));
+        assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 3 * BASE);
+        assert!(!Rewards::<Runtime>::contains_key(ALICE));
+    });
+}
+
+#[test]
+fn claim_rewards_fails_without_rewards() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(Court::claim_rewards(Origin::signed(ALICE)), Error::<Runtime>::NoRewards);
+    });
+}
+
+#[test]
+fn delegate_reserves_funds_and_increases_the_stake_of_the_juror() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        assert_ok!(Court::delegate(Origin::signed(BOB), ALICE, 3 * BASE));
+        assert_ok!(Court::delegate(Origin::signed(BOB), ALICE, BASE));
+        assert_eq!(Delegations::<Runtime>::get(ALICE, BOB), 4 * BASE);
+        assert_eq!(DelegatedStakes::<Runtime>::get(ALICE), 4 * BASE);
+        assert_eq!(
+            Balances::reserved_balance_named(&Court::delegation_reserve_id(), &BOB),
+            4 * BASE
+        );
+        assert_eq!(Balances::reserved_balance(&BOB), 4 * BASE);
+        assert_eq!(Court::total_stake(&ALICE), 6 * BASE);
+    });
+}
+
+#[test]
+fn delegate_fails_if_juror_does_not_exist() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Court::delegate(Origin::signed(BOB), ALICE, BASE),
+            Error::<Runtime>::JurorDoesNotExists
+        );
+    });
+}
+
+#[test]
+fn delegate_fails_if_juror_delegates_to_self() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        assert_noop!(
+            Court::delegate(Origin::signed(ALICE), ALICE, BASE),
+            Error::<Runtime>::CannotDelegateToSelf
+        );
+    });
+}
+
+#[test]
+fn delegate_fails_if_amount_is_zero() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        assert_noop!(
+            Court::delegate(Origin::signed(BOB), ALICE, 0),
+            Error::<Runtime>::ZeroDelegation
+        );
+    });
+}
+
+#[test]
+fn delegate_fails_if_the_juror_has_the_maximum_number_of_delegators() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        let delegators: Vec<_> =
+            (0..u128::from(CourtMaxDelegators::get())).map(|i| 100 + i).collect();
+        for delegator in &delegators {
+            let _ = Balances::deposit_creating(delegator, INITIAL_BALANCE);
+            assert_ok!(Court::delegate(Origin::signed(*delegator), ALICE, BASE));
+        }
+        assert_eq!(DelegatorCounts::<Runtime>::get(ALICE), CourtMaxDelegators::get());
+        assert_noop!(
+            Court::delegate(Origin::signed(BOB), ALICE, BASE),
+            Error::<Runtime>::MaxDelegatorsReached
+        );
+        // Existing delegators can still increase their delegation.
+        assert_ok!(Court::delegate(Origin::signed(delegators[0]), ALICE, BASE));
+        assert_ok!(Court::undelegate(Origin::signed(delegators[0]), ALICE));
+        assert_ok!(Court::delegate(Origin::signed(BOB), ALICE, BASE));
+    });
+}
+
+#[test]
+fn delegate_fails_if_juror_is_exiting() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::prepare_exit_court(Origin::signed(ALICE)).unwrap();
+        assert_noop!(
+            Court::delegate(Origin::signed(BOB), ALICE, BASE),
+            Error::<Runtime>::JurorIsExiting
+        );
+    });
+}
+
+#[test]
+fn delegate_fails_if_juror_has_active_cases() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 4 * BASE).unwrap();
+        Court::join_court(Origin::signed(CHARLIE), 6 * BASE).unwrap();
+        let _ = Balances::deposit_creating(&DELEGATOR, INITIAL_BALANCE);
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        commit_and_reveal_votes(&[
+            (ALICE, OutcomeReport::Scalar(1)),
+            (BOB, OutcomeReport::Scalar(2)),
+            (CHARLIE, OutcomeReport::Scalar(3)),
+        ]);
+        // Once the votes are revealed, delegating to a winning juror must not buy a share of the
+        // rewards of the court case.
+        assert_noop!(
+            Court::delegate(Origin::signed(DELEGATOR), ALICE, 2 * BASE),
+            Error::<Runtime>::JurorHasActiveCases
+        );
+        let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
+        assert_eq!(Rewards::<Runtime>::get(ALICE), 3 * BASE);
+        assert_eq!(Rewards::<Runtime>::get(DELEGATOR), 0);
+    });
+}
+
 #[test]
 fn exit_court_successfully_removes_a_juror_and_frees_balances() {
     ExtBuilder::default().build().execute_with(|| {
-        assert_ok!(Court::join_court(Origin::signed(ALICE)));
+        assert_ok!(Court::join_court(Origin::signed(ALICE), 2 * BASE));
         assert_eq!(Jurors::<Runtime>::iter().count(), 1);
         assert_eq!(Balances::free_balance(ALICE), 998 * BASE);
         assert_eq!(Balances::reserved_balance_named(&Court::reserve_id(), &ALICE), 2 * BASE);
+        assert_ok!(Court::prepare_exit_court(Origin::signed(ALICE)));
+        System::set_block_number(System::block_number() + JurorUnbondingPeriod::get());
         assert_ok!(Court::exit_court(Origin::signed(ALICE)));
         assert_eq!(Jurors::<Runtime>::iter().count(), 0);
         assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
         assert_eq!(Balances::reserved_balance_named(&Court::reserve_id(), &ALICE), 0);
+        assert!(ExitRequests::<Runtime>::get(ALICE).is_none());
+    });
+}
+
+#[test]
+fn exit_court_returns_delegations() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::delegate(Origin::signed(BOB), ALICE, 3 * BASE).unwrap();
+        Court::prepare_exit_court(Origin::signed(ALICE)).unwrap();
+        System::set_block_number(System::block_number() + JurorUnbondingPeriod::get());
+        assert_ok!(Court::exit_court(Origin::signed(ALICE)));
+        assert_eq!(Balances::reserved_balance(&BOB), 0);
+        assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+        assert!(!Delegations::<Runtime>::contains_key(ALICE, BOB));
+        assert_eq!(DelegatedStakes::<Runtime>::get(ALICE), 0);
+        assert!(!DelegatorCounts::<Runtime>::contains_key(ALICE));
+    });
+}
+
+#[test]
+fn exit_court_fails_if_juror_has_not_prepared_exit() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        assert_noop!(
+            Court::exit_court(Origin::signed(ALICE)),
+            Error::<Runtime>::JurorHasNotPreparedExit
+        );
+    });
+}
+
+#[test]
+fn exit_court_fails_before_the_end_of_the_unbonding_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::prepare_exit_court(Origin::signed(ALICE)).unwrap();
+        System::set_block_number(System::block_number() + JurorUnbondingPeriod::get() - 1);
+        assert_noop!(
+            Court::exit_court(Origin::signed(ALICE)),
+            Error::<Runtime>::UnbondingPeriodNotOver
+        );
+    });
+}
+
+#[test]
+fn exit_court_fails_if_juror_has_active_cases() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        Court::prepare_exit_court(Origin::signed(ALICE)).unwrap();
+        System::set_block_number(System::block_number() + JurorUnbondingPeriod::get());
+        assert_noop!(
+            Court::exit_court(Origin::signed(ALICE)),
+            Error::<Runtime>::JurorHasActiveCases
+        );
+    });
+}
+
+#[test]
+fn exit_court_succeeds_once_the_court_cases_of_the_juror_are_settled() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        setup_disputed_market(&[ALICE]);
+        assert_eq!(JurorCases::<Runtime>::get(ALICE).unwrap().into_inner(), vec![0]);
+        Court::prepare_exit_court(Origin::signed(ALICE)).unwrap();
+        commit_and_reveal_votes(&[(ALICE, OutcomeReport::Scalar(1))]);
+        let mut market = DEFAULT_MARKET;
+        market.status = MarketStatus::Disputed;
+        let _ = Court::on_resolution(&[], &0, &market).unwrap();
+        assert!(!JurorCases::<Runtime>::contains_key(ALICE));
+        System::set_block_number(System::block_number() + JurorUnbondingPeriod::get());
+        assert_ok!(Court::exit_court(Origin::signed(ALICE)));
     });
 }
 
@@ -89,24 +414,34 @@ fn exit_court_will_not_remove_an_unknown_juror() {
 }
 
 #[test]
-fn join_court_reserves_balance_according_to_the_number_of_jurors() {
+fn join_court_reserves_the_stake_of_the_juror() {
     ExtBuilder::default().build().execute_with(|| {
         assert_eq!(Balances::free_balance(ALICE), 1000 * BASE);
-        assert_ok!(Court::join_court(Origin::signed(ALICE)));
+        assert_ok!(Court::join_court(Origin::signed(ALICE), 2 * BASE));
         assert_eq!(Balances::free_balance(ALICE), 998 * BASE);
         assert_eq!(Balances::reserved_balance_named(&Court::reserve_id(), &ALICE), 2 * BASE);
 
         assert_eq!(Balances::free_balance(BOB), 1000 * BASE);
-        assert_ok!(Court::join_court(Origin::signed(BOB)));
-        assert_eq!(Balances::free_balance(BOB), 996 * BASE);
-        assert_eq!(Balances::reserved_balance_named(&Court::reserve_id(), &BOB), 4 * BASE);
+        assert_ok!(Court::join_court(Origin::signed(BOB), 5 * BASE));
+        assert_eq!(Balances::free_balance(BOB), 995 * BASE);
+        assert_eq!(Balances::reserved_balance_named(&Court::reserve_id(), &BOB), 5 * BASE);
+    });
+}
+
+#[test]
+fn join_court_fails_if_the_stake_is_below_the_minimum() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Court::join_court(Origin::signed(ALICE), MinJurorStake::get() - 1),
+            Error::<Runtime>::BelowMinJurorStake
+        );
     });
 }
 
 #[test]
 fn join_court_successfully_stores_a_juror() {
     ExtBuilder::default().build().execute_with(|| {
-        assert_ok!(Court::join_court(Origin::signed(ALICE)));
+        assert_ok!(Court::join_court(Origin::signed(ALICE), 2 * BASE));
         assert_eq!(
             Jurors::<Runtime>::iter().next().unwrap(),
             (ALICE, Juror { status: JurorStatus::Ok })
@@ -117,9 +452,9 @@ fn join_court_successfully_stores_a_juror() {
 #[test]
 fn join_court_will_not_insert_an_already_stored_juror() {
     ExtBuilder::default().build().execute_with(|| {
-        assert_ok!(Court::join_court(Origin::signed(ALICE)));
+        assert_ok!(Court::join_court(Origin::signed(ALICE), 2 * BASE));
         assert_noop!(
-            Court::join_court(Origin::signed(ALICE)),
+            Court::join_court(Origin::signed(ALICE), 2 * BASE),
             Error::<Runtime>::JurorAlreadyExists
         );
     });
@@ -153,14 +488,100 @@ fn on_resolution_denies_non_court_markets() {
 fn on_dispute_stores_jurors_that_should_vote() {
     ExtBuilder::default().build().execute_with(|| {
         setup_blocks(123);
-        let _ = Court::join_court(Origin::signed(ALICE));
-        let _ = Court::join_court(Origin::signed(BOB));
+        let _ = Court::join_court(Origin::signed(ALICE), 2 * BASE);
+        let _ = Court::join_court(Origin::signed(BOB), 2 * BASE);
         Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
         assert_noop!(
-            Court::join_court(Origin::signed(ALICE)),
+            Court::join_court(Origin::signed(ALICE), 2 * BASE),
             Error::<Runtime>::JurorAlreadyExists
         );
-        assert_eq!(RequestedJurors::<Runtime>::iter().count(), 2);
+        assert_eq!(requested_jurors().len(), 2);
+    });
+}
+
+#[test]
+fn on_dispute_opens_a_court_case() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 3 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let court = Courts::<Runtime>::get(0).unwrap();
+        let mut jurors = court.jurors.into_inner();
+        jurors.sort();
+        assert_eq!(jurors, vec![(ALICE, 2 * BASE), (BOB, 3 * BASE)]);
+        assert_eq!(court.round, 0);
+        assert_eq!(court.total_stake, 5 * BASE);
+        let voting_ends = System::block_number() + CourtCaseDuration::get();
+        assert_eq!(court.voting_ends, voting_ends);
+        assert_eq!(court.reveal_ends, voting_ends + CourtRevealDuration::get());
+        assert_eq!(
+            court.appeal_ends,
+            voting_ends + CourtRevealDuration::get() + CourtAppealDuration::get()
+        );
+    });
+}
+
+#[test]
+fn on_dispute_does_not_select_jurors_with_the_maximum_number_of_cases() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        let max_cases: u128 = CourtMaxCasesPerJuror::get().into();
+        for market_id in 0..max_cases {
+            Court::on_dispute(&[], &market_id, &DEFAULT_MARKET).unwrap();
+        }
+        assert_eq!(
+            JurorCases::<Runtime>::get(ALICE).unwrap().into_inner(),
+            (0..max_cases).collect::<Vec<_>>()
+        );
+        Court::join_court(Origin::signed(BOB), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &max_cases, &DEFAULT_MARKET).unwrap();
+        let jurors: Vec<_> = Courts::<Runtime>::get(max_cases)
+            .unwrap()
+            .jurors
+            .into_iter()
+            .map(|(ai, _)| ai)
+            .collect();
+        assert_eq!(jurors, vec![BOB]);
+    });
+}
+
+#[test]
+fn on_dispute_fails_without_randomness() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        RandomnessAvailable::set(&false);
+        assert_noop!(
+            Court::on_dispute(&[], &0, &DEFAULT_MARKET),
+            Error::<Runtime>::RandomnessUnavailable
+        );
+        RandomnessAvailable::set(&true);
+        assert_ok!(Court::on_dispute(&[], &0, &DEFAULT_MARKET));
+        assert_eq!(requested_jurors(), vec![ALICE]);
+    });
+}
+
+#[test]
+fn on_dispute_fails_if_the_market_already_has_a_court_case() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        assert_noop!(
+            Court::on_dispute(&[], &0, &DEFAULT_MARKET),
+            Error::<Runtime>::CourtCaseAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn get_auto_resolve_returns_the_end_of_the_appeal_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let voting_ends = Courts::<Runtime>::get(0).unwrap().voting_ends;
+        assert_eq!(
+            Court::get_auto_resolve(&[], &0, &DEFAULT_MARKET).unwrap(),
+            Some(voting_ends + CourtRevealDuration::get() + CourtAppealDuration::get())
+        );
     });
 }
 
@@ -169,14 +590,18 @@ fn on_dispute_stores_jurors_that_should_vote() {
 fn on_resolution_awards_winners_and_slashes_losers() {
     ExtBuilder::default().build().execute_with(|| {
         setup_blocks(2);
-        Court::join_court(Origin::signed(ALICE)).unwrap();
-        Court::join_court(Origin::signed(BOB)).unwrap();
-        Court::join_court(Origin::signed(CHARLIE)).unwrap();
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 4 * BASE).unwrap();
+        Court::join_court(Origin::signed(CHARLIE), 6 * BASE).unwrap();
         Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
-        Court::vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(1)).unwrap();
-        Court::vote(Origin::signed(BOB), 0, OutcomeReport::Scalar(2)).unwrap();
-        Court::vote(Origin::signed(CHARLIE), 0, OutcomeReport::Scalar(3)).unwrap();
+        commit_and_reveal_votes(&[
+            (ALICE, OutcomeReport::Scalar(1)),
+            (BOB, OutcomeReport::Scalar(2)),
+            (CHARLIE, OutcomeReport::Scalar(3)),
+        ]);
         let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
+        assert_eq!(Rewards::<Runtime>::get(ALICE), 3 * BASE);
+        assert_ok!(Court::claim_rewards(Origin::signed(ALICE)));
         assert_eq!(Balances::free_balance(ALICE), 998 * BASE + 3 * BASE);
         assert_eq!(Balances::reserved_balance_named(&Court::reserve_id(), &ALICE), 2 * BASE);
         assert_eq!(Balances::free_balance(BOB), 996 * BASE);
@@ -190,43 +615,96 @@ fn on_resolution_awards_winners_and_slashes_losers() {
 fn on_resolution_decides_market_outcome_based_on_the_majority() {
     ExtBuilder::default().build().execute_with(|| {
         setup_blocks(2);
-        Court::join_court(Origin::signed(ALICE)).unwrap();
-        Court::join_court(Origin::signed(BOB)).unwrap();
-        Court::join_court(Origin::signed(CHARLIE)).unwrap();
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(CHARLIE), 2 * BASE).unwrap();
         Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
-        Court::vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(1)).unwrap();
-        Court::vote(Origin::signed(BOB), 0, OutcomeReport::Scalar(1)).unwrap();
-        Court::vote(Origin::signed(CHARLIE), 0, OutcomeReport::Scalar(2)).unwrap();
+        commit_and_reveal_votes(&[
+            (ALICE, OutcomeReport::Scalar(1)),
+            (BOB, OutcomeReport::Scalar(1)),
+            (CHARLIE, OutcomeReport::Scalar(2)),
+        ]);
         let outcome = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
         assert_eq!(outcome, Some(OutcomeReport::Scalar(1)));
     });
 }
 
 #[test]
-fn on_resolution_sets_late_jurors_as_tardy() {
+fn on_resolution_sets_jurors_that_did_not_vote_as_tardy() {
     ExtBuilder::default().build().execute_with(|| {
         setup_blocks(2);
-        Court::join_court(Origin::signed(ALICE)).unwrap();
-        Court::join_court(Origin::signed(BOB)).unwrap();
-        Court::vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(1)).unwrap();
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 2 * BASE).unwrap();
         Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        commit_and_reveal_votes(&[(ALICE, OutcomeReport::Scalar(1))]);
         let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
         assert_eq!(Jurors::<Runtime>::get(ALICE).unwrap().status, JurorStatus::Ok);
         assert_eq!(Jurors::<Runtime>::get(BOB).unwrap().status, JurorStatus::Tardy);
     });
 }
 
+#[test]
+fn on_resolution_sets_jurors_that_did_not_reveal_their_vote_as_tardy() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let salt = salt(BOB);
+        let commitment = commitment(BOB, &OutcomeReport::Scalar(2), &salt);
+        assert_ok!(Court::commit_vote(Origin::signed(BOB), 0, commitment));
+        commit_and_reveal_votes(&[(ALICE, OutcomeReport::Scalar(1))]);
+        let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
+        assert_eq!(Jurors::<Runtime>::get(ALICE).unwrap().status, JurorStatus::Ok);
+        assert_eq!(Jurors::<Runtime>::get(BOB).unwrap().status, JurorStatus::Tardy);
+    });
+}
+
+#[test]
+fn on_resolution_ignores_unrevealed_votes() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(CHARLIE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        for who in [BOB, CHARLIE] {
+            let salt = salt(who);
+            let commitment = commitment(who, &OutcomeReport::Scalar(2), &salt);
+            assert_ok!(Court::commit_vote(Origin::signed(who), 0, commitment));
+        }
+        commit_and_reveal_votes(&[(ALICE, OutcomeReport::Scalar(1))]);
+        let outcome = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
+        assert_eq!(outcome, Some(OutcomeReport::Scalar(1)));
+    });
+}
+
+#[test]
+fn on_resolution_fails_if_no_vote_was_revealed() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let salt = salt(ALICE);
+        let commitment = commitment(ALICE, &OutcomeReport::Scalar(1), &salt);
+        assert_ok!(Court::commit_vote(Origin::signed(ALICE), 0, commitment));
+        assert_noop!(Court::on_resolution(&[], &0, &DEFAULT_MARKET), Error::<Runtime>::NoVotes);
+    });
+}
+
 #[test]
 fn on_resolution_sets_jurors_that_voted_on_the_second_most_voted_outcome_as_tardy() {
     ExtBuilder::default().build().execute_with(|| {
         setup_blocks(2);
-        Court::join_court(Origin::signed(ALICE)).unwrap();
-        Court::join_court(Origin::signed(BOB)).unwrap();
-        Court::join_court(Origin::signed(CHARLIE)).unwrap();
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(CHARLIE), 2 * BASE).unwrap();
         Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
-        Court::vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(1)).unwrap();
-        Court::vote(Origin::signed(BOB), 0, OutcomeReport::Scalar(1)).unwrap();
-        Court::vote(Origin::signed(CHARLIE), 0, OutcomeReport::Scalar(2)).unwrap();
+        commit_and_reveal_votes(&[
+            (ALICE, OutcomeReport::Scalar(1)),
+            (BOB, OutcomeReport::Scalar(1)),
+            (CHARLIE, OutcomeReport::Scalar(2)),
+        ]);
         let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
         assert_eq!(Jurors::<Runtime>::get(CHARLIE).unwrap().status, JurorStatus::Tardy);
     });
@@ -236,11 +714,11 @@ fn on_resolution_sets_jurors_that_voted_on_the_second_most_voted_outcome_as_tard
 fn on_resolution_punishes_tardy_jurors_that_failed_to_vote_a_second_time() {
     ExtBuilder::default().build().execute_with(|| {
         setup_blocks(2);
-        Court::join_court(Origin::signed(ALICE)).unwrap();
-        Court::join_court(Origin::signed(BOB)).unwrap();
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 4 * BASE).unwrap();
         Court::set_stored_juror_as_tardy(&BOB).unwrap();
-        Court::vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(1)).unwrap();
         Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        commit_and_reveal_votes(&[(ALICE, OutcomeReport::Scalar(1))]);
         let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
         let join_court_stake = 40000000000;
         let slash = join_court_stake / 5;
@@ -251,18 +729,61 @@ fn on_resolution_punishes_tardy_jurors_that_failed_to_vote_a_second_time() {
 }
 
 #[test]
-fn on_resolution_removes_requested_jurors_and_votes() {
+fn on_resolution_returns_the_bond_if_the_appeal_changed_the_outcome() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        setup_disputed_market(&[ALICE, BOB, CHARLIE]);
+        vote_and_move_to_appeal_period(OutcomeReport::Scalar(1));
+        assert_ok!(Court::appeal(Origin::signed(APPELLANT), 0));
+        vote_and_move_to_appeal_period(OutcomeReport::Scalar(2));
+        let mut market = DEFAULT_MARKET;
+        market.status = MarketStatus::Disputed;
+        let outcome = Court::on_resolution(&[], &0, &market).unwrap();
+        assert_eq!(outcome, Some(OutcomeReport::Scalar(2)));
+        assert_eq!(Balances::reserved_balance(&APPELLANT), 0);
+        assert_eq!(Balances::free_balance(&APPELLANT), INITIAL_BALANCE);
+        assert!(Appeals::<Runtime>::get(0).is_empty());
+        assert!(!Courts::<Runtime>::contains_key(0));
+    });
+}
+
+#[test]
+fn on_resolution_slashes_the_bond_if_the_appeal_did_not_change_the_outcome() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        setup_disputed_market(&[ALICE, BOB, CHARLIE]);
+        vote_and_move_to_appeal_period(OutcomeReport::Scalar(1));
+        assert_ok!(Court::appeal(Origin::signed(APPELLANT), 0));
+        vote_and_move_to_appeal_period(OutcomeReport::Scalar(1));
+        let mut market = DEFAULT_MARKET;
+        market.status = MarketStatus::Disputed;
+        let outcome = Court::on_resolution(&[], &0, &market).unwrap();
+        assert_eq!(outcome, Some(OutcomeReport::Scalar(1)));
+        assert_eq!(Balances::reserved_balance(&APPELLANT), 0);
+        assert_eq!(Balances::free_balance(&APPELLANT), INITIAL_BALANCE - CourtAppealBond::get());
+        assert_eq!(
+            Balances::free_balance(Court::treasury_account_id()),
+            INITIAL_BALANCE + CourtAppealBond::get()
+        );
+    });
+}
+
+#[test]
+fn on_resolution_removes_the_court_case_and_votes() {
     ExtBuilder::default().build().execute_with(|| {
         setup_blocks(2);
-        Court::join_court(Origin::signed(ALICE)).unwrap();
-        Court::join_court(Origin::signed(BOB)).unwrap();
-        Court::join_court(Origin::signed(CHARLIE)).unwrap();
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(CHARLIE), 2 * BASE).unwrap();
         Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
-        Court::vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(1)).unwrap();
-        Court::vote(Origin::signed(BOB), 0, OutcomeReport::Scalar(1)).unwrap();
-        Court::vote(Origin::signed(CHARLIE), 0, OutcomeReport::Scalar(2)).unwrap();
+        commit_and_reveal_votes(&[
+            (ALICE, OutcomeReport::Scalar(1)),
+            (BOB, OutcomeReport::Scalar(1)),
+            (CHARLIE, OutcomeReport::Scalar(2)),
+        ]);
         let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
-        assert_eq!(RequestedJurors::<Runtime>::iter().count(), 0);
+        assert!(!Courts::<Runtime>::contains_key(0));
+        assert_eq!(Court::get_auto_resolve(&[], &0, &DEFAULT_MARKET).unwrap(), None);
         assert_eq!(Votes::<Runtime>::iter().count(), 0);
     });
 }
@@ -272,7 +793,7 @@ fn random_jurors_returns_an_unique_different_subset_of_jurors() {
     ExtBuilder::default().build().execute_with(|| {
         setup_blocks(123);
 
-        let mut rng = Court::rng();
+        let mut rng = Court::rng().unwrap();
         let random_jurors = Court::random_jurors(DEFAULT_SET_OF_JURORS, 2, &mut rng);
         let mut at_least_one_set_is_different = false;
 
@@ -301,37 +822,392 @@ fn random_jurors_returns_an_unique_different_subset_of_jurors() {
     });
 }
 
+#[test]
+fn on_resolution_shares_rewards_with_delegators() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 4 * BASE).unwrap();
+        Court::join_court(Origin::signed(CHARLIE), 6 * BASE).unwrap();
+        let _ = Balances::deposit_creating(&DELEGATOR, INITIAL_BALANCE);
+        Court::delegate(Origin::signed(DELEGATOR), ALICE, 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        commit_and_reveal_votes(&[
+            (ALICE, OutcomeReport::Scalar(1)),
+            (BOB, OutcomeReport::Scalar(2)),
+            (CHARLIE, OutcomeReport::Scalar(3)),
+        ]);
+        let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
+        // Charlie's slash of 3 BASE is split equally between Alice and her delegator.
+        assert_eq!(Rewards::<Runtime>::get(ALICE), 3 * BASE / 2);
+        assert_eq!(Rewards::<Runtime>::get(DELEGATOR), 3 * BASE / 2);
+        assert_eq!(Delegations::<Runtime>::get(ALICE, DELEGATOR), 2 * BASE);
+    });
+}
+
+#[test]
+fn on_resolution_slashes_delegators_of_losers() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 4 * BASE).unwrap();
+        Court::join_court(Origin::signed(CHARLIE), 6 * BASE).unwrap();
+        let _ = Balances::deposit_creating(&DELEGATOR, INITIAL_BALANCE);
+        Court::delegate(Origin::signed(DELEGATOR), CHARLIE, 4 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        commit_and_reveal_votes(&[
+            (ALICE, OutcomeReport::Scalar(1)),
+            (BOB, OutcomeReport::Scalar(2)),
+            (CHARLIE, OutcomeReport::Scalar(3)),
+        ]);
+        let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
+        assert_eq!(Balances::reserved_balance(&DELEGATOR), 2 * BASE);
+        assert_eq!(Delegations::<Runtime>::get(CHARLIE, DELEGATOR), 2 * BASE);
+        assert_eq!(DelegatedStakes::<Runtime>::get(CHARLIE), 2 * BASE);
+        assert_eq!(Rewards::<Runtime>::get(ALICE), 5 * BASE);
+    });
+}
+
+#[test]
+fn on_resolution_only_slashes_the_delegation_reserve_of_delegators() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(2);
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 4 * BASE).unwrap();
+        Court::join_court(Origin::signed(CHARLIE), 6 * BASE).unwrap();
+        let _ = Balances::deposit_creating(&DELEGATOR, INITIAL_BALANCE);
+        Court::delegate(Origin::signed(DELEGATOR), CHARLIE, 4 * BASE).unwrap();
+        // Funds the delegator reserved for something else.
+        assert_ok!(Balances::reserve(&DELEGATOR, 10 * BASE));
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        commit_and_reveal_votes(&[
+            (ALICE, OutcomeReport::Scalar(1)),
+            (BOB, OutcomeReport::Scalar(2)),
+            (CHARLIE, OutcomeReport::Scalar(3)),
+        ]);
+        let _ = Court::on_resolution(&[], &0, &DEFAULT_MARKET).unwrap();
+        assert_eq!(
+            Balances::reserved_balance_named(&Court::delegation_reserve_id(), &DELEGATOR),
+            2 * BASE
+        );
+        assert_eq!(Balances::reserved_balance(&DELEGATOR), 12 * BASE);
+    });
+}
+
+#[test]
+fn prepare_exit_court_excludes_the_juror_from_new_court_cases() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::join_court(Origin::signed(BOB), 2 * BASE).unwrap();
+        assert_ok!(Court::prepare_exit_court(Origin::signed(ALICE)));
+        assert_eq!(
+            ExitRequests::<Runtime>::get(ALICE),
+            Some(System::block_number() + JurorUnbondingPeriod::get())
+        );
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        assert_eq!(requested_jurors(), vec![BOB]);
+    });
+}
+
+#[test]
+fn prepare_exit_court_fails_if_juror_is_already_exiting() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        assert_ok!(Court::prepare_exit_court(Origin::signed(ALICE)));
+        assert_noop!(
+            Court::prepare_exit_court(Origin::signed(ALICE)),
+            Error::<Runtime>::JurorIsExiting
+        );
+    });
+}
+
+#[test]
+fn random_jurors_selects_jurors_proportional_to_their_stake() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(123);
+        let jurors = [
+            (1, Juror { status: JurorStatus::Ok }, BASE),
+            (2, Juror { status: JurorStatus::Ok }, 1_000_000 * BASE),
+        ];
+        let mut rng = Court::rng().unwrap();
+        let mut first_is_whale = 0;
+        for _ in 0..100 {
+            let random_jurors = Court::random_jurors(&jurors, 1, &mut rng);
+            if random_jurors[0].0 == 2 {
+                first_is_whale += 1;
+            }
+        }
+        assert!(first_is_whale > 90);
+    });
+}
+
+#[test]
+fn random_jurors_never_selects_jurors_without_stake_or_a_juror_twice() {
+    ExtBuilder::default().build().execute_with(|| {
+        setup_blocks(123);
+        let jurors = [
+            (1, Juror { status: JurorStatus::Ok }, 0),
+            (2, Juror { status: JurorStatus::Ok }, BASE),
+            (3, Juror { status: JurorStatus::Ok }, 2 * BASE),
+        ];
+        let mut rng = Court::rng().unwrap();
+        let mut random_jurors: Vec<_> =
+            Court::random_jurors(&jurors, 3, &mut rng).into_iter().map(|el| el.0).collect();
+        random_jurors.sort();
+        assert_eq!(random_jurors, vec![2, 3]);
+    });
+}
+
+#[test]
+fn necessary_jurors_num_grows_with_every_round() {
+    assert_eq!(Court::necessary_jurors_num(0), 3);
+    assert_eq!(Court::necessary_jurors_num(1), 7);
+    assert_eq!(Court::necessary_jurors_num(2), 15);
+    assert_eq!(Court::necessary_jurors_num(3), 31);
+}
+
 #[test]
 fn random_jurors_returns_a_subset_of_jurors() {
     ExtBuilder::default().build().execute_with(|| {
         setup_blocks(123);
-        let mut rng = Court::rng();
+        let mut rng = Court::rng().unwrap();
         let random_jurors = Court::random_jurors(DEFAULT_SET_OF_JURORS, 2, &mut rng);
-        for (_, juror) in random_jurors {
-            assert!(DEFAULT_SET_OF_JURORS.iter().any(|el| &el.1 == juror));
+        for juror in random_jurors {
+            assert!(DEFAULT_SET_OF_JURORS.contains(juror));
         }
     });
 }
 
 #[test]
-fn vote_will_not_accept_unknown_accounts() {
+fn commit_vote_will_not_accept_unknown_accounts() {
     ExtBuilder::default().build().execute_with(|| {
         assert_noop!(
-            Court::vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(0)),
+            Court::commit_vote(Origin::signed(ALICE), 0, Default::default()),
             Error::<Runtime>::OnlyJurorsCanVote
         );
     });
 }
 
 #[test]
-fn vote_will_stored_outcome_from_a_juror() {
+fn commit_vote_fails_if_the_juror_was_not_requested() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        assert_noop!(
+            Court::commit_vote(Origin::signed(ALICE), 0, Default::default()),
+            Error::<Runtime>::JurorNotRequested
+        );
+    });
+}
+
+#[test]
+fn commit_vote_fails_after_the_voting_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let block_limit = Courts::<Runtime>::get(0).unwrap().voting_ends;
+        System::set_block_number(block_limit + 1);
+        assert_noop!(
+            Court::commit_vote(Origin::signed(ALICE), 0, Default::default()),
+            Error::<Runtime>::VotingPeriodExpired
+        );
+    });
+}
+
+#[test]
+fn commit_vote_stores_the_commitment_of_a_juror() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let salt = salt(ALICE);
+        let commitment = commitment(ALICE, &OutcomeReport::Scalar(0), &salt);
+        assert_ok!(Court::commit_vote(Origin::signed(ALICE), 0, commitment));
+        assert_eq!(Votes::<Runtime>::get(0, ALICE).unwrap(), Vote::Secret { commitment });
+    });
+}
+
+#[test]
+fn reveal_vote_fails_before_the_end_of_the_voting_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let salt = salt(ALICE);
+        let commitment = commitment(ALICE, &OutcomeReport::Scalar(0), &salt);
+        assert_ok!(Court::commit_vote(Origin::signed(ALICE), 0, commitment));
+        assert_noop!(
+            Court::reveal_vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(0), salt),
+            Error::<Runtime>::RevealPeriodNotStarted
+        );
+    });
+}
+
+#[test]
+fn reveal_vote_fails_after_the_reveal_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let salt = salt(ALICE);
+        let commitment = commitment(ALICE, &OutcomeReport::Scalar(0), &salt);
+        assert_ok!(Court::commit_vote(Origin::signed(ALICE), 0, commitment));
+        let block_limit = Courts::<Runtime>::get(0).unwrap().voting_ends;
+        System::set_block_number(block_limit + CourtRevealDuration::get() + 1);
+        assert_noop!(
+            Court::reveal_vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(0), salt),
+            Error::<Runtime>::RevealPeriodExpired
+        );
+    });
+}
+
+#[test]
+fn reveal_vote_fails_without_a_commitment() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let block_limit = Courts::<Runtime>::get(0).unwrap().voting_ends;
+        System::set_block_number(block_limit + 1);
+        assert_noop!(
+            Court::reveal_vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(0), salt(ALICE)),
+            Error::<Runtime>::NoCommittedVote
+        );
+    });
+}
+
+#[test]
+fn reveal_vote_fails_if_outcome_or_salt_do_not_match_the_commitment() {
     ExtBuilder::default().build().execute_with(|| {
-        let _ = Court::join_court(Origin::signed(ALICE));
-        let _ = Court::vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(0));
-        assert_eq!(Votes::<Runtime>::get(ALICE, 0).unwrap(), (0, OutcomeReport::Scalar(0)));
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        let wrong_salt = salt(BOB);
+        let salt = salt(ALICE);
+        let commitment = commitment(ALICE, &OutcomeReport::Scalar(0), &salt);
+        assert_ok!(Court::commit_vote(Origin::signed(ALICE), 0, commitment));
+        let block_limit = Courts::<Runtime>::get(0).unwrap().voting_ends;
+        System::set_block_number(block_limit + 1);
+        assert_noop!(
+            Court::reveal_vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(1), salt),
+            Error::<Runtime>::CommitmentMismatch
+        );
+        assert_noop!(
+            Court::reveal_vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(0), wrong_salt),
+            Error::<Runtime>::CommitmentMismatch
+        );
     });
 }
 
+#[test]
+fn reveal_vote_fails_if_the_vote_was_already_revealed() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        commit_and_reveal_votes(&[(ALICE, OutcomeReport::Scalar(0))]);
+        assert_noop!(
+            Court::reveal_vote(Origin::signed(ALICE), 0, OutcomeReport::Scalar(0), salt(ALICE)),
+            Error::<Runtime>::VoteAlreadyRevealed
+        );
+    });
+}
+
+#[test]
+fn reveal_vote_stores_the_outcome_of_a_juror() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        commit_and_reveal_votes(&[(ALICE, OutcomeReport::Scalar(0))]);
+        let salt = salt(ALICE);
+        assert_eq!(
+            Votes::<Runtime>::get(0, ALICE).unwrap(),
+            Vote::Revealed {
+                commitment: commitment(ALICE, &OutcomeReport::Scalar(0), &salt),
+                outcome: OutcomeReport::Scalar(0),
+                salt,
+            }
+        );
+    });
+}
+
+#[test]
+fn undelegate_returns_the_delegation() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::delegate(Origin::signed(BOB), ALICE, 3 * BASE).unwrap();
+        assert_ok!(Court::undelegate(Origin::signed(BOB), ALICE));
+        assert_eq!(Balances::reserved_balance(&BOB), 0);
+        assert!(!Delegations::<Runtime>::contains_key(ALICE, BOB));
+        assert_eq!(DelegatedStakes::<Runtime>::get(ALICE), 0);
+        assert_eq!(DelegatorCounts::<Runtime>::get(ALICE), 0);
+    });
+}
+
+#[test]
+fn undelegate_fails_without_a_delegation() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        assert_noop!(
+            Court::undelegate(Origin::signed(BOB), ALICE),
+            Error::<Runtime>::DelegationNotFound
+        );
+    });
+}
+
+#[test]
+fn undelegate_fails_if_juror_has_active_cases() {
+    ExtBuilder::default().build().execute_with(|| {
+        Court::join_court(Origin::signed(ALICE), 2 * BASE).unwrap();
+        Court::delegate(Origin::signed(BOB), ALICE, 3 * BASE).unwrap();
+        Court::on_dispute(&[], &0, &DEFAULT_MARKET).unwrap();
+        assert_noop!(
+            Court::undelegate(Origin::signed(BOB), ALICE),
+            Error::<Runtime>::JurorHasActiveCases
+        );
+    });
+}
+
+// Commits the votes of all jurors, moves to the reveal period of market `0` and reveals them.
+fn commit_and_reveal_votes(votes: &[(AccountIdTest, OutcomeReport)]) {
+    for (who, outcome) in votes {
+        let commitment = commitment(*who, outcome, &salt(*who));
+        assert_ok!(Court::commit_vote(Origin::signed(*who), 0, commitment));
+    }
+    System::set_block_number(Courts::<Runtime>::get(0).unwrap().voting_ends + 1);
+    for (who, outcome) in votes {
+        assert_ok!(Court::reveal_vote(Origin::signed(*who), 0, outcome.clone(), salt(*who)));
+    }
+}
+
+// Jurors requested for the current round of market `0`.
+fn requested_jurors() -> Vec<AccountIdTest> {
+    Courts::<Runtime>::get(0)
+        .map(|court| court.jurors.into_iter().map(|(ai, _)| ai).collect())
+        .unwrap_or_default()
+}
+
+// Lets `jurors` join the court, funds the appellant and disputes market `0` using the court.
+fn setup_disputed_market(jurors: &[AccountIdTest]) {
+    for juror in jurors {
+        let _ = Balances::deposit_creating(juror, MinJurorStake::get());
+        assert_ok!(Court::join_court(Origin::signed(*juror), MinJurorStake::get()));
+    }
+    let _ = Balances::deposit_creating(&APPELLANT, INITIAL_BALANCE);
+    let mut market = DEFAULT_MARKET;
+    market.status = MarketStatus::Disputed;
+    MarketCommons::push_market(market.clone()).unwrap();
+    Court::on_dispute(&[], &0, &market).unwrap();
+}
+
+// All requested jurors of market `0` vote for `outcome`, then moves to the appeal period.
+fn vote_and_move_to_appeal_period(outcome: OutcomeReport) {
+    let votes: Vec<_> = requested_jurors().into_iter().map(|ai| (ai, outcome.clone())).collect();
+    commit_and_reveal_votes(&votes);
+    System::set_block_number(Courts::<Runtime>::get(0).unwrap().reveal_ends + 1);
+}
+
+fn commitment(who: AccountIdTest, outcome: &OutcomeReport, salt: &HashTest) -> HashTest {
+    BlakeTwo256::hash_of(&(who, outcome, salt))
+}
+
+fn salt(who: AccountIdTest) -> HashTest {
+    BlakeTwo256::hash_of(&who)
+}
+
 fn setup_blocks(num_blocks: u32) {
     for _ in 0..num_blocks {
         let current_block_number = System::block_number() + 1;
@@ -339,7 +1215,6 @@ fn setup_blocks(num_blocks: u32) {
         let current_digest = System::digest();
 
         System::initialize(&current_block_number, &parent_block_hash, &current_digest);
-        RandomnessCollectiveFlip::on_initialize(current_block_number);
         System::finalize();
         System::set_block_number(current_block_number);
     }
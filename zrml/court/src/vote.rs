@@ -0,0 +1,39 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use zeitgeist_primitives::types::OutcomeReport;
+
+/// A juror's vote on the outcome of a disputed market.
+///
+/// Jurors first commit to the hash of `(juror, outcome, salt)` and reveal the outcome and salt
+/// after the voting period, so that nobody can see how the others voted before voting.
+#[derive(
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    parity_scale_codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+)]
+pub enum Vote<Hash> {
+    /// The juror committed to a vote, but has not revealed it yet.
+    Secret { commitment: Hash },
+    /// The juror revealed a vote which matches the commitment.
+    Revealed { commitment: Hash, outcome: OutcomeReport, salt: Hash },
+}
@@ -45,23 +45,70 @@ use frame_support::{traits::Get, weights::Weight};
 ///  Trait containing the required functions for weight retrival within
 /// zrml_court (automatically generated)
 pub trait WeightInfoZeitgeist {
-    fn exit_court() -> Weight;
+    fn appeal() -> Weight;
+    fn claim_rewards() -> Weight;
+    fn delegate() -> Weight;
+    fn exit_court(d: u32) -> Weight;
     fn join_court() -> Weight;
-    fn vote() -> Weight;
+    fn prepare_exit_court() -> Weight;
+    fn undelegate() -> Weight;
+    fn commit_vote() -> Weight;
+    fn reveal_vote() -> Weight;
 }
 
 /// Weight functions for zrml_court (automatically generated)
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
+    // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: Court Courts (r:1 w:1)
+    // Storage: Court Appeals (r:1 w:1)
+    // Storage: Balances Reserves (r:2 w:2)
+    // Storage: Court Votes (r:2 w:1)
+    // Storage: Court Jurors (r:2 w:0)
+    // Storage: PredictionMarkets MarketIdsPerDisputeBlock (r:2 w:2)
+    // Storage: Court JurorsSelectionNonce (r:1 w:1)
+    // Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+    fn appeal() -> Weight {
+        Weight::from_ref_time(148_320_000)
+            .saturating_add(T::DbWeight::get().reads(13))
+            .saturating_add(T::DbWeight::get().writes(8))
+    }
+    // Storage: Court Rewards (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn claim_rewards() -> Weight {
+        Weight::from_ref_time(36_480_000)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    // Storage: Court Jurors (r:1 w:0)
+    // Storage: Court ExitRequests (r:1 w:0)
+    // Storage: Court JurorCases (r:1 w:0)
+    // Storage: Court Delegations (r:1 w:1)
+    // Storage: Court DelegatorCounts (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Court DelegatedStakes (r:1 w:1)
+    fn delegate() -> Weight {
+        Weight::from_ref_time(55_640_000)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
     // Storage: Court Jurors (r:1 w:1)
+    // Storage: Court ExitRequests (r:1 w:1)
+    // Storage: Court JurorCases (r:2 w:1)
     // Storage: Balances Reserves (r:1 w:1)
+    // Storage: Court Delegations (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Court DelegatedStakes (r:0 w:1)
+    // Storage: Court DelegatorCounts (r:0 w:1)
     // Storage: Court CounterForJurors (r:1 w:1)
-    // Storage: Court RequestedJurors (r:1 w:0)
-    // Storage: Court Votes (r:1 w:0)
-    fn exit_court() -> Weight {
-        Weight::from_ref_time(76_500_000)
-            .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(3))
+    fn exit_court(d: u32) -> Weight {
+        Weight::from_ref_time(61_270_000)
+            // Standard Error: 21_000
+            .saturating_add(Weight::from_ref_time(17_940_000).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(7))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
     }
     // Storage: Court Jurors (r:1 w:1)
     // Storage: Court CounterForJurors (r:1 w:1)
@@ -72,10 +119,35 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes(3))
     }
     // Storage: Court Jurors (r:1 w:0)
+    // Storage: Court Courts (r:1 w:0)
     // Storage: Court Votes (r:0 w:1)
-    fn vote() -> Weight {
-        Weight::from_ref_time(25_540_000)
-            .saturating_add(T::DbWeight::get().reads(1))
+    fn commit_vote() -> Weight {
+        Weight::from_ref_time(31_210_000)
+            .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+    // Storage: Court Jurors (r:1 w:0)
+    // Storage: Court ExitRequests (r:1 w:1)
+    fn prepare_exit_court() -> Weight {
+        Weight::from_ref_time(24_670_000)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    // Storage: Court Courts (r:1 w:0)
+    // Storage: Court Votes (r:1 w:1)
+    fn reveal_vote() -> Weight {
+        Weight::from_ref_time(38_640_000)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    // Storage: Court Delegations (r:1 w:1)
+    // Storage: Court JurorCases (r:1 w:0)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Court DelegatorCounts (r:1 w:1)
+    // Storage: Court DelegatedStakes (r:1 w:1)
+    fn undelegate() -> Weight {
+        Weight::from_ref_time(38_910_000)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
 }
@@ -0,0 +1,185 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::{fmt::Display, str::FromStr};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::{Codec, MaxEncodedLen};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use zeitgeist_primitives::types::{OutcomeReport, SerdeWrapper};
+use zrml_global_disputes::types::{OutcomeInfo, VoterLockInfo, WinnerInfo};
+
+pub use zrml_global_disputes_runtime_api::GlobalDisputesApi as GlobalDisputesRuntimeApi;
+
+#[rpc(client, server)]
+pub trait GlobalDisputesApi<BlockHash, AccountId, Balance, MarketId>
+where
+    Balance: FromStr + Display + MaxEncodedLen,
+{
+    #[method(name = "globalDisputes_winner")]
+    async fn winner(
+        &self,
+        market_id: MarketId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<WinnerInfo<SerdeWrapper<Balance>, Vec<AccountId>>>>;
+
+    #[method(name = "globalDisputes_outcomes")]
+    async fn outcomes(
+        &self,
+        market_id: MarketId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(OutcomeReport, OutcomeInfo<SerdeWrapper<Balance>, Vec<AccountId>>)>>;
+
+    #[method(name = "globalDisputes_voterLocks")]
+    async fn voter_locks(
+        &self,
+        voter: AccountId,
+        market_id: MarketId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<VoterLockInfo<SerdeWrapper<Balance>>>>;
+}
+
+/// A struct that implements the [`GlobalDisputesApi`].
+pub struct GlobalDisputes<C, B> {
+    client: Arc<C>,
+    _marker: core::marker::PhantomData<B>,
+}
+
+impl<C, B> GlobalDisputes<C, B> {
+    /// Create a new `GlobalDisputes` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        GlobalDisputes { client, _marker: Default::default() }
+    }
+}
+
+pub enum Error {
+    /// The call to the runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+// Wraps the balances of `info` so that they are serialized as strings.
+fn to_outcome_info<Balance: MaxEncodedLen, OwnerInfo>(
+    info: OutcomeInfo<Balance, OwnerInfo>,
+) -> OutcomeInfo<SerdeWrapper<Balance>, OwnerInfo> {
+    OutcomeInfo {
+        outcome_sum: SerdeWrapper(info.outcome_sum),
+        voter_sum: SerdeWrapper(info.voter_sum),
+        owners: info.owners,
+        fee: SerdeWrapper(info.fee),
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, MarketId>
+    GlobalDisputesApiServer<<Block as BlockT>::Hash, AccountId, Balance, MarketId>
+    for GlobalDisputes<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: GlobalDisputesRuntimeApi<Block, AccountId, Balance, MarketId>,
+    AccountId: Codec + Send + Sync + 'static,
+    Balance: Codec + MaybeDisplay + MaybeFromStr + MaxEncodedLen + Send + Sync + 'static,
+    MarketId: Codec + Send + Sync + 'static,
+{
+    /// If block hash is not supplied, the best block is assumed.
+    async fn winner(
+        &self,
+        market_id: MarketId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<WinnerInfo<SerdeWrapper<Balance>, Vec<AccountId>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let res = api.winner(&at, market_id).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to get the winner of the global dispute.",
+                Some(e.to_string()),
+            ))
+        })?;
+        Ok(res.map(|winner_info| WinnerInfo {
+            outcome: winner_info.outcome,
+            outcome_info: to_outcome_info(winner_info.outcome_info),
+            is_finished: winner_info.is_finished,
+        }))
+    }
+
+    /// If block hash is not supplied, the best block is assumed.
+    async fn outcomes(
+        &self,
+        market_id: MarketId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(OutcomeReport, OutcomeInfo<SerdeWrapper<Balance>, Vec<AccountId>>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let res = api.outcomes(&at, market_id).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to get the outcomes of the global dispute.",
+                Some(e.to_string()),
+            ))
+        })?;
+        Ok(res.into_iter().map(|(outcome, info)| (outcome, to_outcome_info(info))).collect())
+    }
+
+    /// If block hash is not supplied, the best block is assumed.
+    async fn voter_locks(
+        &self,
+        voter: AccountId,
+        market_id: MarketId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<VoterLockInfo<SerdeWrapper<Balance>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let res = api.voter_locks(&at, voter, market_id).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to get the locks of the voter.",
+                Some(e.to_string()),
+            ))
+        })?;
+        Ok(res.map(|lock_info| VoterLockInfo {
+            locked: SerdeWrapper(lock_info.locked),
+            conviction: lock_info.conviction,
+            votes: lock_info
+                .votes
+                .into_iter()
+                .map(|(outcome, amount)| (outcome, SerdeWrapper(amount)))
+                .collect(),
+        }))
+    }
+}
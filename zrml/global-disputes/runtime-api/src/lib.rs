@@ -0,0 +1,38 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+use zeitgeist_primitives::types::OutcomeReport;
+use zrml_global_disputes::types::{OutcomeInfo, VoterLockInfo, WinnerInfo};
+
+sp_api::decl_runtime_apis! {
+    pub trait GlobalDisputesApi<AccountId, Balance, MarketId> where
+        AccountId: Codec,
+        Balance: Codec,
+        MarketId: Codec,
+    {
+        fn winner(market_id: MarketId) -> Option<WinnerInfo<Balance, Vec<AccountId>>>;
+        fn outcomes(
+            market_id: MarketId,
+        ) -> Vec<(OutcomeReport, OutcomeInfo<Balance, Vec<AccountId>>)>;
+        fn voter_locks(voter: AccountId, market_id: MarketId) -> Option<VoterLockInfo<Balance>>;
+    }
+}
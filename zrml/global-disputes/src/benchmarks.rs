@@ -30,13 +30,14 @@ use crate::{
 };
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::{
+    dispatch::UnfilteredDispatchable,
     sp_runtime::traits::StaticLookup,
-    traits::{Currency, Get},
+    traits::{Currency, EnsureOrigin, Get},
     BoundedVec,
 };
 use frame_system::RawOrigin;
 use num_traits::ops::checked::CheckedRem;
-use sp_runtime::traits::{Bounded, SaturatedConversion, Saturating};
+use sp_runtime::traits::{Bounded, SaturatedConversion, Saturating, Zero};
 use sp_std::prelude::*;
 use zeitgeist_primitives::types::OutcomeReport;
 
@@ -67,6 +68,7 @@ benchmarks! {
         let outcome = OutcomeReport::Scalar(0);
         let amount: BalanceOf<T> = T::MinOutcomeVoteAmount::get().saturated_into();
         deposit::<T>(&caller);
+        <GlobalDisputesEnabled<T>>::put(true);
         for i in 1..=o {
             let owner = account("outcomes_owner", i, 0);
             GlobalDisputes::<T>::push_voting_outcome(
@@ -92,10 +94,22 @@ benchmarks! {
         // minus one to ensure, that we use the worst case
         // for using a new winner info after the vote_on_outcome call
         let vote_sum = amount - 1u128.saturated_into();
-        let outcome_info = OutcomeInfo { outcome_sum: vote_sum, owners: Default::default() };
+        let outcome_info = OutcomeInfo {
+            outcome_sum: vote_sum,
+            voter_sum: Zero::zero(),
+            owners: Default::default(),
+            fee: Zero::zero(),
+        };
         let winner_info = WinnerInfo {outcome: outcome.clone(), is_finished: false, outcome_info};
         <Winners<T>>::insert(market_id, winner_info);
-    }: _(RawOrigin::Signed(caller.clone()), market_id, outcome.clone(), amount)
+
+        // voting with conviction additionally writes the vote conviction
+        let conviction = if T::TallyMode::get() == TallyMode::Conviction {
+            Conviction::Locked6x
+        } else {
+            Conviction::None
+        };
+    }: _(RawOrigin::Signed(caller.clone()), market_id, outcome.clone(), amount, conviction)
     verify {
         assert_last_event::<T>(
             Event::VotedOnOutcome::<T> {
@@ -103,6 +117,7 @@ benchmarks! {
                 voter: caller,
                 outcome,
                 vote_amount: amount,
+                conviction,
             }
             .into(),
         );
@@ -120,10 +135,16 @@ benchmarks! {
         }
         let owners = BoundedVec::try_from(owners).unwrap();
         let outcome = OutcomeReport::Scalar(0);
-        let outcome_info = OutcomeInfo { outcome_sum: vote_sum, owners };
-        // is_finished is false,
+        let outcome_info = OutcomeInfo {
+            outcome_sum: vote_sum,
+            voter_sum: Zero::zero(),
+            owners,
+            fee: Zero::zero(),
+        };
+        // is_finished is true, but the conviction of each vote keeps the lock,
         // because we need `lock_needed` to be greater zero to set a lock.
-        let winner_info = WinnerInfo {outcome, is_finished: false, outcome_info};
+        let winner_info = WinnerInfo {outcome, is_finished: true, outcome_info};
+        let finished_at = frame_system::Pallet::<T>::block_number();
 
         let caller: T::AccountId = whitelisted_caller();
         let voter: T::AccountId = account("voter", 0, 0);
@@ -135,6 +156,9 @@ benchmarks! {
             let locked_balance: BalanceOf<T> = i.saturated_into();
             vote_locks.try_push((market_id, locked_balance)).unwrap();
             <Winners<T>>::insert(market_id, winner_info.clone());
+            <FinishedAt<T>>::insert(market_id, finished_at);
+            <VoteConvictions<T>>::insert(&voter, market_id, Conviction::Locked6x);
+            <ConvictionVoters<T>>::insert(market_id, 1);
         }
         <Locks<T>>::insert(voter.clone(), vote_locks.clone());
     }: {
@@ -160,7 +184,12 @@ benchmarks! {
         }
         let owners = BoundedVec::try_from(owners).unwrap();
         let outcome = OutcomeReport::Scalar(0);
-        let outcome_info = OutcomeInfo { outcome_sum: vote_sum, owners };
+        let outcome_info = OutcomeInfo {
+            outcome_sum: vote_sum,
+            voter_sum: Zero::zero(),
+            owners,
+            fee: Zero::zero(),
+        };
         // is_finished is true,
         // because we need `lock_needed` to be zero to remove all locks.
         let winner_info = WinnerInfo {outcome, is_finished: true, outcome_info};
@@ -177,8 +206,23 @@ benchmarks! {
             let locked_balance: BalanceOf<T> = 1u128.saturated_into();
             vote_locks.try_push((market_id, locked_balance)).unwrap();
             <Winners<T>>::insert(market_id, winner_info.clone());
+            // the conviction lock is already over, so it is removed
+            <FinishedAt<T>>::insert(market_id, T::BlockNumber::from(0u32));
+            <VoteConvictions<T>>::insert(&voter, market_id, Conviction::Locked2x);
+            <ConvictionVoters<T>>::insert(market_id, 1);
+            // the voter voted on the winning outcome and is rewarded
+            let reward_account = GlobalDisputes::<T>::reward_account(&market_id);
+            deposit::<T>(&reward_account);
+            <Votes<T>>::insert((&voter, market_id, OutcomeReport::Scalar(0)), vote_sum);
+            let reward_info = VoterRewardInfo {
+                pool: T::VotingOutcomeFee::get(),
+                voter_sum: vote_sum,
+                claimed: Zero::zero(),
+            };
+            <VoterRewards<T>>::insert(market_id, reward_info);
         }
         <Locks<T>>::insert(voter.clone(), vote_locks);
+        frame_system::Pallet::<T>::set_block_number(T::ConvictionLockPeriod::get());
     }: {
         <Pallet<T>>::unlock_vote_balance(
             RawOrigin::Signed(caller.clone()).into(),
@@ -188,6 +232,9 @@ benchmarks! {
     } verify {
         let lock_info = <Locks<T>>::get(&voter);
         assert!(lock_info.is_empty());
+        assert!(<VoteConvictions<T>>::iter_prefix(&voter).next().is_none());
+        assert!(<Votes<T>>::iter().next().is_none());
+        assert!(<FinishedAt<T>>::iter().next().is_none());
     }
 
     add_vote_outcome {
@@ -205,7 +252,12 @@ benchmarks! {
             owners.push(owner);
         }
         let owners = BoundedVec::try_from(owners).unwrap();
-        let outcome_info = OutcomeInfo { outcome_sum: 42u128.saturated_into(), owners };
+        let outcome_info = OutcomeInfo {
+            outcome_sum: 42u128.saturated_into(),
+            voter_sum: Zero::zero(),
+            owners,
+            fee: Zero::zero(),
+        };
         let winner_info = WinnerInfo {
             outcome: OutcomeReport::Scalar(0),
             is_finished: false,
@@ -251,7 +303,9 @@ benchmarks! {
             is_finished: true,
             outcome_info: OutcomeInfo {
                 outcome_sum: 42u128.saturated_into(),
+                voter_sum: Zero::zero(),
                 owners,
+                fee: Zero::zero(),
             },
         };
         <Winners<T>>::insert(market_id, winner_info.clone());
@@ -309,7 +363,9 @@ benchmarks! {
             is_finished: true,
             outcome_info: OutcomeInfo {
                 outcome_sum: 42u128.saturated_into(),
+                voter_sum: Zero::zero(),
                 owners,
+                fee: Zero::zero(),
             },
         };
         <Winners<T>>::insert(market_id, winner_info);
@@ -337,6 +393,7 @@ benchmarks! {
 
         let market_id: MarketIdOf<T> = 0u128.saturated_into();
 
+        <GlobalDisputesEnabled<T>>::put(true);
         for i in 1..=k {
             let owner = account("outcomes_owner", i, 0);
             GlobalDisputes::<T>::push_voting_outcome(
@@ -358,13 +415,17 @@ benchmarks! {
 
         let outcome_info = OutcomeInfo {
             outcome_sum: 42u128.saturated_into(),
-            owners
+            voter_sum: Zero::zero(),
+            owners,
+            fee: Zero::zero(),
         };
         <Outcomes<T>>::insert(market_id, winner_outcome.clone(), outcome_info);
 
         let outcome_info = OutcomeInfo {
             outcome_sum: 42u128.saturated_into(),
-            owners: Default::default()
+            voter_sum: Zero::zero(),
+            owners: Default::default(),
+            fee: Zero::zero(),
         };
         let winner_info = WinnerInfo {outcome: winner_outcome, is_finished: true, outcome_info};
         <Winners<T>>::insert(market_id, winner_info);
@@ -380,6 +441,15 @@ benchmarks! {
         assert_last_event::<T>(Event::OutcomesFullyCleaned::<T> { market_id }.into());
     }
 
+    set_global_disputes_enabled {
+        let origin = T::SetEnabledOrigin::successful_origin();
+        let call = Call::<T>::set_global_disputes_enabled { enabled: true };
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(<GlobalDisputesEnabled<T>>::get());
+        assert_last_event::<T>(Event::GlobalDisputesEnabledSet::<T> { enabled: true }.into());
+    }
+
     impl_benchmark_test_suite!(
         GlobalDisputes,
         crate::mock::ExtBuilder::default().build(),
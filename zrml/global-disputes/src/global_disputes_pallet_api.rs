@@ -81,4 +81,14 @@ pub trait GlobalDisputesPalletApi<MarketId, AccountId, Balance> {
     fn is_not_started(market_id: &MarketId) -> bool {
         !Self::is_started(market_id)
     }
+
+    /// Check if new global disputes are allowed to start.
+    fn is_enabled() -> bool;
+
+    /// Allow or forbid the start of new global disputes. Only used to set up benchmarks.
+    ///
+    /// # Arguments
+    /// - `enabled` - Whether new global disputes are allowed to start.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_enabled(enabled: bool);
 }
@@ -35,29 +35,40 @@ mod pallet {
     use frame_support::{
         ensure, log,
         pallet_prelude::{
-            DispatchResultWithPostInfo, OptionQuery, StorageDoubleMap, StorageMap, ValueQuery,
+            DispatchResultWithPostInfo, NMapKey, OptionQuery, StorageDoubleMap, StorageMap,
+            StorageNMap, StorageValue, ValueQuery,
         },
         sp_runtime::traits::StaticLookup,
+        storage::{with_transaction, TransactionOutcome},
         traits::{
-            Currency, ExistenceRequirement, Get, IsType, LockIdentifier, LockableCurrency,
-            WithdrawReasons,
+            Currency, EnsureOrigin, ExistenceRequirement, Get, Hooks, IsType, LockIdentifier,
+            LockableCurrency, WithdrawReasons,
         },
+        weights::Weight,
         Blake2_128Concat, BoundedVec, PalletId, Twox64Concat,
     };
     use frame_system::{ensure_signed, pallet_prelude::OriginFor};
     use sp_runtime::{
         traits::{AccountIdConversion, CheckedDiv, Saturating, Zero},
-        DispatchResult,
+        DispatchError, DispatchResult, Perbill, Perquintill,
     };
     use sp_std::{vec, vec::Vec};
     use zeitgeist_primitives::types::OutcomeReport;
     use zrml_market_commons::MarketCommonsPalletApi;
 
+    const ON_IDLE_MIN_WEIGHT: Weight = Weight::from_ref_time(1_000_000);
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The currency implementation used to lock tokens for voting.
         type Currency: LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
 
+        /// The number of blocks of one lock period for votes with conviction.
+        /// A vote with conviction stays locked for a multiple of this period
+        /// after the global dispute has finished.
+        #[pallet::constant]
+        type ConvictionLockPeriod: Get<Self::BlockNumber>;
+
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
         /// The vote lock identifier.
@@ -94,6 +105,18 @@ mod pallet {
         #[pallet::constant]
         type RemoveKeysLimit: Get<u32>;
 
+        /// The origin that is allowed to enable or disable the start of new global disputes.
+        type SetEnabledOrigin: EnsureOrigin<Self::Origin>;
+
+        /// The way the votes on voting outcomes are tallied.
+        #[pallet::constant]
+        type TallyMode: Get<TallyMode>;
+
+        /// The share of the funds collected in a global dispute,
+        /// which is distributed among the voters of the winning outcome.
+        #[pallet::constant]
+        type VoterRewardShare: Get<Perbill>;
+
         /// The fee required to add a voting outcome.
         #[pallet::constant]
         type VotingOutcomeFee: Get<BalanceOf<Self>>;
@@ -108,6 +131,7 @@ mod pallet {
 
     pub type OutcomeInfoOf<T> = OutcomeInfo<BalanceOf<T>, OwnerInfoOf<T>>;
     pub type WinnerInfoOf<T> = WinnerInfo<BalanceOf<T>, OwnerInfoOf<T>>;
+    pub type VoterRewardInfoOf<T> = VoterRewardInfo<BalanceOf<T>>;
     type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
     type OwnerInfoOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxOwners>;
     pub type LockInfoOf<T> =
@@ -124,6 +148,24 @@ mod pallet {
     pub type Locks<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, LockInfoOf<T>, ValueQuery>;
 
+    /// Whether new global disputes are allowed to start. Global disputes which are already
+    /// running are not affected by this switch.
+    #[pallet::storage]
+    pub type GlobalDisputesEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// The number of voters with conviction in the global dispute of a market,
+    /// whose tokens are not unlocked yet.
+    #[pallet::storage]
+    pub type ConvictionVoters<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, u32, ValueQuery>;
+
+    /// Maps the market id to the block in which the global dispute has finished.
+    /// Only stored for global disputes with voters with conviction, as long as some of them
+    /// did not unlock their tokens.
+    #[pallet::storage]
+    pub type FinishedAt<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, T::BlockNumber, OptionQuery>;
+
     /// Maps the market id to the outcome and providing information about the outcome.
     #[pallet::storage]
     pub type Outcomes<T: Config> = StorageDoubleMap<
@@ -142,6 +184,48 @@ mod pallet {
     pub type Winners<T: Config> =
         StorageMap<_, Twox64Concat, MarketIdOf<T>, WinnerInfoOf<T>, OptionQuery>;
 
+    /// The highest conviction with which a voter voted in the global dispute of a market.
+    /// It applies to all tokens the voter locked in this global dispute.
+    /// Votes without conviction are not stored.
+    ///
+    /// TWOX-NOTE: SAFE as `AccountId`s are crypto hashes anyway.
+    #[pallet::storage]
+    pub type VoteConvictions<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        MarketIdOf<T>,
+        Conviction,
+        OptionQuery,
+    >;
+
+    /// Maps the market id to the reward for the voters of the winning outcome.
+    #[pallet::storage]
+    pub type VoterRewards<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, VoterRewardInfoOf<T>, OptionQuery>;
+
+    /// The votes which a voter cast on an outcome of the global dispute of a market.
+    /// The entries are removed when the voter unlocks the votes of the finished global dispute.
+    ///
+    /// TWOX-NOTE: SAFE as `AccountId`s are crypto hashes anyway.
+    #[pallet::storage]
+    pub type Votes<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, T::AccountId>,
+            NMapKey<Twox64Concat, MarketIdOf<T>>,
+            NMapKey<Blake2_128Concat, OutcomeReport>,
+        ),
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// The finished global disputes, whose outcomes are not purged or whose outcome owners are
+    /// not rewarded yet. They are cleaned automatically in `on_idle`.
+    #[pallet::storage]
+    pub type FinishedDisputes<T: Config> = StorageMap<_, Twox64Concat, MarketIdOf<T>, ()>;
+
     #[pallet::event]
     #[pallet::generate_deposit(fn deposit_event)]
     pub enum Event<T>
@@ -154,6 +238,8 @@ mod pallet {
             owner: AccountIdOf<T>,
             outcome: OutcomeReport,
         },
+        /// The start of new global disputes has been enabled or disabled.
+        GlobalDisputesEnabledSet { enabled: bool },
         /// The winner of the global dispute system is determined.
         GlobalDisputeWinnerDetermined { market_id: MarketIdOf<T> },
         /// No funds could be spent as reward to the outcome owner(s).
@@ -164,12 +250,15 @@ mod pallet {
         OutcomesPartiallyCleaned { market_id: MarketIdOf<T> },
         /// The outcomes storage item is fully cleaned.
         OutcomesFullyCleaned { market_id: MarketIdOf<T> },
+        /// A voter of the winning outcome has been rewarded.
+        VoterRewarded { voter: AccountIdOf<T>, market_id: MarketIdOf<T>, reward: BalanceOf<T> },
         /// A vote happened on an outcome.
         VotedOnOutcome {
             voter: AccountIdOf<T>,
             market_id: MarketIdOf<T>,
             outcome: OutcomeReport,
             vote_amount: BalanceOf<T>,
+            conviction: Conviction,
         },
     }
 
@@ -177,8 +266,12 @@ mod pallet {
     pub enum Error<T> {
         /// Sender tried to vote with an amount below a defined minium.
         AmountTooLow,
+        /// Voting with conviction is not allowed in the linear tally mode.
+        ConvictionVotingDisabled,
         /// The global dispute period is already over and the winner is determined.
         GlobalDisputeAlreadyFinished,
+        /// The start of new global disputes is disabled.
+        GlobalDisputesDisabled,
         /// Sender does not have enough funds for the vote on an outcome.
         InsufficientAmount,
         /// The maximum amount of owners is reached.
@@ -197,6 +290,16 @@ mod pallet {
         OutcomesNotFullyCleaned,
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_idle(_: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            if remaining_weight < ON_IDLE_MIN_WEIGHT {
+                return Weight::zero();
+            }
+            Self::clean_finished_disputes(remaining_weight)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Add voting outcome to a global dispute in exchange for a constant fee.
@@ -232,7 +335,13 @@ mod pallet {
 
             let voting_outcome_fee = T::VotingOutcomeFee::get();
 
-            Self::push_voting_outcome(&market_id, outcome.clone(), &owner, voting_outcome_fee)?;
+            Self::do_push_voting_outcome(
+                &market_id,
+                outcome.clone(),
+                &owner,
+                voting_outcome_fee,
+                voting_outcome_fee,
+            )?;
 
             let reward_account = Self::reward_account(&market_id);
 
@@ -252,6 +361,7 @@ mod pallet {
 
         /// Purge all outcomes to allow the winning outcome owner(s) to get their reward.
         /// Fails if the global dispute is not concluded yet.
+        /// Finished global disputes are also purged automatically in `on_idle`.
         ///
         /// # Arguments
         ///
@@ -272,42 +382,14 @@ mod pallet {
         ) -> DispatchResultWithPostInfo {
             ensure_signed(origin)?;
 
-            let mut winner_info =
-                <Winners<T>>::get(market_id).ok_or(Error::<T>::NoGlobalDisputeStarted)?;
-            ensure!(winner_info.is_finished, Error::<T>::UnfinishedGlobalDispute);
-
-            let winning_outcome: Option<OutcomeInfoOf<T>> =
-                <Outcomes<T>>::get(market_id, &winner_info.outcome);
-            let mut owners_len = 0u32;
-            // move the winning outcome info to Winners before it gets drained
-            if let Some(outcome_info) = winning_outcome {
-                owners_len = outcome_info.owners.len() as u32;
-                // storage write is needed here in case,
-                // that the first call of reward_outcome_owner doesn't reward the owners
-                // this can happen if there are more than RemoveKeysLimit keys to remove
-                winner_info.outcome_info = outcome_info;
-                <Winners<T>>::insert(market_id, winner_info);
-            }
-
-            let mut removed_keys_amount = 0u32;
-            for (_, i) in
-                <Outcomes<T>>::drain_prefix(market_id).take(T::RemoveKeysLimit::get() as usize)
-            {
-                owners_len = owners_len.max(i.owners.len() as u32);
-                removed_keys_amount = removed_keys_amount.saturating_add(1u32);
-            }
-
-            if <Outcomes<T>>::iter_prefix(market_id).next().is_none() {
-                Self::deposit_event(Event::OutcomesFullyCleaned { market_id });
-            } else {
-                Self::deposit_event(Event::OutcomesPartiallyCleaned { market_id });
-            }
-
-            // weight for max owners, because we don't know
-            Ok((Some(T::WeightInfo::purge_outcomes(removed_keys_amount, owners_len))).into())
+            let (weight, _) = Self::do_purge_outcomes(market_id)?;
+            Ok(Some(weight).into())
         }
 
         /// Reward the collected fees to the owner(s) of a voting outcome.
+        /// This happens automatically in `on_idle` after all outcomes are purged.
+        /// The reward of the voters of the winning outcome, which is not claimed yet, stays in
+        /// the reward account.
         /// Fails if outcomes is not already purged.
         ///
         /// # Arguments
@@ -329,71 +411,24 @@ mod pallet {
         ) -> DispatchResultWithPostInfo {
             ensure_signed(origin)?;
 
-            ensure!(
-                <Outcomes<T>>::iter_prefix(market_id).next().is_none(),
-                <Error<T>>::OutcomesNotFullyCleaned
-            );
-
-            let winner_info =
-                <Winners<T>>::get(market_id).ok_or(Error::<T>::NoGlobalDisputeStarted)?;
-            ensure!(winner_info.is_finished, Error::<T>::UnfinishedGlobalDispute);
-
-            let reward_account = Self::reward_account(&market_id);
-            let reward_account_free_balance = T::Currency::free_balance(&reward_account);
-            let owners_len = winner_info.outcome_info.owners.len() as u32;
-
-            if reward_account_free_balance.is_zero() {
-                Self::deposit_event(Event::OutcomeOwnersRewardedWithNoFunds { market_id });
-                // return early case if there is no reward
-                return Ok((Some(T::WeightInfo::reward_outcome_owner_no_funds(owners_len))).into());
-            }
-
-            let mut remainder = reward_account_free_balance;
-            let owners_len_in_balance: BalanceOf<T> = <BalanceOf<T>>::from(owners_len);
-            if let Some(reward_per_each) =
-                reward_account_free_balance.checked_div(&owners_len_in_balance)
-            {
-                for winner in winner_info.outcome_info.owners.iter() {
-                    // *Should* always be equal to `reward_per_each`
-                    let reward = remainder.min(reward_per_each);
-                    remainder = remainder.saturating_sub(reward);
-                    // Reward the loosing funds to the winners
-                    let res = T::Currency::transfer(
-                        &reward_account,
-                        winner,
-                        reward,
-                        ExistenceRequirement::AllowDeath,
-                    );
-                    // not really much we can do if it fails
-                    debug_assert!(
-                        res.is_ok(),
-                        "Global Disputes: Rewarding a outcome owner failed."
-                    );
-                }
-            } else {
-                log::error!(
-                    "Global Disputes: There should be always at least one owner for a voting \
-                     outcome."
-                );
-                debug_assert!(false);
-            }
-
-            Self::deposit_event(Event::OutcomeOwnersRewarded {
-                market_id,
-                owners: winner_info.outcome_info.owners.to_vec(),
-            });
-
-            Ok((Some(T::WeightInfo::reward_outcome_owner_with_funds(owners_len))).into())
+            let weight = Self::do_reward_outcome_owner(market_id)?;
+            Ok(Some(weight).into())
         }
 
         /// Vote on existing voting outcomes by locking native tokens.
         /// Fails if the global dispute has not started or has already finished.
         ///
+        /// In the conviction tally mode, the vote counts `amount` times the multiplier
+        /// of the `conviction`, but the tokens stay locked for the lock periods of the
+        /// `conviction` after the global dispute has finished.
+        ///
         /// # Arguments
         ///
         /// - `market_id`: The id of the market.
         /// - `outcome`: The existing outcome report to vote on.
         /// - `amount`: The amount to vote with.
+        /// - `conviction`: The conviction to vote with. Must be `Conviction::None`
+        /// in the linear tally mode.
         ///
         /// # Weight
         ///
@@ -409,8 +444,12 @@ mod pallet {
             #[pallet::compact] market_id: MarketIdOf<T>,
             outcome: OutcomeReport,
             #[pallet::compact] amount: BalanceOf<T>,
+            conviction: Conviction,
         ) -> DispatchResultWithPostInfo {
             let voter = ensure_signed(origin)?;
+            if T::TallyMode::get() == TallyMode::Linear {
+                ensure!(conviction == Conviction::None, Error::<T>::ConvictionVotingDisabled);
+            }
             let voter_free_balance = T::Currency::free_balance(&voter);
             ensure!(amount <= voter_free_balance, Error::<T>::InsufficientAmount);
             ensure!(amount >= T::MinOutcomeVoteAmount::get(), Error::<T>::AmountTooLow);
@@ -428,8 +467,13 @@ mod pallet {
             // If the `outcome_sum` decreases, it would lead to more storage,
             // because the winning outcome could have a smaller `outcome_sum`
             // than the second highest `outcome_sum`.
-            let add_to_outcome_sum = |a| {
-                outcome_info.outcome_sum = outcome_info.outcome_sum.saturating_add(a);
+            let add_to_outcome_sum = |a: BalanceOf<T>| {
+                let votes = conviction.votes(a);
+                outcome_info.outcome_sum = outcome_info.outcome_sum.saturating_add(votes);
+                outcome_info.voter_sum = outcome_info.voter_sum.saturating_add(votes);
+                <Votes<T>>::mutate((&voter, market_id, &outcome), |v| {
+                    *v = v.saturating_add(votes);
+                });
                 Self::update_winner(&market_id, &outcome, outcome_info.outcome_sum);
                 <Outcomes<T>>::insert(market_id, &outcome, outcome_info);
             };
@@ -465,17 +509,30 @@ mod pallet {
 
             <Locks<T>>::insert(&voter, lock_info);
 
+            if conviction != Conviction::None {
+                <VoteConvictions<T>>::mutate(&voter, market_id, |prev| {
+                    if prev.is_none() {
+                        <ConvictionVoters<T>>::mutate(market_id, |n| *n = n.saturating_add(1));
+                    }
+                    *prev = Some(prev.map_or(conviction, |c| c.max(conviction)));
+                });
+            }
+
             Self::deposit_event(Event::VotedOnOutcome {
                 market_id,
                 voter,
                 outcome,
                 vote_amount: amount,
+                conviction,
             });
             Ok(Some(T::WeightInfo::vote_on_outcome(outcome_owners_len, vote_lock_counter)).into())
         }
 
         /// Return all locked native tokens in a global dispute.
-        /// Fails if the global dispute is not concluded yet.
+        /// The tokens of a global dispute which is not concluded yet stay locked.
+        /// The same applies to votes with conviction, until their lock periods are over.
+        /// When the tokens of a finished global dispute are unlocked,
+        /// the voter receives their share of the reward for the voters of the winning outcome.
         ///
         /// # Arguments
         ///
@@ -506,14 +563,22 @@ mod pallet {
             let mut lock_needed: BalanceOf<T> = Zero::zero();
             let mut lock_info = <Locks<T>>::get(&voter);
             let vote_lock_counter = lock_info.len() as u32;
+            let now = <frame_system::Pallet<T>>::block_number();
             // Inside retain we follow these rules:
-            // 1. Remove all locks from resolved (/ finished) global disputes.
-            // 2. Then find the maximum lock from all unresolved global disputes.
+            // 1. Remove all locks from resolved (/ finished) global disputes,
+            // unless the conviction of the vote still locks them.
+            // 2. Then find the maximum lock from all remaining locks.
             lock_info.retain(|&(market_id, locked_balance)| {
                 // weight component MaxOwners comes from querying the winner information
                 match <Winners<T>>::get(market_id) {
                     Some(winner_info) => {
                         if winner_info.is_finished {
+                            if Self::is_conviction_locked(&voter, &market_id, now) {
+                                lock_needed = lock_needed.max(locked_balance);
+                                return true;
+                            }
+                            Self::remove_vote_conviction(&voter, &market_id);
+                            Self::reward_voter(&voter, &market_id, &winner_info.outcome);
                             false
                         } else {
                             lock_needed = lock_needed.max(locked_balance);
@@ -527,6 +592,9 @@ mod pallet {
                         );
                         debug_assert!(false);
                         // unlock these funds
+                        Self::remove_vote_conviction(&voter, &market_id);
+                        let _ =
+                            <Votes<T>>::clear_prefix((voter.clone(), market_id), u32::MAX, None);
                         false
                     }
                 }
@@ -558,6 +626,27 @@ mod pallet {
                 .into())
             }
         }
+
+        /// Enable or disable the start of new global disputes.
+        /// Global disputes which are already running are not affected.
+        /// Ensures the `SetEnabledOrigin` in the runtime.
+        ///
+        /// # Arguments
+        ///
+        /// - `enabled`: Whether new global disputes are allowed to start.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::weight(T::WeightInfo::set_global_disputes_enabled())]
+        pub fn set_global_disputes_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            T::SetEnabledOrigin::ensure_origin(origin)?;
+
+            <GlobalDisputesEnabled<T>>::put(enabled);
+
+            Self::deposit_event(Event::GlobalDisputesEnabledSet { enabled });
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -565,37 +654,117 @@ mod pallet {
             T::GlobalDisputesPalletId::get().into_sub_account_truncating(market_id)
         }
 
-        fn update_winner(market_id: &MarketIdOf<T>, outcome: &OutcomeReport, amount: BalanceOf<T>) {
-            <Winners<T>>::mutate(market_id, |highest: &mut Option<WinnerInfoOf<T>>| {
-                *highest = Some(highest.clone().map_or(
-                    WinnerInfo::new(outcome.clone(), amount),
-                    |prev_winner_info| {
-                        if amount >= prev_winner_info.outcome_info.outcome_sum {
-                            WinnerInfo::new(outcome.clone(), amount)
-                        } else {
-                            prev_winner_info
-                        }
-                    },
-                ));
-            });
+        /// The current leader of the global dispute of `market_id` or the winner if the global
+        /// dispute has finished. Unless the outcomes are purged, the outcome information is taken
+        /// from the voting outcome, so that it contains its owners.
+        pub fn winner(
+            market_id: &MarketIdOf<T>,
+        ) -> Option<WinnerInfo<BalanceOf<T>, Vec<AccountIdOf<T>>>> {
+            let winner_info = <Winners<T>>::get(market_id)?;
+            let outcome_info = <Outcomes<T>>::get(market_id, &winner_info.outcome)
+                .unwrap_or(winner_info.outcome_info);
+            Some(WinnerInfo {
+                outcome: winner_info.outcome,
+                outcome_info: OutcomeInfo {
+                    outcome_sum: outcome_info.outcome_sum,
+                    voter_sum: outcome_info.voter_sum,
+                    owners: outcome_info.owners.into_inner(),
+                    fee: outcome_info.fee,
+                },
+                is_finished: winner_info.is_finished,
+            })
         }
-    }
 
-    impl<T> GlobalDisputesPalletApi<MarketIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Pallet<T>
-    where
-        T: Config,
-    {
-        fn push_voting_outcome(
+        /// All voting outcomes of the global dispute of `market_id`, which are not purged yet.
+        pub fn outcomes(
+            market_id: &MarketIdOf<T>,
+        ) -> Vec<(OutcomeReport, OutcomeInfo<BalanceOf<T>, Vec<AccountIdOf<T>>>)> {
+            <Outcomes<T>>::iter_prefix(market_id)
+                .map(|(outcome, outcome_info)| {
+                    let outcome_info = OutcomeInfo {
+                        outcome_sum: outcome_info.outcome_sum,
+                        voter_sum: outcome_info.voter_sum,
+                        owners: outcome_info.owners.into_inner(),
+                        fee: outcome_info.fee,
+                    };
+                    (outcome, outcome_info)
+                })
+                .collect()
+        }
+
+        /// The tokens `voter` locked in the global dispute of `market_id` and their votes,
+        /// or `None` if they are not (or no longer) locked.
+        pub fn voter_locks(
+            voter: &T::AccountId,
+            market_id: &MarketIdOf<T>,
+        ) -> Option<VoterLockInfo<BalanceOf<T>>> {
+            let lock_info = <Locks<T>>::get(voter);
+            let index = lock_info.binary_search_by_key(market_id, |i| i.0).ok()?;
+            let locked = lock_info[index].1;
+            let conviction = <VoteConvictions<T>>::get(voter, market_id).unwrap_or_default();
+            let votes = <Votes<T>>::iter_prefix((voter.clone(), *market_id)).collect();
+            Some(VoterLockInfo { locked, conviction, votes })
+        }
+
+        /// Check if the conviction of `voter` in the finished global dispute of `market_id`
+        /// still locks the voted tokens at block `now`.
+        fn is_conviction_locked(
+            voter: &T::AccountId,
+            market_id: &MarketIdOf<T>,
+            now: T::BlockNumber,
+        ) -> bool {
+            let conviction = match <VoteConvictions<T>>::get(voter, market_id) {
+                Some(conviction) => conviction,
+                None => return false,
+            };
+            let finished_at = match <FinishedAt<T>>::get(market_id) {
+                Some(finished_at) => finished_at,
+                None => {
+                    log::warn!(
+                        "Global Disputes: Finishing block is not found for market with id {:?}.",
+                        market_id
+                    );
+                    debug_assert!(false);
+                    // don't lock the tokens forever
+                    return false;
+                }
+            };
+            let lock_duration =
+                T::ConvictionLockPeriod::get().saturating_mul(conviction.lock_periods().into());
+            now < finished_at.saturating_add(lock_duration)
+        }
+
+        /// Remove the conviction of `voter` in the global dispute of `market_id`.
+        /// Removes `FinishedAt` once no voter with conviction is left.
+        fn remove_vote_conviction(voter: &T::AccountId, market_id: &MarketIdOf<T>) {
+            if <VoteConvictions<T>>::take(voter, market_id).is_none() {
+                return;
+            }
+            let remaining = <ConvictionVoters<T>>::get(market_id).saturating_sub(1);
+            if remaining == 0 {
+                <ConvictionVoters<T>>::remove(market_id);
+                <FinishedAt<T>>::remove(market_id);
+            } else {
+                <ConvictionVoters<T>>::insert(market_id, remaining);
+            }
+        }
+
+        /// Add `owner` to the voting `outcome` with `initial_vote_balance`.
+        /// `fee` is the fee paid to add a new outcome, which is not part of the voter reward.
+        fn do_push_voting_outcome(
             market_id: &MarketIdOf<T>,
             outcome: OutcomeReport,
             owner: &T::AccountId,
             initial_vote_balance: BalanceOf<T>,
+            fee: BalanceOf<T>,
         ) -> DispatchResult {
             match <Winners<T>>::get(market_id) {
                 Some(winner_info) if winner_info.is_finished => {
                     return Err(Error::<T>::GlobalDisputeAlreadyFinished.into());
                 }
-                _ => (),
+                Some(_) => (),
+                // the first voting outcome starts the global dispute
+                None => ensure!(Self::is_enabled(), Error::<T>::GlobalDisputesDisabled),
             }
             match <Outcomes<T>>::get(market_id, &outcome) {
                 Some(mut outcome_info) => {
@@ -612,8 +781,12 @@ mod pallet {
                     // adding one item to BoundedVec can not fail
                     if let Ok(owners) = BoundedVec::try_from(vec![owner.clone()]) {
                         Self::update_winner(market_id, &outcome, initial_vote_balance);
-                        let outcome_info =
-                            OutcomeInfo { outcome_sum: initial_vote_balance, owners };
+                        let outcome_info = OutcomeInfo {
+                            outcome_sum: initial_vote_balance,
+                            voter_sum: Zero::zero(),
+                            owners,
+                            fee,
+                        };
                         <Outcomes<T>>::insert(market_id, outcome, outcome_info);
                     } else {
                         log::error!("Global Disputes: Could not construct a bounded vector.");
@@ -624,6 +797,271 @@ mod pallet {
             Ok(())
         }
 
+        /// Reserve `VoterRewardShare` of the funds in the reward account,
+        /// without the fee of the winning `outcome`, for the voters of the winning `outcome`.
+        fn reserve_voter_reward(market_id: &MarketIdOf<T>, outcome: &OutcomeReport) {
+            let outcome_info = match <Outcomes<T>>::get(market_id, outcome) {
+                Some(outcome_info) => outcome_info,
+                None => return,
+            };
+            let voter_sum = outcome_info.voter_sum;
+            if voter_sum.is_zero() {
+                return;
+            }
+            // the fee of the winning outcome is not taken from a losing outcome owner
+            let funds = T::Currency::free_balance(&Self::reward_account(market_id))
+                .saturating_sub(outcome_info.fee);
+            let pool = T::VoterRewardShare::get().mul_floor(funds);
+            if pool.is_zero() {
+                return;
+            }
+            let reward_info = VoterRewardInfo { pool, voter_sum, claimed: Zero::zero() };
+            <VoterRewards<T>>::insert(market_id, reward_info);
+        }
+
+        /// Pay `voter` the share of the voter reward,
+        /// which corresponds to their votes on the winning `outcome`,
+        /// and remove all votes of `voter` in the global dispute of `market_id`.
+        fn reward_voter(voter: &T::AccountId, market_id: &MarketIdOf<T>, outcome: &OutcomeReport) {
+            let votes = <Votes<T>>::get((voter, market_id, outcome));
+            let _ = <Votes<T>>::clear_prefix((voter.clone(), *market_id), u32::MAX, None);
+            if votes.is_zero() {
+                return;
+            }
+            <VoterRewards<T>>::mutate(market_id, |reward_info| {
+                if let Some(reward_info) = reward_info {
+                    let share = Perquintill::from_rational(votes, reward_info.voter_sum);
+                    let unclaimed = reward_info.pool.saturating_sub(reward_info.claimed);
+                    let reward = share.mul_floor(reward_info.pool).min(unclaimed);
+                    if reward.is_zero() {
+                        return;
+                    }
+                    let res = T::Currency::transfer(
+                        &Self::reward_account(market_id),
+                        voter,
+                        reward,
+                        ExistenceRequirement::AllowDeath,
+                    );
+                    if res.is_ok() {
+                        reward_info.claimed = reward_info.claimed.saturating_add(reward);
+                        Self::deposit_event(Event::VoterRewarded {
+                            voter: voter.clone(),
+                            market_id: *market_id,
+                            reward,
+                        });
+                    } else {
+                        log::error!(
+                            "Global Disputes: Rewarding a voter failed for market with id {:?}.",
+                            market_id
+                        );
+                        debug_assert!(false);
+                    }
+                }
+            });
+        }
+
+        /// The part of the voter reward of `market_id`, which is not claimed yet.
+        fn unclaimed_voter_reward(market_id: &MarketIdOf<T>) -> BalanceOf<T> {
+            <VoterRewards<T>>::get(market_id).map_or(Zero::zero(), |reward_info| {
+                reward_info.pool.saturating_sub(reward_info.claimed)
+            })
+        }
+
+        /// Purge at most `RemoveKeysLimit` outcomes of the finished global dispute of
+        /// `market_id`. Returns the consumed weight and if all outcomes are purged.
+        fn do_purge_outcomes(market_id: MarketIdOf<T>) -> Result<(Weight, bool), DispatchError> {
+            let mut winner_info =
+                <Winners<T>>::get(market_id).ok_or(Error::<T>::NoGlobalDisputeStarted)?;
+            ensure!(winner_info.is_finished, Error::<T>::UnfinishedGlobalDispute);
+
+            let winning_outcome: Option<OutcomeInfoOf<T>> =
+                <Outcomes<T>>::get(market_id, &winner_info.outcome);
+            let mut owners_len = 0u32;
+            // move the winning outcome info to Winners before it gets drained
+            if let Some(outcome_info) = winning_outcome {
+                owners_len = outcome_info.owners.len() as u32;
+                // storage write is needed here in case,
+                // that the first call of reward_outcome_owner doesn't reward the owners
+                // this can happen if there are more than RemoveKeysLimit keys to remove
+                winner_info.outcome_info = outcome_info;
+                <Winners<T>>::insert(market_id, winner_info);
+            }
+
+            let mut removed_keys_amount = 0u32;
+            for (_, i) in
+                <Outcomes<T>>::drain_prefix(market_id).take(T::RemoveKeysLimit::get() as usize)
+            {
+                owners_len = owners_len.max(i.owners.len() as u32);
+                removed_keys_amount = removed_keys_amount.saturating_add(1u32);
+            }
+
+            let is_fully_cleaned = <Outcomes<T>>::iter_prefix(market_id).next().is_none();
+            if is_fully_cleaned {
+                Self::deposit_event(Event::OutcomesFullyCleaned { market_id });
+            } else {
+                Self::deposit_event(Event::OutcomesPartiallyCleaned { market_id });
+            }
+
+            Ok((T::WeightInfo::purge_outcomes(removed_keys_amount, owners_len), is_fully_cleaned))
+        }
+
+        /// Reward the collected fees, except for the unclaimed voter reward,
+        /// to the owner(s) of the winning outcome of `market_id`.
+        /// Returns the consumed weight.
+        fn do_reward_outcome_owner(market_id: MarketIdOf<T>) -> Result<Weight, DispatchError> {
+            ensure!(
+                <Outcomes<T>>::iter_prefix(market_id).next().is_none(),
+                <Error<T>>::OutcomesNotFullyCleaned
+            );
+
+            let winner_info =
+                <Winners<T>>::get(market_id).ok_or(Error::<T>::NoGlobalDisputeStarted)?;
+            ensure!(winner_info.is_finished, Error::<T>::UnfinishedGlobalDispute);
+
+            let reward_account = Self::reward_account(&market_id);
+            // keep the unclaimed reward of the voters
+            let reward_account_free_balance = T::Currency::free_balance(&reward_account)
+                .saturating_sub(Self::unclaimed_voter_reward(&market_id));
+            let owners_len = winner_info.outcome_info.owners.len() as u32;
+            <FinishedDisputes<T>>::remove(market_id);
+
+            if reward_account_free_balance.is_zero() {
+                Self::deposit_event(Event::OutcomeOwnersRewardedWithNoFunds { market_id });
+                // return early case if there is no reward
+                return Ok(T::WeightInfo::reward_outcome_owner_no_funds(owners_len));
+            }
+
+            let mut remainder = reward_account_free_balance;
+            let owners_len_in_balance: BalanceOf<T> = <BalanceOf<T>>::from(owners_len);
+            if let Some(reward_per_each) =
+                reward_account_free_balance.checked_div(&owners_len_in_balance)
+            {
+                for winner in winner_info.outcome_info.owners.iter() {
+                    // *Should* always be equal to `reward_per_each`
+                    let reward = remainder.min(reward_per_each);
+                    remainder = remainder.saturating_sub(reward);
+                    // Reward the loosing funds to the winners
+                    let res = T::Currency::transfer(
+                        &reward_account,
+                        winner,
+                        reward,
+                        ExistenceRequirement::AllowDeath,
+                    );
+                    // not really much we can do if it fails
+                    debug_assert!(
+                        res.is_ok(),
+                        "Global Disputes: Rewarding a outcome owner failed."
+                    );
+                }
+            } else {
+                log::error!(
+                    "Global Disputes: There should be always at least one owner for a voting \
+                     outcome."
+                );
+                debug_assert!(false);
+            }
+
+            Self::deposit_event(Event::OutcomeOwnersRewarded {
+                market_id,
+                owners: winner_info.outcome_info.owners.to_vec(),
+            });
+
+            Ok(T::WeightInfo::reward_outcome_owner_with_funds(owners_len))
+        }
+
+        /// Purge the outcomes of finished global disputes and reward their outcome owners,
+        /// as long as the remaining `weight` suffices. The first key of `FinishedDisputes`
+        /// is the cursor, which stays on a global dispute until it is fully cleaned.
+        /// Returns the consumed weight.
+        fn clean_finished_disputes(weight: Weight) -> Weight {
+            let max_weight_per_step =
+                T::WeightInfo::purge_outcomes(T::RemoveKeysLimit::get(), T::MaxOwners::get())
+                    .saturating_add(
+                        T::WeightInfo::reward_outcome_owner_no_funds(T::MaxOwners::get()).max(
+                            T::WeightInfo::reward_outcome_owner_with_funds(T::MaxOwners::get()),
+                        ),
+                    );
+            let cursor_weight = T::DbWeight::get().reads(1);
+            let mut total_weight = Weight::zero();
+            while total_weight.saturating_add(cursor_weight).saturating_add(max_weight_per_step)
+                <= weight
+            {
+                total_weight = total_weight.saturating_add(cursor_weight);
+                let market_id = match <FinishedDisputes<T>>::iter_keys().next() {
+                    Some(market_id) => market_id,
+                    None => break,
+                };
+                let _ = with_transaction(|| {
+                    let res = Self::do_purge_outcomes(market_id).and_then(
+                        |(purge_weight, is_fully_cleaned)| {
+                            if is_fully_cleaned {
+                                let reward_weight = Self::do_reward_outcome_owner(market_id)?;
+                                Ok(purge_weight.saturating_add(reward_weight))
+                            } else {
+                                Ok(purge_weight)
+                            }
+                        },
+                    );
+                    match res {
+                        Ok(step_weight) => {
+                            total_weight = total_weight.saturating_add(step_weight);
+                            TransactionOutcome::Commit(Ok(()))
+                        }
+                        Err(err) => {
+                            total_weight = total_weight.saturating_add(max_weight_per_step);
+                            TransactionOutcome::Rollback(Err(err))
+                        }
+                    }
+                })
+                .map_err(|err| {
+                    log::warn!(
+                        "Global Disputes: Cleaning the global dispute of market {:?} failed with \
+                         error: {:?}",
+                        market_id,
+                        err,
+                    );
+                    // skip the global dispute to not get stuck on it
+                    <FinishedDisputes<T>>::remove(market_id);
+                });
+            }
+            total_weight
+        }
+
+        fn update_winner(market_id: &MarketIdOf<T>, outcome: &OutcomeReport, amount: BalanceOf<T>) {
+            <Winners<T>>::mutate(market_id, |highest: &mut Option<WinnerInfoOf<T>>| {
+                *highest = Some(highest.clone().map_or(
+                    WinnerInfo::new(outcome.clone(), amount),
+                    |prev_winner_info| {
+                        if amount >= prev_winner_info.outcome_info.outcome_sum {
+                            WinnerInfo::new(outcome.clone(), amount)
+                        } else {
+                            prev_winner_info
+                        }
+                    },
+                ));
+            });
+        }
+    }
+
+    impl<T> GlobalDisputesPalletApi<MarketIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Pallet<T>
+    where
+        T: Config,
+    {
+        fn push_voting_outcome(
+            market_id: &MarketIdOf<T>,
+            outcome: OutcomeReport,
+            owner: &T::AccountId,
+            initial_vote_balance: BalanceOf<T>,
+        ) -> DispatchResult {
+            Self::do_push_voting_outcome(
+                market_id,
+                outcome,
+                owner,
+                initial_vote_balance,
+                Zero::zero(),
+            )
+        }
+
         fn get_voting_outcome_info(
             market_id: &MarketIdOf<T>,
             outcome: &OutcomeReport,
@@ -638,6 +1076,12 @@ mod pallet {
                     winner_info.is_finished = true;
                     let winner_outcome = winner_info.outcome.clone();
                     <Winners<T>>::insert(market_id, winner_info);
+                    if <ConvictionVoters<T>>::contains_key(market_id) {
+                        let now = <frame_system::Pallet<T>>::block_number();
+                        <FinishedAt<T>>::insert(market_id, now);
+                    }
+                    Self::reserve_voter_reward(market_id, &winner_outcome);
+                    <FinishedDisputes<T>>::insert(market_id, ());
                     Self::deposit_event(Event::GlobalDisputeWinnerDetermined {
                         market_id: *market_id,
                     });
@@ -650,5 +1094,14 @@ mod pallet {
         fn is_started(market_id: &MarketIdOf<T>) -> bool {
             <Winners<T>>::get(market_id).is_some()
         }
+
+        fn is_enabled() -> bool {
+            <GlobalDisputesEnabled<T>>::get()
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn set_enabled(enabled: bool) {
+            <GlobalDisputesEnabled<T>>::put(enabled);
+        }
     }
 }
@@ -17,16 +17,18 @@
 
 #![cfg(test)]
 
-use crate::{self as zrml_global_disputes};
+use crate::{self as zrml_global_disputes, types::TallyMode, GlobalDisputesEnabled};
 use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use frame_system::EnsureRoot;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
 };
 use zeitgeist_primitives::{
     constants::mock::{
-        BlockHashCount, GlobalDisputeLockId, GlobalDisputesPalletId, MaxReserves,
-        MinOutcomeVoteAmount, MinimumPeriod, PmPalletId, RemoveKeysLimit, VotingOutcomeFee, BASE,
+        BlockHashCount, GlobalDisputeConvictionLockPeriod, GlobalDisputeLockId,
+        GlobalDisputesPalletId, MaxReserves, MinOutcomeVoteAmount, MinimumPeriod, PmPalletId,
+        RemoveKeysLimit, VoterRewardShare, VotingOutcomeFee, BASE,
     },
     types::{
         AccountIdTest, Balance, BlockNumber, BlockTest, Hash, Index, MarketId, Moment,
@@ -59,9 +61,11 @@ construct_runtime!(
 parameter_types! {
     pub const MaxGlobalDisputeVotes: u32 = 50;
     pub const MaxOwners: u32 = 10;
+    pub storage GlobalDisputeTallyMode: TallyMode = TallyMode::Conviction;
 }
 
 impl crate::Config for Runtime {
+    type ConvictionLockPeriod = GlobalDisputeConvictionLockPeriod;
     type Currency = Balances;
     type Event = Event;
     type GlobalDisputeLockId = GlobalDisputeLockId;
@@ -71,6 +75,9 @@ impl crate::Config for Runtime {
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
+    type SetEnabledOrigin = EnsureRoot<AccountIdTest>;
+    type TallyMode = GlobalDisputeTallyMode;
+    type VoterRewardShare = VoterRewardShare;
     type VotingOutcomeFee = VotingOutcomeFee;
     type WeightInfo = crate::weights::WeightInfo<Runtime>;
 }
@@ -156,7 +163,10 @@ impl ExtBuilder {
 
         let mut t: sp_io::TestExternalities = t.into();
 
-        t.execute_with(|| System::set_block_number(1));
+        t.execute_with(|| {
+            System::set_block_number(1);
+            GlobalDisputesEnabled::<Runtime>::put(true);
+        });
 
         t
     }
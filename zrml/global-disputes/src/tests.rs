@@ -20,18 +20,23 @@
 use crate::{
     global_disputes_pallet_api::GlobalDisputesPalletApi,
     mock::*,
-    types::{OutcomeInfo, WinnerInfo},
-    Error, Event, Locks, MarketIdOf, Outcomes, Winners,
+    types::{Conviction, OutcomeInfo, TallyMode, VoterRewardInfo, WinnerInfo},
+    ConvictionVoters, Error, Event, FinishedAt, FinishedDisputes, GlobalDisputesEnabled, Locks,
+    MarketIdOf, Outcomes, VoteConvictions, VoterRewards, Votes, Winners,
 };
 use frame_support::{
     assert_noop, assert_ok,
-    traits::{Currency, ReservableCurrency},
+    traits::{Currency, Hooks, ReservableCurrency},
+    weights::Weight,
     BoundedVec,
 };
 use pallet_balances::{BalanceLock, Error as BalancesError};
-use sp_runtime::traits::Zero;
+use sp_runtime::{traits::Zero, DispatchError};
 use zeitgeist_primitives::{
-    constants::mock::{GlobalDisputeLockId, MinOutcomeVoteAmount, VotingOutcomeFee, BASE},
+    constants::mock::{
+        GlobalDisputeConvictionLockPeriod, GlobalDisputeLockId, MinOutcomeVoteAmount,
+        VoterRewardShare, VotingOutcomeFee, BASE,
+    },
     types::OutcomeReport,
 };
 
@@ -63,7 +68,9 @@ fn check_outcome_sum(
         <Outcomes<Runtime>>::get(market_id, outcome).unwrap(),
         OutcomeInfo {
             outcome_sum: SETUP_AMOUNT + post_setup_amount,
-            owners: BoundedVec::try_from(vec![ALICE]).unwrap()
+            voter_sum: post_setup_amount,
+            owners: BoundedVec::try_from(vec![ALICE]).unwrap(),
+            fee: Zero::zero(),
         }
     );
 }
@@ -154,7 +161,12 @@ fn add_vote_outcome_fails_if_outcome_already_exists() {
         <Outcomes<Runtime>>::insert(
             market_id,
             OutcomeReport::Scalar(20),
-            OutcomeInfo { outcome_sum: Zero::zero(), owners: Default::default() },
+            OutcomeInfo {
+                outcome_sum: Zero::zero(),
+                voter_sum: Zero::zero(),
+                owners: Default::default(),
+                fee: Zero::zero(),
+            },
         );
         assert_noop!(
             GlobalDisputes::add_vote_outcome(
@@ -198,7 +210,9 @@ fn reward_outcome_owner_works_for_multiple_owners() {
             OutcomeReport::Scalar(20),
             OutcomeInfo {
                 outcome_sum: Zero::zero(),
+                voter_sum: Zero::zero(),
                 owners: BoundedVec::try_from(vec![ALICE, BOB, CHARLIE]).unwrap(),
+                fee: Zero::zero(),
             },
         );
         let _ = Balances::deposit_creating(
@@ -208,7 +222,12 @@ fn reward_outcome_owner_works_for_multiple_owners() {
         let winner_info = WinnerInfo {
             outcome: OutcomeReport::Scalar(20),
             is_finished: true,
-            outcome_info: OutcomeInfo { outcome_sum: 10 * BASE, owners: Default::default() },
+            outcome_info: OutcomeInfo {
+                outcome_sum: 10 * BASE,
+                voter_sum: Zero::zero(),
+                owners: Default::default(),
+                fee: Zero::zero(),
+            },
         };
         <Winners<Runtime>>::insert(market_id, winner_info);
 
@@ -252,15 +271,22 @@ fn reward_outcome_owner_has_dust() {
             OutcomeReport::Scalar(20),
             OutcomeInfo {
                 outcome_sum: Zero::zero(),
+                voter_sum: Zero::zero(),
                 owners: BoundedVec::try_from(vec![ALICE, BOB, CHARLIE, EVE, POOR_PAUL, DAVE])
                     .unwrap(),
+                fee: Zero::zero(),
             },
         );
         let _ = Balances::deposit_creating(&GlobalDisputes::reward_account(&market_id), 100 * BASE);
         let winner_info = WinnerInfo {
             outcome: OutcomeReport::Scalar(20),
             is_finished: true,
-            outcome_info: OutcomeInfo { outcome_sum: 10 * BASE, owners: Default::default() },
+            outcome_info: OutcomeInfo {
+                outcome_sum: 10 * BASE,
+                voter_sum: Zero::zero(),
+                owners: Default::default(),
+                fee: Zero::zero(),
+            },
         };
         <Winners<Runtime>>::insert(market_id, winner_info);
 
@@ -283,7 +309,9 @@ fn reward_outcome_owner_works_for_one_owner() {
             OutcomeReport::Scalar(20),
             OutcomeInfo {
                 outcome_sum: Zero::zero(),
+                voter_sum: Zero::zero(),
                 owners: BoundedVec::try_from(vec![ALICE]).unwrap(),
+                fee: Zero::zero(),
             },
         );
         let _ = Balances::deposit_creating(
@@ -293,7 +321,12 @@ fn reward_outcome_owner_works_for_one_owner() {
         let winner_info = WinnerInfo {
             outcome: OutcomeReport::Scalar(20),
             is_finished: true,
-            outcome_info: OutcomeInfo { outcome_sum: 10 * BASE, owners: Default::default() },
+            outcome_info: OutcomeInfo {
+                outcome_sum: 10 * BASE,
+                voter_sum: Zero::zero(),
+                owners: Default::default(),
+                fee: Zero::zero(),
+            },
         };
         <Winners<Runtime>>::insert(market_id, winner_info);
 
@@ -328,7 +361,12 @@ fn reward_outcome_owner_works_for_no_reward_funds() {
         let winner_info = WinnerInfo {
             outcome: OutcomeReport::Scalar(20),
             is_finished: true,
-            outcome_info: OutcomeInfo { outcome_sum: 10 * BASE, owners: Default::default() },
+            outcome_info: OutcomeInfo {
+                outcome_sum: 10 * BASE,
+                voter_sum: Zero::zero(),
+                owners: Default::default(),
+                fee: Zero::zero(),
+            },
         };
         <Winners<Runtime>>::insert(market_id, winner_info);
 
@@ -395,6 +433,7 @@ fn vote_fails_if_amount_below_min_outcome_vote_amount() {
                 market_id,
                 OutcomeReport::Scalar(40),
                 MinOutcomeVoteAmount::get() - 1,
+                Conviction::None,
             ),
             Error::<Runtime>::AmountTooLow
         );
@@ -425,7 +464,8 @@ fn vote_fails_for_insufficient_funds() {
                 Origin::signed(POOR_PAUL),
                 market_id,
                 OutcomeReport::Scalar(0),
-                50 * BASE
+                50 * BASE,
+                Conviction::None
             ),
             Error::<Runtime>::InsufficientAmount
         );
@@ -443,28 +483,32 @@ fn determine_voting_winner_sets_the_last_outcome_for_same_vote_balances_as_the_c
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(0),
-            42 * BASE
+            42 * BASE,
+            Conviction::None
         ));
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id,
             OutcomeReport::Scalar(20),
-            42 * BASE
+            42 * BASE,
+            Conviction::None
         ));
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(CHARLIE),
             market_id,
             OutcomeReport::Scalar(40),
-            42 * BASE
+            42 * BASE,
+            Conviction::None
         ));
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(EVE),
             market_id,
             OutcomeReport::Scalar(60),
-            42 * BASE
+            42 * BASE,
+            Conviction::None
         ));
 
         assert_eq!(
@@ -488,7 +532,8 @@ fn vote_on_outcome_check_event() {
             Origin::signed(EVE),
             market_id,
             OutcomeReport::Scalar(60),
-            42 * BASE
+            42 * BASE,
+            Conviction::None
         ));
 
         System::assert_last_event(
@@ -497,6 +542,7 @@ fn vote_on_outcome_check_event() {
                 market_id,
                 outcome: OutcomeReport::Scalar(60),
                 vote_amount: 42 * BASE,
+                conviction: Conviction::None,
             }
             .into(),
         );
@@ -540,7 +586,8 @@ fn reserve_before_init_vote_outcome_is_not_allowed_for_voting() {
                 Origin::signed(*disputor),
                 market_id,
                 OutcomeReport::Scalar(0),
-                arbitrary_amount + 1
+                arbitrary_amount + 1,
+                Conviction::None
             ),
             Error::<Runtime>::InsufficientAmount
         );
@@ -549,7 +596,8 @@ fn reserve_before_init_vote_outcome_is_not_allowed_for_voting() {
             Origin::signed(*disputor),
             market_id,
             OutcomeReport::Scalar(0),
-            arbitrary_amount
+            arbitrary_amount,
+            Conviction::None
         ));
 
         assert_eq!(
@@ -576,7 +624,8 @@ fn transfer_fails_with_fully_locked_balance() {
             Origin::signed(*disputor),
             market_id,
             OutcomeReport::Scalar(0),
-            free_balance_disputor_before - arbitrary_amount
+            free_balance_disputor_before - arbitrary_amount,
+            Conviction::None
         ));
 
         assert_eq!(Balances::free_balance(disputor), free_balance_disputor_before);
@@ -608,7 +657,8 @@ fn reserve_fails_with_fully_locked_balance() {
             Origin::signed(*disputor),
             market_id,
             OutcomeReport::Scalar(0),
-            free_balance_disputor_before - arbitrary_amount
+            free_balance_disputor_before - arbitrary_amount,
+            Conviction::None
         ));
 
         assert_eq!(Balances::free_balance(disputor), free_balance_disputor_before);
@@ -636,26 +686,30 @@ fn determine_voting_winner_works_four_outcome_votes() {
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(0),
-            10 * BASE
+            10 * BASE,
+            Conviction::None
         ));
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id,
             OutcomeReport::Scalar(20),
-            10 * BASE
+            10 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(CHARLIE),
             market_id,
             OutcomeReport::Scalar(40),
-            11 * BASE
+            11 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(EVE),
             market_id,
             OutcomeReport::Scalar(60),
-            10 * BASE
+            10 * BASE,
+            Conviction::None
         ));
 
         check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 10 * BASE);
@@ -683,25 +737,29 @@ fn determine_voting_winner_works_three_outcome_votes() {
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(20),
-            30 * BASE
+            30 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id,
             OutcomeReport::Scalar(40),
-            50 * BASE
+            50 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(CHARLIE),
             market_id,
             OutcomeReport::Scalar(0),
-            10 * BASE
+            10 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(EVE),
             market_id,
             OutcomeReport::Scalar(0),
-            41 * BASE
+            41 * BASE,
+            Conviction::None
         ));
 
         check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 51 * BASE);
@@ -727,25 +785,29 @@ fn determine_voting_winner_works_two_outcome_votes() {
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(60),
-            10 * BASE
+            10 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id,
             OutcomeReport::Scalar(20),
-            50 * BASE
+            50 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(CHARLIE),
             market_id,
             OutcomeReport::Scalar(60),
-            20 * BASE
+            20 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(EVE),
             market_id,
             OutcomeReport::Scalar(60),
-            21 * BASE
+            21 * BASE,
+            Conviction::None
         ));
 
         check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 0);
@@ -771,7 +833,8 @@ fn determine_voting_winner_works_with_accumulated_votes_for_alice() {
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(20),
-            BASE
+            BASE,
+            Conviction::None
         ));
         check_outcome_sum(&market_id, OutcomeReport::Scalar(20), BASE);
 
@@ -779,7 +842,8 @@ fn determine_voting_winner_works_with_accumulated_votes_for_alice() {
             Origin::signed(CHARLIE),
             market_id,
             OutcomeReport::Scalar(0),
-            10 * BASE
+            10 * BASE,
+            Conviction::None
         ));
         check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 10 * BASE);
 
@@ -787,7 +851,8 @@ fn determine_voting_winner_works_with_accumulated_votes_for_alice() {
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(20),
-            10 * BASE
+            10 * BASE,
+            Conviction::None
         ));
         check_outcome_sum(&market_id, OutcomeReport::Scalar(20), 11 * BASE);
 
@@ -795,7 +860,8 @@ fn determine_voting_winner_works_with_accumulated_votes_for_alice() {
             Origin::signed(EVE),
             market_id,
             OutcomeReport::Scalar(0),
-            40 * BASE
+            40 * BASE,
+            Conviction::None
         ));
         // Eve and Charlie have more together currently
         check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 50 * BASE);
@@ -805,7 +871,8 @@ fn determine_voting_winner_works_with_accumulated_votes_for_alice() {
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(20),
-            40 * BASE
+            40 * BASE,
+            Conviction::None
         ));
         // votes accumulate
         check_outcome_sum(&market_id, OutcomeReport::Scalar(20), 51 * BASE);
@@ -829,14 +896,16 @@ fn reward_outcome_owner_cleans_outcome_info() {
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(0),
-            10 * BASE
+            10 * BASE,
+            Conviction::None
         ));
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id,
             OutcomeReport::Scalar(20),
-            10 * BASE
+            10 * BASE,
+            Conviction::None
         ));
 
         check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 10 * BASE);
@@ -869,7 +938,8 @@ fn unlock_clears_lock_info() {
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(0),
-            50 * BASE
+            50 * BASE,
+            Conviction::None
         ));
 
         assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
@@ -920,7 +990,8 @@ fn vote_fails_if_outcome_does_not_exist() {
                 Origin::signed(ALICE),
                 market_id,
                 OutcomeReport::Scalar(42),
-                50 * BASE
+                50 * BASE,
+                Conviction::None
             ),
             Error::<Runtime>::OutcomeDoesNotExist
         );
@@ -947,25 +1018,29 @@ fn locking_works_for_one_market() {
             Origin::signed(ALICE),
             market_id,
             OutcomeReport::Scalar(0),
-            50 * BASE
+            50 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id,
             OutcomeReport::Scalar(20),
-            40 * BASE
+            40 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(CHARLIE),
             market_id,
             OutcomeReport::Scalar(40),
-            30 * BASE
+            30 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(EVE),
             market_id,
             OutcomeReport::Scalar(60),
-            20 * BASE
+            20 * BASE,
+            Conviction::None
         ));
 
         assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 50 * BASE)]);
@@ -1031,26 +1106,30 @@ fn locking_works_for_two_markets_with_stronger_first_unlock() {
             Origin::signed(ALICE),
             market_id_1,
             OutcomeReport::Scalar(0),
-            50 * BASE
+            50 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id_1,
             OutcomeReport::Scalar(20),
-            40 * BASE
+            40 * BASE,
+            Conviction::None
         ));
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(ALICE),
             market_id_2,
             OutcomeReport::Scalar(0),
-            30 * BASE
+            30 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id_2,
             OutcomeReport::Scalar(20),
-            20 * BASE
+            20 * BASE,
+            Conviction::None
         ));
 
         assert_eq!(
@@ -1121,26 +1200,30 @@ fn locking_works_for_two_markets_with_weaker_first_unlock() {
             Origin::signed(ALICE),
             market_id_1,
             OutcomeReport::Scalar(0),
-            50 * BASE
+            50 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id_1,
             OutcomeReport::Scalar(20),
-            40 * BASE
+            40 * BASE,
+            Conviction::None
         ));
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(ALICE),
             market_id_2,
             OutcomeReport::Scalar(0),
-            30 * BASE
+            30 * BASE,
+            Conviction::None
         ));
         assert_ok!(GlobalDisputes::vote_on_outcome(
             Origin::signed(BOB),
             market_id_2,
             OutcomeReport::Scalar(20),
-            20 * BASE
+            20 * BASE,
+            Conviction::None
         ));
 
         assert_eq!(
@@ -1193,3 +1276,541 @@ fn locking_works_for_two_markets_with_weaker_first_unlock() {
         assert!(Balances::locks(BOB).is_empty());
     });
 }
+
+#[test]
+fn vote_on_outcome_with_conviction_multiplies_the_outcome_sum() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+
+        setup_vote_outcomes_with_hundred(&market_id);
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(0),
+            50 * BASE,
+            Conviction::Locked3x
+        ));
+
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 150 * BASE);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 50 * BASE)]);
+        assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
+        assert_eq!(<VoteConvictions<Runtime>>::get(ALICE, market_id), Some(Conviction::Locked3x));
+        assert_eq!(<Winners<Runtime>>::get(market_id).unwrap().outcome, OutcomeReport::Scalar(0));
+    });
+}
+
+#[test]
+fn vote_on_outcome_keeps_the_highest_conviction() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+
+        setup_vote_outcomes_with_hundred(&market_id);
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(0),
+            10 * BASE,
+            Conviction::Locked4x
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            10 * BASE,
+            Conviction::Locked2x
+        ));
+
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 40 * BASE);
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(20), 20 * BASE);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 20 * BASE)]);
+        assert_eq!(<VoteConvictions<Runtime>>::get(ALICE, market_id), Some(Conviction::Locked4x));
+    });
+}
+
+#[test]
+fn vote_on_outcome_fails_with_conviction_in_linear_tally_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+
+        setup_vote_outcomes_with_hundred(&market_id);
+
+        GlobalDisputeTallyMode::set(&TallyMode::Linear);
+
+        assert_noop!(
+            GlobalDisputes::vote_on_outcome(
+                Origin::signed(ALICE),
+                market_id,
+                OutcomeReport::Scalar(0),
+                50 * BASE,
+                Conviction::Locked2x
+            ),
+            Error::<Runtime>::ConvictionVotingDisabled
+        );
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(0),
+            50 * BASE,
+            Conviction::None
+        ));
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 50 * BASE);
+    });
+}
+
+#[test]
+fn unlock_vote_balance_respects_the_conviction_lock_periods() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+
+        setup_vote_outcomes_with_hundred(&market_id);
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(0),
+            50 * BASE,
+            Conviction::Locked3x
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(20),
+            40 * BASE,
+            Conviction::None
+        ));
+
+        System::set_block_number(5);
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+        assert_eq!(<FinishedAt<Runtime>>::get(market_id), Some(5));
+
+        // votes without conviction are unlocked as soon as the global dispute has finished
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), BOB));
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
+        assert!(Balances::locks(BOB).is_empty());
+
+        // `Locked3x` locks for two lock periods after the global dispute has finished
+        let unlock_at = 5 + 2 * GlobalDisputeConvictionLockPeriod::get();
+        System::set_block_number(unlock_at - 1);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), ALICE));
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 50 * BASE)]);
+        assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
+
+        System::set_block_number(unlock_at);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), ALICE));
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert!(Balances::locks(ALICE).is_empty());
+        assert_eq!(<VoteConvictions<Runtime>>::get(ALICE, market_id), None);
+        // the last voter with conviction unlocked
+        assert!(!<ConvictionVoters<Runtime>>::contains_key(market_id));
+        assert_eq!(<FinishedAt<Runtime>>::get(market_id), None);
+    });
+}
+
+#[test]
+fn finished_at_is_kept_until_all_voters_with_conviction_unlocked() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+
+        setup_vote_outcomes_with_hundred(&market_id);
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(0),
+            50 * BASE,
+            Conviction::Locked2x
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(0),
+            10 * BASE,
+            Conviction::Locked3x
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(20),
+            40 * BASE,
+            Conviction::Locked2x
+        ));
+        assert_eq!(<ConvictionVoters<Runtime>>::get(market_id), 2);
+
+        System::set_block_number(5);
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+
+        // `Locked2x` locks for one lock period, `Locked3x` for two
+        System::set_block_number(5 + GlobalDisputeConvictionLockPeriod::get());
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), BOB));
+        assert_eq!(<ConvictionVoters<Runtime>>::get(market_id), 1);
+        assert_eq!(<FinishedAt<Runtime>>::get(market_id), Some(5));
+
+        System::set_block_number(5 + 2 * GlobalDisputeConvictionLockPeriod::get());
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), ALICE));
+        assert!(!<ConvictionVoters<Runtime>>::contains_key(market_id));
+        assert_eq!(<FinishedAt<Runtime>>::get(market_id), None);
+    });
+}
+
+#[test]
+fn finished_at_is_not_stored_without_voters_with_conviction() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+
+        setup_vote_outcomes_with_hundred(&market_id);
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(0),
+            50 * BASE,
+            Conviction::None
+        ));
+
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+        assert_eq!(<FinishedAt<Runtime>>::get(market_id), None);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(ALICE), ALICE));
+        assert!(Balances::locks(ALICE).is_empty());
+    });
+}
+
+fn setup_voter_reward(market_id: &MarketIdOf<Runtime>) {
+    setup_vote_outcomes_with_hundred(market_id);
+    let _ = Balances::deposit_creating(&GlobalDisputes::reward_account(market_id), 10 * BASE);
+
+    assert_ok!(GlobalDisputes::vote_on_outcome(
+        Origin::signed(BOB),
+        *market_id,
+        OutcomeReport::Scalar(20),
+        30 * BASE,
+        Conviction::None
+    ));
+    assert_ok!(GlobalDisputes::vote_on_outcome(
+        Origin::signed(CHARLIE),
+        *market_id,
+        OutcomeReport::Scalar(20),
+        10 * BASE,
+        Conviction::None
+    ));
+    assert_ok!(GlobalDisputes::vote_on_outcome(
+        Origin::signed(EVE),
+        *market_id,
+        OutcomeReport::Scalar(40),
+        20 * BASE,
+        Conviction::None
+    ));
+
+    assert_eq!(
+        GlobalDisputes::determine_voting_winner(market_id).unwrap(),
+        OutcomeReport::Scalar(20)
+    );
+}
+
+#[test]
+fn unlock_vote_balance_rewards_voters_of_the_winning_outcome_pro_rata() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_voter_reward(&market_id);
+
+        // `VoterRewardShare` is 50 %
+        assert_eq!(
+            <VoterRewards<Runtime>>::get(market_id).unwrap(),
+            VoterRewardInfo { pool: 5 * BASE, voter_sum: 40 * BASE, claimed: Zero::zero() }
+        );
+
+        let free_balance_bob_before = Balances::free_balance(&BOB);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), BOB));
+        System::assert_last_event(
+            Event::<Runtime>::VoterRewarded { voter: BOB, market_id, reward: 375 * BASE / 100 }
+                .into(),
+        );
+        assert_eq!(Balances::free_balance(&BOB), free_balance_bob_before + 375 * BASE / 100);
+        assert_eq!(<Votes<Runtime>>::get((BOB, market_id, OutcomeReport::Scalar(20))), 0);
+
+        let free_balance_charlie_before = Balances::free_balance(&CHARLIE);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), CHARLIE));
+        assert_eq!(
+            Balances::free_balance(&CHARLIE),
+            free_balance_charlie_before + 125 * BASE / 100
+        );
+
+        // voters of losing outcomes are not rewarded
+        let free_balance_eve_before = Balances::free_balance(&EVE);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), EVE));
+        assert_eq!(Balances::free_balance(&EVE), free_balance_eve_before);
+        assert_eq!(<Votes<Runtime>>::get((EVE, market_id, OutcomeReport::Scalar(40))), 0);
+
+        assert_eq!(<VoterRewards<Runtime>>::get(market_id).unwrap().claimed, 5 * BASE);
+        assert_eq!(Balances::free_balance(GlobalDisputes::reward_account(&market_id)), 5 * BASE);
+
+        // unlocking again does not reward twice
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), BOB));
+        assert_eq!(Balances::free_balance(&BOB), free_balance_bob_before + 375 * BASE / 100);
+    });
+}
+
+#[test]
+fn reward_outcome_owner_keeps_the_unclaimed_voter_reward() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_voter_reward(&market_id);
+
+        assert_ok!(GlobalDisputes::purge_outcomes(Origin::signed(ALICE), market_id));
+        let free_balance_alice_before = Balances::free_balance(&ALICE);
+        assert_ok!(GlobalDisputes::reward_outcome_owner(Origin::signed(ALICE), market_id));
+        assert_eq!(Balances::free_balance(&ALICE), free_balance_alice_before + 5 * BASE);
+        assert_eq!(Balances::free_balance(GlobalDisputes::reward_account(&market_id)), 5 * BASE);
+
+        let free_balance_bob_before = Balances::free_balance(&BOB);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), BOB));
+        assert_eq!(Balances::free_balance(&BOB), free_balance_bob_before + 375 * BASE / 100);
+        let free_balance_charlie_before = Balances::free_balance(&CHARLIE);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(Origin::signed(BOB), CHARLIE));
+        assert_eq!(
+            Balances::free_balance(&CHARLIE),
+            free_balance_charlie_before + 125 * BASE / 100
+        );
+        assert!(Balances::free_balance(GlobalDisputes::reward_account(&market_id)).is_zero());
+    });
+}
+
+#[test]
+fn voter_reward_does_not_contain_the_fee_of_the_winning_outcome() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        assert_ok!(GlobalDisputes::push_voting_outcome(
+            &market_id,
+            OutcomeReport::Scalar(0),
+            &ALICE,
+            SETUP_AMOUNT
+        ));
+        assert_ok!(GlobalDisputes::add_vote_outcome(
+            Origin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(20),
+        ));
+        assert_ok!(GlobalDisputes::add_vote_outcome(
+            Origin::signed(CHARLIE),
+            market_id,
+            OutcomeReport::Scalar(40),
+        ));
+        assert_eq!(
+            <Outcomes<Runtime>>::get(market_id, OutcomeReport::Scalar(20)).unwrap().fee,
+            VotingOutcomeFee::get()
+        );
+        assert!(
+            <Outcomes<Runtime>>::get(market_id, OutcomeReport::Scalar(0)).unwrap().fee.is_zero()
+        );
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(EVE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            2 * SETUP_AMOUNT,
+            Conviction::None
+        ));
+        assert_eq!(
+            GlobalDisputes::determine_voting_winner(&market_id).unwrap(),
+            OutcomeReport::Scalar(20)
+        );
+
+        // only the fee of the losing outcome of CHARLIE is shared with the voters
+        let pool = VoterRewardShare::get().mul_floor(VotingOutcomeFee::get());
+        assert_eq!(
+            <VoterRewards<Runtime>>::get(market_id).unwrap(),
+            VoterRewardInfo { pool, voter_sum: 2 * SETUP_AMOUNT, claimed: Zero::zero() }
+        );
+    });
+}
+
+#[test]
+fn on_idle_purges_outcomes_and_rewards_outcome_owners_of_finished_disputes() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_vote_outcomes_with_hundred(&market_id);
+        let _ = Balances::deposit_creating(
+            &GlobalDisputes::reward_account(&market_id),
+            3 * VotingOutcomeFee::get(),
+        );
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(0),
+            10 * BASE,
+            Conviction::None
+        ));
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+        assert!(<FinishedDisputes<Runtime>>::contains_key(market_id));
+
+        let free_balance_alice_before = Balances::free_balance(&ALICE);
+        GlobalDisputes::on_idle(System::block_number(), Weight::MAX);
+
+        System::assert_has_event(Event::<Runtime>::OutcomesFullyCleaned { market_id }.into());
+        System::assert_last_event(
+            Event::<Runtime>::OutcomeOwnersRewarded { market_id, owners: vec![ALICE] }.into(),
+        );
+        assert!(<Outcomes<Runtime>>::iter_prefix(market_id).next().is_none());
+        assert!(!<FinishedDisputes<Runtime>>::contains_key(market_id));
+        // the unclaimed voter reward stays in the reward account
+        let voter_reward = VoterRewardShare::get().mul_floor(3 * VotingOutcomeFee::get());
+        assert_eq!(
+            Balances::free_balance(&ALICE),
+            free_balance_alice_before + 3 * VotingOutcomeFee::get() - voter_reward
+        );
+        assert_eq!(
+            Balances::free_balance(GlobalDisputes::reward_account(&market_id)),
+            voter_reward
+        );
+    });
+}
+
+#[test]
+fn on_idle_does_nothing_without_enough_weight() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_vote_outcomes_with_hundred(&market_id);
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+
+        let weight = GlobalDisputes::on_idle(System::block_number(), Weight::from_ref_time(1));
+
+        assert_eq!(weight, Weight::zero());
+        assert!(<Outcomes<Runtime>>::iter_prefix(market_id).next().is_some());
+        assert!(<FinishedDisputes<Runtime>>::contains_key(market_id));
+    });
+}
+
+#[test]
+fn reward_outcome_owner_removes_the_finished_dispute_from_on_idle() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_vote_outcomes_with_hundred(&market_id);
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+
+        assert_ok!(GlobalDisputes::purge_outcomes(Origin::signed(ALICE), market_id));
+        assert!(<FinishedDisputes<Runtime>>::contains_key(market_id));
+        assert_ok!(GlobalDisputes::reward_outcome_owner(Origin::signed(ALICE), market_id));
+        assert!(!<FinishedDisputes<Runtime>>::contains_key(market_id));
+    });
+}
+
+#[test]
+fn set_global_disputes_enabled_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(GlobalDisputes::set_global_disputes_enabled(Origin::root(), false));
+        System::assert_last_event(
+            Event::<Runtime>::GlobalDisputesEnabledSet { enabled: false }.into(),
+        );
+        assert!(!<GlobalDisputesEnabled<Runtime>>::get());
+        assert!(!GlobalDisputes::is_enabled());
+
+        assert_ok!(GlobalDisputes::set_global_disputes_enabled(Origin::root(), true));
+        System::assert_last_event(
+            Event::<Runtime>::GlobalDisputesEnabledSet { enabled: true }.into(),
+        );
+        assert!(GlobalDisputes::is_enabled());
+    });
+}
+
+#[test]
+fn set_global_disputes_enabled_fails_on_bad_origin() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            GlobalDisputes::set_global_disputes_enabled(Origin::signed(ALICE), false),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn push_voting_outcome_fails_to_start_a_global_dispute_if_disabled() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        assert_ok!(GlobalDisputes::set_global_disputes_enabled(Origin::root(), false));
+
+        assert_noop!(
+            GlobalDisputes::push_voting_outcome(
+                &market_id,
+                OutcomeReport::Scalar(0),
+                &ALICE,
+                SETUP_AMOUNT
+            ),
+            Error::<Runtime>::GlobalDisputesDisabled
+        );
+        assert!(!GlobalDisputes::is_started(&market_id));
+    });
+}
+
+#[test]
+fn push_voting_outcome_works_for_running_global_dispute_if_disabled() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        GlobalDisputes::push_voting_outcome(&market_id, OutcomeReport::Scalar(0), &ALICE, 0)
+            .unwrap();
+        assert_ok!(GlobalDisputes::set_global_disputes_enabled(Origin::root(), false));
+
+        assert_ok!(GlobalDisputes::push_voting_outcome(
+            &market_id,
+            OutcomeReport::Scalar(20),
+            &BOB,
+            SETUP_AMOUNT
+        ));
+        assert_eq!(
+            GlobalDisputes::get_voting_outcome_info(&market_id, &OutcomeReport::Scalar(20)),
+            Some((SETUP_AMOUNT, vec![BOB])),
+        );
+    });
+}
+
+#[test]
+fn winner_outcomes_and_voter_locks_return_the_dispute_state() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        assert_eq!(GlobalDisputes::winner(&market_id), None);
+
+        setup_vote_outcomes_with_hundred(&market_id);
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(20),
+            50 * BASE,
+            Conviction::Locked2x
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            Origin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(40),
+            10 * BASE,
+            Conviction::None
+        ));
+
+        let outcome_info = OutcomeInfo {
+            outcome_sum: SETUP_AMOUNT + 100 * BASE,
+            voter_sum: 100 * BASE,
+            owners: vec![ALICE],
+            fee: Zero::zero(),
+        };
+        assert_eq!(
+            GlobalDisputes::winner(&market_id),
+            Some(WinnerInfo {
+                outcome: OutcomeReport::Scalar(20),
+                outcome_info: outcome_info.clone(),
+                is_finished: false,
+            })
+        );
+
+        let outcomes = GlobalDisputes::outcomes(&market_id);
+        assert_eq!(outcomes.len(), 4);
+        assert!(outcomes.contains(&(OutcomeReport::Scalar(20), outcome_info)));
+
+        let voter_locks = GlobalDisputes::voter_locks(&BOB, &market_id).unwrap();
+        assert_eq!(voter_locks.locked, 60 * BASE);
+        assert_eq!(voter_locks.conviction, Conviction::Locked2x);
+        assert_eq!(voter_locks.votes.len(), 2);
+        assert!(voter_locks.votes.contains(&(OutcomeReport::Scalar(20), 100 * BASE)));
+        assert!(voter_locks.votes.contains(&(OutcomeReport::Scalar(40), 10 * BASE)));
+        assert_eq!(GlobalDisputes::voter_locks(&CHARLIE, &market_id), None);
+    });
+}
@@ -1,18 +1,27 @@
 use frame_support::pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo};
-use sp_runtime::traits::Saturating;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::vec::Vec;
 use zeitgeist_primitives::types::OutcomeReport;
 
 /// The information about a voting outcome of a global dispute.
 #[derive(Debug, TypeInfo, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct OutcomeInfo<Balance, OwnerInfo> {
     /// The current sum of all locks on this outcome.
     pub outcome_sum: Balance,
+    /// The part of `outcome_sum` which was voted by voters, without the initial vote balances
+    /// of the owners.
+    pub voter_sum: Balance,
     /// The vector of owners of the outcome.
     pub owners: OwnerInfo,
+    /// The fee which the owner paid to add the outcome with `add_vote_outcome`.
+    /// It is not part of the voter reward, if the outcome wins.
+    pub fee: Balance,
 }
 
 /// The information about the current highest winning outcome.
-#[derive(TypeInfo, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+#[derive(Debug, TypeInfo, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct WinnerInfo<Balance, OwnerInfo> {
     /// The outcome, which is in the lead.
     pub outcome: OutcomeReport,
@@ -22,9 +31,108 @@ pub struct WinnerInfo<Balance, OwnerInfo> {
     pub is_finished: bool,
 }
 
-impl<Balance: Saturating, OwnerInfo: Default> WinnerInfo<Balance, OwnerInfo> {
+impl<Balance: Saturating + Zero, OwnerInfo: Default> WinnerInfo<Balance, OwnerInfo> {
     pub fn new(outcome: OutcomeReport, vote_sum: Balance) -> Self {
-        let outcome_info = OutcomeInfo { outcome_sum: vote_sum, owners: Default::default() };
+        let outcome_info = OutcomeInfo {
+            outcome_sum: vote_sum,
+            voter_sum: Zero::zero(),
+            owners: Default::default(),
+            fee: Zero::zero(),
+        };
         WinnerInfo { outcome, is_finished: false, outcome_info }
     }
 }
+
+/// The reward for the voters of the winning outcome of a finished global dispute.
+#[derive(Debug, TypeInfo, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+pub struct VoterRewardInfo<Balance> {
+    /// The amount which is distributed among the voters of the winning outcome.
+    pub pool: Balance,
+    /// The sum of all votes of voters on the winning outcome.
+    pub voter_sum: Balance,
+    /// The amount which is already paid out to the voters.
+    pub claimed: Balance,
+}
+
+/// The votes of a voter in the global dispute of a market.
+#[derive(Debug, TypeInfo, Decode, Encode, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct VoterLockInfo<Balance> {
+    /// The amount of tokens, which are locked for the global dispute.
+    pub locked: Balance,
+    /// The highest conviction, with which the voter voted.
+    pub conviction: Conviction,
+    /// The votes on each outcome, which are added to the `outcome_sum`.
+    pub votes: Vec<(OutcomeReport, Balance)>,
+}
+
+/// The conviction with which a voter locks their tokens on a voting outcome.
+///
+/// A higher conviction multiplies the vote of the locked amount,
+/// but keeps the tokens locked for more lock periods after the global dispute has finished.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Decode,
+    Default,
+    Encode,
+    Eq,
+    MaxEncodedLen,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum Conviction {
+    /// 1x votes, unlocked as soon as the global dispute has finished.
+    #[default]
+    None,
+    /// 2x votes, locked for one lock period after the global dispute.
+    Locked2x,
+    /// 3x votes, locked for two lock periods after the global dispute.
+    Locked3x,
+    /// 4x votes, locked for four lock periods after the global dispute.
+    Locked4x,
+    /// 5x votes, locked for eight lock periods after the global dispute.
+    Locked5x,
+    /// 6x votes, locked for sixteen lock periods after the global dispute.
+    Locked6x,
+}
+
+impl Conviction {
+    /// The number of lock periods the tokens stay locked after the global dispute has finished.
+    pub fn lock_periods(self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            Conviction::Locked2x => 1,
+            Conviction::Locked3x => 2,
+            Conviction::Locked4x => 4,
+            Conviction::Locked5x => 8,
+            Conviction::Locked6x => 16,
+        }
+    }
+
+    /// The votes which the locked `amount` adds to the `outcome_sum`.
+    pub fn votes<Balance: From<u8> + Saturating>(self, amount: Balance) -> Balance {
+        let multiplier: u8 = match self {
+            Conviction::None => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 3,
+            Conviction::Locked4x => 4,
+            Conviction::Locked5x => 5,
+            Conviction::Locked6x => 6,
+        };
+        amount.saturating_mul(multiplier.into())
+    }
+}
+
+/// The way the votes on voting outcomes are tallied.
+#[derive(Clone, Copy, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub enum TallyMode {
+    /// Every locked token counts as one vote. Voting with conviction is rejected.
+    Linear,
+    /// Voters may lock their tokens for longer in exchange for a conviction multiplier.
+    Conviction,
+}
@@ -52,6 +52,7 @@ pub trait WeightInfoZeitgeist {
     fn reward_outcome_owner_with_funds(o: u32) -> Weight;
     fn reward_outcome_owner_no_funds(o: u32) -> Weight;
     fn purge_outcomes(k: u32, o: u32) -> Weight;
+    fn set_global_disputes_enabled() -> Weight;
 }
 
 /// Weight functions for zrml_global_disputes (automatically generated)
@@ -61,19 +62,24 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     // Storage: GlobalDisputes Outcomes (r:1 w:1)
     // Storage: GlobalDisputes Locks (r:1 w:1)
     // Storage: Balances Locks (r:1 w:1)
+    // Storage: GlobalDisputes VoteConvictions (r:1 w:1)
+    // Storage: GlobalDisputes ConvictionVoters (r:1 w:1)
+    // Storage: GlobalDisputes Votes (r:1 w:1)
     fn vote_on_outcome(o: u32, v: u32) -> Weight {
-        Weight::from_ref_time(78_818_000)
+        Weight::from_ref_time(85_412_000)
             // Standard Error: 21_000
             .saturating_add(Weight::from_ref_time(23_000).saturating_mul(o.into()))
             // Standard Error: 3_000
             .saturating_add(Weight::from_ref_time(90_000).saturating_mul(v.into()))
-            .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
     // Storage: GlobalDisputes Locks (r:1 w:1)
     // Storage: Balances Locks (r:1 w:1)
     // Storage: System Account (r:1 w:0)
     // Storage: GlobalDisputes Winners (r:5 w:0)
+    // Storage: GlobalDisputes VoteConvictions (r:5 w:5)
+    // Storage: GlobalDisputes FinishedAt (r:5 w:0)
     fn unlock_vote_balance_set(l: u32, o: u32) -> Weight {
         Weight::from_ref_time(43_696_000)
             // Standard Error: 10_000
@@ -81,22 +87,29 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             // Standard Error: 61_000
             .saturating_add(Weight::from_ref_time(1_467_000).saturating_mul(o.into()))
             .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(l.into())))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(l.into())))
             .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(l.into())))
     }
     // Storage: GlobalDisputes Locks (r:1 w:1)
     // Storage: Balances Locks (r:1 w:1)
-    // Storage: System Account (r:1 w:0)
+    // Storage: System Account (r:6 w:6)
     // Storage: GlobalDisputes Winners (r:5 w:0)
+    // Storage: GlobalDisputes VoteConvictions (r:5 w:5)
+    // Storage: GlobalDisputes FinishedAt (r:5 w:5)
+    // Storage: GlobalDisputes ConvictionVoters (r:5 w:5)
+    // Storage: GlobalDisputes Votes (r:10 w:5)
+    // Storage: GlobalDisputes VoterRewards (r:5 w:5)
     fn unlock_vote_balance_remove(l: u32, o: u32) -> Weight {
         Weight::from_ref_time(40_327_000)
             // Standard Error: 9_000
-            .saturating_add(Weight::from_ref_time(3_508_000).saturating_mul(l.into()))
+            .saturating_add(Weight::from_ref_time(35_212_000).saturating_mul(l.into()))
             // Standard Error: 55_000
             .saturating_add(Weight::from_ref_time(1_279_000).saturating_mul(o.into()))
             .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(l.into())))
+            .saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(l.into())))
             .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(l.into())))
     }
     // Storage: GlobalDisputes Winners (r:1 w:1)
     // Storage: GlobalDisputes Outcomes (r:1 w:1)
@@ -108,21 +121,27 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     }
     // Storage: GlobalDisputes Outcomes (r:1 w:0)
     // Storage: GlobalDisputes Winners (r:1 w:0)
+    // Storage: GlobalDisputes VoterRewards (r:1 w:0)
     // Storage: System Account (r:2 w:2)
+    // Storage: GlobalDisputes FinishedDisputes (r:0 w:1)
     fn reward_outcome_owner_with_funds(o: u32) -> Weight {
-        Weight::from_ref_time(54_189_000)
+        Weight::from_ref_time(57_032_000)
             // Standard Error: 75_000
             .saturating_add(Weight::from_ref_time(29_563_000).saturating_mul(o.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(o.into())))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(2))
             .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(o.into())))
     }
     // Storage: GlobalDisputes Outcomes (r:1 w:0)
     // Storage: GlobalDisputes Winners (r:1 w:0)
+    // Storage: GlobalDisputes VoterRewards (r:1 w:0)
     // Storage: System Account (r:1 w:0)
+    // Storage: GlobalDisputes FinishedDisputes (r:0 w:1)
     fn reward_outcome_owner_no_funds(_o: u32) -> Weight {
-        Weight::from_ref_time(46_706_000).saturating_add(T::DbWeight::get().reads(3))
+        Weight::from_ref_time(51_288_000)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
     }
     // Storage: GlobalDisputes Winners (r:1 w:1)
     // Storage: GlobalDisputes Outcomes (r:3 w:2)
@@ -137,4 +156,8 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes(2))
             .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
     }
+    // Storage: GlobalDisputes GlobalDisputesEnabled (r:0 w:1)
+    fn set_global_disputes_enabled() -> Weight {
+        Weight::from_ref_time(18_120_000).saturating_add(T::DbWeight::get().writes(1))
+    }
 }
@@ -21,14 +21,171 @@
 )]
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::pallet::{BalanceOf, Call, Config, Pallet};
 #[cfg(test)]
 use crate::Pallet as LiquidityMining;
-use frame_benchmarking::benchmarks;
+use crate::{
+    market_incentives_params::MarketIncentivesParams,
+    owned_values_params::OwnedValuesParams,
+    pallet::{
+        BalanceOf, Call, Config, CurrencyOf, IncentiveProgramId, IncentivePrograms,
+        MarketIncentivePrograms, MarketIncentives, MomentOf, OwnedValues, Pallet,
+    },
+    IncentiveProgram,
+};
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get, NamedReservableCurrency};
 use frame_system::RawOrigin;
-use sp_runtime::traits::Bounded;
+use orml_traits::MultiCurrency;
+use sp_runtime::{
+    traits::{Bounded, Saturating},
+    FixedU128,
+};
+use zeitgeist_primitives::types::{
+    Asset, Deadlines, Market, MarketBonds, MarketCreation, MarketDisputeMechanism, MarketPeriod,
+    MarketStatus, MarketType, ScoringRule,
+};
+use zrml_market_commons::MarketCommonsPalletApi;
+
+fn shares<T: Config>() -> BalanceOf<T> {
+    BalanceOf::<T>::from(u32::MAX)
+}
+
+// Inserts a funded incentive program for `market_id`, in which `owner` owns all shares and
+// accrued incentives.
+fn setup_incentive_program<T: Config>(
+    program_id: IncentiveProgramId,
+    market_id: T::MarketId,
+    owner: &T::AccountId,
+) {
+    let budget = shares::<T>().saturating_mul(10u8.into());
+    <IncentivePrograms<T>>::insert(
+        program_id,
+        IncentiveProgram {
+            sponsor: owner.clone(),
+            market_id,
+            asset: Asset::Ztg,
+            start: 0u8.into(),
+            end: 100u8.into(),
+            per_block: shares::<T>(),
+            remaining: budget,
+            last_update: 0u8.into(),
+            reward_per_share: FixedU128::from(1),
+            deposit: T::IncentiveProgramDeposit::get(),
+        },
+    );
+    <MarketIncentivePrograms<T>>::mutate(market_id, |program_ids| {
+        program_ids.try_push(program_id).unwrap()
+    });
+    T::AssetManager::deposit(Asset::Ztg, &Pallet::<T>::program_account_id(program_id), budget)
+        .unwrap();
+}
+
+fn setup_owned_values<T: Config>(market_id: T::MarketId, owner: &T::AccountId, is_closed: bool) {
+    <MarketIncentives<T>>::insert(
+        market_id,
+        MarketIncentivesParams {
+            is_closed,
+            reward_per_share: FixedU128::from(1),
+            total_shares: shares::<T>(),
+            ..Default::default()
+        },
+    );
+    <OwnedValues<T>>::insert(
+        market_id,
+        owner,
+        OwnedValuesParams {
+            holding_since: Default::default(),
+            pending_rewards: shares::<T>(),
+            reward_debt: BalanceOf::<T>::from(0u8),
+            total_shares: shares::<T>(),
+        },
+    );
+}
 
 benchmarks! {
+    claim_program_rewards {
+        let caller: T::AccountId = whitelisted_caller();
+        let market_id = T::MarketId::from(0u8);
+        setup_owned_values::<T>(market_id, &caller, false);
+        setup_incentive_program::<T>(0, market_id, &caller);
+    }: claim_program_rewards(RawOrigin::Signed(caller), 0)
+
+    claim_rewards {
+        let p in 0..T::MaxIncentivePrograms::get();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let market_id = T::MarketId::from(0u8);
+        // Claims of closed markets record the shares of the caller in all incentive programs.
+        setup_owned_values::<T>(market_id, &caller, true);
+        for program_id in 0..p {
+            setup_incentive_program::<T>(program_id.into(), market_id, &caller);
+        }
+        let _ = CurrencyOf::<T>::deposit_creating(
+            &Pallet::<T>::pallet_account_id(),
+            shares::<T>().saturating_mul(10u8.into()),
+        );
+    }: claim_rewards(RawOrigin::Signed(caller), market_id)
+
+    close_incentive_program {
+        let caller: T::AccountId = whitelisted_caller();
+        let market_id = T::MarketId::from(0u8);
+        setup_incentive_program::<T>(0, market_id, &caller);
+        let deposit = T::IncentiveProgramDeposit::get();
+        let _ = CurrencyOf::<T>::deposit_creating(&caller, deposit.saturating_mul(2u8.into()));
+        CurrencyOf::<T>::reserve_named(&Pallet::<T>::reserve_id(), &caller, deposit)?;
+        frame_system::Pallet::<T>::set_block_number(
+            T::IncentiveProgramClaimPeriod::get().saturating_add(100u8.into()),
+        );
+    }: close_incentive_program(RawOrigin::Signed(caller), 0)
+    verify {
+        assert!(<IncentivePrograms<T>>::get(0).is_none());
+        assert!(<MarketIncentivePrograms<T>>::get(market_id).is_empty());
+    }
+
+    create_incentive_program {
+        let caller: T::AccountId = whitelisted_caller();
+        let market_id = T::MarketCommons::push_market(Market {
+            base_asset: Asset::Ztg,
+            creation: MarketCreation::Permissionless,
+            creator_fee: 0,
+            creator: caller.clone(),
+            market_type: MarketType::Categorical(2),
+            dispute_mechanism: MarketDisputeMechanism::Authorized,
+            metadata: Default::default(),
+            oracle: caller.clone(),
+            period: MarketPeriod::Block(Default::default()),
+            deadlines: Deadlines {
+                grace_period: 1_u32.into(),
+                oracle_duration: 1_u32.into(),
+                dispute_duration: 1_u32.into(),
+            },
+            report: None,
+            resolved_outcome: None,
+            scoring_rule: ScoringRule::CPMM,
+            status: MarketStatus::Active,
+            bonds: MarketBonds::default(),
+        })?;
+        let per_block = shares::<T>();
+        T::AssetManager::deposit(
+            Asset::Ztg,
+            &caller,
+            per_block.saturating_mul(1_000u32.into()),
+        )?;
+        let start = frame_system::Pallet::<T>::block_number();
+        let end = start + 100u8.into();
+    }: create_incentive_program(
+        RawOrigin::Signed(caller),
+        market_id,
+        Asset::Ztg,
+        start,
+        end,
+        per_block
+    )
+
+    set_min_holding_period {
+        let min_holding_period = MomentOf::<T>::from(1_000u32);
+    }: set_min_holding_period(RawOrigin::Root, min_holding_period)
+
     set_per_block_distribution {
         let balance = BalanceOf::<T>::max_value();
     }: set_per_block_distribution(RawOrigin::Root, balance)
@@ -0,0 +1,107 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::utils::calculate_reward_per_share;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::{
+    traits::{Saturating, UniqueSaturatedFrom, UniqueSaturatedInto},
+    FixedU128,
+};
+use zeitgeist_primitives::types::Asset;
+
+/// Incentive program funded by a sponsor for a single market.
+///
+/// The program distributes `per_block` units of `asset` in every block of `start..end` among
+/// the liquidity providers of the market, proportionally to their pool shares.
+///
+/// # Types
+///
+/// * `AI`: Account Id
+/// * `BA`: BAlance
+/// * `BN`: Block Number
+/// * `MI`: Market Id
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[derive(
+    scale_info::TypeInfo,
+    Clone,
+    Debug,
+    Decode,
+    Encode,
+    Eq,
+    MaxEncodedLen,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+pub struct IncentiveProgram<AI, BA, BN, MI>
+where
+    AI: MaxEncodedLen,
+    BA: MaxEncodedLen,
+    BN: MaxEncodedLen,
+    MI: MaxEncodedLen,
+{
+    /// The account that funded the program and receives its leftovers.
+    pub sponsor: AI,
+    /// The market whose liquidity providers are incentivized.
+    pub market_id: MI,
+    /// The asset in which the incentives are paid.
+    pub asset: Asset<MI>,
+    /// The first block that distributes incentives.
+    pub start: BN,
+    /// The block from which on no incentives are distributed anymore.
+    pub end: BN,
+    /// Incentives distributed in each block.
+    pub per_block: BA,
+    /// Funds of the program that were not distributed yet.
+    pub remaining: BA,
+    /// The block up to which the incentives were distributed.
+    pub last_update: BN,
+    /// Accumulated incentives per share since `start`.
+    pub reward_per_share: FixedU128,
+    /// The amount of the native currency that the sponsor reserved for the program.
+    pub deposit: BA,
+}
+
+impl<AI, BA, BN, MI> IncentiveProgram<AI, BA, BN, MI>
+where
+    AI: MaxEncodedLen,
+    BA: Copy
+        + MaxEncodedLen
+        + Ord
+        + Saturating
+        + UniqueSaturatedFrom<u128>
+        + UniqueSaturatedInto<u128>,
+    BN: Copy + MaxEncodedLen + Ord + Saturating + UniqueSaturatedInto<u128>,
+    MI: MaxEncodedLen,
+{
+    /// Distributes the incentives of the blocks since `last_update` up to `now` among
+    /// `total_shares`. Incentives of blocks without any shares remain with the program.
+    pub fn accrue(&mut self, now: BN, total_shares: BA) {
+        let until = now.max(self.start).min(self.end);
+        if until <= self.last_update {
+            return;
+        }
+        let blocks: u128 = until.saturating_sub(self.last_update).unique_saturated_into();
+        let incentive =
+            self.per_block.saturating_mul(BA::unique_saturated_from(blocks)).min(self.remaining);
+        if let Some(increment) = calculate_reward_per_share(incentive, total_shares) {
+            self.reward_per_share = self.reward_per_share.saturating_add(increment);
+            self.remaining = self.remaining.saturating_sub(incentive);
+        }
+        self.last_update = until;
+    }
+}
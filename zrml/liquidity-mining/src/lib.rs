@@ -24,7 +24,10 @@ extern crate alloc;
 mod utils;
 
 mod benchmarks;
+mod incentive_program;
 mod liquidity_mining_pallet_api;
+mod market_incentives_params;
+pub mod migrations;
 mod mock;
 mod owned_values_params;
 mod tests;
@@ -32,60 +35,276 @@ mod track_incentives_based_on_bought_shares;
 mod track_incentives_based_on_sold_shares;
 pub mod weights;
 
+pub use incentive_program::IncentiveProgram;
 pub use liquidity_mining_pallet_api::LiquidityMiningPalletApi;
 pub use pallet::*;
 
 #[frame_support::pallet]
 mod pallet {
     use crate::{
+        incentive_program::IncentiveProgram, market_incentives_params::MarketIncentivesParams,
         owned_values_params::OwnedValuesParams,
         track_incentives_based_on_bought_shares::TrackIncentivesBasedOnBoughtShares,
         track_incentives_based_on_sold_shares::TrackIncentivesBasedOnSoldShares,
-        utils::{
-            calculate_average_blocks_of_a_time_period, calculate_perthousand,
-            calculate_perthousand_value,
-        },
-        weights::WeightInfoZeitgeist,
-        LiquidityMiningPalletApi,
+        weights::WeightInfoZeitgeist, LiquidityMiningPalletApi,
     };
-    use alloc::vec::Vec;
     use core::marker::PhantomData;
     #[cfg(feature = "std")]
     use frame_support::traits::GenesisBuild;
     use frame_support::{
         dispatch::DispatchResult,
-        log,
+        ensure, log,
         storage::{
-            types::{StorageDoubleMap, StorageValue, ValueQuery},
+            types::{OptionQuery, StorageDoubleMap, StorageMap, StorageValue, ValueQuery},
             with_transaction,
         },
         traits::{
-            Currency, ExistenceRequirement, Get, Hooks, IsType, StorageVersion, WithdrawReasons,
+            Currency, ExistenceRequirement, Get, Hooks, IsType, NamedReservableCurrency,
+            StorageVersion,
         },
-        Blake2_128Concat, PalletId, Twox64Concat,
+        transactional, Blake2_128Concat, BoundedVec, PalletId, Twox64Concat,
     };
-    use frame_system::{ensure_root, pallet_prelude::OriginFor};
+    use frame_system::{ensure_root, ensure_signed, pallet_prelude::OriginFor};
+    use orml_traits::MultiCurrency;
     use sp_runtime::{
-        traits::{AccountIdConversion, Saturating},
-        TransactionOutcome,
+        traits::{AccountIdConversion, CheckedMul, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+        ArithmeticError, FixedU128, TransactionOutcome,
     };
     use zeitgeist_primitives::{
         traits::MarketId,
-        types::{MarketPeriod, MaxRuntimeUsize},
+        types::{Asset, MaxRuntimeUsize},
     };
     use zrml_market_commons::MarketCommonsPalletApi;
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     pub(crate) type BalanceOf<T> =
         <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::Balance;
     pub(crate) type CurrencyOf<T> =
         <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Currency;
+    pub(crate) type IncentiveProgramOf<T> = IncentiveProgram<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+        <T as Config>::MarketId,
+    >;
     pub(crate) type MomentOf<T> = <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Moment;
 
+    pub type IncentiveProgramId = u128;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Claims all incentives that an account accrued in an incentive program so far.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::weight(T::WeightInfo::claim_program_rewards())]
+        // MARK(non-transactional): Once `transfer` is successful, the storage writes won't fail.
+        pub fn claim_program_rewards(
+            origin: OriginFor<T>,
+            #[pallet::compact] program_id: IncentiveProgramId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut program = <IncentivePrograms<T>>::get(program_id)
+                .ok_or(Error::<T>::IncentiveProgramNotFound)?;
+            let market = <MarketIncentives<T>>::get(program.market_id);
+            program.accrue(<frame_system::Pallet<T>>::block_number(), market.total_shares);
+            let mut values = Self::program_rewards(program_id, &program.market_id, &who);
+            ensure!(
+                values
+                    .is_holding_period_over(T::MarketCommons::now(), <MinHoldingPeriod<T>>::get()),
+                Error::<T>::MinHoldingPeriodNotOver
+            );
+            values.settle(program.reward_per_share);
+            let rewards = values.pending_rewards;
+            ensure!(!rewards.is_zero(), Error::<T>::NoRewardsToClaim);
+
+            T::AssetManager::transfer(
+                program.asset,
+                &Self::program_account_id(program_id),
+                &who,
+                rewards,
+            )
+            .map_err(|_err| Error::<T>::FundDoesNotHaveEnoughBalance)?;
+
+            values.pending_rewards = Zero::zero();
+            <IncentiveProgramRewards<T>>::insert(program_id, &who, values);
+            <IncentivePrograms<T>>::insert(program_id, program);
+            Self::deposit_event(Event::ClaimedProgramRewards(who, program_id, rewards));
+            Ok(())
+        }
+
+        /// Claims all incentives that an account accrued in a market so far.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(p)`, where `p` is the number of incentive programs of the market.
+        #[pallet::weight(T::WeightInfo::claim_rewards(T::MaxIncentivePrograms::get()))]
+        // MARK(non-transactional): Once `transfer` is successful, the storage writes won't fail.
+        pub fn claim_rewards(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: T::MarketId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let now = T::MarketCommons::now();
+            let mut market = <MarketIncentives<T>>::get(market_id);
+            market.accrue(now);
+            let mut values = <OwnedValues<T>>::try_get(market_id, &who)
+                .map_err(|_| Error::<T>::NoRewardsToClaim)?;
+            ensure!(
+                values.is_holding_period_over(now, <MinHoldingPeriod<T>>::get()),
+                Error::<T>::MinHoldingPeriodNotOver
+            );
+            values.settle(market.reward_per_share);
+            let rewards = values.pending_rewards;
+            ensure!(!rewards.is_zero(), Error::<T>::NoRewardsToClaim);
+
+            CurrencyOf::<T>::transfer(
+                &Self::pallet_account_id(),
+                &who,
+                rewards,
+                ExistenceRequirement::AllowDeath,
+            )
+            .map_err(|_err| Error::<T>::FundDoesNotHaveEnoughBalance)?;
+
+            if market.is_closed {
+                // Nothing accrues on closed markets, so the account is done with this market once
+                // its shares are recorded by the incentive programs of the market.
+                Self::settle_incentive_programs(&market_id, &who, values.total_shares, now);
+                <OwnedValues<T>>::remove(market_id, &who);
+            } else {
+                values.pending_rewards = Zero::zero();
+                <OwnedValues<T>>::insert(market_id, &who, values);
+            }
+            <MarketIncentives<T>>::insert(market_id, market);
+
+            Self::deposit_event(Event::ClaimedRewards(who, market_id, rewards));
+            Ok(())
+        }
+
+        /// Removes an incentive program and transfers its leftover back to its sponsor.
+        ///
+        /// The leftover consists of the funds that the program didn't distribute and the
+        /// incentives that weren't claimed within `IncentiveProgramClaimPeriod` after the end of
+        /// the program. Can only be called by the sponsor once the claim period is over. Unreserves
+        /// the deposit of the sponsor and frees the slot of the program in its market.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::weight(T::WeightInfo::close_incentive_program())]
+        // MARK(non-transactional): Once `transfer` is successful, the storage writes won't fail.
+        pub fn close_incentive_program(
+            origin: OriginFor<T>,
+            #[pallet::compact] program_id: IncentiveProgramId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let program = <IncentivePrograms<T>>::get(program_id)
+                .ok_or(Error::<T>::IncentiveProgramNotFound)?;
+            ensure!(program.sponsor == who, Error::<T>::NotIncentiveProgramSponsor);
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                program.end.saturating_add(T::IncentiveProgramClaimPeriod::get()) <= now,
+                Error::<T>::IncentiveProgramIsActive
+            );
+            let program_account = Self::program_account_id(program_id);
+            let leftover = T::AssetManager::free_balance(program.asset, &program_account);
+
+            T::AssetManager::transfer(program.asset, &program_account, &who, leftover)?;
+
+            CurrencyOf::<T>::unreserve_named(&Self::reserve_id(), &who, program.deposit);
+            <MarketIncentivePrograms<T>>::mutate(program.market_id, |program_ids| {
+                program_ids.retain(|id| *id != program_id)
+            });
+            <IncentivePrograms<T>>::remove(program_id);
+            Self::deposit_event(Event::IncentiveProgramClosed(program_id, leftover));
+            Ok(())
+        }
+
+        /// Creates an incentive program for the liquidity providers of `market_id`.
+        ///
+        /// The program distributes `per_block` units of `asset` in every block from `start` to
+        /// `end` and is funded upfront by the caller. Everyone can sponsor a program for any
+        /// market whose incentives weren't closed yet. The sponsor must reserve
+        /// `IncentiveProgramDeposit` until the program is closed.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::weight(T::WeightInfo::create_incentive_program())]
+        #[transactional]
+        pub fn create_incentive_program(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: T::MarketId,
+            asset: Asset<T::MarketId>,
+            start: T::BlockNumber,
+            end: T::BlockNumber,
+            #[pallet::compact] per_block: BalanceOf<T>,
+        ) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+            let _ = T::MarketCommons::market(&market_id)?;
+            ensure!(
+                !<MarketIncentives<T>>::get(market_id).is_closed,
+                Error::<T>::MarketIncentivesClosed
+            );
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(now <= start && start < end, Error::<T>::InvalidIncentiveProgramPeriod);
+            ensure!(!per_block.is_zero(), Error::<T>::ZeroIncentive);
+            let blocks: u128 = end.saturating_sub(start).unique_saturated_into();
+            let budget = per_block
+                .checked_mul(&BalanceOf::<T>::unique_saturated_from(blocks))
+                .ok_or(ArithmeticError::Overflow)?;
+
+            let program_id = <NextIncentiveProgramId<T>>::get();
+            let next_program_id = program_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+            <MarketIncentivePrograms<T>>::try_mutate(market_id, |program_ids| {
+                program_ids.try_push(program_id)
+            })
+            .map_err(|_| Error::<T>::TooManyIncentivePrograms)?;
+            let deposit = T::IncentiveProgramDeposit::get();
+            CurrencyOf::<T>::reserve_named(&Self::reserve_id(), &sponsor, deposit)?;
+            T::AssetManager::transfer(
+                asset,
+                &sponsor,
+                &Self::program_account_id(program_id),
+                budget,
+            )?;
+
+            <IncentivePrograms<T>>::insert(
+                program_id,
+                IncentiveProgram {
+                    sponsor: sponsor.clone(),
+                    market_id,
+                    asset,
+                    start,
+                    end,
+                    per_block,
+                    remaining: budget,
+                    last_update: start,
+                    reward_per_share: FixedU128::zero(),
+                    deposit,
+                },
+            );
+            <NextIncentiveProgramId<T>>::put(next_program_id);
+            Self::deposit_event(Event::IncentiveProgramCreated(program_id, market_id, sponsor));
+            Ok(())
+        }
+
+        /// Sets the minimum period for which accounts must own their shares to receive incentives.
+        /// Accounts that sell shares earlier forfeit the incentives they didn't claim yet.
+        #[pallet::weight(T::WeightInfo::set_min_holding_period())]
+        // MARK(non-transactional): `set_min_holding_period` is infallible.
+        pub fn set_min_holding_period(
+            origin: OriginFor<T>,
+            min_holding_period: MomentOf<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            <MinHoldingPeriod<T>>::put(min_holding_period);
+            Ok(())
+        }
+
         #[pallet::weight(T::WeightInfo::set_per_block_distribution())]
         // MARK(non-transactional): `set_per_block_distribution` is infallible.
         pub fn set_per_block_distribution(
@@ -100,8 +319,25 @@ mod pallet {
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        /// Transfers the assets of incentive programs.
+        type AssetManager: MultiCurrency<
+            Self::AccountId,
+            Balance = BalanceOf<Self>,
+            CurrencyId = Asset<Self::MarketId>,
+        >;
+
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
+        /// The number of blocks after the end of an incentive program in which liquidity
+        /// providers can still claim its incentives, before the sponsor can close the program.
+        #[pallet::constant]
+        type IncentiveProgramClaimPeriod: Get<Self::BlockNumber>;
+
+        /// The amount of the native currency that the sponsor of an incentive program reserves
+        /// until the program is closed.
+        #[pallet::constant]
+        type IncentiveProgramDeposit: Get<BalanceOf<Self>>;
+
         type MarketCommons: MarketCommonsPalletApi<
             AccountId = Self::AccountId,
             BlockNumber = Self::BlockNumber,
@@ -110,6 +346,10 @@ mod pallet {
 
         type MarketId: MarketId;
 
+        /// The maximum number of incentive programs of a single market.
+        #[pallet::constant]
+        type MaxIncentivePrograms: Get<u32>;
+
         #[pallet::constant]
         type PalletId: Get<PalletId>;
 
@@ -124,9 +364,16 @@ mod pallet {
     {
         /// The number of markets that received incentives in a block
         AddedIncentives(MaxRuntimeUsize),
-        /// The total amount of incentives distributed to accounts along side the number
-        /// of accounts that received these incentives.
-        DistributedIncentives(BalanceOf<T>, MaxRuntimeUsize),
+        /// An account claimed the incentives it accrued in an incentive program
+        ClaimedProgramRewards(T::AccountId, IncentiveProgramId, BalanceOf<T>),
+        /// An account claimed the incentives it accrued in a market
+        ClaimedRewards(T::AccountId, T::MarketId, BalanceOf<T>),
+        /// A market stopped accruing incentives
+        ClosedMarketIncentives(T::MarketId),
+        /// The sponsor of an incentive program closed it and withdrew its leftover
+        IncentiveProgramClosed(IncentiveProgramId, BalanceOf<T>),
+        /// An account created an incentive program for a market
+        IncentiveProgramCreated(IncentiveProgramId, T::MarketId, T::AccountId),
         /// The number of markets that subtracted incentives in a block
         SubtractedIncentives(MaxRuntimeUsize),
     }
@@ -135,6 +382,24 @@ mod pallet {
     pub enum Error<T> {
         /// Pallet account does not have enough funds
         FundDoesNotHaveEnoughBalance,
+        /// The incentive program is still distributing incentives or its claim period isn't over
+        IncentiveProgramIsActive,
+        /// There is no incentive program with the given id
+        IncentiveProgramNotFound,
+        /// The period of the incentive program is empty or starts in the past
+        InvalidIncentiveProgramPeriod,
+        /// The incentives of the market were already closed
+        MarketIncentivesClosed,
+        /// The account didn't own its shares for the minimum holding period yet
+        MinHoldingPeriodNotOver,
+        /// The account has no incentives to claim in the market
+        NoRewardsToClaim,
+        /// Only the sponsor of an incentive program can close it
+        NotIncentiveProgramSponsor,
+        /// The market already has the maximum number of incentive programs
+        TooManyIncentivePrograms,
+        /// Incentive programs must distribute a non-zero amount per block
+        ZeroIncentive,
     }
 
     #[cfg(feature = "std")]
@@ -180,7 +445,7 @@ mod pallet {
                 if added_len > 0 {
                     Self::deposit_event(Event::AddedIncentives(added_len.into()));
                 }
-                let subtracted_len = TrackIncentivesBasedOnSoldShares::<T>::exec();
+                let subtracted_len = TrackIncentivesBasedOnSoldShares::<T>::exec(block);
                 if subtracted_len > 0 {
                     Self::deposit_event(Event::SubtractedIncentives(subtracted_len.into()));
                 }
@@ -209,6 +474,74 @@ mod pallet {
         pub(crate) fn pallet_account_id() -> T::AccountId {
             T::PalletId::get().into_account_truncating()
         }
+
+        #[inline]
+        pub(crate) fn reserve_id() -> [u8; 8] {
+            T::PalletId::get().0
+        }
+
+        // Holds the funds of an incentive program
+        #[inline]
+        pub(crate) fn program_account_id(program_id: IncentiveProgramId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(program_id)
+        }
+
+        // Distributes the incentives of all programs of `market_id` up to `now`. Must be called
+        // before the total shares of the market change.
+        pub(crate) fn accrue_incentive_programs(
+            market_id: &T::MarketId,
+            now: T::BlockNumber,
+            total_shares: BalanceOf<T>,
+        ) {
+            for program_id in <MarketIncentivePrograms<T>>::get(market_id) {
+                <IncentivePrograms<T>>::mutate(program_id, |program| {
+                    if let Some(program) = program {
+                        program.accrue(now, total_shares);
+                    }
+                });
+            }
+        }
+
+        // Settles the incentive programs of `market_id` for `account_id`, whose shares are about
+        // to change to `total_shares`. Must be called after `accrue_incentive_programs` and
+        // before `OwnedValues` is updated.
+        pub(crate) fn settle_incentive_programs(
+            market_id: &T::MarketId,
+            account_id: &T::AccountId,
+            total_shares: BalanceOf<T>,
+            now: MomentOf<T>,
+        ) {
+            let min_holding_period = <MinHoldingPeriod<T>>::get();
+            for program_id in <MarketIncentivePrograms<T>>::get(market_id) {
+                let reward_per_share =
+                    if let Some(program) = <IncentivePrograms<T>>::get(program_id) {
+                        program.reward_per_share
+                    } else {
+                        continue;
+                    };
+                let mut values = Self::program_rewards(program_id, market_id, account_id);
+                values.settle(reward_per_share);
+                values.set_total_shares(total_shares, reward_per_share, now, min_holding_period);
+                <IncentiveProgramRewards<T>>::insert(program_id, account_id, values);
+            }
+        }
+
+        // Incentive program values of `account_id`. Accounts without values didn't change their
+        // shares since the program was created, so they still own the shares of `OwnedValues`.
+        fn program_rewards(
+            program_id: IncentiveProgramId,
+            market_id: &T::MarketId,
+            account_id: &T::AccountId,
+        ) -> OwnedValuesParams<BalanceOf<T>, MomentOf<T>> {
+            <IncentiveProgramRewards<T>>::get(program_id, account_id).unwrap_or_else(|| {
+                let owned_values = <OwnedValues<T>>::get(market_id, account_id);
+                OwnedValuesParams {
+                    holding_since: owned_values.holding_since,
+                    total_shares: owned_values.total_shares,
+                    ..Default::default()
+                }
+            })
+        }
     }
 
     impl<T> LiquidityMiningPalletApi for Pallet<T>
@@ -230,64 +563,23 @@ mod pallet {
             })
         }
 
-        fn distribute_market_incentives(market_id: &Self::MarketId) -> DispatchResult {
-            let pallet_account_id = Pallet::<T>::pallet_account_id();
-            let mut final_total_incentives = BalanceOf::<T>::from(0u8);
-
-            let values: Vec<_> = <OwnedValues<T>>::drain_prefix(market_id)
-                .filter_map(
-                    |(
-                        account_id,
-                        OwnedValuesParams {
-                            participated_blocks,
-                            perpetual_incentives,
-                            total_incentives: local_total_incentives,
-                            ..
-                        },
-                    )| {
-                        let actual_perpetual_incentives = {
-                            let opt = match T::MarketCommons::market(market_id).ok()?.period {
-                                MarketPeriod::Block(range) => calculate_perthousand(
-                                    participated_blocks,
-                                    &range.end.saturating_sub(range.start),
-                                ),
-                                MarketPeriod::Timestamp(range) => calculate_perthousand(
-                                    participated_blocks,
-                                    &calculate_average_blocks_of_a_time_period::<T>(&range),
-                                ),
-                            };
-                            let ptd_balance = opt.map(|ptd| ptd.into())?;
-                            calculate_perthousand_value(ptd_balance, perpetual_incentives)
-                        };
-                        let final_incentives =
-                            actual_perpetual_incentives.saturating_add(local_total_incentives);
-                        final_total_incentives =
-                            final_total_incentives.saturating_add(final_incentives);
-                        Some((account_id, final_incentives))
-                    },
-                )
-                .collect();
-
-            CurrencyOf::<T>::ensure_can_withdraw(
-                &pallet_account_id,
-                final_total_incentives,
-                WithdrawReasons::all(),
-                CurrencyOf::<T>::free_balance(&pallet_account_id)
-                    .saturating_sub(final_total_incentives),
-            )
-            .map_err(|_err| Error::<T>::FundDoesNotHaveEnoughBalance)?;
-
-            let accounts_len = values.len().into();
-            for (account_id, incentives) in values {
-                CurrencyOf::<T>::transfer(
-                    &pallet_account_id,
-                    &account_id,
-                    incentives,
-                    ExistenceRequirement::AllowDeath,
-                )
-                .map_err(|_err| Error::<T>::FundDoesNotHaveEnoughBalance)?;
+        fn close_market_incentives(market_id: &Self::MarketId) -> DispatchResult {
+            // Incentive programs of the market stop distributing and their remaining funds can
+            // be withdrawn by their sponsors.
+            let now = <frame_system::Pallet<T>>::block_number();
+            let total_shares = <MarketIncentives<T>>::get(market_id).total_shares;
+            for program_id in <MarketIncentivePrograms<T>>::get(market_id) {
+                <IncentivePrograms<T>>::mutate(program_id, |program| {
+                    if let Some(program) = program {
+                        program.accrue(now, total_shares);
+                        program.end = program.end.min(now.max(program.start));
+                    }
+                });
             }
-            Self::deposit_event(Event::DistributedIncentives(final_total_incentives, accounts_len));
+            <MarketIncentives<T>>::mutate(market_id, |market| {
+                market.close(T::MarketCommons::now())
+            });
+            Self::deposit_event(Event::ClosedMarketIncentives(*market_id));
             Ok(())
         }
 
@@ -326,16 +618,63 @@ mod pallet {
         ValueQuery,
     >;
 
-    /// Owned balances (not shares) that are going to be distributed as incentives. Automatically
+    /// Values of each account in each incentive program. Accounts without values own the shares
+    /// of `OwnedValues` and didn't accrue any incentives of the program yet.
+    #[pallet::storage]
+    pub type IncentiveProgramRewards<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        IncentiveProgramId,
+        Twox64Concat,
+        T::AccountId,
+        OwnedValuesParams<BalanceOf<T>, MomentOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Incentive programs funded by sponsors.
+    #[pallet::storage]
+    pub type IncentivePrograms<T: Config> =
+        StorageMap<_, Blake2_128Concat, IncentiveProgramId, IncentiveProgramOf<T>, OptionQuery>;
+
+    /// Accumulated incentives per share and total shares of each market. Automatically
     /// *updated* after each finalized block.
     #[pallet::storage]
+    pub type MarketIncentives<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::MarketId,
+        MarketIncentivesParams<BalanceOf<T>, MomentOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Incentive programs of each market.
+    #[pallet::storage]
+    pub type MarketIncentivePrograms<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::MarketId,
+        BoundedVec<IncentiveProgramId, T::MaxIncentivePrograms>,
+        ValueQuery,
+    >;
+
+    /// The minimum period for which accounts must own their shares to receive incentives.
+    #[pallet::storage]
+    pub type MinHoldingPeriod<T: Config> = StorageValue<_, MomentOf<T>, ValueQuery>;
+
+    /// The id of the next incentive program.
+    #[pallet::storage]
+    pub type NextIncentiveProgramId<T: Config> = StorageValue<_, IncentiveProgramId, ValueQuery>;
+
+    /// Owned shares and incentives (not shares) of each account. Incentives are settled whenever
+    /// the owned shares change and are paid out by `claim_rewards`.
+    #[pallet::storage]
     pub type OwnedValues<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         T::MarketId,
         Twox64Concat,
         T::AccountId,
-        OwnedValuesParams<BalanceOf<T>, T::BlockNumber>,
+        OwnedValuesParams<BalanceOf<T>, MomentOf<T>>,
         ValueQuery,
     >;
 
@@ -29,9 +29,9 @@ pub trait LiquidityMiningPalletApi {
     /// It is up to the caller to synchronize the amount of shares between different pallets
     fn add_shares(account_id: Self::AccountId, market_id: Self::MarketId, shares: Self::Balance);
 
-    /// Removes a given `market_id` period from the storage distributing incentives to all
-    /// related accounts.
-    fn distribute_market_incentives(market_id: &Self::MarketId) -> DispatchResult;
+    /// Stops the accrual of incentives for a given `market_id`. Incentives that were already
+    /// accrued can still be claimed by the related accounts.
+    fn close_market_incentives(market_id: &Self::MarketId) -> DispatchResult;
 
     /// Decreases the number of stored pool shares of an account on a given market.
     ///
@@ -0,0 +1,121 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::{
+    traits::{CheckedDiv, Saturating, UniqueSaturatedInto, Zero},
+    FixedPointNumber, FixedU128,
+};
+
+/// Parameters used by the `MarketIncentives` storage.
+///
+/// Incentives of a market are streamed at `reward_rate` until `period_end`, so every account
+/// receives incentives proportionally to its share-seconds, i.e., its shares multiplied by the
+/// time it owned them.
+///
+/// # Types
+///
+/// * `BA`: BAlance
+/// * `MO`: MOment
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[derive(
+    scale_info::TypeInfo,
+    Clone,
+    Debug,
+    Decode,
+    Default,
+    Encode,
+    Eq,
+    MaxEncodedLen,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+pub struct MarketIncentivesParams<BA, MO>
+where
+    BA: MaxEncodedLen,
+    MO: MaxEncodedLen,
+{
+    /// Whether the market stopped receiving incentives.
+    pub is_closed: bool,
+    /// The moment up to which the streamed incentives were distributed.
+    pub last_update: MO,
+    /// The moment at which the stream of incentives ends.
+    pub period_end: MO,
+    /// Accumulated incentives per share since the start of the market.
+    pub reward_per_share: FixedU128,
+    /// Incentives streamed per moment (millisecond).
+    pub reward_rate: FixedU128,
+    /// Total quantity of shares owned by all accounts.
+    pub total_shares: BA,
+}
+
+impl<BA, MO> MarketIncentivesParams<BA, MO>
+where
+    BA: Copy + MaxEncodedLen + UniqueSaturatedInto<u128>,
+    MO: Copy + MaxEncodedLen + Ord + Saturating + UniqueSaturatedInto<u128>,
+{
+    /// Distributes the incentives streamed since `last_update` up to `now` among `total_shares`.
+    /// Incentives streamed while nobody owns shares are not distributed.
+    pub fn accrue(&mut self, now: MO) {
+        let until = now.min(self.period_end);
+        if until > self.last_update {
+            let elapsed = until.saturating_sub(self.last_update);
+            let streamed = self.reward_rate.saturating_mul(fixed(elapsed));
+            if let Some(increment) = streamed.checked_div(&fixed(self.total_shares)) {
+                self.reward_per_share = self.reward_per_share.saturating_add(increment);
+            }
+        }
+        self.last_update = self.last_update.max(now);
+    }
+
+    /// Streams `incentive` and the incentives that weren't streamed yet from `now` until
+    /// `period_end`. Must be called after the shares of `now` were updated, so that accounts
+    /// only receive incentives for the time they own shares.
+    pub fn add_incentive(&mut self, incentive: BA, now: MO, period_end: MO) {
+        self.accrue(now);
+        let unstreamed =
+            self.reward_rate.saturating_mul(fixed(self.period_end.saturating_sub(now)));
+        let total = unstreamed.saturating_add(fixed(incentive));
+        if let Some(reward_rate) = total.checked_div(&fixed(period_end.saturating_sub(now))) {
+            self.reward_rate = reward_rate;
+            self.period_end = period_end;
+        } else {
+            // The period is over, so the incentives are distributed at once.
+            if let Some(increment) = total.checked_div(&fixed(self.total_shares)) {
+                self.reward_per_share = self.reward_per_share.saturating_add(increment);
+            }
+            self.reward_rate = FixedU128::zero();
+            self.period_end = now;
+        }
+    }
+
+    /// Stops the stream of incentives at `now`.
+    pub fn close(&mut self, now: MO) {
+        self.accrue(now);
+        self.is_closed = true;
+        self.reward_rate = FixedU128::zero();
+        self.period_end = self.period_end.min(now);
+    }
+}
+
+fn fixed<N>(value: N) -> FixedU128
+where
+    N: UniqueSaturatedInto<u128>,
+{
+    FixedU128::saturating_from_integer(value.unique_saturated_into())
+}
@@ -0,0 +1,275 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    owned_values_params::OwnedValuesParams,
+    utils::{calculate_perthousand, calculate_perthousand_value},
+    BalanceOf, Config, MarketIncentives, MomentOf, OwnedValues, Pallet,
+};
+use alloc::collections::BTreeMap;
+use frame_support::{
+    dispatch::Weight,
+    log,
+    pallet_prelude::PhantomData,
+    traits::{Get, OnRuntimeUpgrade, StorageVersion},
+    RuntimeDebug,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    SaturatedConversion,
+};
+use zeitgeist_primitives::{constants::MILLISECS_PER_BLOCK, types::MarketPeriod};
+use zrml_market_commons::MarketCommonsPalletApi;
+
+const LIQUIDITY_MINING_REQUIRED_STORAGE_VERSION: u16 = 0;
+const LIQUIDITY_MINING_NEXT_STORAGE_VERSION: u16 = 1;
+
+/// The layout of `OwnedValuesParams` before incentives were accrued per share.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OldOwnedValuesParams<Balance, BlockNumber> {
+    pub participated_blocks: BlockNumber,
+    pub perpetual_incentives: Balance,
+    pub total_incentives: Balance,
+    pub total_shares: Balance,
+}
+
+pub struct MigrateOwnedValuesToRewardsPerShare<T>(PhantomData<T>);
+
+// Converts the values of `OwnedValues` into the per share layout and seeds the total shares of
+// `MarketIncentives`. The incentives which accounts earned under the old rules, i.e., their
+// incentives and their perpetual incentives scaled by the share of the market period they
+// participated in, become claimable pending rewards.
+impl<T: Config> OnRuntimeUpgrade for MigrateOwnedValuesToRewardsPerShare<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut total_weight = T::DbWeight::get().reads(1);
+        let liquidity_mining_version = StorageVersion::get::<Pallet<T>>();
+        if liquidity_mining_version != LIQUIDITY_MINING_REQUIRED_STORAGE_VERSION {
+            log::info!(
+                "MigrateOwnedValuesToRewardsPerShare: liquidity mining version is {:?}, require \
+                 {:?};",
+                liquidity_mining_version,
+                LIQUIDITY_MINING_REQUIRED_STORAGE_VERSION,
+            );
+            return total_weight;
+        }
+        log::info!("MigrateOwnedValuesToRewardsPerShare: Starting...");
+
+        let mut translated = 0u64;
+        let mut market_shares = BTreeMap::<T::MarketId, BalanceOf<T>>::new();
+        OwnedValues::<T>::translate::<OldOwnedValuesParams<BalanceOf<T>, T::BlockNumber>, _>(
+            |market_id, _, old_values| {
+                translated = translated.saturating_add(1);
+                let perpetual_incentives = Self::earned_perpetual_incentives(
+                    &market_id,
+                    old_values.participated_blocks,
+                    old_values.perpetual_incentives,
+                );
+                let total_shares = market_shares.entry(market_id).or_insert_with(Zero::zero);
+                *total_shares = total_shares.saturating_add(old_values.total_shares);
+                // `MarketIncentives` is empty before the migration, so the reward per share of
+                // every market is zero and so is the reward debt.
+                Some(OwnedValuesParams {
+                    holding_since: Zero::zero(),
+                    pending_rewards: old_values
+                        .total_incentives
+                        .saturating_add(perpetual_incentives),
+                    reward_debt: Zero::zero(),
+                    total_shares: old_values.total_shares,
+                })
+            },
+        );
+        log::info!("MigrateOwnedValuesToRewardsPerShare: Upgraded {} owned values.", translated);
+        // Each translation reads the market to calculate the perpetual incentives.
+        total_weight = total_weight.saturating_add(
+            T::DbWeight::get().reads_writes(translated.saturating_mul(2), translated),
+        );
+
+        let now = T::MarketCommons::now();
+        let markets = market_shares.len() as u64;
+        for (market_id, total_shares) in market_shares {
+            MarketIncentives::<T>::mutate(market_id, |market| {
+                market.accrue(now);
+                market.total_shares = market.total_shares.saturating_add(total_shares);
+            });
+        }
+        log::info!("MigrateOwnedValuesToRewardsPerShare: Seeded {} markets.", markets);
+        total_weight = total_weight
+            .saturating_add(T::DbWeight::get().reads_writes(markets.saturating_add(1), markets));
+
+        StorageVersion::new(LIQUIDITY_MINING_NEXT_STORAGE_VERSION).put::<Pallet<T>>();
+        total_weight = total_weight.saturating_add(T::DbWeight::get().writes(1));
+        log::info!("MigrateOwnedValuesToRewardsPerShare: Done!");
+        total_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        let mut market_shares = BTreeMap::<T::MarketId, BalanceOf<T>>::new();
+        for (market_id, _, values) in OwnedValues::<T>::iter() {
+            let total_shares = market_shares.entry(market_id).or_insert_with(Zero::zero);
+            *total_shares = total_shares.saturating_add(values.total_shares);
+        }
+        for (market_id, total_shares) in market_shares {
+            assert_eq!(MarketIncentives::<T>::get(market_id).total_shares, total_shares);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Config> MigrateOwnedValuesToRewardsPerShare<T> {
+    // The perpetual incentives an account earned under the old rules, i.e., `perpetual_incentives`
+    // scaled by the share of the market period the account participated in.
+    fn earned_perpetual_incentives(
+        market_id: &T::MarketId,
+        participated_blocks: T::BlockNumber,
+        perpetual_incentives: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let period = match T::MarketCommons::market(market_id) {
+            Ok(market) => market.period,
+            Err(_) => return Zero::zero(),
+        };
+        let period_blocks = match period {
+            MarketPeriod::Block(range) => range.end.saturating_sub(range.start),
+            MarketPeriod::Timestamp(range) => {
+                let period_time: MomentOf<T> = range.end.saturating_sub(range.start);
+                let period_blocks: u32 =
+                    (period_time / MILLISECS_PER_BLOCK.into()).saturated_into();
+                period_blocks.into()
+            }
+        };
+        calculate_perthousand(participated_blocks, &period_blocks)
+            .map(|ptd| calculate_perthousand_value(ptd.into(), perpetual_incentives))
+            .unwrap_or_else(Zero::zero)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock::{ExtBuilder, Runtime, ALICE, BOB},
+        tests::create_default_market,
+    };
+    use frame_support::{
+        migration::put_storage_value, Blake2_128Concat, StorageHasher, Twox64Concat,
+    };
+    use sp_runtime::FixedU128;
+    use zeitgeist_primitives::types::{AccountIdTest, Balance, BlockNumber, MarketId};
+
+    const LIQUIDITY_MINING: &[u8] = b"LiquidityMining";
+    const OWNED_VALUES: &[u8] = b"OwnedValues";
+
+    #[test]
+    fn on_runtime_upgrade_increments_the_storage_version() {
+        ExtBuilder::default().build().execute_with(|| {
+            set_up_chain();
+            MigrateOwnedValuesToRewardsPerShare::<Runtime>::on_runtime_upgrade();
+            assert_eq!(
+                StorageVersion::get::<Pallet<Runtime>>(),
+                LIQUIDITY_MINING_NEXT_STORAGE_VERSION
+            );
+        });
+    }
+
+    #[test]
+    fn on_runtime_upgrade_converts_owned_values_and_seeds_market_incentives() {
+        ExtBuilder::default().build().execute_with(|| {
+            set_up_chain();
+            create_default_market(0, 0..100);
+            put_old_values(
+                0,
+                ALICE,
+                OldOwnedValuesParams {
+                    participated_blocks: 25,
+                    perpetual_incentives: 400,
+                    total_incentives: 30,
+                    total_shares: 1_000,
+                },
+            );
+            put_old_values(
+                0,
+                BOB,
+                OldOwnedValuesParams {
+                    participated_blocks: 50,
+                    perpetual_incentives: 200,
+                    total_incentives: 10,
+                    total_shares: 500,
+                },
+            );
+
+            MigrateOwnedValuesToRewardsPerShare::<Runtime>::on_runtime_upgrade();
+
+            // Alice participated in 25% of the market period and Bob in 50%.
+            assert_eq!(
+                OwnedValues::<Runtime>::get(0, ALICE),
+                OwnedValuesParams {
+                    holding_since: 0,
+                    pending_rewards: 130,
+                    reward_debt: 0,
+                    total_shares: 1_000,
+                }
+            );
+            assert_eq!(
+                OwnedValues::<Runtime>::get(0, BOB),
+                OwnedValuesParams {
+                    holding_since: 0,
+                    pending_rewards: 110,
+                    reward_debt: 0,
+                    total_shares: 500,
+                }
+            );
+            let market = MarketIncentives::<Runtime>::get(0);
+            assert_eq!(market.total_shares, 1_500);
+            assert_eq!(market.reward_per_share, FixedU128::zero());
+        });
+    }
+
+    #[test]
+    fn on_runtime_upgrade_is_noop_if_versions_are_not_correct() {
+        ExtBuilder::default().build().execute_with(|| {
+            StorageVersion::new(LIQUIDITY_MINING_NEXT_STORAGE_VERSION).put::<Pallet<Runtime>>();
+            let values = OwnedValuesParams {
+                holding_since: 0,
+                pending_rewards: 30,
+                reward_debt: 0,
+                total_shares: 1_000,
+            };
+            OwnedValues::<Runtime>::insert(0, ALICE, values.clone());
+
+            MigrateOwnedValuesToRewardsPerShare::<Runtime>::on_runtime_upgrade();
+
+            assert_eq!(OwnedValues::<Runtime>::get(0, ALICE), values);
+            assert_eq!(MarketIncentives::<Runtime>::get(0).total_shares, 0);
+        });
+    }
+
+    fn put_old_values(
+        market_id: MarketId,
+        account: AccountIdTest,
+        values: OldOwnedValuesParams<Balance, BlockNumber>,
+    ) {
+        let mut hash = market_id.using_encoded(Blake2_128Concat::hash).as_ref().to_vec();
+        hash.extend(account.using_encoded(Twox64Concat::hash).as_ref());
+        put_storage_value(LIQUIDITY_MINING, OWNED_VALUES, &hash, values);
+    }
+
+    fn set_up_chain() {
+        StorageVersion::new(LIQUIDITY_MINING_REQUIRED_STORAGE_VERSION).put::<Pallet<Runtime>>();
+    }
+}
@@ -28,17 +28,19 @@ use sp_runtime::{
 };
 use zeitgeist_primitives::{
     constants::mock::{
-        BlockHashCount, ExistentialDeposit, LiquidityMiningPalletId, MaxLocks, MaxReserves,
-        MinimumPeriod, PmPalletId, BASE,
+        BlockHashCount, ExistentialDeposit, ExistentialDeposits, GetNativeCurrencyId,
+        IncentiveProgramClaimPeriod, IncentiveProgramDeposit, LiquidityMiningPalletId,
+        MaxIncentivePrograms, MaxLocks, MaxReserves, MinimumPeriod, PmPalletId, BASE,
     },
     types::{
-        AccountIdTest, Balance, BlockNumber, BlockTest, Hash, Index, MarketId, Moment,
-        UncheckedExtrinsicTest,
+        AccountIdTest, Amount, Balance, BasicCurrencyAdapter, BlockNumber, BlockTest, CurrencyId,
+        Hash, Index, MarketId, Moment, UncheckedExtrinsicTest,
     },
 };
 
 pub const ALICE: AccountIdTest = 0;
 pub const BOB: AccountIdTest = 1;
+pub const CHARLIE: AccountIdTest = 2;
 
 construct_runtime!(
     pub enum Runtime
@@ -48,17 +50,23 @@ construct_runtime!(
         UncheckedExtrinsic = UncheckedExtrinsicTest<Runtime>,
     {
         Balances: pallet_balances::{Call, Config<T>, Event<T>, Pallet, Storage},
+        Currencies: orml_currencies::{Pallet},
         LiquidityMining: zrml_liquidity_mining::{Config<T>, Event<T>, Pallet},
         MarketCommons: zrml_market_commons::{Pallet, Storage},
         System: frame_system::{Call, Config, Event<T>, Pallet, Storage},
         Timestamp: pallet_timestamp::{Pallet},
+        Tokens: orml_tokens::{Config<T>, Event<T>, Pallet, Storage},
     }
 );
 
 impl crate::Config for Runtime {
+    type AssetManager = Currencies;
     type Event = ();
+    type IncentiveProgramClaimPeriod = IncentiveProgramClaimPeriod;
+    type IncentiveProgramDeposit = IncentiveProgramDeposit;
     type MarketCommons = MarketCommons;
     type MarketId = MarketId;
+    type MaxIncentivePrograms = MaxIncentivePrograms;
     type PalletId = LiquidityMiningPalletId;
     type WeightInfo = crate::weights::WeightInfo<Runtime>;
 }
@@ -90,6 +98,29 @@ impl frame_system::Config for Runtime {
     type OnSetCode = ();
 }
 
+impl orml_currencies::Config for Runtime {
+    type GetNativeCurrencyId = GetNativeCurrencyId;
+    type MultiCurrency = Tokens;
+    type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances>;
+    type WeightInfo = ();
+}
+
+impl orml_tokens::Config for Runtime {
+    type Amount = Amount;
+    type Balance = Balance;
+    type CurrencyId = CurrencyId;
+    type DustRemovalWhitelist = Everything;
+    type Event = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type MaxLocks = MaxLocks;
+    type MaxReserves = MaxReserves;
+    type OnDust = ();
+    type OnKilledTokenAccount = ();
+    type OnNewTokenAccount = ();
+    type ReserveIdentifier = [u8; 8];
+    type WeightInfo = ();
+}
+
 impl pallet_balances::Config for Runtime {
     type AccountStore = System;
     type Balance = Balance;
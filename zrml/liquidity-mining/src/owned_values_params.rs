@@ -15,14 +15,19 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::utils::calculate_rewards;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::{
+    traits::{Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+    FixedU128,
+};
 
 /// Parameters used by the `OwnedValues` storage.
 ///
 /// # Types
 ///
 /// * `BA`: BAlance
-/// * `BN`: Block Number
+/// * `MO`: MOment
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 #[derive(
     scale_info::TypeInfo,
@@ -37,18 +42,66 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
     PartialEq,
     PartialOrd,
 )]
-pub struct OwnedValuesParams<BA, BN>
+pub struct OwnedValuesParams<BA, MO>
 where
     BA: MaxEncodedLen,
-    BN: MaxEncodedLen,
+    MO: MaxEncodedLen,
 {
-    /// The number of blocks an account participated in a market period.
-    pub participated_blocks: BN,
-    /// Owned amount of perpetual incentives. Won't go away when accounts exist early and is not
-    /// attached to any share
-    pub perpetual_incentives: BA,
-    /// Owned incentives. Related to the total number of shares.
-    pub total_incentives: BA,
-    /// Owned quantity of shares. Related to the total amount of incentives.
+    /// The moment since when the account continuously owns shares.
+    pub holding_since: MO,
+    /// Incentives that were settled but not claimed yet.
+    pub pending_rewards: BA,
+    /// Incentives of `total_shares` that were already accounted for, i.e., `total_shares` times
+    /// the `reward_per_share` of the market at the last update.
+    pub reward_debt: BA,
+    /// Owned quantity of shares.
     pub total_shares: BA,
 }
+
+impl<BA, MO> OwnedValuesParams<BA, MO>
+where
+    BA: Copy
+        + MaxEncodedLen
+        + Ord
+        + Saturating
+        + UniqueSaturatedFrom<u128>
+        + UniqueSaturatedInto<u128>
+        + Zero,
+    MO: Copy + MaxEncodedLen + Ord + Saturating,
+{
+    /// Whether the account owned its shares for at least `min_holding_period` at `now`.
+    pub fn is_holding_period_over(&self, now: MO, min_holding_period: MO) -> bool {
+        now >= self.holding_since.saturating_add(min_holding_period)
+    }
+
+    /// Moves the incentives that accrued since the last update into `pending_rewards`.
+    pub fn settle(&mut self, reward_per_share: FixedU128) {
+        let accrued = calculate_rewards(reward_per_share, self.total_shares);
+        self.pending_rewards =
+            self.pending_rewards.saturating_add(accrued.saturating_sub(self.reward_debt));
+        self.reward_debt = accrued;
+    }
+
+    /// Sets the owned quantity of shares at `now`. Must be preceded by a call to `settle` with the
+    /// same `reward_per_share`, otherwise accrued incentives are lost.
+    ///
+    /// Accounts that reduce their shares before they owned them for `min_holding_period` forfeit
+    /// their pending incentives.
+    pub fn set_total_shares(
+        &mut self,
+        total_shares: BA,
+        reward_per_share: FixedU128,
+        now: MO,
+        min_holding_period: MO,
+    ) {
+        if total_shares < self.total_shares && !self.is_holding_period_over(now, min_holding_period)
+        {
+            self.pending_rewards = BA::zero();
+        }
+        if self.total_shares.is_zero() && !total_shares.is_zero() {
+            self.holding_since = now;
+        }
+        self.total_shares = total_shares;
+        self.reward_debt = calculate_rewards(reward_per_share, total_shares);
+    }
+}
@@ -18,24 +18,38 @@
 #![cfg(test)]
 
 use crate::{
-    mock::{Balances, ExtBuilder, LiquidityMining, Origin, Runtime, System, ALICE, BOB},
+    mock::{
+        Balances, Currencies, ExtBuilder, LiquidityMining, Origin, Runtime, System, Timestamp,
+        ALICE, BOB, CHARLIE,
+    },
     track_incentives_based_on_bought_shares::TrackIncentivesBasedOnBoughtShares,
     track_incentives_based_on_sold_shares::TrackIncentivesBasedOnSoldShares,
-    BlockBoughtShares, BlockSoldShares, LiquidityMiningPalletApi as _, OwnedValues,
+    BlockBoughtShares, BlockSoldShares, Error, IncentivePrograms, LiquidityMiningPalletApi as _,
+    MarketIncentivePrograms, MarketIncentives, MinHoldingPeriod, OwnedValues,
 };
 use core::ops::Range;
 use frame_support::{
-    assert_err, assert_ok,
+    assert_err, assert_noop, assert_ok,
     dispatch::DispatchError,
     traits::{Currency, OnFinalize},
 };
 use frame_system::RawOrigin;
-use zeitgeist_primitives::types::{
-    Asset, Deadlines, Market, MarketBonds, MarketCreation, MarketDisputeMechanism, MarketPeriod,
-    MarketStatus, MarketType, ScoringRule,
+use orml_traits::MultiCurrency;
+use sp_runtime::{FixedPointNumber, FixedU128};
+use zeitgeist_primitives::{
+    constants::{
+        mock::{IncentiveProgramClaimPeriod, IncentiveProgramDeposit, MaxIncentivePrograms},
+        BASE, MILLISECS_PER_BLOCK,
+    },
+    types::{
+        Asset, Deadlines, Market, MarketBonds, MarketCreation, MarketDisputeMechanism,
+        MarketPeriod, MarketStatus, MarketType, ScoringRule,
+    },
 };
 use zrml_market_commons::Markets;
 
+const FOREIGN_ASSET: Asset<u128> = Asset::ForeignAsset(0);
+
 #[test]
 fn blocks_shares_are_updated_after_each_block() {
     ExtBuilder::default().build().execute_with(|| {
@@ -51,35 +65,242 @@ fn blocks_shares_are_updated_after_each_block() {
 }
 
 #[test]
-fn distribute_market_incentives_removes_market_and_distributes_all_incentives_to_lps() {
+fn claim_rewards_transfers_accrued_incentives_to_the_caller() {
     ExtBuilder::default().build().execute_with(|| {
         let initial_alice_balance = Balances::free_balance(ALICE);
-        create_default_market(0, 1..5);
-        create_default_market(1, 1..5);
+        run_to_block(2);
+        create_default_market(0, 1..11);
 
         LiquidityMining::add_shares(ALICE, 0, 10);
-        LiquidityMining::add_shares(ALICE, 1, 10);
         LiquidityMining::on_finalize(2);
+        run_to_block(11);
 
-        assert_eq!(<OwnedValues<Runtime>>::iter().count(), 2);
-        assert_ok!(LiquidityMining::distribute_market_incentives(&0));
-        assert_ok!(LiquidityMining::distribute_market_incentives(&1));
-        assert_eq!(<OwnedValues<Runtime>>::iter().count(), 0);
+        // The only market receives all incentives of the block once the market ends
+        let per_block_incentives = ExtBuilder::default().per_block_incentives;
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(ALICE), 0));
+        assert_approx(Balances::free_balance(ALICE), initial_alice_balance + per_block_incentives);
+        assert_eq!(<OwnedValues<Runtime>>::get(0, ALICE).pending_rewards, 0);
+        assert_eq!(<OwnedValues<Runtime>>::get(0, ALICE).total_shares, 10);
+    });
+}
+
+#[test]
+fn claim_rewards_fails_if_there_is_nothing_to_claim() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_default_market(0, 1..11);
+        assert_noop!(
+            LiquidityMining::claim_rewards(Origin::signed(ALICE), 0),
+            Error::<Runtime>::NoRewardsToClaim
+        );
+
+        LiquidityMining::add_shares(ALICE, 0, 10);
+        LiquidityMining::on_finalize(2);
+        // Nothing was streamed yet
+        assert_noop!(
+            LiquidityMining::claim_rewards(Origin::signed(ALICE), 0),
+            Error::<Runtime>::NoRewardsToClaim
+        );
+        run_to_block(3);
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(ALICE), 0));
+        assert_noop!(
+            LiquidityMining::claim_rewards(Origin::signed(ALICE), 0),
+            Error::<Runtime>::NoRewardsToClaim
+        );
+    });
+}
+
+#[test]
+fn incentives_are_split_by_share_seconds() {
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_alice_balance = Balances::free_balance(ALICE);
+        let per_block_incentives = ExtBuilder::default().per_block_incentives;
+        run_to_block(1);
+        create_default_market(0, 1..11);
+
+        // The incentive of block 1 is streamed until the end of the market
+        LiquidityMining::add_shares(ALICE, 0, 10);
+        LiquidityMining::on_finalize(1);
+        // Half of it was streamed to ALICE, the rest and the incentive of block 6 are streamed
+        // to ALICE and BOB
+        run_to_block(6);
+        LiquidityMining::add_shares(BOB, 0, 10);
+        LiquidityMining::on_finalize(6);
+        run_to_block(11);
+
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(ALICE), 0));
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(BOB), 0));
+        assert_approx(
+            Balances::free_balance(ALICE),
+            initial_alice_balance + per_block_incentives / 4 * 5,
+        );
+        assert_approx(Balances::free_balance(BOB), per_block_incentives / 4 * 3);
+    });
+}
+
+#[test]
+fn shares_bought_and_sold_in_the_same_block_receive_no_incentives() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        LiquidityMining::add_shares(ALICE, 0, 10);
+        LiquidityMining::on_finalize(1);
+
+        run_to_block(6);
+        LiquidityMining::add_shares(BOB, 0, 1_000);
+        LiquidityMining::remove_shares(&BOB, &0, 1_000);
+        LiquidityMining::on_finalize(6);
+
+        run_to_block(11);
+        assert_noop!(
+            LiquidityMining::claim_rewards(Origin::signed(BOB), 0),
+            Error::<Runtime>::NoRewardsToClaim
+        );
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(ALICE), 0));
+    });
+}
+
+#[test]
+fn late_liquidity_providers_dont_receive_incentives_streamed_before_they_joined() {
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_alice_balance = Balances::free_balance(ALICE);
+        let per_block_incentives = ExtBuilder::default().per_block_incentives;
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        LiquidityMining::add_shares(ALICE, 0, 10);
+        LiquidityMining::on_finalize(1);
+
+        // BOB buys many shares right before the market closes
+        run_to_block(10);
+        LiquidityMining::add_shares(BOB, 0, 90);
+        LiquidityMining::on_finalize(10);
+        assert_ok!(LiquidityMining::close_market_incentives(&0));
+
+        assert_noop!(
+            LiquidityMining::claim_rewards(Origin::signed(BOB), 0),
+            Error::<Runtime>::NoRewardsToClaim
+        );
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(ALICE), 0));
+        assert_eq!(<MarketIncentives<Runtime>>::get(0).reward_rate, FixedU128::from(0));
+        assert_approx(
+            Balances::free_balance(ALICE),
+            initial_alice_balance + per_block_incentives / 10 * 9,
+        );
+    });
+}
 
-        // In this case, each market have the same amount of incentives
-        let market_incentives = ExtBuilder::default().per_block_incentives / 2;
-        // Perpetual balance for the entire campaign
-        let entire_market_perpetual_balance = market_incentives / 1000;
-        // Account only stayed 1 block out of 4 (25%), i.e, lost 75% of the perpetual balance
-        let actual_market_perpetual_balance = entire_market_perpetual_balance / 4;
-        // Ordinary balance
-        let actual_market_incentives = market_incentives - entire_market_perpetual_balance;
+#[test]
+fn selling_shares_before_the_min_holding_period_forfeits_pending_incentives() {
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_alice_balance = Balances::free_balance(ALICE);
+        let per_block_incentives = ExtBuilder::default().per_block_incentives;
+        assert_ok!(LiquidityMining::set_min_holding_period(
+            RawOrigin::Root.into(),
+            5 * u64::from(MILLISECS_PER_BLOCK)
+        ));
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        LiquidityMining::add_shares(ALICE, 0, 10);
+        LiquidityMining::add_shares(BOB, 0, 10);
+        LiquidityMining::on_finalize(1);
 
-        let new_balance = initial_alice_balance
-            + 2 * actual_market_incentives
-            + 2 * actual_market_perpetual_balance;
+        // ALICE and BOB received half of the incentives streamed in the first two blocks
+        run_to_block(3);
+        LiquidityMining::remove_shares(&BOB, &0, 10);
+        LiquidityMining::on_finalize(3);
+        let bob_values = <OwnedValues<Runtime>>::get(0, BOB);
+        assert_eq!(bob_values.pending_rewards, 0);
+        assert_eq!(bob_values.total_shares, 0);
 
-        assert_eq!(Balances::free_balance(ALICE), new_balance);
+        run_to_block(11);
+        assert_noop!(
+            LiquidityMining::claim_rewards(Origin::signed(BOB), 0),
+            Error::<Runtime>::NoRewardsToClaim
+        );
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(ALICE), 0));
+        assert_approx(
+            Balances::free_balance(ALICE),
+            initial_alice_balance + per_block_incentives / 10 * 9,
+        );
+    });
+}
+
+#[test]
+fn selling_shares_after_the_min_holding_period_keeps_pending_incentives() {
+    ExtBuilder::default().build().execute_with(|| {
+        let per_block_incentives = ExtBuilder::default().per_block_incentives;
+        assert_ok!(LiquidityMining::set_min_holding_period(
+            RawOrigin::Root.into(),
+            5 * u64::from(MILLISECS_PER_BLOCK)
+        ));
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        LiquidityMining::add_shares(BOB, 0, 10);
+        LiquidityMining::on_finalize(1);
+
+        run_to_block(6);
+        LiquidityMining::remove_shares(&BOB, &0, 10);
+        LiquidityMining::on_finalize(6);
+
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(BOB), 0));
+        assert_approx(Balances::free_balance(BOB), per_block_incentives / 2);
+    });
+}
+
+#[test]
+fn claims_fail_before_the_min_holding_period_is_over() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(LiquidityMining::set_min_holding_period(
+            RawOrigin::Root.into(),
+            5 * u64::from(MILLISECS_PER_BLOCK)
+        ));
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        create_foreign_asset_program(1, 11);
+        LiquidityMining::add_shares(BOB, 0, 10);
+        LiquidityMining::on_finalize(1);
+
+        run_to_block(5);
+        assert_noop!(
+            LiquidityMining::claim_rewards(Origin::signed(BOB), 0),
+            Error::<Runtime>::MinHoldingPeriodNotOver
+        );
+        assert_noop!(
+            LiquidityMining::claim_program_rewards(Origin::signed(BOB), 0),
+            Error::<Runtime>::MinHoldingPeriodNotOver
+        );
+
+        run_to_block(6);
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(BOB), 0));
+        assert_ok!(LiquidityMining::claim_program_rewards(Origin::signed(BOB), 0));
+    });
+}
+
+#[test]
+fn close_market_incentives_stops_accrual_and_claims_clean_up_storage() {
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_alice_balance = Balances::free_balance(ALICE);
+        let per_block_incentives = ExtBuilder::default().per_block_incentives;
+        run_to_block(1);
+        create_default_market(0, 1..11);
+
+        LiquidityMining::add_shares(ALICE, 0, 10);
+        LiquidityMining::on_finalize(1);
+        run_to_block(6);
+        assert_ok!(LiquidityMining::close_market_incentives(&0));
+        assert!(<MarketIncentives<Runtime>>::get(0).is_closed);
+
+        LiquidityMining::add_shares(ALICE, 0, 10);
+        LiquidityMining::on_finalize(6);
+        assert_eq!(<OwnedValues<Runtime>>::get(0, ALICE).total_shares, 10);
+
+        // Only the incentives streamed until the market was closed are distributed
+        run_to_block(11);
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(ALICE), 0));
+        assert_approx(
+            Balances::free_balance(ALICE),
+            initial_alice_balance + per_block_incentives / 2,
+        );
+        assert_eq!(<OwnedValues<Runtime>>::iter().count(), 0);
     });
 }
 
@@ -101,7 +322,7 @@ fn genesis_has_lm_account_and_initial_per_block_distribution() {
 #[test]
 fn owned_balances_are_updated_after_bought_shares() {
     ExtBuilder::default().build().execute_with(|| {
-        System::set_block_number(5);
+        run_to_block(5);
         create_default_market(0, 1..11);
         create_default_market(1, 1..21);
 
@@ -115,43 +336,37 @@ fn owned_balances_are_updated_after_bought_shares() {
         assert_eq!(vec[2].2.total_shares, 100);
         assert_eq!(vec[1].2.total_shares, 200);
         assert_eq!(vec[0].2.total_shares, 300);
+        assert!(vec.iter().all(|el| el.2.holding_since == 60_000));
 
         // Market 0 has 42.8% of `per_block_incentives`
         let market_incentives_0 = 4280000000;
         // Market 1 has 57.1% of `per_block_incentives`
         let market_incentives_1 = 5710000000;
 
-        // 0.1% of `market_incentives_0`.
-        let perpetual_incentives_alice_0 = market_incentives_0 / 1000;
-
-        // One share value for Market 1
-        let market_1_one_share_value = one_share_value(market_incentives_1, 500);
-        // Raw incentives for ALICE on Market 1
-        let raw_incentives_alice_1 = market_1_one_share_value * 200;
-        // Raw incentives for BOB on Market 1
-        let raw_incentives_bob_1 = market_1_one_share_value * 300;
-        // 0.1% of `raw_incentives_ALICE_1`
-        let perpetual_incentives_alice_1 = raw_incentives_alice_1 / 1000;
-        // 0.1% of `raw_incentives_BOB_1`
-        let perpetual_incentives_bob_1 = raw_incentives_bob_1 / 1000;
-
-        assert_eq!(vec[2].2.perpetual_incentives, perpetual_incentives_alice_0);
-        assert_eq!(vec[1].2.perpetual_incentives, perpetual_incentives_alice_1);
-        assert_eq!(vec[0].2.perpetual_incentives, perpetual_incentives_bob_1);
-
-        assert_eq!(vec[2].2.total_incentives, market_incentives_0 - perpetual_incentives_alice_0);
+        // The incentives are streamed until the end of each market
+        let market_0 = <MarketIncentives<Runtime>>::get(0);
+        assert_eq!(market_0.total_shares, 100);
+        assert_eq!(market_0.reward_per_share, FixedU128::from(0));
+        assert_eq!(market_0.period_end, 132_000);
+        assert_eq!(
+            market_0.reward_rate,
+            FixedU128::saturating_from_rational(market_incentives_0, 72_000)
+        );
+        let market_1 = <MarketIncentives<Runtime>>::get(1);
+        assert_eq!(market_1.total_shares, 500);
+        assert_eq!(market_1.reward_per_share, FixedU128::from(0));
+        assert_eq!(market_1.period_end, 252_000);
         assert_eq!(
-            vec[1].2.total_incentives,
-            raw_incentives_alice_1 - perpetual_incentives_alice_1
+            market_1.reward_rate,
+            FixedU128::saturating_from_rational(market_incentives_1, 192_000)
         );
-        assert_eq!(vec[0].2.total_incentives, raw_incentives_bob_1 - perpetual_incentives_bob_1);
     });
 }
 
 #[test]
 fn owned_balances_are_updated_after_sold_shares() {
     ExtBuilder::default().build().execute_with(|| {
-        System::set_block_number(5);
+        run_to_block(5);
         create_default_market(0, 1..11);
         create_default_market(1, 1..21);
 
@@ -159,31 +374,29 @@ fn owned_balances_are_updated_after_sold_shares() {
         LiquidityMining::add_shares(ALICE, 1, 200);
         LiquidityMining::on_finalize(5);
 
+        run_to_block(8);
         LiquidityMining::remove_shares(&ALICE, &0, 100);
         LiquidityMining::remove_shares(&ALICE, &1, 50);
-        TrackIncentivesBasedOnSoldShares::<Runtime>::exec();
+        TrackIncentivesBasedOnSoldShares::<Runtime>::exec(8);
 
         let vec = <OwnedValues<Runtime>>::iter().collect::<Vec<_>>();
 
-        // Market 0 has 42.8% of `per_block_incentives`
-        let market_incentives_0 = 4280000000;
-        // Market 1 has 57.1% of `per_block_incentives`
-        let market_incentives_1 = 5710000000;
-
-        // 0.1% of `market_incentives_0`.
-        let perpetual_incentives_alice_0 = market_incentives_0 / 1000;
-        // 0.1% of `market_incentives_1`.
-        let perpetual_incentives_alice_1 = market_incentives_1 / 1000;
-        // Alice sold 25% of her owned shares
-        let incentives_alice_1 = (market_incentives_1 - perpetual_incentives_alice_1) / 4 * 3;
+        // Market 0 streamed 3 of its 6 remaining blocks of 42.8% of `per_block_incentives`
+        let market_incentives_0 = 4280000000 / 2;
+        // Market 1 streamed 3 of its 16 remaining blocks of 57.1% of `per_block_incentives`
+        let market_incentives_1 = 5710000000 / 16 * 3;
 
+        // Accrued incentives are kept after selling shares
         assert_eq!(vec[1].2.total_shares, 0);
-        assert_eq!(vec[1].2.total_incentives, 0);
-        assert_eq!(vec[1].2.perpetual_incentives, perpetual_incentives_alice_0);
+        assert_approx(vec[1].2.pending_rewards, market_incentives_0);
+        assert_eq!(vec[1].2.reward_debt, 0);
 
         assert_eq!(vec[0].2.total_shares, 150);
-        assert_eq!(vec[0].2.total_incentives, incentives_alice_1);
-        assert_eq!(vec[0].2.perpetual_incentives, perpetual_incentives_alice_1);
+        assert_approx(vec[0].2.pending_rewards, market_incentives_1);
+        assert_approx(vec[0].2.reward_debt, market_incentives_1 / 4 * 3);
+
+        assert_eq!(<MarketIncentives<Runtime>>::get(0).total_shares, 0);
+        assert_eq!(<MarketIncentives<Runtime>>::get(1).total_shares, 150);
     });
 }
 
@@ -198,7 +411,260 @@ fn only_sudo_can_change_per_block_distribution() {
     });
 }
 
-fn create_default_market(market_id: u128, period: Range<u64>) {
+#[test]
+fn only_sudo_can_change_min_holding_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(LiquidityMining::set_min_holding_period(RawOrigin::Root.into(), 100));
+        assert_eq!(<MinHoldingPeriod<Runtime>>::get(), 100);
+        assert_err!(
+            LiquidityMining::set_min_holding_period(Origin::signed(ALICE), 100),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn create_incentive_program_funds_the_program() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        assert_ok!(Currencies::deposit(FOREIGN_ASSET, &ALICE, 10 * BASE));
+
+        assert_ok!(LiquidityMining::create_incentive_program(
+            Origin::signed(ALICE),
+            0,
+            FOREIGN_ASSET,
+            1,
+            11,
+            BASE
+        ));
+
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &ALICE), 0);
+        let program_account = crate::Pallet::<Runtime>::program_account_id(0);
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &program_account), 10 * BASE);
+        assert_eq!(<MarketIncentivePrograms<Runtime>>::get(0).into_inner(), vec![0]);
+        let program = <IncentivePrograms<Runtime>>::get(0).unwrap();
+        assert_eq!(program.sponsor, ALICE);
+        assert_eq!(program.remaining, 10 * BASE);
+        assert_eq!(program.deposit, IncentiveProgramDeposit::get());
+        assert_eq!(Balances::reserved_balance(&ALICE), IncentiveProgramDeposit::get());
+    });
+}
+
+#[test]
+fn create_incentive_program_fails_if_the_sponsor_cannot_reserve_the_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        assert_ok!(Currencies::deposit(FOREIGN_ASSET, &BOB, 10 * BASE));
+
+        assert_noop!(
+            LiquidityMining::create_incentive_program(
+                Origin::signed(BOB),
+                0,
+                FOREIGN_ASSET,
+                1,
+                11,
+                BASE
+            ),
+            pallet_balances::Error::<Runtime>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn create_incentive_program_fails_on_invalid_parameters() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(5);
+        create_default_market(0, 1..11);
+        assert_ok!(Currencies::deposit(FOREIGN_ASSET, &ALICE, 10 * BASE));
+
+        let create = |start, end, per_block| {
+            LiquidityMining::create_incentive_program(
+                Origin::signed(ALICE),
+                0,
+                FOREIGN_ASSET,
+                start,
+                end,
+                per_block,
+            )
+        };
+        assert_noop!(create(4, 11, BASE), Error::<Runtime>::InvalidIncentiveProgramPeriod);
+        assert_noop!(create(6, 6, BASE), Error::<Runtime>::InvalidIncentiveProgramPeriod);
+        assert_noop!(create(6, 11, 0), Error::<Runtime>::ZeroIncentive);
+
+        assert_ok!(LiquidityMining::close_market_incentives(&0));
+        assert_noop!(create(6, 11, BASE), Error::<Runtime>::MarketIncentivesClosed);
+    });
+}
+
+#[test]
+fn incentive_programs_are_split_by_the_pool_shares_of_each_block() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        create_foreign_asset_program(1, 11);
+
+        // Nobody owns shares until block 2, so the incentives of block 2 remain undistributed
+        LiquidityMining::add_shares(BOB, 0, 10);
+        LiquidityMining::on_finalize(2);
+        // BOB owns all shares in blocks 3 and 4
+        LiquidityMining::add_shares(CHARLIE, 0, 10);
+        LiquidityMining::on_finalize(4);
+        // BOB and CHARLIE own half of the shares in blocks 5 and 6
+        run_to_block(6);
+
+        assert_ok!(LiquidityMining::claim_program_rewards(Origin::signed(BOB), 0));
+        assert_ok!(LiquidityMining::claim_program_rewards(Origin::signed(CHARLIE), 0));
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &BOB), 3 * BASE);
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &CHARLIE), BASE);
+        assert_noop!(
+            LiquidityMining::claim_program_rewards(Origin::signed(BOB), 0),
+            Error::<Runtime>::NoRewardsToClaim
+        );
+    });
+}
+
+#[test]
+fn liquidity_providers_of_a_market_receive_incentives_of_new_programs() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        LiquidityMining::add_shares(BOB, 0, 10);
+        LiquidityMining::on_finalize(1);
+
+        create_foreign_asset_program(2, 12);
+        run_to_block(5);
+
+        assert_ok!(LiquidityMining::claim_program_rewards(Origin::signed(BOB), 0));
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &BOB), 3 * BASE);
+    });
+}
+
+#[test]
+fn close_incentive_program_returns_the_leftover_to_the_sponsor() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        create_foreign_asset_program(1, 11);
+        LiquidityMining::add_shares(BOB, 0, 10);
+        LiquidityMining::on_finalize(2);
+
+        run_to_block(11);
+        assert_ok!(LiquidityMining::claim_program_rewards(Origin::signed(BOB), 0));
+        // Liquidity providers can still claim their incentives during the claim period
+        assert_noop!(
+            LiquidityMining::close_incentive_program(Origin::signed(ALICE), 0),
+            Error::<Runtime>::IncentiveProgramIsActive
+        );
+        run_to_block(11 + IncentiveProgramClaimPeriod::get());
+        assert_noop!(
+            LiquidityMining::close_incentive_program(Origin::signed(BOB), 0),
+            Error::<Runtime>::NotIncentiveProgramSponsor
+        );
+        assert_ok!(LiquidityMining::close_incentive_program(Origin::signed(ALICE), 0));
+
+        // The incentives of block 2 were not distributed
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &ALICE), BASE);
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &BOB), 9 * BASE);
+        assert_eq!(Balances::reserved_balance(&ALICE), 0);
+        assert!(<IncentivePrograms<Runtime>>::get(0).is_none());
+        assert!(<MarketIncentivePrograms<Runtime>>::get(0).is_empty());
+        assert_noop!(
+            LiquidityMining::close_incentive_program(Origin::signed(ALICE), 0),
+            Error::<Runtime>::IncentiveProgramNotFound
+        );
+    });
+}
+
+#[test]
+fn close_incentive_program_returns_unclaimed_incentives_and_frees_the_slot_of_the_program() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        for _ in 0..MaxIncentivePrograms::get() {
+            create_foreign_asset_program(1, 11);
+        }
+        LiquidityMining::add_shares(BOB, 0, 10);
+        LiquidityMining::on_finalize(1);
+        assert_ok!(Currencies::deposit(FOREIGN_ASSET, &ALICE, 10 * BASE));
+        assert_noop!(
+            LiquidityMining::create_incentive_program(
+                Origin::signed(ALICE),
+                0,
+                FOREIGN_ASSET,
+                2,
+                12,
+                BASE
+            ),
+            Error::<Runtime>::TooManyIncentivePrograms
+        );
+
+        run_to_block(11 + IncentiveProgramClaimPeriod::get());
+        assert_ok!(LiquidityMining::close_incentive_program(Origin::signed(ALICE), 0));
+
+        // BOB didn't claim the incentives of the program within the claim period
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &ALICE), 20 * BASE);
+        assert_noop!(
+            LiquidityMining::claim_program_rewards(Origin::signed(BOB), 0),
+            Error::<Runtime>::IncentiveProgramNotFound
+        );
+        create_foreign_asset_program(21, 31);
+        assert_eq!(<MarketIncentivePrograms<Runtime>>::get(0).into_inner(), vec![1, 2, 3, 4]);
+    });
+}
+
+#[test]
+fn close_market_incentives_ends_incentive_programs() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(1);
+        create_default_market(0, 1..11);
+        create_foreign_asset_program(1, 11);
+        LiquidityMining::add_shares(BOB, 0, 10);
+        LiquidityMining::on_finalize(1);
+
+        run_to_block(5);
+        assert_ok!(LiquidityMining::close_market_incentives(&0));
+        assert_eq!(<IncentivePrograms<Runtime>>::get(0).unwrap().end, 5);
+
+        // Shares of closed markets are recorded by the programs before they are cleaned up
+        assert_ok!(LiquidityMining::claim_rewards(Origin::signed(BOB), 0));
+        assert_ok!(LiquidityMining::claim_program_rewards(Origin::signed(BOB), 0));
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &BOB), 4 * BASE);
+
+        run_to_block(5 + IncentiveProgramClaimPeriod::get());
+        assert_ok!(LiquidityMining::close_incentive_program(Origin::signed(ALICE), 0));
+        assert_eq!(Currencies::free_balance(FOREIGN_ASSET, &ALICE), 6 * BASE);
+    });
+}
+
+// Streamed incentives are rounded down by a few units of the smallest denomination.
+fn assert_approx(actual: u128, expected: u128) {
+    assert!(actual <= expected && expected - actual < 100, "{} != {}", actual, expected);
+}
+
+// Moves to `block` and advances the timestamp by the average block time.
+fn run_to_block(block: u64) {
+    System::set_block_number(block);
+    Timestamp::set_timestamp(block * u64::from(MILLISECS_PER_BLOCK));
+}
+
+// Lets ALICE fund an incentive program for market 0, which distributes `BASE` of
+// `FOREIGN_ASSET` per block.
+fn create_foreign_asset_program(start: u64, end: u64) {
+    let budget = u128::from(end - start) * BASE;
+    assert_ok!(Currencies::deposit(FOREIGN_ASSET, &ALICE, budget));
+    assert_ok!(LiquidityMining::create_incentive_program(
+        Origin::signed(ALICE),
+        0,
+        FOREIGN_ASSET,
+        start,
+        end,
+        BASE
+    ));
+}
+
+pub(crate) fn create_default_market(market_id: u128, period: Range<u64>) {
     Markets::<Runtime>::insert(
         market_id,
         Market {
@@ -224,8 +690,3 @@ fn create_default_market(market_id: u128, period: Range<u64>) {
         },
     );
 }
-
-// One bought share value of a particular market in a particular block
-fn one_share_value(market_incentives: u128, total_shares: u128) -> u128 {
-    market_incentives / total_shares
-}
@@ -17,21 +17,17 @@
 
 use crate::{
     utils::{calculate_perthousand, calculate_perthousand_value},
-    BalanceOf, BlockBoughtShares, MomentOf, OwnedValues, PerBlockIncentive,
+    BalanceOf, BlockBoughtShares, MarketIncentives, MinHoldingPeriod, MomentOf, OwnedValues,
+    Pallet, PerBlockIncentive,
 };
 use alloc::{collections::BTreeSet, vec::Vec};
 use core::marker::PhantomData;
-use sp_runtime::traits::{CheckedDiv, Saturating};
-use zeitgeist_primitives::types::MarketPeriod;
+use sp_runtime::{traits::Saturating, SaturatedConversion};
+use zeitgeist_primitives::{constants::MILLISECS_PER_BLOCK, types::MarketPeriod};
 use zrml_market_commons::MarketCommonsPalletApi;
 
-// Per-thousand that every incentive should transfer to the perpetual balance.
-// Currently is 0.1%
-const PERPETUAL_PTD: u8 = 1;
-
 pub struct TrackIncentivesBasedOnBoughtShares<T>(PhantomData<T>);
 
-// No-one can have more balance than `Balance::MAX` so most functions saturate rewards.
 impl<T> TrackIncentivesBasedOnBoughtShares<T>
 where
     T: crate::Config,
@@ -40,52 +36,41 @@ where
         let per_block_incentives = <PerBlockIncentive<T>>::get();
         let market_incentives = Self::markets_incentives(per_block_incentives, curr_block)?;
         let market_incentives_len = market_incentives.len();
-        let ppb = BalanceOf::<T>::from(PERPETUAL_PTD);
+        let now = T::MarketCommons::now();
+        let min_holding_period = <MinHoldingPeriod<T>>::get();
 
-        for (market_id, incentive) in market_incentives {
-            let mut total_bought_shares = BalanceOf::<T>::from(0u8);
-            for (_, shares) in <BlockBoughtShares<T>>::iter_prefix(market_id) {
-                total_bought_shares = total_bought_shares.saturating_add(shares);
-            }
-            let opt = Self::bought_share_value(&incentive, &total_bought_shares);
-            let share_value = if let Some(el) = opt {
-                el
-            } else {
+        for (market_id, incentive, period_end) in market_incentives {
+            let mut market = <MarketIncentives<T>>::get(market_id);
+            if market.is_closed {
                 continue;
-            };
+            }
+            // Incentives streamed so far belong to the accounts that owned shares until now.
+            market.accrue(now);
+            Pallet::<T>::accrue_incentive_programs(&market_id, curr_block, market.total_shares);
             for (account_id, bought_shares) in <BlockBoughtShares<T>>::iter_prefix(market_id) {
-                let raw_incentives = share_value.saturating_mul(bought_shares);
-                let perpetual_incentives = calculate_perthousand_value(ppb, raw_incentives);
-                let incentives = raw_incentives.saturating_sub(perpetual_incentives);
-                <OwnedValues<T>>::mutate(market_id, account_id, |values| {
-                    let one = T::BlockNumber::from(1u8);
-
-                    values.perpetual_incentives =
-                        values.perpetual_incentives.saturating_add(perpetual_incentives);
-                    values.total_incentives = values.total_incentives.saturating_add(incentives);
-                    values.total_shares = values.total_shares.saturating_add(bought_shares);
-                    values.participated_blocks = values.participated_blocks.saturating_add(one);
-                });
+                let mut values = <OwnedValues<T>>::get(market_id, &account_id);
+                let total_shares = values.total_shares.saturating_add(bought_shares);
+                Pallet::<T>::settle_incentive_programs(&market_id, &account_id, total_shares, now);
+                values.settle(market.reward_per_share);
+                values.set_total_shares(
+                    total_shares,
+                    market.reward_per_share,
+                    now,
+                    min_holding_period,
+                );
+                <OwnedValues<T>>::insert(market_id, account_id, values);
+                market.total_shares = market.total_shares.saturating_add(bought_shares);
             }
+            // The incentive of the current block is streamed until the end of the market, so
+            // that accounts receive it proportionally to their share-seconds.
+            market.add_incentive(incentive, now, period_end);
+            <MarketIncentives<T>>::insert(market_id, market);
         }
 
         let _ = <BlockBoughtShares<T>>::clear(u32::max_value(), None);
         Some(market_incentives_len)
     }
 
-    // ZTG value of one bought share for the current block being produced. Or in other words:
-    // Determines how much a share will be worth given the amount of ZTG for liquidity
-    // mining and the total number of bought shares for the current block.
-    //
-    // `None` result means no-one purchased a share.
-    #[inline]
-    fn bought_share_value(
-        incentive: &BalanceOf<T>,
-        total_bought_shares: &BalanceOf<T>,
-    ) -> Option<BalanceOf<T>> {
-        incentive.checked_div(total_bought_shares)
-    }
-
     // How much incentive each market will receive
     #[allow(
         // `normalized_total` variable needs to be evaluated first
@@ -94,7 +79,7 @@ where
     fn markets_incentives(
         per_block_incentives: BalanceOf<T>,
         curr_block: T::BlockNumber,
-    ) -> Option<Vec<(T::MarketId, BalanceOf<T>)>> {
+    ) -> Option<Vec<(T::MarketId, BalanceOf<T>, MomentOf<T>)>> {
         let mut normalized_total = BalanceOf::<T>::from(0u8);
         let markets_periods: BTreeSet<_> = <BlockBoughtShares<T>>::iter().map(|el| el.0).collect();
         let now = T::MarketCommons::now();
@@ -105,19 +90,37 @@ where
                 let period = T::MarketCommons::market(&market_id).ok()?.period;
                 let normalized_value = Self::normalize_market(curr_block, now, &period);
                 normalized_total = normalized_total.saturating_add(normalized_value);
-                Some((market_id, normalized_value))
+                Some((market_id, normalized_value, Self::period_end(curr_block, now, &period)))
             })
             .collect();
         normalized_values
             .into_iter()
-            .map(|(market_id, normalized_value)| {
+            .map(|(market_id, normalized_value, period_end)| {
                 let ptd = calculate_perthousand(normalized_value, &normalized_total)?.into();
                 let incentive = calculate_perthousand_value(ptd, per_block_incentives);
-                Some((market_id, incentive))
+                Some((market_id, incentive, period_end))
             })
             .collect()
     }
 
+    // The moment at which a market ends. Block based periods are converted using the average
+    // block time.
+    fn period_end(
+        curr_block: T::BlockNumber,
+        now: MomentOf<T>,
+        period: &MarketPeriod<T::BlockNumber, MomentOf<T>>,
+    ) -> MomentOf<T> {
+        match period {
+            MarketPeriod::Block(range) => {
+                let remaining_blocks: u32 = range.end.saturating_sub(curr_block).saturated_into();
+                let remaining_time = MomentOf::<T>::from(remaining_blocks)
+                    .saturating_mul(MILLISECS_PER_BLOCK.into());
+                now.saturating_add(remaining_time)
+            }
+            MarketPeriod::Timestamp(range) => range.end,
+        }
+    }
+
     // Takes any market parameter and outputs a number that will be used as a percentage
     // to calculate how much incentives each individual market will receive.
     //
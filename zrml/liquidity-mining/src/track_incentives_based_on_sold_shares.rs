@@ -15,46 +15,49 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{
-    utils::{calculate_perthousand, calculate_perthousand_value},
-    BlockSoldShares, OwnedValues,
-};
+use crate::{BlockSoldShares, MarketIncentives, MinHoldingPeriod, OwnedValues, Pallet};
 use core::marker::PhantomData;
 use sp_runtime::traits::Saturating;
+use zrml_market_commons::MarketCommonsPalletApi;
 
 pub struct TrackIncentivesBasedOnSoldShares<T>(PhantomData<T>);
 
-// No-one can have more balance than `Balance::MAX` so most functions saturate rewards.
 impl<T> TrackIncentivesBasedOnSoldShares<T>
 where
     T: crate::Config,
 {
-    pub(crate) fn exec() -> usize {
+    pub(crate) fn exec(curr_block: T::BlockNumber) -> usize {
         let mut counter = 0;
+        let now = T::MarketCommons::now();
+        let min_holding_period = <MinHoldingPeriod<T>>::get();
         for (market_id, account_id, sold_shares) in <BlockSoldShares<T>>::iter() {
-            let values = if let Ok(e) = <OwnedValues<T>>::try_get(market_id, account_id.clone()) {
+            let mut values = if let Ok(e) = <OwnedValues<T>>::try_get(market_id, account_id.clone())
+            {
                 e
             } else {
                 // Trying to retrieve an account that doesn't have any bought shares
                 continue;
             };
-
-            let ptd = if let Some(el) = calculate_perthousand(sold_shares, &values.total_shares) {
-                el.into()
-            } else {
-                // `total_shares` is zero
+            let mut market = <MarketIncentives<T>>::get(market_id);
+            if market.is_closed {
+                // Owned shares are frozen once nothing accrues anymore
                 continue;
-            };
+            }
 
-            let balance_to_subtract = calculate_perthousand_value(ptd, values.total_incentives);
+            // Incentives accrued so far are kept, the sold shares just stop accruing new ones,
+            // unless the shares weren't owned for the minimum holding period. Selling more shares
+            // than owned is capped to the owned quantity.
+            let removed_shares = sold_shares.min(values.total_shares);
+            let total_shares = values.total_shares.saturating_sub(removed_shares);
+            market.accrue(now);
+            Pallet::<T>::accrue_incentive_programs(&market_id, curr_block, market.total_shares);
+            Pallet::<T>::settle_incentive_programs(&market_id, &account_id, total_shares, now);
+            values.settle(market.reward_per_share);
+            values.set_total_shares(total_shares, market.reward_per_share, now, min_holding_period);
+            market.total_shares = market.total_shares.saturating_sub(removed_shares);
 
-            <OwnedValues<T>>::mutate(market_id, account_id, |values| {
-                // `total_balance` or `total_shares` can be less than 0 but this possible
-                // scenario is ignored here.
-                values.total_incentives =
-                    values.total_incentives.saturating_sub(balance_to_subtract);
-                values.total_shares = values.total_shares.saturating_sub(sold_shares);
-            });
+            <OwnedValues<T>>::insert(market_id, account_id, values);
+            <MarketIncentives<T>>::insert(market_id, market);
             counter = counter.saturating_add(1);
         }
         let _ = <BlockSoldShares<T>>::clear(u32::max_value(), None);
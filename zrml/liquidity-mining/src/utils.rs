@@ -15,36 +15,12 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::MomentOf;
-use core::ops::{Div, Range};
+use core::ops::Div;
 use sp_runtime::{
-    traits::{CheckedDiv, Saturating, UniqueSaturatedInto},
-    SaturatedConversion,
+    traits::{CheckedDiv, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto},
+    FixedPointNumber, FixedU128, SaturatedConversion,
 };
-use zeitgeist_primitives::constants::MILLISECS_PER_BLOCK;
 
-// Calculates the **average** number of blocks occurred between the starting and ending time period
-// of a market.
-//
-// To convert the block number type to the moment type, is is necessary to first convert the
-// block number value to `u32`, which caps the maximum output to `u32::MAX`. Since this function
-// is only used to evaluate perpetual balances, such limitation shouldn't be a problem.
-pub fn calculate_average_blocks_of_a_time_period<T>(range: &Range<MomentOf<T>>) -> T::BlockNumber
-where
-    T: crate::Config,
-{
-    let total_value_time = range.end.saturating_sub(range.start);
-    let mpb_balance = MILLISECS_PER_BLOCK.into();
-    // The following won't overflow because `MILLISECS_PER_BLOCK` is not zero.
-    let total_value_blocks = total_value_time / mpb_balance;
-    let total_value_blocks_u32: u32 = total_value_blocks.saturated_into();
-    total_value_blocks_u32.into()
-}
-
-// Per-thousand compared to `total_value` and a given `value`. For example, if total is 200,
-// then 6 is 3% of 200.
-//
-// Results currently can't have more than 00.0% accuracy.
 pub fn calculate_perthousand<T>(value: T, total_value: &T) -> Option<u16>
 where
     T: CheckedDiv + From<u16> + Saturating + UniqueSaturatedInto<u16>,
@@ -64,3 +40,24 @@ where
     let _1000_balance = T::from(1000u16);
     total_value.saturating_mul(perthousand) / _1000_balance
 }
+
+// How much `reward_per_share` increases when `incentive` is spread across `total_shares`.
+//
+// `None` result means that no shares are owned.
+pub fn calculate_reward_per_share<T>(incentive: T, total_shares: T) -> Option<FixedU128>
+where
+    T: UniqueSaturatedInto<u128>,
+{
+    FixedU128::checked_from_rational(
+        incentive.saturated_into::<u128>(),
+        total_shares.saturated_into::<u128>(),
+    )
+}
+
+// Incentives accrued by `shares` given the accumulated `reward_per_share`.
+pub fn calculate_rewards<T>(reward_per_share: FixedU128, shares: T) -> T
+where
+    T: UniqueSaturatedFrom<u128> + UniqueSaturatedInto<u128>,
+{
+    T::unique_saturated_from(reward_per_share.saturating_mul_int(shares.saturated_into::<u128>()))
+}
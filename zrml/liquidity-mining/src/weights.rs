@@ -45,12 +45,73 @@ use frame_support::{traits::Get, weights::Weight};
 ///  Trait containing the required functions for weight retrival within
 /// zrml_liquidity_mining (automatically generated)
 pub trait WeightInfoZeitgeist {
+    fn claim_program_rewards() -> Weight;
+    fn claim_rewards(p: u32) -> Weight;
+    fn close_incentive_program() -> Weight;
+    fn create_incentive_program() -> Weight;
+    fn set_min_holding_period() -> Weight;
     fn set_per_block_distribution() -> Weight;
 }
 
 /// Weight functions for zrml_liquidity_mining (automatically generated)
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
+    // Storage: LiquidityMining IncentivePrograms (r:1 w:1)
+    // Storage: LiquidityMining MarketIncentives (r:1 w:0)
+    // Storage: LiquidityMining IncentiveProgramRewards (r:1 w:1)
+    // Storage: LiquidityMining OwnedValues (r:1 w:0)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: LiquidityMining MinHoldingPeriod (r:1 w:0)
+    // Storage: Tokens Accounts (r:2 w:2)
+    fn claim_program_rewards() -> Weight {
+        Weight::from_ref_time(61_340_000)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: LiquidityMining MarketIncentives (r:1 w:1)
+    // Storage: LiquidityMining OwnedValues (r:2 w:1)
+    // Storage: LiquidityMining MinHoldingPeriod (r:1 w:0)
+    // Storage: System Account (r:2 w:2)
+    // Storage: LiquidityMining MarketIncentivePrograms (r:1 w:0)
+    // Storage: LiquidityMining IncentivePrograms (r:1 w:0)
+    // Storage: LiquidityMining IncentiveProgramRewards (r:1 w:1)
+    fn claim_rewards(p: u32) -> Weight {
+        Weight::from_ref_time(53_870_000)
+            // Standard Error: 12_000
+            .saturating_add(Weight::from_ref_time(13_710_000).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+    }
+    // Storage: LiquidityMining IncentivePrograms (r:1 w:1)
+    // Storage: Tokens Accounts (r:2 w:2)
+    // Storage: Balances Reserves (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: LiquidityMining MarketIncentivePrograms (r:1 w:1)
+    fn close_incentive_program() -> Weight {
+        Weight::from_ref_time(63_480_000)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+    // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: LiquidityMining MarketIncentives (r:1 w:0)
+    // Storage: LiquidityMining NextIncentiveProgramId (r:1 w:1)
+    // Storage: LiquidityMining MarketIncentivePrograms (r:1 w:1)
+    // Storage: Balances Reserves (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Tokens Accounts (r:2 w:2)
+    // Storage: LiquidityMining IncentivePrograms (r:0 w:1)
+    fn create_incentive_program() -> Weight {
+        Weight::from_ref_time(79_160_000)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+    // Storage: LiquidityMining MinHoldingPeriod (r:0 w:1)
+    fn set_min_holding_period() -> Weight {
+        Weight::from_ref_time(7_920_000).saturating_add(T::DbWeight::get().writes(1))
+    }
     // Storage: LiquidityMining PerBlockIncentive (r:0 w:1)
     fn set_per_block_distribution() -> Weight {
         Weight::from_ref_time(8_150_000).saturating_add(T::DbWeight::get().writes(1))
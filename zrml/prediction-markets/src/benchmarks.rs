@@ -40,10 +40,11 @@ use zeitgeist_primitives::{
     types::{
         Asset, Deadlines, MarketCreation, MarketDisputeMechanism, MarketPeriod, MarketStatus,
         MarketType, MaxRuntimeUsize, MultiHash, OutcomeReport, PoolStatus, ScoringRule,
-        SubsidyUntil,
+        SerdeWrapper, SubsidyUntil,
     },
 };
 use zrml_authorized::Pallet as AuthorizedPallet;
+use zrml_global_disputes::GlobalDisputesPalletApi;
 use zrml_market_commons::MarketCommonsPalletApi;
 
 use frame_support::{traits::Hooks, BoundedVec};
@@ -105,6 +106,35 @@ fn create_market_common<T: Config + pallet_timestamp::Config>(
     Ok((caller, market_id))
 }
 
+// Creates a CPMM market with `categories` outcomes and an active pool.
+fn create_market_with_open_pool<T: Config + pallet_timestamp::Config>(
+    categories: u16,
+) -> Result<(T::AccountId, MarketIdOf<T>), &'static str> {
+    let range_start: MomentOf<T> = <zrml_market_commons::Pallet<T>>::now();
+    let range_end: MomentOf<T> = 1_000_000u64.saturated_into();
+    let (caller, market_id) = create_market_common::<T>(
+        MarketCreation::Permissionless,
+        MarketType::Categorical(categories),
+        ScoringRule::CPMM,
+        Some(MarketPeriod::Timestamp(range_start..range_end)),
+    )?;
+    let min_liquidity: BalanceOf<T> = MinLiquidity::get().saturated_into();
+    Pallet::<T>::buy_complete_set(
+        RawOrigin::Signed(caller.clone()).into(),
+        market_id,
+        min_liquidity,
+    )?;
+    let weight_len: usize = MaxRuntimeUsize::from(categories).into();
+    Pallet::<T>::deploy_swap_pool_for_market(
+        RawOrigin::Signed(caller.clone()).into(),
+        market_id,
+        MaxSwapFee::get().saturated_into(),
+        min_liquidity,
+        vec![MinWeight::get(); weight_len],
+    )?;
+    Ok((caller, market_id))
+}
+
 fn create_close_and_report_market<T: Config + pallet_timestamp::Config>(
     permission: MarketCreation,
     options: MarketType,
@@ -482,6 +512,7 @@ benchmarks! {
             T::AuthorizedDisputeResolutionOrigin::successful_origin(),
             market_id.into(),
             OutcomeReport::Scalar(0),
+            None,
         )?;
 
         let last_dispute = disputes.last().unwrap();
@@ -537,6 +568,7 @@ benchmarks! {
             T::AuthorizedDisputeResolutionOrigin::successful_origin(),
             market_id.into(),
             OutcomeReport::Categorical(0),
+            None,
         )?;
 
         let last_dispute = disputes.last().unwrap();
@@ -831,20 +863,15 @@ benchmarks! {
         let current_block: T::BlockNumber = (max_dispute_len + 1).saturated_into();
         <frame_system::Pallet<T>>::set_block_number(current_block);
 
-        #[cfg(feature = "with-global-disputes")]
-        {
-            let global_dispute_end = current_block + T::GlobalDisputePeriod::get();
-            // the complexity depends on MarketIdsPerDisputeBlock at the current block
-            // this is because a variable number of market ids need to be decoded from the storage
-            MarketIdsPerDisputeBlock::<T>::insert(global_dispute_end, market_ids_1);
-        }
+        let global_dispute_end = current_block + T::GlobalDisputePeriod::get();
+        // the complexity depends on MarketIdsPerDisputeBlock at the current block
+        // this is because a variable number of market ids need to be decoded from the storage
+        MarketIdsPerDisputeBlock::<T>::insert(global_dispute_end, market_ids_1);
+        T::GlobalDisputes::set_enabled(true);
 
         let call = Call::<T>::start_global_dispute { market_id };
     }: {
-        #[cfg(feature = "with-global-disputes")]
         call.dispatch_bypass_filter(RawOrigin::Signed(caller).into())?;
-        #[cfg(not(feature = "with-global-disputes"))]
-        let _ = call.dispatch_bypass_filter(RawOrigin::Signed(caller).into());
     }
 
     dispute_authorized {
@@ -920,6 +947,7 @@ benchmarks! {
             T::AuthorizedDisputeResolutionOrigin::successful_origin(),
             market_id.into(),
             OutcomeReport::Categorical(0),
+            None,
         )?;
         let market = <zrml_market_commons::Pallet::<T>>::market(&market_id)?;
     }: {
@@ -965,6 +993,7 @@ benchmarks! {
             T::AuthorizedDisputeResolutionOrigin::successful_origin(),
             market_id.into(),
             OutcomeReport::Scalar(0),
+            None,
         )?;
         let market = <zrml_market_commons::Pallet::<T>>::market(&market_id)?;
     }: {
@@ -1241,6 +1270,34 @@ benchmarks! {
         let _ = <Pallet<T>>::process_subsidy_collecting_markets(current_block, current_time);
     }
 
+    join_pool_with_base_asset {
+        let a in (T::MinCategories::get().into())..T::MaxCategories::get().into();
+        let (caller, market_id) = create_market_with_open_pool::<T>(a.saturated_into())?;
+        let amount: BalanceOf<T> = MinLiquidity::get().saturated_into();
+    }: _(RawOrigin::Signed(caller), market_id, amount, Zero::zero())
+
+    exit_pool_to_base_asset {
+        let a in (T::MinCategories::get().into())..T::MaxCategories::get().into();
+        let (caller, market_id) = create_market_with_open_pool::<T>(a.saturated_into())?;
+        let pool_id = <zrml_market_commons::Pallet::<T>>::market_pool(&market_id)?;
+        // Buying the first outcome leaves the pool unbalanced, so all other outcome tokens
+        // received from the pool must be sold back to it.
+        T::Swaps::swap_exact_amount_in(
+            caller.clone(),
+            pool_id,
+            Asset::Ztg,
+            (MinLiquidity::get() / 10).saturated_into(),
+            Asset::CategoricalOutcome(market_id, 0),
+            Some(Zero::zero()),
+            None,
+        )?;
+        let pool_shares = T::AssetManager::free_balance(
+            Asset::PoolShare(SerdeWrapper(pool_id)),
+            &caller,
+        );
+        let pool_amount = pool_shares / 2u8.saturated_into();
+    }: _(RawOrigin::Signed(caller), market_id, pool_amount, Zero::zero())
+
     impl_benchmark_test_suite!(
         PredictionMarket,
         crate::mock::ExtBuilder::default().build(),
@@ -54,21 +54,20 @@ mod pallet {
     use {orml_traits::asset_registry::Inspect, zeitgeist_primitives::types::CustomMetadata};
 
     use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
-    use sp_arithmetic::per_things::{Perbill, Percent};
+    use sp_arithmetic::per_things::{PerThing, Perbill, Percent, Perquintill};
     use sp_runtime::{
         traits::{CheckedDiv, Saturating, Zero},
         DispatchError, DispatchResult, SaturatedConversion,
     };
     use zeitgeist_primitives::{
-        constants::MILLISECS_PER_BLOCK,
+        constants::{BASE, MILLISECS_PER_BLOCK},
         traits::{DisputeApi, DisputeResolutionApi, Swaps, ZeitgeistAssetManager},
         types::{
             Asset, Bond, Deadlines, Market, MarketBonds, MarketCreation, MarketDispute,
             MarketDisputeMechanism, MarketPeriod, MarketStatus, MarketType, MultiHash,
-            OutcomeReport, Report, ScalarPosition, ScoringRule, SubsidyUntil,
+            OutcomeReport, PoolId, Report, ScalarPosition, ScoringRule, SerdeWrapper, SubsidyUntil,
         },
     };
-    #[cfg(feature = "with-global-disputes")]
     use zrml_global_disputes::GlobalDisputesPalletApi;
 
     use zrml_liquidity_mining::LiquidityMiningPalletApi;
@@ -441,6 +440,9 @@ mod pallet {
                     ScoringRule::CPMM => {
                         m.status = MarketStatus::Active;
                     }
+                    ScoringRule::LMSR { .. } => {
+                        return Err(Error::<T>::InvalidScoringRule.into());
+                    }
                     ScoringRule::RikiddoSigmoidFeeMarketEma => {
                         m.status = MarketStatus::CollectingSubsidy;
                         status = MarketStatus::CollectingSubsidy;
@@ -1001,128 +1003,12 @@ mod pallet {
             let sender = ensure_signed(origin)?;
 
             let market = <zrml_market_commons::Pallet<T>>::market(&market_id)?;
-            let market_account = <zrml_market_commons::Pallet<T>>::market_account(market_id);
-
             ensure!(market.status == MarketStatus::Resolved, Error::<T>::MarketIsNotResolved);
-
-            // Check to see if the sender has any winning shares.
             let resolved_outcome =
-                market.resolved_outcome.ok_or(Error::<T>::MarketIsNotResolved)?;
-
-            let winning_assets = match resolved_outcome {
-                OutcomeReport::Categorical(category_index) => {
-                    let winning_currency_id = Asset::CategoricalOutcome(market_id, category_index);
-                    let winning_balance =
-                        T::AssetManager::free_balance(winning_currency_id, &sender);
-
-                    ensure!(winning_balance > BalanceOf::<T>::zero(), Error::<T>::NoWinningBalance);
-
-                    // Ensure the market account has enough to pay out - if this is
-                    // ever not true then we have an accounting problem.
-                    ensure!(
-                        T::AssetManager::free_balance(market.base_asset, &market_account)
-                            >= winning_balance,
-                        Error::<T>::InsufficientFundsInMarketAccount,
-                    );
-
-                    vec![(winning_currency_id, winning_balance, winning_balance)]
-                }
-                OutcomeReport::Scalar(value) => {
-                    let long_currency_id = Asset::ScalarOutcome(market_id, ScalarPosition::Long);
-                    let short_currency_id = Asset::ScalarOutcome(market_id, ScalarPosition::Short);
-                    let long_balance = T::AssetManager::free_balance(long_currency_id, &sender);
-                    let short_balance = T::AssetManager::free_balance(short_currency_id, &sender);
-
-                    ensure!(
-                        long_balance > BalanceOf::<T>::zero()
-                            || short_balance > BalanceOf::<T>::zero(),
-                        Error::<T>::NoWinningBalance
-                    );
-
-                    let bound = if let MarketType::Scalar(range) = market.market_type {
-                        range
-                    } else {
-                        return Err(Error::<T>::InvalidMarketType.into());
-                    };
-
-                    let calc_payouts = |final_value: u128,
-                                        low: u128,
-                                        high: u128|
-                     -> (Perbill, Perbill) {
-                        if final_value <= low {
-                            return (Perbill::zero(), Perbill::one());
-                        }
-                        if final_value >= high {
-                            return (Perbill::one(), Perbill::zero());
-                        }
-
-                        let payout_long: Perbill = Perbill::from_rational(
-                            final_value.saturating_sub(low),
-                            high.saturating_sub(low),
-                        );
-                        let payout_short: Perbill = Perbill::from_parts(
-                            Perbill::one().deconstruct().saturating_sub(payout_long.deconstruct()),
-                        );
-                        (payout_long, payout_short)
-                    };
-
-                    let (long_percent, short_percent) =
-                        calc_payouts(value, *bound.start(), *bound.end());
-
-                    let long_payout = long_percent.mul_floor(long_balance);
-                    let short_payout = short_percent.mul_floor(short_balance);
-                    // Ensure the market account has enough to pay out - if this is
-                    // ever not true then we have an accounting problem.
-                    ensure!(
-                        T::AssetManager::free_balance(market.base_asset, &market_account)
-                            >= long_payout.saturating_add(short_payout),
-                        Error::<T>::InsufficientFundsInMarketAccount,
-                    );
-
-                    vec![
-                        (long_currency_id, long_payout, long_balance),
-                        (short_currency_id, short_payout, short_balance),
-                    ]
-                }
-            };
+                market.resolved_outcome.as_ref().ok_or(Error::<T>::MarketIsNotResolved)?;
 
-            for (currency_id, payout, balance) in winning_assets {
-                // Destroy the shares.
-                T::AssetManager::slash(currency_id, &sender, balance);
-
-                // Pay out the winner.
-                let remaining_bal =
-                    T::AssetManager::free_balance(market.base_asset, &market_account);
-                let actual_payout = payout.min(remaining_bal);
-
-                T::AssetManager::transfer(
-                    market.base_asset,
-                    &market_account,
-                    &sender,
-                    actual_payout,
-                )?;
-                // The if-check prevents scalar markets to emit events even if sender only owns one
-                // of the outcome tokens.
-                if balance != <BalanceOf<T>>::zero() {
-                    Self::deposit_event(Event::TokensRedeemed(
-                        market_id,
-                        currency_id,
-                        balance,
-                        actual_payout,
-                        sender.clone(),
-                    ));
-                }
-            }
-
-            // Weight correction
-            if let OutcomeReport::Categorical(_) = resolved_outcome {
-                return Ok(Some(T::WeightInfo::redeem_shares_categorical()).into());
-            } else if let OutcomeReport::Scalar(_) = resolved_outcome {
-                return Ok(Some(T::WeightInfo::redeem_shares_scalar()).into());
-            }
-
-            let default_weight: Option<Weight> = None;
-            Ok((default_weight, Pays::No).into())
+            let weight = Self::do_redeem_shares(sender, market_id, &market, resolved_outcome)?;
+            Ok(Some(weight).into())
         }
 
         /// Rejects a market that is waiting for approval from the advisory committee.
@@ -1279,40 +1165,7 @@ mod pallet {
             #[pallet::compact] amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
-            ensure!(amount != BalanceOf::<T>::zero(), Error::<T>::ZeroAmount);
-
-            let market = <zrml_market_commons::Pallet<T>>::market(&market_id)?;
-            ensure!(market.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
-            Self::ensure_market_is_active(&market)?;
-
-            let market_account = <zrml_market_commons::Pallet<T>>::market_account(market_id);
-            ensure!(
-                T::AssetManager::free_balance(market.base_asset, &market_account) >= amount,
-                "Market account does not have sufficient reserves.",
-            );
-
-            let assets = Self::outcome_assets(market_id, &market);
-
-            // verify first.
-            for asset in assets.iter() {
-                // Ensures that the sender has sufficient amount of each
-                // share in the set.
-                ensure!(
-                    T::AssetManager::free_balance(*asset, &sender) >= amount,
-                    Error::<T>::InsufficientShareBalance,
-                );
-            }
-
-            // write last.
-            for asset in assets.iter() {
-                T::AssetManager::slash(*asset, &sender, amount);
-            }
-
-            T::AssetManager::transfer(market.base_asset, &market_account, &sender, amount)?;
-
-            Self::deposit_event(Event::SoldCompleteSet(market_id, amount, sender));
-            let assets_len: u32 = assets.len().saturated_into();
-            Ok(Some(T::WeightInfo::sell_complete_set(assets_len)).into())
+            Self::do_sell_complete_set(sender, market_id, amount)
         }
 
         /// When the `MaxDisputes` amount of disputes is reached,
@@ -1330,77 +1183,243 @@ mod pallet {
         #[transactional]
         pub fn start_global_dispute(
             origin: OriginFor<T>,
-            #[allow(dead_code, unused)]
-            #[pallet::compact]
-            market_id: MarketIdOf<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
         ) -> DispatchResultWithPostInfo {
             ensure_signed(origin)?;
 
-            #[cfg(feature = "with-global-disputes")]
-            {
-                let market = <zrml_market_commons::Pallet<T>>::market(&market_id)?;
-                ensure!(market.status == MarketStatus::Disputed, Error::<T>::InvalidMarketStatus);
+            ensure!(T::GlobalDisputes::is_enabled(), Error::<T>::GlobalDisputesDisabled);
 
-                ensure!(
-                    market.dispute_mechanism == MarketDisputeMechanism::SimpleDisputes,
-                    Error::<T>::InvalidDisputeMechanism
-                );
-
-                let disputes = <Disputes<T>>::get(market_id);
-                ensure!(
-                    disputes.len() == T::MaxDisputes::get() as usize,
-                    Error::<T>::MaxDisputesNeeded
-                );
-
-                ensure!(
-                    T::GlobalDisputes::is_not_started(&market_id),
-                    Error::<T>::GlobalDisputeAlreadyStarted
-                );
+            let market = <zrml_market_commons::Pallet<T>>::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Disputed, Error::<T>::InvalidMarketStatus);
 
-                // add report outcome to voting choices
-                if let Some(report) = &market.report {
-                    T::GlobalDisputes::push_voting_outcome(
-                        &market_id,
-                        report.outcome.clone(),
-                        &report.by,
-                        <BalanceOf<T>>::zero(),
-                    )?;
+            let disputes = <Disputes<T>>::get(market_id);
+            match market.dispute_mechanism {
+                MarketDisputeMechanism::SimpleDisputes => {
+                    ensure!(
+                        disputes.len() == T::MaxDisputes::get() as usize,
+                        Error::<T>::MaxDisputesNeeded
+                    );
                 }
-
-                for (index, MarketDispute { at: _, by, outcome }) in disputes.iter().enumerate() {
-                    let dispute_bond = default_dispute_bond::<T>(index);
-                    T::GlobalDisputes::push_voting_outcome(
-                        &market_id,
-                        outcome.clone(),
-                        by,
-                        dispute_bond,
-                    )?;
+                // An authorized market escalates to a global dispute if its report was
+                // appealed or if the authority failed to report in time.
+                MarketDisputeMechanism::Authorized => {
+                    ensure!(
+                        T::Authorized::has_failed(&disputes, &market_id, &market)?,
+                        Error::<T>::DisputeMechanismHasNotFailed
+                    );
+                }
+                MarketDisputeMechanism::Court => {
+                    return Err(Error::<T>::InvalidDisputeMechanism.into());
                 }
+            }
 
-                // TODO(#372): Allow court with global disputes.
-                // ensure, that global disputes controls the resolution now
-                // it does not end after the dispute period now, but after the global dispute end
+            ensure!(
+                T::GlobalDisputes::is_not_started(&market_id),
+                Error::<T>::GlobalDisputeAlreadyStarted
+            );
 
-                // ignore first of tuple because we always have max disputes
-                let (_, ids_len_2) = Self::clear_auto_resolve(&market_id)?;
+            // add report outcome to voting choices
+            if let Some(report) = &market.report {
+                T::GlobalDisputes::push_voting_outcome(
+                    &market_id,
+                    report.outcome.clone(),
+                    &report.by,
+                    <BalanceOf<T>>::zero(),
+                )?;
+            }
 
-                let now = <frame_system::Pallet<T>>::block_number();
-                let global_dispute_end = now.saturating_add(T::GlobalDisputePeriod::get());
-                let market_ids_len = <MarketIdsPerDisputeBlock<T>>::try_mutate(
-                    global_dispute_end,
-                    |ids| -> Result<u32, DispatchError> {
-                        ids.try_push(market_id).map_err(|_| <Error<T>>::StorageOverflow)?;
-                        Ok(ids.len() as u32)
-                    },
+            for (index, MarketDispute { at: _, by, outcome }) in disputes.iter().enumerate() {
+                let dispute_bond = default_dispute_bond::<T>(index);
+                T::GlobalDisputes::push_voting_outcome(
+                    &market_id,
+                    outcome.clone(),
+                    by,
+                    dispute_bond,
                 )?;
+            }
+
+            // TODO(#372): Allow court with global disputes.
+            // ensure, that global disputes controls the resolution now
+            // it does not end after the dispute period now, but after the global dispute end
+
+            // ignore first of tuple because the number of disputes is bounded
+            let (_, ids_len_2) = Self::clear_auto_resolve(&market_id)?;
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let global_dispute_end = now.saturating_add(T::GlobalDisputePeriod::get());
+            let market_ids_len = <MarketIdsPerDisputeBlock<T>>::try_mutate(
+                global_dispute_end,
+                |ids| -> Result<u32, DispatchError> {
+                    ids.try_push(market_id).map_err(|_| <Error<T>>::StorageOverflow)?;
+                    Ok(ids.len() as u32)
+                },
+            )?;
+
+            Self::deposit_event(Event::GlobalDisputeStarted(market_id));
+
+            Ok(Some(T::WeightInfo::start_global_dispute(market_ids_len, ids_len_2)).into())
+        }
+
+        /// Provides liquidity to the pool of a market using only the market's base asset.
+        ///
+        /// A part of `amount` is used to buy complete sets. The outcome tokens and the rest of
+        /// the base asset are then deposited into the pool in proportion to its balances.
+        /// Anything that can't be deposited remains in the wallet of the sender.
+        ///
+        /// # Arguments
+        ///
+        /// * `market_id`: The identifier of the market whose pool is joined.
+        /// * `amount`: The maximum amount of the base asset that is spent.
+        /// * `min_pool_amount`: The minimum amount of pool shares the sender must receive.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of assets in the pool.
+        #[pallet::weight(
+            T::WeightInfo::join_pool_with_base_asset(T::MaxCategories::get().saturating_add(1).into())
+        )]
+        #[transactional]
+        pub fn join_pool_with_base_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            #[pallet::compact] min_pool_amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            ensure!(amount != BalanceOf::<T>::zero(), Error::<T>::ZeroAmount);
 
-                Self::deposit_event(Event::GlobalDisputeStarted(market_id));
+            let market = <zrml_market_commons::Pallet<T>>::market(&market_id)?;
+            ensure!(market.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+            Self::ensure_market_is_active(&market)?;
+
+            let pool_id = <zrml_market_commons::Pallet<T>>::market_pool(&market_id)?;
+            let balances = T::Swaps::pool_balances(pool_id)?;
+            let (pool_amount, complete_sets) =
+                Self::calculate_pool_join_with_base_asset(pool_id, &market, &balances, amount)?;
+            ensure!(
+                pool_amount != BalanceOf::<T>::zero() && pool_amount >= min_pool_amount,
+                Error::<T>::PoolSharesBelowLimit
+            );
+
+            let base_asset_before = T::AssetManager::free_balance(market.base_asset, &sender);
+            Self::do_buy_complete_set(sender.clone(), market_id, complete_sets)?;
+            let max_assets_in = balances
+                .iter()
+                .map(|(asset, _)| {
+                    if *asset == market.base_asset {
+                        amount.saturating_sub(complete_sets)
+                    } else {
+                        complete_sets
+                    }
+                })
+                .collect();
+            T::Swaps::pool_join(sender.clone(), pool_id, pool_amount, max_assets_in)?;
+            let amount_in = base_asset_before
+                .saturating_sub(T::AssetManager::free_balance(market.base_asset, &sender));
 
-                Ok(Some(T::WeightInfo::start_global_dispute(market_ids_len, ids_len_2)).into())
+            Self::deposit_event(Event::JoinedPoolWithBaseAsset(
+                market_id,
+                amount_in,
+                pool_amount,
+                sender,
+            ));
+            let assets_len: u32 = balances.len().saturated_into();
+            Ok(Some(T::WeightInfo::join_pool_with_base_asset(assets_len)).into())
+        }
+
+        /// Withdraws liquidity from the pool of a market and converts it into the market's base
+        /// asset.
+        ///
+        /// The outcome tokens received from the pool are sold as complete sets. The leftover
+        /// outcome tokens are then sold to the pool. Leftovers which can't be sold, for example
+        /// because the trade would drain the pool, remain in the wallet of the sender.
+        ///
+        /// # Arguments
+        ///
+        /// * `market_id`: The identifier of the market whose pool is exited.
+        /// * `pool_amount`: The amount of pool shares that are burned.
+        /// * `min_amount_out`: The minimum amount of the base asset the sender must receive.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of assets in the pool.
+        #[pallet::weight(
+            T::WeightInfo::exit_pool_to_base_asset(T::MaxCategories::get().saturating_add(1).into())
+        )]
+        #[transactional]
+        pub fn exit_pool_to_base_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            #[pallet::compact] pool_amount: BalanceOf<T>,
+            #[pallet::compact] min_amount_out: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            ensure!(pool_amount != BalanceOf::<T>::zero(), Error::<T>::ZeroAmount);
+
+            let market = <zrml_market_commons::Pallet<T>>::market(&market_id)?;
+            ensure!(market.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+            Self::ensure_market_is_active(&market)?;
+
+            let pool_id = <zrml_market_commons::Pallet<T>>::market_pool(&market_id)?;
+            let pool = T::Swaps::pool(pool_id)?;
+            let base_asset_before = T::AssetManager::free_balance(market.base_asset, &sender);
+            let outcomes_before: Vec<_> = pool
+                .assets
+                .iter()
+                .filter(|asset| **asset != market.base_asset)
+                .map(|asset| (*asset, T::AssetManager::free_balance(*asset, &sender)))
+                .collect();
+
+            let min_assets_out = vec![BalanceOf::<T>::zero(); pool.assets.len()];
+            T::Swaps::pool_exit(sender.clone(), pool_id, pool_amount, min_assets_out)?;
+
+            let outcomes_received: Vec<_> = outcomes_before
+                .into_iter()
+                .map(|(asset, before)| {
+                    (asset, T::AssetManager::free_balance(asset, &sender).saturating_sub(before))
+                })
+                .collect();
+            let complete_sets = outcomes_received
+                .iter()
+                .map(|(_, amount)| *amount)
+                .min()
+                .unwrap_or_else(BalanceOf::<T>::zero);
+            if complete_sets != BalanceOf::<T>::zero() {
+                Self::do_sell_complete_set(sender.clone(), market_id, complete_sets)?;
+            }
+            for (asset, amount) in outcomes_received {
+                let leftover = amount.saturating_sub(complete_sets);
+                if leftover == BalanceOf::<T>::zero() {
+                    continue;
+                }
+                let _ = with_transaction(|| {
+                    match T::Swaps::swap_exact_amount_in(
+                        sender.clone(),
+                        pool_id,
+                        asset,
+                        leftover,
+                        market.base_asset,
+                        Some(BalanceOf::<T>::zero()),
+                        None,
+                    ) {
+                        Ok(weight) => TransactionOutcome::Commit(Ok(weight)),
+                        Err(err) => TransactionOutcome::Rollback(Err(err)),
+                    }
+                });
             }
 
-            #[cfg(not(feature = "with-global-disputes"))]
-            Err(Error::<T>::GlobalDisputesDisabled.into())
+            let amount_out = T::AssetManager::free_balance(market.base_asset, &sender)
+                .saturating_sub(base_asset_before);
+            ensure!(amount_out >= min_amount_out, Error::<T>::BaseAssetBelowLimit);
+
+            Self::deposit_event(Event::ExitedPoolToBaseAsset(
+                market_id,
+                pool_amount,
+                amount_out,
+                sender,
+            ));
+            let assets_len: u32 = pool.assets.len().saturated_into();
+            Ok(Some(T::WeightInfo::exit_pool_to_base_asset(assets_len)).into())
         }
     }
 
@@ -1472,7 +1491,6 @@ mod pallet {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
         /// See [`GlobalDisputesPalletApi`].
-        #[cfg(feature = "with-global-disputes")]
         type GlobalDisputes: GlobalDisputesPalletApi<
             MarketIdOf<Self>,
             Self::AccountId,
@@ -1480,7 +1498,6 @@ mod pallet {
         >;
 
         /// The number of blocks the global dispute period remains open.
-        #[cfg(feature = "with-global-disputes")]
         type GlobalDisputePeriod: Get<Self::BlockNumber>;
 
         type LiquidityMining: LiquidityMiningPalletApi<
@@ -1678,6 +1695,8 @@ mod pallet {
         OracleDurationSmallerThanMinOracleDuration,
         /// Specified dispute_duration is greater than MaxDisputeDuration.
         DisputeDurationGreaterThanMaxDisputeDuration,
+        /// The dispute mechanism of the market is still able to come to a conclusion.
+        DisputeMechanismHasNotFailed,
         /// Specified grace_period is greater than MaxGracePeriod.
         GracePeriodGreaterThanMaxGracePeriod,
         /// Specified oracle_duration is greater than MaxOracleDuration.
@@ -1690,6 +1709,10 @@ mod pallet {
         InvalidBaseAsset,
         /// A foreign asset in not registered in AssetRegistry.
         UnregisteredForeignAsset,
+        /// The amount of pool shares received is below the specified limit.
+        PoolSharesBelowLimit,
+        /// The amount of the base asset received is below the specified limit.
+        BaseAssetBelowLimit,
     }
 
     #[pallet::event]
@@ -1742,6 +1765,22 @@ mod pallet {
         ),
         /// The global dispute was started. \[market_id\]
         GlobalDisputeStarted(MarketIdOf<T>),
+        /// Liquidity was provided using only the base asset.
+        /// \[market_id, amount_in, pool_amount, who\]
+        JoinedPoolWithBaseAsset(
+            MarketIdOf<T>,
+            BalanceOf<T>,
+            BalanceOf<T>,
+            <T as frame_system::Config>::AccountId,
+        ),
+        /// Liquidity was withdrawn and converted into the base asset.
+        /// \[market_id, pool_amount, amount_out, who\]
+        ExitedPoolToBaseAsset(
+            MarketIdOf<T>,
+            BalanceOf<T>,
+            BalanceOf<T>,
+            <T as frame_system::Config>::AccountId,
+        ),
     }
 
     #[pallet::hooks]
@@ -2103,6 +2142,72 @@ mod pallet {
             Ok((ids_len, disputes_len))
         }
 
+        /// Calculates the amount of pool shares that can be minted for `amount` units of the base
+        /// asset and the amount of complete sets that must be bought for that purpose.
+        ///
+        /// The amount of pool shares is reduced by a margin which bounds the rounding of the
+        /// pool's fixed point math, so that the total never exceeds `amount`. The amount of
+        /// complete sets is then the exact amount `pool_join` requires of the outcome with the
+        /// largest balance in the pool.
+        fn calculate_pool_join_with_base_asset(
+            pool_id: PoolId,
+            market: &MarketOf<T>,
+            balances: &[(Asset<MarketIdOf<T>>, BalanceOf<T>)],
+            amount: BalanceOf<T>,
+        ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+            let total_issuance: u128 =
+                T::AssetManager::total_issuance(Asset::PoolShare(SerdeWrapper(pool_id)))
+                    .saturated_into();
+            let mut base_balance = 0u128;
+            let mut max_outcome_balance = 0u128;
+            for (asset, balance) in balances.iter() {
+                let balance: u128 = (*balance).saturated_into();
+                if *asset == market.base_asset {
+                    base_balance = balance;
+                } else {
+                    max_outcome_balance = max_outcome_balance.max(balance);
+                }
+            }
+            let total_balance = base_balance.saturating_add(max_outcome_balance);
+            ensure!(
+                total_issuance != 0 && max_outcome_balance != 0,
+                Error::<T>::PoolSharesBelowLimit
+            );
+
+            // Let `S` be the total issuance, `T` the total balance and `p` the pool amount. The
+            // pool calculates the ratio `r = bdiv(p, S) <= p * BASE / S + 1/2` and transfers
+            // `bmul(r, B) <= r * B / BASE + 1/2 <= p * B / S + B / (2 * BASE) + 1/2` of an asset
+            // with balance `B`. The base asset and the complete sets therefore cost at most
+            // `p * T / S + T / (2 * BASE) + 1`. The estimate `amount * S / T` of the pool amount
+            // is rounded up by at most `S / ACCURACY`, so reducing it by
+            // `S / ACCURACY + S / (2 * BASE) + S / T` keeps the cost below `amount`. The
+            // additional 3 units account for the integer divisions of the margin.
+            let margin = (total_issuance / u128::from(Perquintill::ACCURACY))
+                .saturating_add(total_issuance / BASE.saturating_mul(2))
+                .saturating_add(total_issuance / total_balance)
+                .saturating_add(3);
+            let pool_amount: BalanceOf<T> =
+                Perquintill::from_rational(amount.saturated_into::<u128>(), total_balance)
+                    .mul_floor(total_issuance)
+                    .saturating_sub(margin)
+                    .saturated_into();
+            if pool_amount.is_zero() {
+                return Ok((Zero::zero(), Zero::zero()));
+            }
+
+            let mut complete_sets = BalanceOf::<T>::zero();
+            for (asset, amount_in) in T::Swaps::pool_join_amounts(pool_id, pool_amount)? {
+                // `pool_join` fails if it would transfer nothing.
+                if amount_in.is_zero() {
+                    return Ok((Zero::zero(), Zero::zero()));
+                }
+                if asset != market.base_asset {
+                    complete_sets = complete_sets.max(amount_in);
+                }
+            }
+            Ok((pool_amount, complete_sets))
+        }
+
         pub(crate) fn do_buy_complete_set(
             who: T::AccountId,
             market_id: MarketIdOf<T>,
@@ -2131,6 +2236,163 @@ mod pallet {
             Ok(Some(T::WeightInfo::buy_complete_set(assets_len)).into())
         }
 
+        pub(crate) fn do_sell_complete_set(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(amount != BalanceOf::<T>::zero(), Error::<T>::ZeroAmount);
+
+            let market = <zrml_market_commons::Pallet<T>>::market(&market_id)?;
+            ensure!(market.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+            Self::ensure_market_is_active(&market)?;
+
+            let market_account = <zrml_market_commons::Pallet<T>>::market_account(market_id);
+            ensure!(
+                T::AssetManager::free_balance(market.base_asset, &market_account) >= amount,
+                "Market account does not have sufficient reserves.",
+            );
+
+            let assets = Self::outcome_assets(market_id, &market);
+
+            // verify first.
+            for asset in assets.iter() {
+                // Ensures that the seller has sufficient amount of each
+                // share in the set.
+                ensure!(
+                    T::AssetManager::free_balance(*asset, &who) >= amount,
+                    Error::<T>::InsufficientShareBalance,
+                );
+            }
+
+            // write last.
+            for asset in assets.iter() {
+                T::AssetManager::slash(*asset, &who, amount);
+            }
+
+            T::AssetManager::transfer(market.base_asset, &market_account, &who, amount)?;
+
+            Self::deposit_event(Event::SoldCompleteSet(market_id, amount, who));
+            let assets_len: u32 = assets.len().saturated_into();
+            Ok(Some(T::WeightInfo::sell_complete_set(assets_len)).into())
+        }
+
+        /// Redeems the winning shares of `who` in the market `market_id`, which resolved to
+        /// `resolved_outcome`.
+        pub(crate) fn do_redeem_shares(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            market: &MarketOf<T>,
+            resolved_outcome: &OutcomeReport,
+        ) -> Result<Weight, DispatchError> {
+            let market_account = <zrml_market_commons::Pallet<T>>::market_account(market_id);
+
+            // Check to see if `who` has any winning shares.
+            let winning_assets = match *resolved_outcome {
+                OutcomeReport::Categorical(category_index) => {
+                    let winning_currency_id = Asset::CategoricalOutcome(market_id, category_index);
+                    let winning_balance = T::AssetManager::free_balance(winning_currency_id, &who);
+
+                    ensure!(winning_balance > BalanceOf::<T>::zero(), Error::<T>::NoWinningBalance);
+
+                    // Ensure the market account has enough to pay out - if this is
+                    // ever not true then we have an accounting problem.
+                    ensure!(
+                        T::AssetManager::free_balance(market.base_asset, &market_account)
+                            >= winning_balance,
+                        Error::<T>::InsufficientFundsInMarketAccount,
+                    );
+
+                    vec![(winning_currency_id, winning_balance, winning_balance)]
+                }
+                OutcomeReport::Scalar(value) => {
+                    let long_currency_id = Asset::ScalarOutcome(market_id, ScalarPosition::Long);
+                    let short_currency_id = Asset::ScalarOutcome(market_id, ScalarPosition::Short);
+                    let long_balance = T::AssetManager::free_balance(long_currency_id, &who);
+                    let short_balance = T::AssetManager::free_balance(short_currency_id, &who);
+
+                    ensure!(
+                        long_balance > BalanceOf::<T>::zero()
+                            || short_balance > BalanceOf::<T>::zero(),
+                        Error::<T>::NoWinningBalance
+                    );
+
+                    let bound = if let MarketType::Scalar(range) = &market.market_type {
+                        range
+                    } else {
+                        return Err(Error::<T>::InvalidMarketType.into());
+                    };
+
+                    let calc_payouts = |final_value: u128,
+                                        low: u128,
+                                        high: u128|
+                     -> (Perbill, Perbill) {
+                        if final_value <= low {
+                            return (Perbill::zero(), Perbill::one());
+                        }
+                        if final_value >= high {
+                            return (Perbill::one(), Perbill::zero());
+                        }
+
+                        let payout_long: Perbill = Perbill::from_rational(
+                            final_value.saturating_sub(low),
+                            high.saturating_sub(low),
+                        );
+                        let payout_short: Perbill = Perbill::from_parts(
+                            Perbill::one().deconstruct().saturating_sub(payout_long.deconstruct()),
+                        );
+                        (payout_long, payout_short)
+                    };
+
+                    let (long_percent, short_percent) =
+                        calc_payouts(value, *bound.start(), *bound.end());
+
+                    let long_payout = long_percent.mul_floor(long_balance);
+                    let short_payout = short_percent.mul_floor(short_balance);
+                    // Ensure the market account has enough to pay out - if this is
+                    // ever not true then we have an accounting problem.
+                    ensure!(
+                        T::AssetManager::free_balance(market.base_asset, &market_account)
+                            >= long_payout.saturating_add(short_payout),
+                        Error::<T>::InsufficientFundsInMarketAccount,
+                    );
+
+                    vec![
+                        (long_currency_id, long_payout, long_balance),
+                        (short_currency_id, short_payout, short_balance),
+                    ]
+                }
+            };
+
+            for (currency_id, payout, balance) in winning_assets {
+                // Destroy the shares.
+                T::AssetManager::slash(currency_id, &who, balance);
+
+                // Pay out the winner.
+                let remaining_bal =
+                    T::AssetManager::free_balance(market.base_asset, &market_account);
+                let actual_payout = payout.min(remaining_bal);
+
+                T::AssetManager::transfer(market.base_asset, &market_account, &who, actual_payout)?;
+                // The if-check prevents scalar markets to emit events even if `who` only owns one
+                // of the outcome tokens.
+                if balance != <BalanceOf<T>>::zero() {
+                    Self::deposit_event(Event::TokensRedeemed(
+                        market_id,
+                        currency_id,
+                        balance,
+                        actual_payout,
+                        who.clone(),
+                    ));
+                }
+            }
+
+            match resolved_outcome {
+                OutcomeReport::Categorical(_) => Ok(T::WeightInfo::redeem_shares_categorical()),
+                OutcomeReport::Scalar(_) => Ok(T::WeightInfo::redeem_shares_scalar()),
+            }
+        }
+
         pub(crate) fn do_reject_market(
             market_id: &MarketIdOf<T>,
             market: MarketOf<T>,
@@ -2392,7 +2654,6 @@ mod pallet {
 
             let mut resolved_outcome_option = None;
 
-            #[cfg(feature = "with-global-disputes")]
             if let Some(o) = T::GlobalDisputes::determine_voting_winner(market_id) {
                 resolved_outcome_option = Some(o);
             }
@@ -2418,6 +2679,10 @@ mod pallet {
             let resolved_outcome =
                 resolved_outcome_option.unwrap_or_else(|| report.outcome.clone());
 
+            if market.dispute_mechanism == MarketDisputeMechanism::Authorized {
+                T::Authorized::on_resolved(market_id, &resolved_outcome)?;
+            }
+
             let mut correct_reporters: Vec<T::AccountId> = Vec::new();
 
             // If the oracle reported right, return the OracleBond, otherwise slash it to
@@ -2487,7 +2752,7 @@ mod pallet {
             total_weight = total_weight.saturating_add(clean_up_weight);
             // TODO: https://github.com/zeitgeistpm/zeitgeist/issues/815
             // Following call should return weight consumed by it.
-            T::LiquidityMining::distribute_market_incentives(market_id)?;
+            T::LiquidityMining::close_market_incentives(market_id)?;
 
             // NOTE: Currently we don't clean up outcome assets.
             // TODO(#792): Remove outcome assets for accounts! Delete "resolved" assets of `orml_tokens` with storage migration.
@@ -2773,13 +3038,27 @@ mod pallet {
                 return Ok(T::DbWeight::get().reads(1));
             };
             let market_account = <zrml_market_commons::Pallet<T>>::market_account(*market_id);
+            // Redeem the winning outcome tokens held by the pool, so that liquidity providers
+            // receive the base asset when exiting the pool.
+            let redeem_weight = if market.scoring_rule == ScoringRule::CPMM {
+                let pool_account = T::Swaps::pool_account_id(&pool_id);
+                match Self::do_redeem_shares(pool_account, *market_id, market, outcome_report) {
+                    Ok(weight) => weight,
+                    Err(err) if err == Error::<T>::NoWinningBalance.into() => {
+                        T::DbWeight::get().reads(2)
+                    }
+                    Err(err) => return Err(err),
+                }
+            } else {
+                Weight::zero()
+            };
             let weight = T::Swaps::clean_up_pool(
                 &market.market_type,
                 pool_id,
                 outcome_report,
                 &market_account,
             )?;
-            Ok(weight.saturating_add(T::DbWeight::get().reads(2)))
+            Ok(weight.saturating_add(redeem_weight).saturating_add(T::DbWeight::get().reads(2)))
         }
 
         // Creates a pool for the market and registers the market in the list of markets
@@ -2867,6 +3146,11 @@ mod pallet {
             Self::ensure_market_period_is_valid(&period)?;
             Self::ensure_market_deadlines_are_valid(&deadlines)?;
             Self::ensure_market_type_is_valid(&market_type)?;
+            // Only the pools of `zrml-swaps` support LMSR, markets can't be created with it yet.
+            ensure!(
+                !matches!(scoring_rule, ScoringRule::LMSR { .. }),
+                Error::<T>::InvalidScoringRule
+            );
 
             if scoring_rule == ScoringRule::RikiddoSigmoidFeeMarketEma {
                 Self::ensure_market_start_is_in_time(&period)?;
@@ -2874,6 +3158,7 @@ mod pallet {
             let status: MarketStatus = match creation {
                 MarketCreation::Permissionless => match scoring_rule {
                     ScoringRule::CPMM => MarketStatus::Active,
+                    ScoringRule::LMSR { .. } => return Err(Error::<T>::InvalidScoringRule.into()),
                     ScoringRule::RikiddoSigmoidFeeMarketEma => MarketStatus::CollectingSubsidy,
                 },
                 MarketCreation::Advised => MarketStatus::Proposed,
@@ -442,8 +442,9 @@ use alloc::string::ToString;
 use frame_support::{migration::storage_key_iter, Twox64Concat};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::traits::Saturating;
-use zeitgeist_primitives::types::AuthorityReport;
-use zrml_authorized::Pallet as AuthorizedPallet;
+use zrml_authorized::{
+    migrations::OldAuthorityReport as AuthorityReport, Pallet as AuthorizedPallet,
+};
 
 const AUTHORIZED: &[u8] = b"Authorized";
 const AUTHORIZED_OUTCOME_REPORTS: &[u8] = b"AuthorizedOutcomeReports";
@@ -24,7 +24,7 @@
 use crate as prediction_markets;
 use frame_support::{
     construct_runtime, ord_parameter_types, parameter_types,
-    traits::{Everything, NeverEnsureOrigin, OnFinalize, OnInitialize},
+    traits::{Everything, NeverEnsureOrigin, OnFinalize, OnInitialize, Randomness},
 };
 use frame_system::EnsureSignedBy;
 #[cfg(feature = "parachain")]
@@ -37,15 +37,23 @@ use sp_runtime::{
 use substrate_fixed::{types::extra::U33, FixedI128, FixedU128};
 use zeitgeist_primitives::{
     constants::mock::{
-        AuthorizedPalletId, BalanceFractionalDecimals, BlockHashCount, CorrectionPeriod,
-        CourtCaseDuration, CourtPalletId, DisputeFactor, ExistentialDeposit, ExistentialDeposits,
-        ExitFee, GetNativeCurrencyId, LiquidityMiningPalletId, MaxApprovals, MaxAssets,
-        MaxCategories, MaxDisputeDuration, MaxDisputes, MaxEditReasonLen, MaxGracePeriod,
-        MaxInRatio, MaxMarketLifetime, MaxOracleDuration, MaxOutRatio, MaxRejectReasonLen,
-        MaxReserves, MaxSubsidyPeriod, MaxSwapFee, MaxTotalWeight, MaxWeight, MinAssets,
-        MinCategories, MinDisputeDuration, MinLiquidity, MinOracleDuration, MinSubsidy,
-        MinSubsidyPeriod, MinWeight, MinimumPeriod, PmPalletId, SimpleDisputesPalletId,
-        StakeWeight, SwapsPalletId, TreasuryPalletId, BASE, CENT, MILLISECS_PER_BLOCK,
+        ArbitrageBountyPalletId, ArbitrageKeeperShare, AuthorityAppealBond, AuthorityAppealPeriod,
+        AuthorityReportPeriod, AuthorizedPalletId, BalanceFractionalDecimals, BlockHashCount,
+        CorrectionPeriod, CourtAppealBond, CourtAppealDuration, CourtCaseDuration, CourtMaxAppeals,
+        CourtMaxCasesPerJuror, CourtMaxDelegators, CourtMaxJurors, CourtPalletId,
+        CourtRevealDuration, CreatorSwapFeeShare, DisputeFactor, ExistentialDeposit,
+        ExistentialDeposits, ExitFee, GetNativeCurrencyId, GlobalDisputeConvictionLockPeriod,
+        GlobalDisputeLockId, GlobalDisputePeriod, GlobalDisputesPalletId,
+        IncentiveProgramClaimPeriod, IncentiveProgramDeposit, JurorUnbondingPeriod,
+        LiquidityMiningPalletId, MaxApprovals, MaxAssets, MaxCategories, MaxDisputeDuration,
+        MaxDisputes, MaxEditReasonLen, MaxGlobalDisputeVotes, MaxGracePeriod, MaxInRatio,
+        MaxIncentivePrograms, MaxMarketLifetime, MaxOracleDuration, MaxOutRatio, MaxOwners,
+        MaxRejectReasonLen, MaxReserves, MaxSubsidyPeriod, MaxSwapFee, MaxTotalWeight, MaxWeight,
+        MinAssets, MinCategories, MinDisputeDuration, MinJurorStake, MinLiquidity,
+        MinOracleDuration, MinOutcomeVoteAmount, MinSubsidy, MinSubsidyPeriod, MinWeight,
+        MinimumPeriod, PmPalletId, ProtocolSwapFeeShare, RemoveKeysLimit, SimpleDisputesPalletId,
+        SwapsPalletId, TreasuryPalletId, VoterRewardShare, VotingOutcomeFee, BASE, CENT,
+        MILLISECS_PER_BLOCK,
     },
     types::{
         AccountIdTest, Amount, Asset, Balance, BasicCurrencyAdapter, BlockNumber, BlockTest,
@@ -53,11 +61,7 @@ use zeitgeist_primitives::{
     },
 };
 
-#[cfg(feature = "with-global-disputes")]
-use zeitgeist_primitives::constants::mock::{
-    GlobalDisputeLockId, GlobalDisputePeriod, GlobalDisputesPalletId, MaxGlobalDisputeVotes,
-    MaxOwners, MinOutcomeVoteAmount, RemoveKeysLimit, VotingOutcomeFee,
-};
+use zrml_global_disputes::types::TallyMode;
 
 use zrml_rikiddo::types::{EmaMarketVolume, FeeSigmoid, RikiddoSigmoidMV};
 
@@ -83,7 +87,6 @@ parameter_types! {
     pub const DisputeBond: Balance = 109 * CENT;
 }
 
-#[cfg(feature = "with-global-disputes")]
 construct_runtime!(
     pub enum Runtime
     where
@@ -110,31 +113,15 @@ construct_runtime!(
     }
 );
 
-#[cfg(not(feature = "with-global-disputes"))]
-construct_runtime!(
-    pub enum Runtime
-    where
-        Block = BlockTest<Runtime>,
-        NodeBlock = BlockTest<Runtime>,
-        UncheckedExtrinsic = UncheckedExtrinsicTest<Runtime>,
-    {
-        Authorized: zrml_authorized::{Event<T>, Pallet, Storage},
-        Balances: pallet_balances::{Call, Config<T>, Event<T>, Pallet, Storage},
-        Court: zrml_court::{Event<T>, Pallet, Storage},
-        AssetManager: orml_currencies::{Call, Pallet, Storage},
-        LiquidityMining: zrml_liquidity_mining::{Config<T>, Event<T>, Pallet},
-        MarketCommons: zrml_market_commons::{Pallet, Storage},
-        PredictionMarkets: prediction_markets::{Event<T>, Pallet, Storage},
-        RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage},
-        RikiddoSigmoidFeeMarketEma: zrml_rikiddo::{Pallet, Storage},
-        SimpleDisputes: zrml_simple_disputes::{Event<T>, Pallet, Storage},
-        Swaps: zrml_swaps::{Call, Event<T>, Pallet},
-        System: frame_system::{Config, Event<T>, Pallet, Storage},
-        Timestamp: pallet_timestamp::{Pallet},
-        Tokens: orml_tokens::{Config<T>, Event<T>, Pallet, Storage},
-        Treasury: pallet_treasury::{Call, Event<T>, Pallet, Storage},
+// Court randomness based on `RandomnessCollectiveFlip`, which is always available.
+pub struct MockRandomness;
+
+impl Randomness<Option<Hash>, BlockNumber> for MockRandomness {
+    fn random(subject: &[u8]) -> (Option<Hash>, BlockNumber) {
+        let (random_hash, block_number) = RandomnessCollectiveFlip::random(subject);
+        (Some(random_hash), block_number)
     }
-);
+}
 
 impl crate::Config for Runtime {
     type AdvisoryBond = AdvisoryBond;
@@ -149,9 +136,7 @@ impl crate::Config for Runtime {
     type DisputeBond = DisputeBond;
     type DisputeFactor = DisputeFactor;
     type Event = Event;
-    #[cfg(feature = "with-global-disputes")]
     type GlobalDisputes = GlobalDisputes;
-    #[cfg(feature = "with-global-disputes")]
     type GlobalDisputePeriod = GlobalDisputePeriod;
     type LiquidityMining = LiquidityMining;
     type MaxCategories = MaxCategories;
@@ -264,32 +249,49 @@ ord_parameter_types! {
 }
 
 impl zrml_authorized::Config for Runtime {
+    type AppealBond = AuthorityAppealBond;
+    type AppealPeriod = AuthorityAppealPeriod;
     type AuthorizedDisputeResolutionOrigin =
         EnsureSignedBy<AuthorizedDisputeResolutionUser, AccountIdTest>;
+    type CollectiveAuthorityOrigin = NeverEnsureOrigin<u8>;
     type CorrectionPeriod = CorrectionPeriod;
     type Event = Event;
     type DisputeResolution = prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
     type PalletId = AuthorizedPalletId;
+    type ReportPeriod = AuthorityReportPeriod;
+    type Slash = Treasury;
     type WeightInfo = zrml_authorized::weights::WeightInfo<Runtime>;
 }
 
 impl zrml_court::Config for Runtime {
+    type AppealBond = CourtAppealBond;
+    type CourtAppealDuration = CourtAppealDuration;
     type CourtCaseDuration = CourtCaseDuration;
+    type CourtRevealDuration = CourtRevealDuration;
     type DisputeResolution = prediction_markets::Pallet<Runtime>;
     type Event = Event;
+    type JurorUnbondingPeriod = JurorUnbondingPeriod;
     type MarketCommons = MarketCommons;
+    type MaxAppeals = CourtMaxAppeals;
+    type MaxCasesPerJuror = CourtMaxCasesPerJuror;
+    type MaxDelegators = CourtMaxDelegators;
+    type MaxJurors = CourtMaxJurors;
+    type MinJurorStake = MinJurorStake;
     type PalletId = CourtPalletId;
-    type Random = RandomnessCollectiveFlip;
-    type StakeWeight = StakeWeight;
+    type Random = MockRandomness;
     type TreasuryPalletId = TreasuryPalletId;
     type WeightInfo = zrml_court::weights::WeightInfo<Runtime>;
 }
 
 impl zrml_liquidity_mining::Config for Runtime {
+    type AssetManager = AssetManager;
     type Event = Event;
+    type IncentiveProgramClaimPeriod = IncentiveProgramClaimPeriod;
+    type IncentiveProgramDeposit = IncentiveProgramDeposit;
     type MarketCommons = MarketCommons;
     type MarketId = MarketId;
+    type MaxIncentivePrograms = MaxIncentivePrograms;
     type PalletId = LiquidityMiningPalletId;
     type WeightInfo = zrml_liquidity_mining::weights::WeightInfo<Runtime>;
 }
@@ -323,10 +325,14 @@ impl zrml_simple_disputes::Config for Runtime {
     type PalletId = SimpleDisputesPalletId;
 }
 
-#[cfg(feature = "with-global-disputes")]
+parameter_types! {
+    pub const GlobalDisputeTallyMode: TallyMode = TallyMode::Conviction;
+}
+
 impl zrml_global_disputes::Config for Runtime {
     type Event = Event;
     type MarketCommons = MarketCommons;
+    type ConvictionLockPeriod = GlobalDisputeConvictionLockPeriod;
     type Currency = Balances;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
@@ -334,11 +340,17 @@ impl zrml_global_disputes::Config for Runtime {
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
+    type SetEnabledOrigin = EnsureSignedBy<Sudo, AccountIdTest>;
+    type TallyMode = GlobalDisputeTallyMode;
+    type VoterRewardShare = VoterRewardShare;
     type VotingOutcomeFee = VotingOutcomeFee;
     type WeightInfo = zrml_global_disputes::weights::WeightInfo<Runtime>;
 }
 
 impl zrml_swaps::Config for Runtime {
+    type ArbitrageBountyPalletId = ArbitrageBountyPalletId;
+    type ArbitrageKeeperShare = ArbitrageKeeperShare;
+    type CreatorSwapFeeShare = CreatorSwapFeeShare;
     type Event = Event;
     type ExitFee = ExitFee;
     type FixedTypeU = <Runtime as zrml_rikiddo::Config>::FixedTypeU;
@@ -357,7 +369,9 @@ impl zrml_swaps::Config for Runtime {
     type MinSubsidyPerAccount = MinSubsidyPerAccount;
     type MinWeight = MinWeight;
     type PalletId = SwapsPalletId;
+    type ProtocolSwapFeeShare = ProtocolSwapFeeShare;
     type RikiddoSigmoidFeeMarketEma = RikiddoSigmoidFeeMarketEma;
+    type TreasuryPalletId = TreasuryPalletId;
     type AssetManager = AssetManager;
     type WeightInfo = zrml_swaps::weights::WeightInfo<Runtime>;
 }
@@ -453,7 +467,9 @@ impl ExtBuilder {
         }
         .assimilate_storage(&mut t)
         .unwrap();
-        t.into()
+        let mut t: sp_io::TestExternalities = t.into();
+        t.execute_with(|| zrml_global_disputes::GlobalDisputesEnabled::<Runtime>::put(true));
+        t
     }
 }
 
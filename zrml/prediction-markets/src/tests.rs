@@ -39,7 +39,8 @@ use zeitgeist_primitives::{
     types::{
         AccountIdTest, Asset, Balance, BlockNumber, Bond, Deadlines, Market, MarketBonds,
         MarketCreation, MarketDisputeMechanism, MarketId, MarketPeriod, MarketStatus, MarketType,
-        Moment, MultiHash, OutcomeReport, PoolStatus, ScalarPosition, ScoringRule,
+        Moment, MultiHash, OutcomeReport, PoolId, PoolStatus, ScalarPosition, ScoringRule,
+        SerdeWrapper,
     },
 };
 use zrml_authorized::Error as AuthorizedError;
@@ -261,6 +262,28 @@ fn create_market_fails_on_min_dispute_period() {
     });
 }
 
+#[test_case(MarketCreation::Permissionless; "permissionless")]
+#[test_case(MarketCreation::Advised; "advised")]
+fn create_market_fails_on_lmsr_scoring_rule(creation: MarketCreation) {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            PredictionMarkets::create_market(
+                Origin::signed(ALICE),
+                Asset::Ztg,
+                BOB,
+                MarketPeriod::Block(123..456),
+                get_deadlines(),
+                gen_metadata(2),
+                creation,
+                MarketType::Categorical(2),
+                MarketDisputeMechanism::SimpleDisputes,
+                ScoringRule::LMSR { liquidity_parameter: 100 * BASE },
+            ),
+            Error::<Runtime>::InvalidScoringRule
+        );
+    });
+}
+
 #[test]
 fn create_market_fails_on_min_oracle_duration() {
     ExtBuilder::default().build().execute_with(|| {
@@ -2288,6 +2311,161 @@ fn it_allows_to_sell_a_complete_set() {
     });
 }
 
+fn deploy_categorical_market_with_pool() -> PoolId {
+    simple_create_categorical_market(
+        Asset::Ztg,
+        MarketCreation::Permissionless,
+        0..100,
+        ScoringRule::CPMM,
+    );
+    let amount = <Runtime as zrml_swaps::Config>::MinLiquidity::get();
+    assert_ok!(PredictionMarkets::buy_complete_set(Origin::signed(BOB), 0, amount));
+    assert_ok!(PredictionMarkets::deploy_swap_pool_for_market(
+        Origin::signed(BOB),
+        0,
+        <Runtime as zrml_swaps::Config>::MaxSwapFee::get(),
+        amount,
+        vec![<Runtime as zrml_swaps::Config>::MinWeight::get(); 2],
+    ));
+    MarketCommons::market_pool(&0).unwrap()
+}
+
+#[test]
+fn join_pool_with_base_asset_deposits_liquidity_and_leaves_leftovers_in_wallet() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let pool_id = deploy_categorical_market_with_pool();
+        let pool_shares_id = Asset::PoolShare(SerdeWrapper(pool_id));
+        let amount = 10 * BASE;
+        assert_ok!(PredictionMarkets::join_pool_with_base_asset(
+            Origin::signed(CHARLIE),
+            0,
+            amount,
+            0
+        ));
+
+        let pool_amount = Tokens::free_balance(pool_shares_id, &CHARLIE);
+        let amount_in = 1_000 * BASE - Balances::free_balance(&CHARLIE);
+        assert!(pool_amount > 0);
+        assert!(amount_in <= amount);
+        // Only rounding dust is not deposited into the pool.
+        assert!(amount_in >= amount - CENT);
+        let market = MarketCommons::market(&0).unwrap();
+        for asset in PredictionMarkets::outcome_assets(0, &market) {
+            assert!(Tokens::free_balance(asset, &CHARLIE) < CENT);
+        }
+        System::assert_last_event(
+            Event::JoinedPoolWithBaseAsset(0, amount_in, pool_amount, CHARLIE).into(),
+        );
+    });
+}
+
+#[test_case(CENT; "cent")]
+#[test_case(BASE + 1; "base")]
+#[test_case(123_456_789_012; "odd")]
+#[test_case(999 * BASE; "large")]
+fn join_pool_with_base_asset_never_spends_more_than_amount(amount: u128) {
+    ExtBuilder::default().build().execute_with(|| {
+        let pool_id = deploy_categorical_market_with_pool();
+        let pool_shares_id = Asset::PoolShare(SerdeWrapper(pool_id));
+        assert_ok!(PredictionMarkets::join_pool_with_base_asset(
+            Origin::signed(CHARLIE),
+            0,
+            amount,
+            0
+        ));
+        assert!(Tokens::free_balance(pool_shares_id, &CHARLIE) > 0);
+        let amount_in = 1_000 * BASE - Balances::free_balance(&CHARLIE);
+        assert!(amount_in <= amount);
+    });
+}
+
+#[test]
+fn join_pool_with_base_asset_fails_if_pool_shares_are_below_limit() {
+    ExtBuilder::default().build().execute_with(|| {
+        deploy_categorical_market_with_pool();
+        assert_noop!(
+            PredictionMarkets::join_pool_with_base_asset(
+                Origin::signed(CHARLIE),
+                0,
+                10 * BASE,
+                5 * BASE
+            ),
+            Error::<Runtime>::PoolSharesBelowLimit
+        );
+    });
+}
+
+#[test]
+fn join_pool_with_base_asset_fails_if_market_has_no_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..100,
+            ScoringRule::CPMM,
+        );
+        assert_noop!(
+            PredictionMarkets::join_pool_with_base_asset(Origin::signed(CHARLIE), 0, BASE, 0),
+            zrml_market_commons::Error::<Runtime>::MarketPoolDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn exit_pool_to_base_asset_sells_all_outcome_tokens() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let pool_id = deploy_categorical_market_with_pool();
+        let pool_shares_id = Asset::PoolShare(SerdeWrapper(pool_id));
+        // Unbalance the pool so that the outcome tokens received by BOB aren't complete sets.
+        assert_ok!(Swaps::swap_exact_amount_in(
+            Origin::signed(CHARLIE),
+            pool_id,
+            Asset::Ztg,
+            10 * BASE,
+            Asset::CategoricalOutcome(0, 0),
+            Some(0),
+            None,
+        ));
+        let pool_amount = Tokens::free_balance(pool_shares_id, &BOB) / 2;
+        let base_balance_before = Balances::free_balance(&BOB);
+        assert_ok!(PredictionMarkets::exit_pool_to_base_asset(
+            Origin::signed(BOB),
+            0,
+            pool_amount,
+            0
+        ));
+
+        let amount_out = Balances::free_balance(&BOB) - base_balance_before;
+        assert!(amount_out > 0);
+        let market = MarketCommons::market(&0).unwrap();
+        for asset in PredictionMarkets::outcome_assets(0, &market) {
+            assert_eq!(Tokens::free_balance(asset, &BOB), 0);
+        }
+        System::assert_last_event(
+            Event::ExitedPoolToBaseAsset(0, pool_amount, amount_out, BOB).into(),
+        );
+    });
+}
+
+#[test]
+fn exit_pool_to_base_asset_fails_if_base_asset_is_below_limit() {
+    ExtBuilder::default().build().execute_with(|| {
+        let pool_id = deploy_categorical_market_with_pool();
+        let pool_amount = Tokens::free_balance(Asset::PoolShare(SerdeWrapper(pool_id)), &BOB) / 2;
+        assert_noop!(
+            PredictionMarkets::exit_pool_to_base_asset(
+                Origin::signed(BOB),
+                0,
+                pool_amount,
+                100 * BASE
+            ),
+            Error::<Runtime>::BaseAssetBelowLimit
+        );
+    });
+}
+
 #[test]
 fn it_does_not_allow_zero_amounts_in_sell_complete_set() {
     ExtBuilder::default().build().execute_with(|| {
@@ -2924,13 +3102,11 @@ fn start_global_dispute_works() {
         run_to_block(dispute_at_0);
         for i in 1..=<Runtime as Config>::MaxDisputes::get() {
             if i == 1 {
-                #[cfg(feature = "with-global-disputes")]
                 assert_noop!(
                     PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), market_id),
                     Error::<Runtime>::InvalidMarketStatus
                 );
             } else {
-                #[cfg(feature = "with-global-disputes")]
                 assert_noop!(
                     PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), market_id),
                     Error::<Runtime>::MaxDisputesNeeded
@@ -2954,50 +3130,47 @@ fn start_global_dispute_works() {
         let removable_market_ids = MarketIdsPerDisputeBlock::<Runtime>::get(dispute_block);
         assert_eq!(removable_market_ids.len(), 1);
 
-        #[cfg(feature = "with-global-disputes")]
-        {
-            use zrml_global_disputes::GlobalDisputesPalletApi;
+        use zrml_global_disputes::GlobalDisputesPalletApi;
 
-            let now = <frame_system::Pallet<Runtime>>::block_number();
-            assert_ok!(PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), market_id));
+        let now = <frame_system::Pallet<Runtime>>::block_number();
+        assert_ok!(PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), market_id));
 
-            // report check
+        // report check
+        assert_eq!(
+            GlobalDisputes::get_voting_outcome_info(&market_id, &OutcomeReport::Categorical(0)),
+            Some((Zero::zero(), vec![BOB])),
+        );
+        for i in 1..=<Runtime as Config>::MaxDisputes::get() {
+            let dispute_bond = crate::default_dispute_bond::<Runtime>((i - 1).into());
             assert_eq!(
-                GlobalDisputes::get_voting_outcome_info(&market_id, &OutcomeReport::Categorical(0)),
-                Some((Zero::zero(), vec![BOB])),
+                GlobalDisputes::get_voting_outcome_info(
+                    &market_id,
+                    &OutcomeReport::Categorical(i.saturated_into())
+                ),
+                Some((dispute_bond, vec![CHARLIE])),
             );
-            for i in 1..=<Runtime as Config>::MaxDisputes::get() {
-                let dispute_bond = crate::default_dispute_bond::<Runtime>((i - 1).into());
-                assert_eq!(
-                    GlobalDisputes::get_voting_outcome_info(
-                        &market_id,
-                        &OutcomeReport::Categorical(i.saturated_into())
-                    ),
-                    Some((dispute_bond, vec![CHARLIE])),
-                );
-            }
+        }
 
-            // remove_last_dispute_from_market_ids_per_dispute_block works
-            let removable_market_ids = MarketIdsPerDisputeBlock::<Runtime>::get(dispute_block);
-            assert_eq!(removable_market_ids.len(), 0);
+        // remove_last_dispute_from_market_ids_per_dispute_block works
+        let removable_market_ids = MarketIdsPerDisputeBlock::<Runtime>::get(dispute_block);
+        assert_eq!(removable_market_ids.len(), 0);
 
-            let market_ids = MarketIdsPerDisputeBlock::<Runtime>::get(
-                now + <Runtime as Config>::GlobalDisputePeriod::get(),
-            );
-            assert_eq!(market_ids, vec![market_id]);
-            assert!(GlobalDisputes::is_started(&market_id));
-            System::assert_last_event(Event::GlobalDisputeStarted(market_id).into());
+        let market_ids = MarketIdsPerDisputeBlock::<Runtime>::get(
+            now + <Runtime as Config>::GlobalDisputePeriod::get(),
+        );
+        assert_eq!(market_ids, vec![market_id]);
+        assert!(GlobalDisputes::is_started(&market_id));
+        System::assert_last_event(Event::GlobalDisputeStarted(market_id).into());
 
-            assert_noop!(
-                PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), market_id),
-                Error::<Runtime>::GlobalDisputeAlreadyStarted
-            );
-        }
+        assert_noop!(
+            PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), market_id),
+            Error::<Runtime>::GlobalDisputeAlreadyStarted
+        );
     });
 }
 
 #[test]
-fn start_global_dispute_fails_on_wrong_mdm() {
+fn start_global_dispute_fails_if_authority_has_not_failed() {
     ExtBuilder::default().build().execute_with(|| {
         let end = 2;
         assert_ok!(PredictionMarkets::create_market(
@@ -3035,29 +3208,140 @@ fn start_global_dispute_fails_on_wrong_mdm() {
         let market = MarketCommons::market(&market_id).unwrap();
         assert_eq!(market.status, MarketStatus::Disputed);
 
-        #[cfg(feature = "with-global-disputes")]
         assert_noop!(
             PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), market_id),
-            Error::<Runtime>::InvalidDisputeMechanism
+            Error::<Runtime>::DisputeMechanismHasNotFailed
         );
     });
 }
 
 #[test]
-fn start_global_dispute_works_without_feature() {
+fn start_global_dispute_works_for_appealed_authorized_market() {
     ExtBuilder::default().build().execute_with(|| {
-        let non_market_id = 0;
+        let end = 2;
+        assert_ok!(PredictionMarkets::create_market(
+            Origin::signed(ALICE),
+            Asset::Ztg,
+            BOB,
+            MarketPeriod::Block(0..end),
+            get_deadlines(),
+            gen_metadata(2),
+            MarketCreation::Permissionless,
+            MarketType::Categorical(<Runtime as Config>::MinCategories::get()),
+            MarketDisputeMechanism::Authorized,
+            ScoringRule::CPMM,
+        ));
+        let market_id = MarketCommons::latest_market_id().unwrap();
+
+        let market = MarketCommons::market(&market_id).unwrap();
+        let grace_period = market.deadlines.grace_period;
+        run_to_block(end + grace_period + 1);
+        assert_ok!(PredictionMarkets::report(
+            Origin::signed(BOB),
+            market_id,
+            OutcomeReport::Categorical(0)
+        ));
+        run_blocks(1);
+        assert_ok!(PredictionMarkets::dispute(
+            Origin::signed(CHARLIE),
+            market_id,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            market_id,
+            OutcomeReport::Categorical(0),
+            None,
+        ));
+        run_blocks(1);
+        assert_ok!(Authorized::appeal(Origin::signed(DAVE), market_id));
+
+        use zrml_global_disputes::GlobalDisputesPalletApi;
 
-        #[cfg(not(feature = "with-global-disputes"))]
+        assert_ok!(PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), market_id));
+        assert!(GlobalDisputes::is_started(&market_id));
+    });
+}
+
+#[test]
+fn authorized_appeal_bond_is_slashed_if_the_report_stands() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 2;
+        assert_ok!(PredictionMarkets::create_market(
+            Origin::signed(ALICE),
+            Asset::Ztg,
+            BOB,
+            MarketPeriod::Block(0..end),
+            get_deadlines(),
+            gen_metadata(2),
+            MarketCreation::Permissionless,
+            MarketType::Categorical(<Runtime as Config>::MinCategories::get()),
+            MarketDisputeMechanism::Authorized,
+            ScoringRule::CPMM,
+        ));
+        let market_id = MarketCommons::latest_market_id().unwrap();
+
+        let market = MarketCommons::market(&market_id).unwrap();
+        let grace_period = market.deadlines.grace_period;
+        run_to_block(end + grace_period + 1);
+        assert_ok!(PredictionMarkets::report(
+            Origin::signed(BOB),
+            market_id,
+            OutcomeReport::Categorical(0)
+        ));
+        run_blocks(1);
+        assert_ok!(PredictionMarkets::dispute(
+            Origin::signed(CHARLIE),
+            market_id,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_ok!(Authorized::authorize_market_outcome(
+            Origin::signed(AuthorizedDisputeResolutionUser::get()),
+            market_id,
+            OutcomeReport::Categorical(1),
+            None,
+        ));
+        run_blocks(1);
+        assert_ok!(Authorized::appeal(Origin::signed(DAVE), market_id));
+        let appeal_bond = <Runtime as zrml_authorized::Config>::AppealBond::get();
+        assert_eq!(Balances::free_balance(&DAVE), INITIAL_BALANCE - appeal_bond);
+
+        // The appeal postpones the resolution.
+        let correction_period = <Runtime as zrml_authorized::Config>::CorrectionPeriod::get();
+        run_blocks(correction_period);
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market.status, MarketStatus::Disputed);
+
+        run_blocks(<Runtime as zrml_authorized::Config>::AppealPeriod::get() - correction_period);
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market.status, MarketStatus::Resolved);
+        assert_eq!(market.resolved_outcome, Some(OutcomeReport::Categorical(1)));
+        assert_eq!(Balances::free_balance(&DAVE), INITIAL_BALANCE - appeal_bond);
+        assert_eq!(Balances::reserved_balance(&DAVE), 0);
+        assert!(zrml_authorized::Appeals::<Runtime>::get(market_id).is_none());
+    });
+}
+
+#[test]
+fn start_global_dispute_fails_on_non_existent_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let non_market_id = 0;
         assert_noop!(
             PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), non_market_id),
-            Error::<Runtime>::GlobalDisputesDisabled
+            zrml_market_commons::Error::<Runtime>::MarketDoesNotExist
         );
+    });
+}
+
+#[test]
+fn start_global_dispute_fails_if_global_disputes_are_disabled() {
+    ExtBuilder::default().build().execute_with(|| {
+        let non_market_id = 0;
+        assert_ok!(GlobalDisputes::set_global_disputes_enabled(Origin::signed(SUDO), false));
 
-        #[cfg(feature = "with-global-disputes")]
         assert_noop!(
             PredictionMarkets::start_global_dispute(Origin::signed(CHARLIE), non_market_id),
-            zrml_market_commons::Error::<Runtime>::MarketDoesNotExist
+            Error::<Runtime>::GlobalDisputesDisabled
         );
     });
 }
@@ -3407,6 +3691,36 @@ fn edit_cycle_for_proposed_markets() {
     });
 }
 
+#[test]
+fn edit_market_fails_on_lmsr_scoring_rule() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Advised,
+            2..4,
+            ScoringRule::CPMM,
+        );
+        let edit_reason = vec![0_u8; <Runtime as Config>::MaxEditReasonLen::get() as usize];
+        assert_ok!(PredictionMarkets::request_edit(Origin::signed(SUDO), 0, edit_reason));
+
+        assert_noop!(
+            PredictionMarkets::edit_market(
+                Origin::signed(ALICE),
+                Asset::Ztg,
+                0,
+                CHARLIE,
+                MarketPeriod::Block(2..4),
+                get_deadlines(),
+                gen_metadata(2),
+                MarketType::Categorical(<Runtime as crate::Config>::MinCategories::get()),
+                MarketDisputeMechanism::SimpleDisputes,
+                ScoringRule::LMSR { liquidity_parameter: 100 * BASE },
+            ),
+            Error::<Runtime>::InvalidScoringRule
+        );
+    });
+}
+
 #[cfg(feature = "parachain")]
 #[test]
 fn edit_market_with_foreign_asset() {
@@ -3746,11 +4060,125 @@ fn market_resolve_does_not_hold_liquidity_withdraw() {
         ));
 
         run_to_block(grace_period + market.deadlines.dispute_duration + 2);
-        assert_ok!(Swaps::pool_exit(Origin::signed(FRED), 0, BASE * 100, vec![0, 0]));
+        // The winning outcome tokens of the pool were redeemed, so the pool only holds the base
+        // asset.
+        let pool = Pools::<Runtime>::get(0).unwrap();
+        assert_eq!(pool.assets, vec![Asset::Ztg]);
+        let fred_balance_before = Balances::free_balance(&FRED);
+        assert_ok!(Swaps::pool_exit(Origin::signed(FRED), 0, BASE * 100, vec![0]));
+        assert_eq!(Balances::free_balance(&FRED), fred_balance_before + 200 * BASE);
+        assert_eq!(Tokens::free_balance(Asset::CategoricalOutcome(0, 2), &FRED), 0);
         assert_ok!(PredictionMarkets::redeem_shares(Origin::signed(BOB), 0));
     })
 }
 
+#[test]
+fn liquidity_providers_only_receive_base_asset_when_exiting_pool_of_resolved_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 100;
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::CPMM,
+        );
+        let market = MarketCommons::market(&0).unwrap();
+        deploy_swap_pool(market.clone(), 0).unwrap();
+        let pool_id = MarketCommons::market_pool(&0).unwrap();
+        let pool_account = Swaps::pool_account_id(&pool_id);
+        let pool_shares_id = Asset::PoolShare(SerdeWrapper(pool_id));
+        assert_ok!(PredictionMarkets::join_pool_with_base_asset(
+            Origin::signed(CHARLIE),
+            0,
+            10 * BASE,
+            0
+        ));
+        assert_ok!(Swaps::swap_exact_amount_in(
+            Origin::signed(BOB),
+            pool_id,
+            Asset::Ztg,
+            BASE,
+            Asset::CategoricalOutcome(0, 0),
+            Some(0),
+            None
+        ));
+
+        let grace_period = end + market.deadlines.grace_period;
+        run_to_block(grace_period + 1);
+        assert_ok!(PredictionMarkets::report(
+            Origin::signed(BOB),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        run_to_block(grace_period + market.deadlines.dispute_duration + 2);
+
+        let pool = Pools::<Runtime>::get(pool_id).unwrap();
+        assert_eq!(pool.pool_status, PoolStatus::Clean);
+        assert_eq!(pool.assets, vec![Asset::Ztg]);
+        let base_weight = pool.weights.as_ref().unwrap()[&Asset::Ztg];
+        assert_eq!(pool.weights.unwrap().len(), 1);
+        assert_eq!(pool.total_weight, Some(base_weight));
+
+        let outcome_assets = PredictionMarkets::outcome_assets(0, &market);
+        let pool_balance_before = Balances::free_balance(&pool_account);
+        let mut total_amount_out = 0;
+        for lp in [FRED, CHARLIE] {
+            let outcome_balances_before = outcome_assets
+                .iter()
+                .map(|asset| Tokens::free_balance(*asset, &lp))
+                .collect::<Vec<_>>();
+            let balance_before = Balances::free_balance(&lp);
+            let pool_amount = Tokens::free_balance(pool_shares_id, &lp);
+            assert_ok!(Swaps::pool_exit(Origin::signed(lp), pool_id, pool_amount, vec![0]));
+            let amount_out = Balances::free_balance(&lp) - balance_before;
+            assert!(amount_out > 0);
+            total_amount_out += amount_out;
+            let outcome_balances_after = outcome_assets
+                .iter()
+                .map(|asset| Tokens::free_balance(*asset, &lp))
+                .collect::<Vec<_>>();
+            assert_eq!(outcome_balances_after, outcome_balances_before);
+            assert_eq!(Tokens::free_balance(pool_shares_id, &lp), 0);
+        }
+        assert_eq!(total_amount_out, pool_balance_before - Balances::free_balance(&pool_account));
+    })
+}
+
+#[test]
+fn on_resolution_redeems_outcome_tokens_of_scalar_market_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 100;
+        simple_create_scalar_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::CPMM,
+        );
+        deploy_swap_pool(MarketCommons::market(&0).unwrap(), 0).unwrap();
+        let pool_id = MarketCommons::market_pool(&0).unwrap();
+        let pool_account = Swaps::pool_account_id(&pool_id);
+        let market = MarketCommons::market(&0).unwrap();
+
+        let grace_period = end + market.deadlines.grace_period;
+        run_to_block(grace_period + 1);
+        assert_ok!(PredictionMarkets::report(Origin::signed(BOB), 0, OutcomeReport::Scalar(125)));
+        run_to_block(grace_period + market.deadlines.dispute_duration + 2);
+
+        let pool = Pools::<Runtime>::get(pool_id).unwrap();
+        assert_eq!(pool.pool_status, PoolStatus::Clean);
+        assert_eq!(pool.assets, vec![Asset::Ztg]);
+        let long = Asset::ScalarOutcome(0, ScalarPosition::Long);
+        let short = Asset::ScalarOutcome(0, ScalarPosition::Short);
+        assert_eq!(Tokens::free_balance(long, &pool_account), 0);
+        assert_eq!(Tokens::free_balance(short, &pool_account), 0);
+        // 100 units of LONG pay 25 each and 100 units of SHORT pay 75 each.
+        assert_eq!(Balances::free_balance(&pool_account), 200 * BASE);
+        System::assert_has_event(
+            Event::TokensRedeemed(0, long, 100 * BASE, 25 * BASE, pool_account).into(),
+        );
+    })
+}
+
 #[test]
 fn authorized_correctly_resolves_disputed_market() {
     // NOTE: Bonds are always in ZTG, irrespective of base_asset.
@@ -3804,12 +4232,14 @@ fn authorized_correctly_resolves_disputed_market() {
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
-            OutcomeReport::Categorical(0)
+            OutcomeReport::Categorical(0),
+            None
         ));
         assert_ok!(Authorized::authorize_market_outcome(
             Origin::signed(AuthorizedDisputeResolutionUser::get()),
             0,
-            OutcomeReport::Categorical(1)
+            OutcomeReport::Categorical(1),
+            None
         ));
 
         let market = MarketCommons::market(&0).unwrap();
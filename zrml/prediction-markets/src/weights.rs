@@ -77,6 +77,8 @@ pub trait WeightInfoZeitgeist {
     fn market_status_manager(b: u32, f: u32) -> Weight;
     fn market_resolution_manager(r: u32, d: u32) -> Weight;
     fn process_subsidy_collecting_markets_dummy() -> Weight;
+    fn join_pool_with_base_asset(a: u32) -> Weight;
+    fn exit_pool_to_base_asset(a: u32) -> Weight;
 }
 
 /// Weight functions for zrml_prediction_markets (automatically generated)
@@ -87,6 +89,8 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     // Storage: System Account (r:8 w:8)
     // Storage: MarketCommons MarketPool (r:1 w:1)
     // Storage: Swaps Pools (r:1 w:1)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens Accounts (r:2 w:2)
     // Storage: Tokens TotalIssuance (r:2 w:2)
     // Storage: PredictionMarkets Disputes (r:1 w:1)
@@ -101,7 +105,7 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(Weight::from_ref_time(425_000).saturating_mul(c.into()))
             // Standard Error: 41_000
             .saturating_add(Weight::from_ref_time(438_000).saturating_mul(r.into()))
-            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads(10))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
             .saturating_add(T::DbWeight::get().writes(8))
@@ -113,6 +117,8 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     // Storage: System Account (r:2 w:2)
     // Storage: MarketCommons MarketPool (r:1 w:1)
     // Storage: Swaps Pools (r:1 w:1)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens Accounts (r:2 w:2)
     // Storage: Tokens TotalIssuance (r:2 w:2)
     // Storage: PredictionMarkets MarketIdsPerReportBlock (r:1 w:1)
@@ -123,7 +129,7 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(Weight::from_ref_time(27_690_000).saturating_mul(a.into()))
             // Standard Error: 39_000
             .saturating_add(Weight::from_ref_time(809_000).saturating_mul(c.into()))
-            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().reads(9))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes(8))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
@@ -283,16 +289,17 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     }
     // Storage: MarketCommons Markets (r:1 w:0)
     // Storage: PredictionMarkets Disputes (r:1 w:0)
+    // Storage: GlobalDisputes GlobalDisputesEnabled (r:1 w:0)
     // Storage: GlobalDisputes Winners (r:1 w:1)
     // Storage: GlobalDisputes Outcomes (r:7 w:7)
     // Storage: PredictionMarkets MarketIdsPerDisputeBlock (r:2 w:2)
     fn start_global_dispute(m: u32, n: u32) -> Weight {
-        Weight::from_ref_time(128_368_000)
+        Weight::from_ref_time(131_904_000)
             // Standard Error: 4_000
             .saturating_add(Weight::from_ref_time(8_000).saturating_mul(m.into()))
             // Standard Error: 4_000
             .saturating_add(Weight::from_ref_time(96_000).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(12))
+            .saturating_add(T::DbWeight::get().reads(13))
             .saturating_add(T::DbWeight::get().writes(10))
     }
     // Storage: PredictionMarkets Disputes (r:1 w:1)
@@ -476,4 +483,41 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+    // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: MarketCommons MarketPool (r:1 w:0)
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Tokens TotalIssuance (r:3 w:3)
+    // Storage: Tokens Accounts (r:4 w:4)
+    // Storage: System Account (r:2 w:2)
+    // Storage: LiquidityMining OwnedValues (r:1 w:1)
+    fn join_pool_with_base_asset(a: u32) -> Weight {
+        Weight::from_ref_time(131_954_000)
+            // Standard Error: 41_000
+            .saturating_add(Weight::from_ref_time(46_313_000).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(a.into())))
+    }
+    // Storage: MarketCommons Markets (r:2 w:0)
+    // Storage: MarketCommons MarketPool (r:1 w:0)
+    // Storage: Swaps Pools (r:2 w:0)
+    // Storage: Swaps DynamicSwapFees (r:2 w:0)
+    // Storage: Swaps WeightSchedules (r:2 w:0)
+    // Storage: Tokens TotalIssuance (r:3 w:3)
+    // Storage: Tokens Accounts (r:4 w:4)
+    // Storage: System Account (r:4 w:4)
+    // Storage: LiquidityMining OwnedValues (r:1 w:1)
+    // Storage: Timestamp Now (r:1 w:0)
+    fn exit_pool_to_base_asset(a: u32) -> Weight {
+        Weight::from_ref_time(156_208_000)
+            // Standard Error: 96_000
+            .saturating_add(Weight::from_ref_time(118_752_000).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(14))
+            .saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(a.into())))
+    }
 }
@@ -32,8 +32,9 @@ use sp_blockchain::HeaderBackend;
 use sp_runtime::{
     generic::BlockId,
     traits::{Block as BlockT, MaybeDisplay, MaybeFromStr, NumberFor},
+    DispatchError,
 };
-use zeitgeist_primitives::types::{Asset, SerdeWrapper};
+use zeitgeist_primitives::types::{Asset, SerdeWrapper, TradeQuote};
 
 pub use zrml_swaps_runtime_api::SwapsApi as SwapsRuntimeApi;
 
@@ -74,6 +75,78 @@ where
         with_fees: bool,
         blocks: Vec<BlockNumber>,
     ) -> RpcResult<Vec<SerdeWrapper<Balance>>>;
+
+    #[method(name = "swaps_getAllSpotPrices")]
+    async fn get_all_spot_prices(
+        &self,
+        pool_id: PoolId,
+        with_fees: bool,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>>;
+
+    #[method(name = "swaps_getAllSpotPricesForPools")]
+    async fn get_all_spot_prices_for_pools(
+        &self,
+        pool_ids: Vec<PoolId>,
+        with_fees: bool,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Option<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>>>>;
+
+    #[method(name = "swaps_quoteSwapExactAmountIn")]
+    async fn quote_swap_exact_amount_in(
+        &self,
+        pool_id: PoolId,
+        asset_in: Asset<MarketId>,
+        asset_amount_in: SerdeWrapper<Balance>,
+        asset_out: Asset<MarketId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TradeQuote<Balance>>;
+
+    #[method(name = "swaps_quoteSwapExactAmountOut")]
+    async fn quote_swap_exact_amount_out(
+        &self,
+        pool_id: PoolId,
+        asset_in: Asset<MarketId>,
+        asset_out: Asset<MarketId>,
+        asset_amount_out: SerdeWrapper<Balance>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TradeQuote<Balance>>;
+
+    #[method(name = "swaps_quotePoolJoinWithExactAssetAmount")]
+    async fn quote_pool_join_with_exact_asset_amount(
+        &self,
+        pool_id: PoolId,
+        asset_in: Asset<MarketId>,
+        asset_amount: SerdeWrapper<Balance>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TradeQuote<Balance>>;
+
+    #[method(name = "swaps_quotePoolJoinWithExactPoolAmount")]
+    async fn quote_pool_join_with_exact_pool_amount(
+        &self,
+        pool_id: PoolId,
+        asset: Asset<MarketId>,
+        pool_amount: SerdeWrapper<Balance>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TradeQuote<Balance>>;
+
+    #[method(name = "swaps_quotePoolExitWithExactAssetAmount")]
+    async fn quote_pool_exit_with_exact_asset_amount(
+        &self,
+        pool_id: PoolId,
+        asset: Asset<MarketId>,
+        asset_amount: SerdeWrapper<Balance>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TradeQuote<Balance>>;
+
+    #[method(name = "swaps_quotePoolExitWithExactPoolAmount")]
+    async fn quote_pool_exit_with_exact_pool_amount(
+        &self,
+        pool_id: PoolId,
+        asset: Asset<MarketId>,
+        pool_amount: SerdeWrapper<Balance>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TradeQuote<Balance>>;
 }
 
 /// A struct that implements the [`SwapsApi`].
@@ -93,16 +166,42 @@ impl<C, B> Swaps<C, B> {
 pub enum Error {
     /// The call to the runtime failed.
     RuntimeError,
+    /// The trade cannot be executed on the pool.
+    InvalidTrade,
+    /// The spot prices of the pool cannot be calculated.
+    InvalidPool,
 }
 
 impl From<Error> for i32 {
     fn from(e: Error) -> i32 {
         match e {
             Error::RuntimeError => 1,
+            Error::InvalidTrade => 2,
+            Error::InvalidPool => 3,
         }
     }
 }
 
+fn to_trade_quote<Balance: MaxEncodedLen>(
+    res: Result<Result<TradeQuote<Balance>, DispatchError>, sp_api::ApiError>,
+) -> RpcResult<TradeQuote<Balance>> {
+    let quote = res.map_err(|e| {
+        CallError::Custom(ErrorObject::owned(
+            Error::RuntimeError.into(),
+            "Unable to quote trade.",
+            Some(e.to_string()),
+        ))
+    })?;
+    quote.map_err(|e| {
+        CallError::Custom(ErrorObject::owned(
+            Error::InvalidTrade.into(),
+            "Trade cannot be executed on this pool.",
+            Some(format!("{:?}", e)),
+        ))
+        .into()
+    })
+}
+
 #[async_trait]
 impl<C, Block, PoolId, AccountId, Balance, MarketId>
     SwapsApiServer<<Block as BlockT>::Hash, NumberFor<Block>, PoolId, AccountId, Balance, MarketId>
@@ -204,4 +303,159 @@ where
             })
             .collect()
     }
+
+    async fn get_all_spot_prices(
+        &self,
+        pool_id: PoolId,
+        with_fees: bool,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let res = api.get_all_spot_prices(&at, &pool_id, with_fees).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to get spot prices.",
+                Some(e.to_string()),
+            ))
+        })?;
+        res.ok_or_else(|| {
+            CallError::Custom(ErrorObject::owned(
+                Error::InvalidPool.into(),
+                "Unable to calculate the spot prices of this pool.",
+                None::<()>,
+            ))
+            .into()
+        })
+    }
+
+    /// Pools whose spot prices cannot be calculated are mapped to `None`.
+    async fn get_all_spot_prices_for_pools(
+        &self,
+        pool_ids: Vec<PoolId>,
+        with_fees: bool,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Option<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        pool_ids
+            .iter()
+            .map(|pool_id| {
+                let res = api.get_all_spot_prices(&at, pool_id, with_fees).map_err(|e| {
+                    CallError::Custom(ErrorObject::owned(
+                        Error::RuntimeError.into(),
+                        "Unable to get spot prices.",
+                        Some(e.to_string()),
+                    ))
+                })?;
+                Ok(res)
+            })
+            .collect()
+    }
+
+    async fn quote_swap_exact_amount_in(
+        &self,
+        pool_id: PoolId,
+        asset_in: Asset<MarketId>,
+        asset_amount_in: SerdeWrapper<Balance>,
+        asset_out: Asset<MarketId>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TradeQuote<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        to_trade_quote(api.quote_swap_exact_amount_in(
+            &at,
+            &pool_id,
+            &asset_in,
+            asset_amount_in.0,
+            &asset_out,
+        ))
+    }
+
+    async fn quote_swap_exact_amount_out(
+        &self,
+        pool_id: PoolId,
+        asset_in: Asset<MarketId>,
+        asset_out: Asset<MarketId>,
+        asset_amount_out: SerdeWrapper<Balance>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TradeQuote<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        to_trade_quote(api.quote_swap_exact_amount_out(
+            &at,
+            &pool_id,
+            &asset_in,
+            &asset_out,
+            asset_amount_out.0,
+        ))
+    }
+
+    async fn quote_pool_join_with_exact_asset_amount(
+        &self,
+        pool_id: PoolId,
+        asset_in: Asset<MarketId>,
+        asset_amount: SerdeWrapper<Balance>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TradeQuote<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        to_trade_quote(api.quote_pool_join_with_exact_asset_amount(
+            &at,
+            &pool_id,
+            &asset_in,
+            asset_amount.0,
+        ))
+    }
+
+    async fn quote_pool_join_with_exact_pool_amount(
+        &self,
+        pool_id: PoolId,
+        asset: Asset<MarketId>,
+        pool_amount: SerdeWrapper<Balance>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TradeQuote<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        to_trade_quote(api.quote_pool_join_with_exact_pool_amount(
+            &at,
+            &pool_id,
+            &asset,
+            pool_amount.0,
+        ))
+    }
+
+    async fn quote_pool_exit_with_exact_asset_amount(
+        &self,
+        pool_id: PoolId,
+        asset: Asset<MarketId>,
+        asset_amount: SerdeWrapper<Balance>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TradeQuote<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        to_trade_quote(api.quote_pool_exit_with_exact_asset_amount(
+            &at,
+            &pool_id,
+            &asset,
+            asset_amount.0,
+        ))
+    }
+
+    async fn quote_pool_exit_with_exact_pool_amount(
+        &self,
+        pool_id: PoolId,
+        asset: Asset<MarketId>,
+        pool_amount: SerdeWrapper<Balance>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TradeQuote<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        to_trade_quote(api.quote_pool_exit_with_exact_pool_amount(
+            &at,
+            &pool_id,
+            &asset,
+            pool_amount.0,
+        ))
+    }
 }
@@ -19,10 +19,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use parity_scale_codec::{Codec, MaxEncodedLen};
-use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
-use zeitgeist_primitives::types::{Asset, SerdeWrapper};
+use sp_runtime::{
+    traits::{MaybeDisplay, MaybeFromStr},
+    DispatchError,
+};
+use sp_std::vec::Vec;
+use zeitgeist_primitives::types::{Asset, SerdeWrapper, TradeQuote};
 
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait SwapsApi<PoolId, AccountId, Balance, MarketId> where
         PoolId: Codec,
         AccountId: Codec,
@@ -37,5 +42,44 @@ sp_api::decl_runtime_apis! {
             asset_out: &Asset<MarketId>,
             with_fees: bool,
         ) -> SerdeWrapper<Balance>;
+        fn get_all_spot_prices(
+            pool_id: &PoolId,
+            with_fees: bool,
+        ) -> Option<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>>;
+        fn get_pool_balances(
+            pool_id: &PoolId,
+        ) -> Option<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>>;
+        fn quote_swap_exact_amount_in(
+            pool_id: &PoolId,
+            asset_in: &Asset<MarketId>,
+            asset_amount_in: Balance,
+            asset_out: &Asset<MarketId>,
+        ) -> Result<TradeQuote<Balance>, DispatchError>;
+        fn quote_swap_exact_amount_out(
+            pool_id: &PoolId,
+            asset_in: &Asset<MarketId>,
+            asset_out: &Asset<MarketId>,
+            asset_amount_out: Balance,
+        ) -> Result<TradeQuote<Balance>, DispatchError>;
+        fn quote_pool_join_with_exact_asset_amount(
+            pool_id: &PoolId,
+            asset_in: &Asset<MarketId>,
+            asset_amount: Balance,
+        ) -> Result<TradeQuote<Balance>, DispatchError>;
+        fn quote_pool_join_with_exact_pool_amount(
+            pool_id: &PoolId,
+            asset: &Asset<MarketId>,
+            pool_amount: Balance,
+        ) -> Result<TradeQuote<Balance>, DispatchError>;
+        fn quote_pool_exit_with_exact_asset_amount(
+            pool_id: &PoolId,
+            asset: &Asset<MarketId>,
+            asset_amount: Balance,
+        ) -> Result<TradeQuote<Balance>, DispatchError>;
+        fn quote_pool_exit_with_exact_pool_amount(
+            pool_id: &PoolId,
+            asset: &Asset<MarketId>,
+            pool_amount: Balance,
+        ) -> Result<TradeQuote<Balance>, DispatchError>;
     }
 }
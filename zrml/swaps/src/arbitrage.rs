@@ -31,8 +31,6 @@ use zeitgeist_primitives::{
 
 type Fixed = u128;
 
-const TOLERANCE: Fixed = BASE / 1_000; // 0.001
-
 /// This trait implements approximations for on-chain arbitrage of CPMM pools using bisection.
 ///
 /// All calculations depend on on-chain, which are passed using the `balances` parameter.
@@ -56,10 +54,12 @@ where
     ///
     /// * `balances`: Maps assets to their current balance.
     /// * `max_iterations`: Maximum number of iterations allowed in the bisection method.
+    /// * `tolerance`: Maximum allowed distance of the total spot price from `1`.
     fn calc_arbitrage_amount_mint_sell(
         &self,
         balances: &BTreeMap<Asset<MarketId>, Balance>,
         max_iterations: usize,
+        tolerance: Fixed,
     ) -> Result<(Balance, usize), &'static str>;
 
     /// Approximate the amount to buy/burn to move the total spot price close to `1`.
@@ -68,10 +68,12 @@ where
     ///
     /// * `balances`: Maps assets to their current balance.
     /// * `max_iterations`: Maximum number of iterations allowed in the bisection method.
+    /// * `tolerance`: Maximum allowed distance of the total spot price from `1`.
     fn calc_arbitrage_amount_buy_burn(
         &self,
         balances: &BTreeMap<Asset<MarketId>, Balance>,
         max_iterations: usize,
+        tolerance: Fixed,
     ) -> Result<(Balance, usize), &'static str>;
 }
 
@@ -122,16 +124,28 @@ where
         &self,
         balances: &BTreeMap<Asset<MarketId>, Balance>,
         max_iterations: usize,
+        tolerance: Fixed,
     ) -> Result<(Balance, usize), &'static str> {
-        self.calc_arbitrage_amount_common(balances, |a| *a == self.base_asset, max_iterations)
+        self.calc_arbitrage_amount_common(
+            balances,
+            |a| *a == self.base_asset,
+            max_iterations,
+            tolerance,
+        )
     }
 
     fn calc_arbitrage_amount_buy_burn(
         &self,
         balances: &BTreeMap<Asset<MarketId>, Balance>,
         max_iterations: usize,
+        tolerance: Fixed,
     ) -> Result<(Balance, usize), &'static str> {
-        self.calc_arbitrage_amount_common(balances, |a| *a != self.base_asset, max_iterations)
+        self.calc_arbitrage_amount_common(
+            balances,
+            |a| *a != self.base_asset,
+            max_iterations,
+            tolerance,
+        )
     }
 }
 
@@ -150,11 +164,13 @@ where
     /// - `balances`: Maps assets to their balance in the pool.
     /// - `cond`: Returns `true` if the asset's balance must be decreased, `false` otherwise.
     /// - `max_iterations`: The maximum number of iterations to use in the bisection method.
+    /// - `tolerance`: The maximum allowed distance of the total spot price from `1`.
     fn calc_arbitrage_amount_common<F>(
         &self,
         balances: &BTreeMap<Asset<MarketId>, Balance>,
         cond: F,
         max_iterations: usize,
+        tolerance: Fixed,
     ) -> Result<(Balance, usize), &'static str>
     where
         F: Fn(&Asset<MarketId>) -> bool;
@@ -170,6 +186,7 @@ where
         balances: &BTreeMap<Asset<MarketId>, Balance>,
         cond: F,
         max_iterations: usize,
+        tolerance: Fixed,
     ) -> Result<(Balance, usize), &'static str>
     where
         F: Fn(&Asset<MarketId>) -> bool,
@@ -201,7 +218,7 @@ where
             0,
             smallest_balance / 2,
             max_iterations,
-            TOLERANCE,
+            tolerance,
         )?;
         Ok((preimage.saturated_into(), iterations))
     }
@@ -210,6 +227,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ARBITRAGE_TOLERANCE;
     use test_case::test_case;
     use zeitgeist_primitives::{
         constants::CENT,
@@ -324,7 +342,8 @@ mod tests {
     ) {
         let pool = construct_pool(None, weights);
         let balances = collect_balances_into_map(pool.assets.clone(), balances);
-        let (amount, _) = pool.calc_arbitrage_amount_buy_burn(&balances, 30).unwrap();
+        let (amount, _) =
+            pool.calc_arbitrage_amount_buy_burn(&balances, 30, ARBITRAGE_TOLERANCE).unwrap();
         assert_eq!(amount, expected);
     }
 
@@ -341,7 +360,8 @@ mod tests {
     ) {
         let pool = construct_pool(None, weights);
         let mut balances = collect_balances_into_map(pool.assets.clone(), balances);
-        let (amount, _) = pool.calc_arbitrage_amount_buy_burn(&balances, 30).unwrap();
+        let (amount, _) =
+            pool.calc_arbitrage_amount_buy_burn(&balances, 30, ARBITRAGE_TOLERANCE).unwrap();
         *balances.get_mut(&pool.assets[0]).unwrap() += amount;
         for asset in &pool.assets[1..] {
             *balances.get_mut(asset).unwrap() -= amount;
@@ -357,7 +377,7 @@ mod tests {
         let pool = construct_pool(None, vec![_3, _1, _1, _1]);
         let balances = collect_balances_into_map(pool.assets[..2].into(), vec![_1; 3]);
         assert_eq!(
-            pool.calc_arbitrage_amount_buy_burn(&balances, usize::MAX),
+            pool.calc_arbitrage_amount_buy_burn(&balances, usize::MAX, ARBITRAGE_TOLERANCE),
             Err("Asset balance missing from BTreeMap"),
         );
     }
@@ -378,7 +398,8 @@ mod tests {
     ) {
         let pool = construct_pool(None, weights);
         let balances = collect_balances_into_map(pool.assets.clone(), balances);
-        let (amount, _) = pool.calc_arbitrage_amount_mint_sell(&balances, 30).unwrap();
+        let (amount, _) =
+            pool.calc_arbitrage_amount_mint_sell(&balances, 30, ARBITRAGE_TOLERANCE).unwrap();
         assert_eq!(amount, expected);
     }
 
@@ -397,7 +418,8 @@ mod tests {
     ) {
         let pool = construct_pool(None, weights);
         let mut balances = collect_balances_into_map(pool.assets.clone(), balances);
-        let (amount, _) = pool.calc_arbitrage_amount_mint_sell(&balances, 30).unwrap();
+        let (amount, _) =
+            pool.calc_arbitrage_amount_mint_sell(&balances, 30, ARBITRAGE_TOLERANCE).unwrap();
         *balances.get_mut(&pool.assets[0]).unwrap() -= amount;
         for asset in &pool.assets[1..] {
             *balances.get_mut(asset).unwrap() += amount;
@@ -413,7 +435,7 @@ mod tests {
         let pool = construct_pool(None, vec![_3, _1, _1, _1]);
         let balances = collect_balances_into_map(pool.assets[..2].into(), vec![_1; 3]);
         assert_eq!(
-            pool.calc_arbitrage_amount_mint_sell(&balances, usize::MAX),
+            pool.calc_arbitrage_amount_mint_sell(&balances, usize::MAX, ARBITRAGE_TOLERANCE),
             Err("Asset balance missing from BTreeMap"),
         );
     }
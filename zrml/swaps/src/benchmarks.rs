@@ -34,15 +34,16 @@ use frame_support::{dispatch::UnfilteredDispatchable, traits::Get, weights::Weig
 use frame_system::RawOrigin;
 use orml_traits::MultiCurrency;
 use sp_runtime::{
-    traits::{SaturatedConversion, Zero},
+    traits::{SaturatedConversion, Saturating, Zero},
     DispatchError,
 };
 use zeitgeist_primitives::{
     constants::{BASE, CENT},
     traits::Swaps as _,
     types::{
-        Asset, Deadlines, Market, MarketBonds, MarketCreation, MarketDisputeMechanism,
-        MarketPeriod, MarketStatus, MarketType, OutcomeReport, PoolId, PoolStatus, ScoringRule,
+        ArbitrageConfig, Asset, Deadlines, Market, MarketBonds, MarketCreation,
+        MarketDisputeMechanism, MarketPeriod, MarketStatus, MarketType, OutcomeReport, PoolId,
+        PoolStatus, ScoringRule,
     },
 };
 use zrml_market_commons::MarketCommonsPalletApi;
@@ -136,6 +137,58 @@ fn initialize_pool<T: Config>(
 
     (pool_id, base_asset, assets, market_id)
 }
+// Creates an active LMSR pool containing `asset_count` assets, the last of which is the base
+// asset. Returns `PoolId`, `Vec<Asset<...>>`
+fn bench_create_pool_lmsr<T: Config>(
+    caller: &T::AccountId,
+    asset_count: usize,
+) -> (PoolId, Vec<Asset<MarketIdOf<T>>>) {
+    let amount = T::MinLiquidity::get();
+    let assets = generate_assets::<T>(caller, asset_count, Some(amount));
+    let base_asset = *assets.last().unwrap();
+    let pool_id = Pallet::<T>::create_pool(
+        caller.clone(),
+        assets.clone(),
+        base_asset,
+        0u8.into(),
+        ScoringRule::LMSR { liquidity_parameter: amount.saturated_into() },
+        Some(T::MaxSwapFee::get()),
+        Some(amount),
+        None,
+    )
+    .unwrap();
+    Pallet::<T>::open_pool(pool_id).unwrap();
+    T::AssetManager::deposit(base_asset, caller, amount).unwrap();
+    (pool_id, assets)
+}
+
+// Pushes an active CPMM market created by `creator` to the storage.
+fn push_market_with_creator<T: Config>(
+    creator: T::AccountId,
+) -> Result<MarketIdOf<T>, DispatchError> {
+    T::MarketCommons::push_market(Market {
+        base_asset: Asset::Ztg,
+        creation: MarketCreation::Permissionless,
+        creator_fee: 0,
+        creator: creator.clone(),
+        market_type: MarketType::Categorical(2),
+        dispute_mechanism: MarketDisputeMechanism::Authorized,
+        metadata: vec![0; 50],
+        oracle: creator,
+        period: MarketPeriod::Block(0u32.into()..1u32.into()),
+        deadlines: Deadlines {
+            grace_period: 1_u32.into(),
+            oracle_duration: 1_u32.into(),
+            dispute_duration: 1_u32.into(),
+        },
+        report: None,
+        resolved_outcome: None,
+        scoring_rule: ScoringRule::CPMM,
+        status: MarketStatus::Active,
+        bonds: MarketBonds::default(),
+    })
+}
+
 // Creates a pool containing `asset_count` (default: max assets) assets.
 // Returns `PoolId`, `Vec<Asset<...>>`, ``MarketId`
 fn bench_create_pool<T: Config>(
@@ -165,6 +218,24 @@ fn bench_create_pool<T: Config>(
     (pool_id, assets, market_id)
 }
 
+// Sets the swap fee of `pool_id` to the maximum and funds the treasury with `asset_in`, so that
+// both the market creator (which is expected to own `asset_in`) and the treasury receive their
+// share of the swap fee.
+fn enable_swap_fee_distribution<T: Config>(pool_id: PoolId, asset_in: Asset<MarketIdOf<T>>) {
+    Pallet::<T>::mutate_pool(pool_id, |pool| {
+        pool.swap_fee = Some(T::MaxSwapFee::get());
+        Ok(())
+    })
+    .unwrap();
+    let treasury_account = Pallet::<T>::treasury_account_id();
+    T::AssetManager::deposit(
+        asset_in,
+        &treasury_account,
+        T::AssetManager::minimum_balance(asset_in),
+    )
+    .unwrap();
+}
+
 benchmarks! {
     admin_clean_up_pool_cpmm_categorical {
         // We're excluding the case of two assets, which would leave us with only one outcome
@@ -305,7 +376,7 @@ benchmarks! {
             PoolsCachedForArbitrage::<T>::insert(pool_id, ());
         }
         let mutation =
-            |pool_id: PoolId| Pallet::<T>::execute_arbitrage(pool_id, ARBITRAGE_MAX_ITERATIONS);
+            |pool_id: PoolId| Pallet::<T>::do_execute_arbitrage(pool_id, None);
     }: {
         Pallet::<T>::apply_to_cached_pools(a, mutation, Weight::MAX)
     } verify {
@@ -469,7 +540,7 @@ benchmarks! {
         .unwrap();
     }: {
         // In order to cap the number of iterations, we just set the `max_iterations` to `b`.
-        Pallet::<T>::execute_arbitrage(pool_id, ARBITRAGE_MAX_ITERATIONS)?
+        Pallet::<T>::do_execute_arbitrage(pool_id, None)?
     } verify {
         // We don't care about the exact arbitrage amount and just want to verify that the correct
         // event was emitted.
@@ -519,7 +590,7 @@ benchmarks! {
         let balance_before = T::AssetManager::free_balance(asset, &pool_account_id);
     }: {
         // In order to cap the number of iterations, we just set the `max_iterations` to `b`.
-        Pallet::<T>::execute_arbitrage(pool_id, ARBITRAGE_MAX_ITERATIONS)?
+        Pallet::<T>::do_execute_arbitrage(pool_id, None)?
     } verify {
         // We don't care about the exact arbitrage amount and just want to verify that the correct
         // event was emitted.
@@ -557,7 +628,7 @@ benchmarks! {
         .unwrap();
     }: {
         // In order to cap the number of iterations, we just set the `max_iterations` to `b`.
-        Pallet::<T>::execute_arbitrage(pool_id, ARBITRAGE_MAX_ITERATIONS)?
+        Pallet::<T>::do_execute_arbitrage(pool_id, None)?
     } verify {
         assert_last_event::<T>(Event::ArbitrageSkipped::<T>(pool_id).into());
     }
@@ -729,6 +800,8 @@ benchmarks! {
         let mut weights = vec![weight_in; asset_count as usize];
         weights[asset_count as usize - 1] = weight_out;
         let caller: T::AccountId = whitelisted_caller();
+        // `bench_create_pool` uses the id of the first market.
+        push_market_with_creator::<T>(caller.clone())?;
         let (pool_id, assets, ..) = bench_create_pool::<T>(
             caller.clone(),
             Some(asset_count as usize),
@@ -739,6 +812,7 @@ benchmarks! {
         );
         let asset_in = assets[0];
         T::AssetManager::deposit(asset_in, &caller, u64::MAX.saturated_into()).unwrap();
+        enable_swap_fee_distribution::<T>(pool_id, asset_in);
         let asset_out = assets[asset_count as usize - 1];
         let min_asset_amount_out: Option<BalanceOf<T>> = Some(0u128.saturated_into());
         let max_price = Some(u128::MAX.saturated_into());
@@ -752,6 +826,50 @@ benchmarks! {
         max_price
     )
 
+    swap_exact_amount_in_cpmm_dynamic_fee {
+        // Same parameters as `swap_exact_amount_in_cpmm`, but the pool is in dynamic fee mode and
+        // both assets are outcomes, so the fee is read from the Rikiddo instance of the pool and
+        // the volume is valued at the spot price of `asset_in` before it's written back.
+        let asset_count = T::MaxAssets::get();
+        let balance: BalanceOf<T> = T::MinLiquidity::get();
+        let asset_amount_in: BalanceOf<T> = bmul(
+            balance.saturated_into(),
+            T::MaxInRatio::get().saturated_into(),
+        )
+        .unwrap()
+        .saturated_into();
+        let weight_in = T::MinWeight::get();
+        let weight_out = 2 * weight_in;
+        let mut weights = vec![weight_in; asset_count as usize];
+        weights[asset_count as usize - 2] = weight_out;
+        let caller: T::AccountId = whitelisted_caller();
+        // `bench_create_pool` uses the id of the first market.
+        push_market_with_creator::<T>(caller.clone())?;
+        let (pool_id, assets, ..) = bench_create_pool::<T>(
+            caller.clone(),
+            Some(asset_count as usize),
+            Some(balance),
+            ScoringRule::CPMM,
+            false,
+            Some(weights),
+        );
+        Pallet::<T>::set_dynamic_swap_fee(RawOrigin::Signed(caller.clone()).into(), pool_id, true)?;
+        let asset_in = assets[0];
+        T::AssetManager::deposit(asset_in, &caller, u64::MAX.saturated_into()).unwrap();
+        enable_swap_fee_distribution::<T>(pool_id, asset_in);
+        let asset_out = assets[asset_count as usize - 2];
+        let min_asset_amount_out: Option<BalanceOf<T>> = Some(0u128.saturated_into());
+        let max_price = Some(u128::MAX.saturated_into());
+    }: swap_exact_amount_in(
+        RawOrigin::Signed(caller),
+        pool_id,
+        asset_in,
+        asset_amount_in,
+        asset_out,
+        min_asset_amount_out,
+        max_price
+    )
+
     swap_exact_amount_in_rikiddo {
         let a in 3 .. T::MaxAssets::get().into();
         let caller: T::AccountId = whitelisted_caller();
@@ -795,6 +913,8 @@ benchmarks! {
         let mut weights = vec![weight_out; asset_count as usize];
         weights[0] = weight_in;
         let caller: T::AccountId = whitelisted_caller();
+        // `bench_create_pool` uses the id of the first market.
+        push_market_with_creator::<T>(caller.clone())?;
         let (pool_id, assets, ..) = bench_create_pool::<T>(
             caller.clone(),
             Some(asset_count as usize),
@@ -805,6 +925,7 @@ benchmarks! {
         );
         let asset_in = assets[0];
         T::AssetManager::deposit(asset_in, &caller, u64::MAX.saturated_into()).unwrap();
+        enable_swap_fee_distribution::<T>(pool_id, asset_in);
         let asset_out = assets[asset_count as usize - 1];
         let max_asset_amount_in: Option<BalanceOf<T>> = Some(u128::MAX.saturated_into());
         let max_price = Some(u128::MAX.saturated_into());
@@ -818,6 +939,49 @@ benchmarks! {
         max_price
     )
 
+    swap_exact_amount_out_cpmm_dynamic_fee {
+        // Same parameters as `swap_exact_amount_out_cpmm`, but the pool is in dynamic fee mode and
+        // both assets are outcomes (see `swap_exact_amount_in_cpmm_dynamic_fee`).
+        let asset_count = T::MaxAssets::get();
+        let balance: BalanceOf<T> = T::MinLiquidity::get();
+        let asset_amount_out: BalanceOf<T> = bmul(
+            balance.saturated_into(),
+            T::MaxOutRatio::get().saturated_into(),
+        )
+        .unwrap()
+        .saturated_into();
+        let weight_out = T::MinWeight::get();
+        let weight_in = 4 * weight_out;
+        let mut weights = vec![weight_out; asset_count as usize];
+        weights[0] = weight_in;
+        let caller: T::AccountId = whitelisted_caller();
+        // `bench_create_pool` uses the id of the first market.
+        push_market_with_creator::<T>(caller.clone())?;
+        let (pool_id, assets, ..) = bench_create_pool::<T>(
+            caller.clone(),
+            Some(asset_count as usize),
+            Some(balance),
+            ScoringRule::CPMM,
+            false,
+            Some(weights),
+        );
+        Pallet::<T>::set_dynamic_swap_fee(RawOrigin::Signed(caller.clone()).into(), pool_id, true)?;
+        let asset_in = assets[0];
+        T::AssetManager::deposit(asset_in, &caller, u64::MAX.saturated_into()).unwrap();
+        enable_swap_fee_distribution::<T>(pool_id, asset_in);
+        let asset_out = assets[asset_count as usize - 2];
+        let max_asset_amount_in: Option<BalanceOf<T>> = Some(u128::MAX.saturated_into());
+        let max_price = Some(u128::MAX.saturated_into());
+    }: swap_exact_amount_out(
+        RawOrigin::Signed(caller),
+        pool_id,
+        asset_in,
+        max_asset_amount_in,
+        asset_out,
+        asset_amount_out,
+        max_price
+    )
+
     swap_exact_amount_out_rikiddo {
         let a in 3 .. T::MaxAssets::get().into();
         let caller: T::AccountId = whitelisted_caller();
@@ -842,6 +1006,65 @@ benchmarks! {
         max_price
     )
 
+    swap_exact_amount_in_lmsr {
+        let a in 2 .. T::MaxAssets::get().into();
+        let caller: T::AccountId = whitelisted_caller();
+        let (pool_id, assets) = bench_create_pool_lmsr::<T>(&caller, a as usize);
+        let asset_amount_in: BalanceOf<T> = BASE.saturated_into();
+        let min_asset_amount_out: Option<BalanceOf<T>> = Some(0u32.into());
+        let max_price = Some(u128::MAX.saturated_into());
+    }: swap_exact_amount_in(
+        RawOrigin::Signed(caller),
+        pool_id,
+        *assets.last().unwrap(),
+        asset_amount_in,
+        assets[0],
+        min_asset_amount_out,
+        max_price
+    )
+
+    swap_exact_amount_out_lmsr {
+        let a in 2 .. T::MaxAssets::get().into();
+        let caller: T::AccountId = whitelisted_caller();
+        let (pool_id, assets) = bench_create_pool_lmsr::<T>(&caller, a as usize);
+        let max_asset_amount_in: Option<BalanceOf<T>> = Some(u128::MAX.saturated_into());
+        let asset_amount_out: BalanceOf<T> = BASE.saturated_into();
+        let max_price = Some(u128::MAX.saturated_into());
+    }: swap_exact_amount_out(
+        RawOrigin::Signed(caller),
+        pool_id,
+        *assets.last().unwrap(),
+        max_asset_amount_in,
+        assets[0],
+        asset_amount_out,
+        max_price
+    )
+
+    swap_exact_amount_in_routed {
+        // Equal weights and balances make the outcomes expensive, so buying an outcome with the
+        // base asset is routed through complete sets, which requires a swap per outcome.
+        let a in 3 .. T::MaxAssets::get().into();
+        let caller: T::AccountId = whitelisted_caller();
+        let (pool_id, assets, ..) = bench_create_pool::<T>(
+            caller.clone(),
+            Some(a as usize),
+            None,
+            ScoringRule::CPMM,
+            false,
+            None,
+        );
+        let base_asset = *assets.last().unwrap();
+        let asset_amount_in: BalanceOf<T> = BASE.saturated_into();
+        T::AssetManager::deposit(base_asset, &caller, asset_amount_in).unwrap();
+    }: swap_exact_amount_in_routed(
+        RawOrigin::Signed(caller),
+        pool_id,
+        base_asset,
+        asset_amount_in,
+        assets[0],
+        0u32.into()
+    )
+
     open_pool {
         let a in 2..T::MaxAssets::get().into();
 
@@ -905,6 +1128,112 @@ benchmarks! {
         ).into());
     }
 
+    set_dynamic_swap_fee {
+        let caller: T::AccountId = whitelisted_caller();
+        // `bench_create_pool` uses the id of the first market.
+        push_market_with_creator::<T>(caller.clone())?;
+        let (pool_id, ..) = bench_create_pool::<T>(
+            caller.clone(),
+            Some(3),
+            None,
+            ScoringRule::CPMM,
+            false,
+            None,
+        );
+    }: _(RawOrigin::Signed(caller), pool_id, true)
+    verify {
+        assert!(DynamicSwapFees::<T>::contains_key(pool_id));
+        assert_last_event::<T>(Event::DynamicSwapFeeEnabled::<T>(pool_id).into());
+    }
+
+    set_weight_schedule {
+        let a in 2..T::MaxAssets::get().into();
+        let caller: T::AccountId = whitelisted_caller();
+        // `bench_create_pool` uses the id of the first market.
+        push_market_with_creator::<T>(caller.clone())?;
+        // The pool must not be opened.
+        let (pool_id, ..) =
+            initialize_pool::<T>(&caller, Some(a as usize), None, ScoringRule::CPMM, None);
+        let start = frame_system::Pallet::<T>::block_number();
+        let end = start.saturating_add(100u32.into());
+        let end_weights = vec![T::MinWeight::get(); a as usize];
+    }: _(RawOrigin::Signed(caller), pool_id, start, end, end_weights)
+    verify {
+        assert!(WeightSchedules::<T>::contains_key(pool_id));
+        assert_last_event::<T>(Event::WeightScheduleSet::<T>(pool_id, start, end).into());
+    }
+
+    set_arbitrage_config {
+        let caller: T::AccountId = whitelisted_caller();
+        // `bench_create_pool` uses the id of the first market.
+        push_market_with_creator::<T>(caller.clone())?;
+        let (pool_id, ..) = bench_create_pool::<T>(
+            caller.clone(),
+            Some(T::MaxAssets::get().into()),
+            None,
+            ScoringRule::CPMM,
+            false,
+            None,
+        );
+        let tolerance = CENT;
+        let max_iterations = ARBITRAGE_MAX_ITERATIONS as u16;
+    }: _(RawOrigin::Signed(caller), pool_id, tolerance, max_iterations)
+    verify {
+        let arbitrage_config = ArbitrageConfig { tolerance, max_iterations };
+        assert_last_event::<T>(Event::ArbitrageConfigSet::<T>(pool_id, arbitrage_config).into());
+    }
+
+    execute_arbitrage {
+        let a in 2..T::MaxAssets::get().into(); // The number of assets in the pool.
+        let asset_count = a as usize;
+
+        let caller: T::AccountId = whitelisted_caller();
+        let balance: BalanceOf<T> = (10_000_000_000 * BASE).saturated_into();
+        let assets = generate_assets::<T>(&caller, asset_count, Some(balance));
+        let base_asset = *assets.last().unwrap();
+
+        // Set weights to [1, 1, ..., 1, a].
+        let outcome_count = asset_count - 1;
+        let outcome_weight = T::MinWeight::get();
+        let mut weights = vec![outcome_weight; outcome_count];
+        weights.push(outcome_count as u128 * outcome_weight);
+
+        // Create a pool with huge balances and only a relatively small difference between them to
+        // cause at least 30 iterations of buy-burn arbitrage.
+        let market_id = 0u8.into();
+        let pool_id = Pallet::<T>::create_pool(
+            caller.clone(),
+            assets.clone(),
+            base_asset,
+            market_id,
+            ScoringRule::CPMM,
+            Some(Zero::zero()),
+            Some(balance),
+            Some(weights),
+        )
+        .unwrap();
+        Pallet::<T>::open_pool(pool_id)?;
+        let pool_account_id = Pallet::<T>::pool_account_id(&pool_id);
+        T::AssetManager::withdraw(base_asset, &pool_account_id, balance / 9u8.saturated_into())
+            .unwrap();
+        PoolsCachedForArbitrage::<T>::insert(pool_id, ());
+
+        // Add enough funds for arbitrage to the prize pool.
+        T::AssetManager::deposit(
+            base_asset,
+            &T::MarketCommons::market_account(market_id),
+            (u128::MAX / 2).saturated_into(),
+        )
+        .unwrap();
+        // Fund the bounty account so that the keeper is rewarded.
+        T::AssetManager::deposit(base_asset, &Pallet::<T>::arbitrage_bounty_account_id(), balance)
+            .unwrap();
+        let keeper: T::AccountId = account("keeper", 0, 0);
+    }: _(RawOrigin::Signed(keeper), pool_id)
+    verify {
+        assert!(!PoolsCachedForArbitrage::<T>::contains_key(pool_id));
+    }
+
     impl_benchmark_test_suite!(
         Swaps,
         crate::mock::ExtBuilder::default().build(),
@@ -22,7 +22,7 @@
 // <https://github.com/balancer-labs/balancer-core>.
 
 use alloc::vec::Vec;
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use zeitgeist_primitives::types::PoolId;
 
@@ -62,3 +62,28 @@ pub struct SwapEvent<AI, AS, B> {
     pub cpep: CommonPoolEventParams<AI>,
     pub max_price: Option<B>,
 }
+
+/// The path taken by a routed swap.
+#[derive(
+    Clone, Copy, Debug, Decode, Encode, Eq, MaxEncodedLen, Ord, PartialEq, PartialOrd, TypeInfo,
+)]
+pub enum SwapRoute {
+    /// The ingoing asset was swapped for the outgoing asset.
+    Direct,
+    /// Complete sets were bought with the base asset and all other outcomes were swapped for the
+    /// outgoing outcome.
+    MintSell,
+    /// The ingoing outcome was swapped for all other outcomes and complete sets were sold for the
+    /// base asset.
+    BuyBurn,
+}
+
+#[derive(Clone, Debug, Decode, Encode, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct SwapRoutedEvent<AI, AS, B> {
+    pub asset_amount_in: B,
+    pub asset_amount_out: B,
+    pub asset_in: AS,
+    pub asset_out: AS,
+    pub cpep: CommonPoolEventParams<AI>,
+    pub route: SwapRoute,
+}
@@ -34,12 +34,14 @@ mod arbitrage;
 mod benchmarks;
 pub mod check_arithm_rslt;
 mod consts;
-mod events;
+pub mod events;
 pub mod fixed;
 pub mod math;
+pub mod math_lmsr;
 pub mod migrations;
 pub mod mock;
 mod root;
+mod router;
 mod tests;
 pub mod weights;
 
@@ -50,8 +52,12 @@ mod pallet {
     use crate::{
         arbitrage::ArbitrageForCpmm,
         check_arithm_rslt::CheckArithmRslt,
-        events::{CommonPoolEventParams, PoolAssetEvent, PoolAssetsEvent, SwapEvent},
+        events::{
+            CommonPoolEventParams, PoolAssetEvent, PoolAssetsEvent, SwapEvent, SwapRoute,
+            SwapRoutedEvent,
+        },
         fixed::{bdiv, bmul},
+        router::RouterForCpmm,
         utils::{
             pool_exit_with_exact_amount, pool_join_with_exact_amount, swap_exact_amount,
             PoolExitWithExactAmountParams, PoolJoinWithExactAmountParams, PoolParams,
@@ -74,7 +80,7 @@ mod pallet {
     use parity_scale_codec::{Decode, Encode};
     use sp_runtime::{
         traits::{AccountIdConversion, CheckedSub, Saturating, Zero},
-        ArithmeticError, DispatchError, DispatchResult, SaturatedConversion,
+        ArithmeticError, DispatchError, DispatchResult, Perbill, SaturatedConversion,
     };
     use substrate_fixed::{
         traits::{FixedSigned, FixedUnsigned, LossyFrom},
@@ -88,8 +94,8 @@ mod pallet {
         constants::{BASE, CENT},
         traits::{Swaps, ZeitgeistAssetManager},
         types::{
-            Asset, MarketType, OutcomeReport, Pool, PoolId, PoolStatus, ResultWithWeightInfo,
-            ScoringRule, SerdeWrapper,
+            ArbitrageConfig, Asset, MarketType, OutcomeReport, Pool, PoolId, PoolStatus,
+            ResultWithWeightInfo, ScoringRule, SerdeWrapper, TradeQuote, WeightSchedule,
         },
     };
     use zrml_liquidity_mining::LiquidityMiningPalletApi;
@@ -111,8 +117,10 @@ mod pallet {
 
     pub(crate) const ARBITRAGE_MAX_ITERATIONS: usize = 30;
     const ARBITRAGE_THRESHOLD: u128 = CENT;
+    pub(crate) const ARBITRAGE_TOLERANCE: u128 = BASE / 1_000; // 0.001
     const MIN_BALANCE: u128 = CENT;
     const ON_IDLE_MIN_WEIGHT: Weight = Weight::from_ref_time(1_000_000);
+    pub(crate) const ROUTER_MAX_ITERATIONS: usize = 30;
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -194,39 +202,13 @@ mod pallet {
             min_assets_out: Vec<BalanceOf<T>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(pool_amount != Zero::zero(), Error::<T>::ZeroAmount);
-            let who_clone = who.clone();
-            let pool = Self::pool_by_id(pool_id)?;
-            // If the pool is still in use, prevent a pool drain.
-            Self::ensure_minimum_liquidity_shares(pool_id, &pool, pool_amount)?;
-            let pool_account_id = Pallet::<T>::pool_account_id(&pool_id);
-            let params = PoolParams {
-                asset_bounds: min_assets_out,
-                event: |evt| Self::deposit_event(Event::PoolExit(evt)),
-                pool_account_id: &pool_account_id,
-                pool_amount,
+            let _ = <Self as Swaps<T::AccountId>>::pool_exit(
+                who,
                 pool_id,
-                pool: &pool,
-                transfer_asset: |amount, amount_bound, asset| {
-                    Self::ensure_minimum_balance(pool_id, &pool, asset, amount)?;
-                    ensure!(amount >= amount_bound, Error::<T>::LimitOut);
-                    T::LiquidityMining::remove_shares(&who, &pool.market_id, amount);
-                    T::AssetManager::transfer(asset, &pool_account_id, &who, amount)?;
-                    Ok(())
-                },
-                transfer_pool: || {
-                    Self::burn_pool_shares(pool_id, &who, pool_amount)?;
-                    Ok(())
-                },
-                fee: |amount: BalanceOf<T>| {
-                    let exit_fee_amount =
-                        bmul(amount.saturated_into(), Self::calc_exit_fee(&pool).saturated_into())?
-                            .saturated_into();
-                    Ok(exit_fee_amount)
-                },
-                who: who_clone,
-            };
-            crate::utils::pool::<_, _, _, _, T>(params)
+                pool_amount,
+                min_assets_out,
+            )?;
+            Ok(())
         }
 
         /// Pool - Remove subsidty from a pool that uses the Rikiddo scoring rule.
@@ -466,34 +448,9 @@ mod pallet {
             max_assets_in: Vec<BalanceOf<T>>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            ensure!(pool_amount != Zero::zero(), Error::<T>::ZeroAmount);
-            let pool = Self::pool_by_id(pool_id)?;
-            ensure!(
-                matches!(pool.pool_status, PoolStatus::Initialized | PoolStatus::Active),
-                Error::<T>::InvalidPoolStatus,
-            );
-            let pool_account_id = Pallet::<T>::pool_account_id(&pool_id);
-
-            let params = PoolParams {
-                asset_bounds: max_assets_in,
-                event: |evt| Self::deposit_event(Event::PoolJoin(evt)),
-                pool_account_id: &pool_account_id,
-                pool_amount,
-                pool_id,
-                pool: &pool,
-                transfer_asset: |amount, amount_bound, asset| {
-                    ensure!(amount <= amount_bound, Error::<T>::LimitIn);
-                    T::AssetManager::transfer(asset, &who, &pool_account_id, amount)?;
-                    T::LiquidityMining::add_shares(who.clone(), pool.market_id, amount);
-                    Ok(())
-                },
-                transfer_pool: || Self::mint_pool_shares(pool_id, &who, pool_amount),
-                fee: |_| Ok(0u128.saturated_into()),
-                who: who.clone(),
-            };
-
-            crate::utils::pool::<_, _, _, _, T>(params)?;
-            Ok(Some(T::WeightInfo::pool_join(pool.assets.len().saturated_into())).into())
+            let weight =
+                <Self as Swaps<T::AccountId>>::pool_join(who, pool_id, pool_amount, max_assets_in)?;
+            Ok(Some(weight).into())
         }
 
         /// Pool - Add subsidy to a pool that uses the Rikiddo scoring rule.
@@ -684,9 +641,12 @@ mod pallet {
         /// # Weight
         ///
         /// Complexity: `O(1)` if the scoring rule is CPMM, `O(n)` where `n` is the amount of
-        /// assets if the scoring rule is Rikiddo.
+        /// assets if the scoring rule is Rikiddo or LMSR.
         // TODO(#790): Replace with maximum of CPMM and Rikiddo benchmark!
-        #[pallet::weight(T::WeightInfo::swap_exact_amount_in_cpmm())]
+        #[pallet::weight(
+            T::WeightInfo::swap_exact_amount_in_cpmm_dynamic_fee()
+                .max(T::WeightInfo::swap_exact_amount_in_lmsr(T::MaxAssets::get().into()))
+        )]
         #[transactional]
         pub fn swap_exact_amount_in(
             origin: OriginFor<T>,
@@ -727,9 +687,12 @@ mod pallet {
         /// # Weight
         ///
         /// Complexity: `O(1)` if the scoring rule is CPMM, `O(n)` where `n` is the amount of
-        /// assets if the scoring rule is Rikiddo.
+        /// assets if the scoring rule is Rikiddo or LMSR.
         // TODO(#790): Replace with maximum of CPMM and Rikiddo benchmark!
-        #[pallet::weight(T::WeightInfo::swap_exact_amount_out_cpmm())]
+        #[pallet::weight(
+            T::WeightInfo::swap_exact_amount_out_cpmm_dynamic_fee()
+                .max(T::WeightInfo::swap_exact_amount_out_lmsr(T::MaxAssets::get().into()))
+        )]
         #[transactional]
         pub fn swap_exact_amount_out(
             origin: OriginFor<T>,
@@ -752,12 +715,280 @@ mod pallet {
             )?;
             Ok(Some(weight).into())
         }
+
+        /// Swap - Exact amount in with routing
+        ///
+        /// Swaps a given `asset_amount_in` of `asset_in` for `asset_out` using the route which
+        /// yields the largest amount of `asset_out`. On CPMM pools, buying an outcome with the base
+        /// asset may instead buy complete sets and swap all other outcomes for `asset_out`, and
+        /// selling an outcome for the base asset may instead swap `asset_in` for all other
+        /// outcomes and sell complete sets. Any part of `asset_amount_in` which isn't required to
+        /// sell complete sets remains with `origin`.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin`: The account whose assets should be transferred.
+        /// * `pool_id`: Unique pool identifier.
+        /// * `asset_in`: Asset entering the pool.
+        /// * `asset_amount_in`: Maximum amount that will be transferred from `origin`.
+        /// * `asset_out`: Asset leaving the pool.
+        /// * `min_asset_amount_out`: Minimum amount of `asset_out` that `origin` receives.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the amount of assets in the pool.
+        #[pallet::weight(
+            Pallet::<T>::swap_exact_amount_in_routed_weight(T::MaxAssets::get().into(), true)
+                .max(T::WeightInfo::swap_exact_amount_in_lmsr(T::MaxAssets::get().into()))
+        )]
+        #[transactional]
+        pub fn swap_exact_amount_in_routed(
+            origin: OriginFor<T>,
+            #[pallet::compact] pool_id: PoolId,
+            asset_in: Asset<MarketIdOf<T>>,
+            #[pallet::compact] asset_amount_in: BalanceOf<T>,
+            asset_out: Asset<MarketIdOf<T>>,
+            #[pallet::compact] min_asset_amount_out: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let weight = Self::do_swap_exact_amount_in_routed(
+                who,
+                pool_id,
+                asset_in,
+                asset_amount_in,
+                asset_out,
+                min_asset_amount_out,
+            )?;
+            Ok(Some(weight).into())
+        }
+
+        /// Enable or disable the dynamic fee mode of a CPMM pool.
+        ///
+        /// In dynamic fee mode, the swap fee rises with the recent trade volume of the pool, as
+        /// measured by the short and long EMA of the volume and mapped to a fee by the fee
+        /// sigmoid of `zrml_rikiddo`. The fee specified at pool creation acts as lower bound of
+        /// the dynamic fee, and `MaxSwapFee` as upper bound.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin`: The creator of the market that the pool belongs to.
+        /// * `pool_id`: Unique pool identifier.
+        /// * `enabled`: Whether to enable or to disable the dynamic fee mode.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::weight(T::WeightInfo::set_dynamic_swap_fee())]
+        #[transactional]
+        pub fn set_dynamic_swap_fee(
+            origin: OriginFor<T>,
+            #[pallet::compact] pool_id: PoolId,
+            enabled: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let pool = Self::pool_by_id(pool_id)?;
+            ensure!(pool.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+            ensure!(
+                matches!(pool.pool_status, PoolStatus::Initialized | PoolStatus::Active),
+                Error::<T>::InvalidPoolStatus
+            );
+            let market = T::MarketCommons::market(&pool.market_id)?;
+            ensure!(market.creator == who, Error::<T>::NotMarketCreator);
+
+            if enabled {
+                ensure!(
+                    !<DynamicSwapFees<T>>::contains_key(pool_id),
+                    Error::<T>::DynamicSwapFeeAlreadyEnabled
+                );
+                let mut rikiddo_instance: RikiddoSigmoidMV<
+                    T::FixedTypeU,
+                    T::FixedTypeS,
+                    FeeSigmoid<T::FixedTypeS>,
+                    EmaMarketVolume<T::FixedTypeU>,
+                > = Default::default();
+                rikiddo_instance.ma_short.config.ema_period = EMA_SHORT;
+                rikiddo_instance.ma_long.config.ema_period = EMA_LONG;
+                rikiddo_instance.ma_long.config.ema_period_estimate_after = Some(EMA_SHORT);
+                T::RikiddoSigmoidFeeMarketEma::create(pool_id, rikiddo_instance)?;
+                <DynamicSwapFees<T>>::insert(pool_id, ());
+                Self::deposit_event(Event::DynamicSwapFeeEnabled(pool_id));
+            } else {
+                ensure!(
+                    <DynamicSwapFees<T>>::contains_key(pool_id),
+                    Error::<T>::DynamicSwapFeeNotEnabled
+                );
+                Self::remove_dynamic_swap_fee(pool_id)?;
+                Self::deposit_event(Event::DynamicSwapFeeDisabled(pool_id));
+            }
+
+            Ok(())
+        }
+
+        /// Set a schedule which moves the weights of a CPMM pool over time.
+        ///
+        /// Between the blocks `start` and `end`, the weights of the pool move linearly from the
+        /// weights set at pool creation to `end_weights`. Afterwards, the pool keeps trading with
+        /// `end_weights`. This allows a pool to open at the odds of the market creator and
+        /// drift toward neutral odds for price discovery, similar to a liquidity bootstrapping
+        /// pool.
+        ///
+        /// The schedule can only be set before the pool is opened, so that traders know the
+        /// weights of the pool in advance.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin`: The creator of the market that the pool belongs to.
+        /// * `pool_id`: Unique pool identifier.
+        /// * `start`: The block at which the weights start moving. Must not be in the past.
+        /// * `end`: The block at which the weights reach `end_weights`. Must be after `start`.
+        /// * `end_weights`: The final weights of the pool's assets, in the order of the assets of
+        /// the pool.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of assets in the pool.
+        #[pallet::weight(T::WeightInfo::set_weight_schedule(end_weights.len() as u32))]
+        #[transactional]
+        pub fn set_weight_schedule(
+            origin: OriginFor<T>,
+            #[pallet::compact] pool_id: PoolId,
+            start: T::BlockNumber,
+            end: T::BlockNumber,
+            end_weights: Vec<u128>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let pool = Self::pool_by_id(pool_id)?;
+            ensure!(pool.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+            ensure!(pool.pool_status == PoolStatus::Initialized, Error::<T>::InvalidPoolStatus);
+            let market = T::MarketCommons::market(&pool.market_id)?;
+            ensure!(market.creator == who, Error::<T>::NotMarketCreator);
+            ensure!(
+                !<WeightSchedules<T>>::contains_key(pool_id),
+                Error::<T>::WeightScheduleAlreadyExists
+            );
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(now <= start && start < end, Error::<T>::InvalidWeightSchedule);
+            Self::check_provided_values_len_must_equal_assets_len(&pool.assets, &end_weights)?;
+
+            let mut weights = BTreeMap::new();
+            let mut total_weight: u128 = 0;
+            for (asset, weight) in pool.assets.iter().copied().zip(end_weights) {
+                ensure!(weight >= T::MinWeight::get(), Error::<T>::BelowMinimumWeight);
+                ensure!(weight <= T::MaxWeight::get(), Error::<T>::AboveMaximumWeight);
+                weights.insert(asset, weight);
+                total_weight = total_weight.check_add_rslt(&weight)?;
+            }
+            ensure!(total_weight <= T::MaxTotalWeight::get(), Error::<T>::MaxTotalWeight);
+
+            let assets_len = pool.assets.len() as u32;
+            <WeightSchedules<T>>::insert(
+                pool_id,
+                WeightSchedule { start, end, end_weights: weights },
+            );
+            Self::deposit_event(Event::WeightScheduleSet(pool_id, start, end));
+            Ok(Some(T::WeightInfo::set_weight_schedule(assets_len)).into())
+        }
+
+        /// Set the parameters of the bisection method used to arbitrage a CPMM pool.
+        ///
+        /// Pools without parameters are arbitraged with a tolerance of `0.001` and at most 30
+        /// iterations.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin`: The creator of the market that the pool belongs to.
+        /// * `pool_id`: Unique pool identifier.
+        /// * `tolerance`: The maximum allowed distance of the total spot price from `1` after
+        /// arbitrage. Must be positive and must not exceed the arbitrage threshold of `0.01`.
+        /// * `max_iterations`: The maximum number of iterations of the bisection method. Must be
+        /// positive and must not exceed 30.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::weight(T::WeightInfo::set_arbitrage_config())]
+        #[transactional]
+        pub fn set_arbitrage_config(
+            origin: OriginFor<T>,
+            #[pallet::compact] pool_id: PoolId,
+            tolerance: u128,
+            max_iterations: u16,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let pool = Self::pool_by_id(pool_id)?;
+            ensure!(pool.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+            ensure!(
+                matches!(pool.pool_status, PoolStatus::Initialized | PoolStatus::Active),
+                Error::<T>::InvalidPoolStatus
+            );
+            let market = T::MarketCommons::market(&pool.market_id)?;
+            ensure!(market.creator == who, Error::<T>::NotMarketCreator);
+            ensure!(
+                tolerance != 0
+                    && tolerance <= ARBITRAGE_THRESHOLD
+                    && max_iterations != 0
+                    && usize::from(max_iterations) <= ARBITRAGE_MAX_ITERATIONS,
+                Error::<T>::InvalidArbitrageConfig
+            );
+
+            let arbitrage_config = ArbitrageConfig { tolerance, max_iterations };
+            <ArbitrageConfigs<T>>::insert(pool_id, arbitrage_config);
+            Self::deposit_event(Event::ArbitrageConfigSet(pool_id, arbitrage_config));
+            Ok(())
+        }
+
+        /// Execute arbitrage on a CPMM pool whose total spot price deviates from `1`.
+        ///
+        /// Anyone may call this function. The caller receives `ArbitrageKeeperShare` of the
+        /// estimated profit of the arbitrage in the base asset of the pool. The reward is paid from
+        /// the account derived from `ArbitrageBountyPalletId`, which is funded by governance, and
+        /// never from the reserves of the pool. The profit is estimated as the arbitrage amount
+        /// times half the distance of the total spot price from `1`, as the total spot price moves
+        /// linearly from its current value to about `1` during arbitrage.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin`: The keeper who executes the arbitrage.
+        /// * `pool_id`: Unique pool identifier.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of assets in the pool.
+        #[pallet::weight(T::WeightInfo::execute_arbitrage(T::MaxAssets::get().into()))]
+        #[transactional]
+        pub fn execute_arbitrage(
+            origin: OriginFor<T>,
+            #[pallet::compact] pool_id: PoolId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let pool = Self::pool_by_id(pool_id)?;
+            ensure!(pool.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+            Self::check_if_pool_is_active(&pool)?;
+            Self::do_execute_arbitrage(pool_id, Some(&who))?;
+            // The pool no longer needs to be arbitraged when idle.
+            PoolsCachedForArbitrage::<T>::remove(pool_id);
+            Ok(Some(T::WeightInfo::execute_arbitrage(pool.assets.len() as u32)).into())
+        }
     }
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
+        /// The pallet identifier of the account which pays the rewards of arbitrage keepers. The
+        /// account must be funded by governance.
+        #[pallet::constant]
+        type ArbitrageBountyPalletId: Get<PalletId>;
+
+        /// The share of the estimated arbitrage profit which is paid to the account that calls
+        /// `execute_arbitrage` from the account derived from `ArbitrageBountyPalletId`.
+        #[pallet::constant]
+        type ArbitrageKeeperShare: Get<Perbill>;
+
+        /// The share of the swap fees of CPMM pools which is paid to the creator of the market.
+        #[pallet::constant]
+        type CreatorSwapFeeShare: Get<Perbill>;
+
         /// The fee for exiting a pool.
         #[pallet::constant]
         type ExitFee: Get<BalanceOf<Self>>;
@@ -842,6 +1073,12 @@ mod pallet {
         #[pallet::constant]
         type PalletId: Get<PalletId>;
 
+        /// The share of the swap fees of CPMM pools which is paid to the treasury. The sum of
+        /// `CreatorSwapFeeShare` and `ProtocolSwapFeeShare` should not exceed 100%; if it does,
+        /// the protocol receives what's left after paying the market creator.
+        #[pallet::constant]
+        type ProtocolSwapFeeShare: Get<Perbill>;
+
         /// The Rikiddo instance that uses a sigmoid fee and ema of market volume
         type RikiddoSigmoidFeeMarketEma: RikiddoMVPallet<
             Balance = BalanceOf<Self>,
@@ -855,6 +1092,10 @@ mod pallet {
             >,
         >;
 
+        /// The treasury which receives the protocol's share of the swap fees.
+        #[pallet::constant]
+        type TreasuryPalletId: Get<PalletId>;
+
         /// Shares of outcome assets and native currency
         type AssetManager: ZeitgeistAssetManager<
             Self::AccountId,
@@ -875,6 +1116,10 @@ mod pallet {
         AssetNotInPool,
         /// The base asset of the swaps pool was None although a value was expected.
         BaseAssetNotFound,
+        /// The dynamic fee mode of the pool is already enabled.
+        DynamicSwapFeeAlreadyEnabled,
+        /// The dynamic fee mode of the pool is not enabled.
+        DynamicSwapFeeNotEnabled,
         /// The spot price of an asset pair was greater than the specified limit.
         BadLimitPrice,
         /// The weight of an asset in a CPMM swap pool is lower than the upper weight cap.
@@ -889,6 +1134,8 @@ mod pallet {
         InvalidAmountArgument,
         /// Could not create CPMM pool since no fee was supplied.
         InvalidFeeArgument,
+        /// The liquidity parameter of an LMSR pool is below the minimum liquidity.
+        InvalidLiquidityParameter,
         /// Dispatch called on pool with invalid status.
         InvalidPoolStatus,
         /// A function that is only valid for pools with specific scoring rules was called for a
@@ -896,14 +1143,23 @@ mod pallet {
         InvalidScoringRule,
         /// A function was called for a swaps pool that does not fulfill the state requirement.
         InvalidStateTransition,
+        /// The arbitrage tolerance must be positive and not exceed the arbitrage threshold, and
+        /// the number of iterations must be positive and not exceed the benchmarked maximum.
+        InvalidArbitrageConfig,
         /// Could not create CPMM pool since no weights were supplied.
         InvalidWeightArgument,
+        /// The weight schedule must start in the future and end after it starts.
+        InvalidWeightSchedule,
         /// A transferal of funds into a swaps pool was above a threshhold specified by the sender.
         LimitIn,
         /// Subsidy amount is too small.
         InvalidSubsidyAmount,
         /// No limit was specified for a swap.
         LimitMissing,
+        /// The sender is not the creator of the market that the pool belongs to.
+        NotMarketCreator,
+        /// The total spot price of the pool is close enough to `1` to skip arbitrage.
+        NoArbitrageOpportunity,
         /// A transferal of funds out of a swaps pool was below a threshhold specified by the
         /// receiver.
         LimitOut,
@@ -949,6 +1205,8 @@ mod pallet {
         SomeIdenticalAssets,
         /// The pool does not support swapping the assets in question.
         UnsupportedTrade,
+        /// The pool already has a weight schedule.
+        WeightScheduleAlreadyExists,
         /// The outcome asset specified as the winning asset was not found in the pool.
         WinningAssetNotFound,
         /// Some amount in a transaction equals zero.
@@ -963,12 +1221,21 @@ mod pallet {
     {
         /// Buy-burn arbitrage was executed on a CPMM pool. \[pool_id, amount\]
         ArbitrageBuyBurn(PoolId, BalanceOf<T>),
+        /// The arbitrage parameters of a CPMM pool were set. \[pool_id, arbitrage_config\]
+        ArbitrageConfigSet(PoolId, ArbitrageConfig),
+        /// A keeper executed arbitrage on a CPMM pool and was paid a share of the estimated
+        /// profit. \[pool_id, keeper, reward\]
+        ArbitrageKeeperRewarded(PoolId, <T as frame_system::Config>::AccountId, BalanceOf<T>),
         /// Mint-sell arbitrage was executed on a CPMM pool. \[pool_id, amount\]
         ArbitrageMintSell(PoolId, BalanceOf<T>),
         /// Arbitrage was skipped on a CPMM pool. \[pool_id\]
         ArbitrageSkipped(PoolId),
         /// Share holder rewards were distributed. \[pool_id, num_accounts_rewarded, amount\]
         DistributeShareHolderRewards(PoolId, u64, BalanceOf<T>),
+        /// The dynamic fee mode of a CPMM pool was disabled. \[pool_id\]
+        DynamicSwapFeeDisabled(PoolId),
+        /// The dynamic fee mode of a CPMM pool was enabled. \[pool_id\]
+        DynamicSwapFeeEnabled(PoolId),
         /// A new pool has been created. \[CommonPoolEventParams, pool, pool_amount, pool_account\]
         PoolCreate(
             CommonPoolEventParams<<T as frame_system::Config>::AccountId>,
@@ -1060,10 +1327,23 @@ mod pallet {
         SwapExactAmountIn(
             SwapEvent<<T as frame_system::Config>::AccountId, Asset<MarketIdOf<T>>, BalanceOf<T>>,
         ),
+        /// An exact amount of an asset was swapped along the best route. \[SwapRoutedEvent\]
+        SwapExactAmountInRouted(
+            SwapRoutedEvent<
+                <T as frame_system::Config>::AccountId,
+                Asset<MarketIdOf<T>>,
+                BalanceOf<T>,
+            >,
+        ),
         /// An exact amount of an asset is leaving the pool. \[SwapEvent\]
         SwapExactAmountOut(
             SwapEvent<<T as frame_system::Config>::AccountId, Asset<MarketIdOf<T>>, BalanceOf<T>>,
         ),
+        /// Parts of a swap fee were paid to the market creator and the treasury.
+        /// \[pool_id, asset, creator_fee, protocol_fee\]
+        SwapFeeDistributed(PoolId, Asset<MarketIdOf<T>>, BalanceOf<T>, BalanceOf<T>),
+        /// A weight schedule was set for a pool. \[pool_id, start, end\]
+        WeightScheduleSet(PoolId, T::BlockNumber, T::BlockNumber),
     }
 
     #[pallet::pallet]
@@ -1081,6 +1361,22 @@ mod pallet {
         ValueQuery,
     >;
 
+    /// Arbitrage parameters of CPMM pools which differ from the default parameters.
+    #[pallet::storage]
+    #[pallet::getter(fn arbitrage_configs)]
+    pub type ArbitrageConfigs<T: Config> = StorageMap<_, Twox64Concat, PoolId, ArbitrageConfig>;
+
+    /// CPMM pools whose swap fee is adjusted to the recent trade volume.
+    #[pallet::storage]
+    #[pallet::getter(fn dynamic_swap_fees)]
+    pub type DynamicSwapFees<T: Config> = StorageMap<_, Twox64Concat, PoolId, ()>;
+
+    /// CPMM pools whose weights move over time.
+    #[pallet::storage]
+    #[pallet::getter(fn weight_schedules)]
+    pub type WeightSchedules<T: Config> =
+        StorageMap<_, Twox64Concat, PoolId, WeightSchedule<T::BlockNumber, MarketIdOf<T>>>;
+
     #[pallet::storage]
     #[pallet::getter(fn pools_cached_for_arbitrage)]
     pub type PoolsCachedForArbitrage<T: Config> = StorageMap<_, Twox64Concat, PoolId, ()>;
@@ -1112,8 +1408,8 @@ mod pallet {
             winning_asset: Asset<MarketIdOf<T>>,
             winner_payout_account: &T::AccountId,
         ) -> Weight {
-            // CPMM handling of market profit not supported
-            if pool.scoring_rule == ScoringRule::CPMM {
+            // CPMM and LMSR handling of market profit not supported
+            if pool.scoring_rule != ScoringRule::RikiddoSigmoidFeeMarketEma {
                 return Weight::from_ref_time(1_000_000);
             }
 
@@ -1262,7 +1558,7 @@ mod pallet {
             }
             Self::apply_to_cached_pools(
                 pool_count.saturated_into(),
-                |pool_id| Self::execute_arbitrage(pool_id, ARBITRAGE_MAX_ITERATIONS),
+                |pool_id| Self::do_execute_arbitrage(pool_id, None),
                 extra_weight_per_pool,
             )
         }
@@ -1306,17 +1602,24 @@ mod pallet {
             total_weight
         }
 
-        /// Execute arbitrage on a single pool.
+        /// Execute arbitrage on a single pool using the pool's arbitrage parameters.
         ///
         /// Arguments:
         ///
         /// * `pool_id`: The id of the pool to arbitrage.
-        /// * `max_iterations`: The maximum number of iterations allowed in the bisection method.
-        pub(crate) fn execute_arbitrage(
+        /// * `keeper`: The account which receives a share of the estimated profit, if any. If
+        /// `keeper` is specified, skipping arbitrage is an error.
+        pub(crate) fn do_execute_arbitrage(
             pool_id: PoolId,
-            max_iterations: usize,
+            keeper: Option<&T::AccountId>,
         ) -> Result<Weight, DispatchError> {
             let pool = Self::pool_by_id(pool_id)?;
+            let ArbitrageConfig { tolerance, max_iterations } = <ArbitrageConfigs<T>>::get(pool_id)
+                .unwrap_or(ArbitrageConfig {
+                    tolerance: ARBITRAGE_TOLERANCE,
+                    max_iterations: ARBITRAGE_MAX_ITERATIONS as u16,
+                });
+            let max_iterations = usize::from(max_iterations);
             let pool_account = Self::pool_account_id(&pool_id);
             let balances = pool
                 .assets
@@ -1329,7 +1632,7 @@ mod pallet {
 
             if total_spot_price > BASE.saturating_add(ARBITRAGE_THRESHOLD) {
                 let (amount, _) =
-                    pool.calc_arbitrage_amount_mint_sell(&balances, max_iterations)?;
+                    pool.calc_arbitrage_amount_mint_sell(&balances, max_iterations, tolerance)?;
                 // Ensure that executing arbitrage doesn't decrease the base asset balance below
                 // the minimum allowed balance.
                 let balance_base_asset =
@@ -1349,9 +1652,14 @@ mod pallet {
                     T::AssetManager::deposit(*t, &pool_account, amount)?;
                 }
                 Self::deposit_event(Event::ArbitrageMintSell(pool_id, amount));
+                if let Some(keeper) = keeper {
+                    let deviation = total_spot_price.saturating_sub(BASE);
+                    Self::reward_arbitrage_keeper(pool_id, &pool, keeper, amount, deviation)?;
+                }
                 Ok(T::WeightInfo::execute_arbitrage_mint_sell(asset_count))
             } else if total_spot_price < BASE.saturating_sub(ARBITRAGE_THRESHOLD) {
-                let (amount, _) = pool.calc_arbitrage_amount_buy_burn(&balances, max_iterations)?;
+                let (amount, _) =
+                    pool.calc_arbitrage_amount_buy_burn(&balances, max_iterations, tolerance)?;
                 // Ensure that executing arbitrage doesn't decrease the outcome asset balances below
                 // the minimum allowed balance.
                 let (smallest_outcome, smallest_outcome_balance) = balances
@@ -1372,47 +1680,250 @@ mod pallet {
                     T::AssetManager::withdraw(*t, &pool_account, amount)?;
                 }
                 Self::deposit_event(Event::ArbitrageBuyBurn(pool_id, amount));
+                if let Some(keeper) = keeper {
+                    let deviation = BASE.saturating_sub(total_spot_price);
+                    Self::reward_arbitrage_keeper(pool_id, &pool, keeper, amount, deviation)?;
+                }
                 Ok(T::WeightInfo::execute_arbitrage_buy_burn(asset_count))
             } else {
+                ensure!(keeper.is_none(), Error::<T>::NoArbitrageOpportunity);
                 Self::deposit_event(Event::ArbitrageSkipped(pool_id));
                 Ok(T::WeightInfo::execute_arbitrage_skipped(asset_count))
             }
         }
 
-        pub fn get_spot_price(
-            pool_id: &PoolId,
-            asset_in: &Asset<MarketIdOf<T>>,
-            asset_out: &Asset<MarketIdOf<T>>,
-            with_fees: bool,
-        ) -> Result<BalanceOf<T>, DispatchError> {
-            let pool = Self::pool_by_id(*pool_id)?;
-            ensure!(pool.assets.binary_search(asset_in).is_ok(), Error::<T>::AssetNotInPool);
-            ensure!(pool.assets.binary_search(asset_out).is_ok(), Error::<T>::AssetNotInPool);
-            let pool_account = Self::pool_account_id(pool_id);
-
-            if pool.scoring_rule == ScoringRule::CPMM {
-                let balance_in = T::AssetManager::free_balance(*asset_in, &pool_account);
-                let balance_out = T::AssetManager::free_balance(*asset_out, &pool_account);
-                let in_weight = Self::pool_weight_rslt(&pool, asset_in)?;
-                let out_weight = Self::pool_weight_rslt(&pool, asset_out)?;
-
-                let swap_fee = if with_fees {
-                    pool.swap_fee.ok_or(Error::<T>::SwapFeeMissing)?
-                } else {
-                    BalanceOf::<T>::zero()
-                };
-
-                return Ok(crate::math::calc_spot_price(
-                    balance_in.saturated_into(),
-                    in_weight,
-                    balance_out.saturated_into(),
-                    out_weight,
-                    swap_fee.saturated_into(),
-                )?
-                .saturated_into());
+        /// Pay `keeper` its share of the estimated profit of arbitrage of `amount` complete sets
+        /// on a pool whose total spot price was `deviation` away from `1`.
+        ///
+        /// The reward is paid in the base asset of the pool from the arbitrage bounty account and
+        /// is capped so that the bounty account's balance of the base asset doesn't drop below the
+        /// minimum allowed balance. If the bounty account can't pay anything or if the reward
+        /// would leave `keeper` below the existential deposit, no reward is paid.
+        fn reward_arbitrage_keeper(
+            pool_id: PoolId,
+            pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
+            keeper: &T::AccountId,
+            amount: BalanceOf<T>,
+            deviation: u128,
+        ) -> DispatchResult {
+            let bounty_account = Self::arbitrage_bounty_account_id();
+            let profit = bmul(amount.saturated_into(), deviation)? / 2;
+            let reward: BalanceOf<T> =
+                T::ArbitrageKeeperShare::get().mul_floor(profit).saturated_into();
+            let max_reward = T::AssetManager::free_balance(pool.base_asset, &bounty_account)
+                .saturating_sub(Self::min_balance(pool.base_asset));
+            let reward = reward.min(max_reward);
+            let keeper_balance_after =
+                T::AssetManager::total_balance(pool.base_asset, keeper).saturating_add(reward);
+            if reward.is_zero()
+                || keeper_balance_after < T::AssetManager::minimum_balance(pool.base_asset)
+            {
+                return Ok(());
             }
+            T::AssetManager::transfer(pool.base_asset, &bounty_account, keeper, reward)?;
+            Self::deposit_event(Event::ArbitrageKeeperRewarded(pool_id, keeper.clone(), reward));
+            Ok(())
+        }
 
-            // TODO(#880): For now rikiddo does not respect with_fees flag.
+        fn do_swap_exact_amount_in_routed(
+            who: T::AccountId,
+            pool_id: PoolId,
+            asset_in: Asset<MarketIdOf<T>>,
+            asset_amount_in: BalanceOf<T>,
+            asset_out: Asset<MarketIdOf<T>>,
+            min_asset_amount_out: BalanceOf<T>,
+        ) -> Result<Weight, DispatchError> {
+            let pool = Self::pool_by_id(pool_id)?;
+            Self::check_if_pool_is_active(&pool)?;
+            ensure!(asset_in != asset_out, Error::<T>::UnsupportedTrade);
+            let balance_in_before = T::AssetManager::free_balance(asset_in, &who);
+            let balance_out_before = T::AssetManager::free_balance(asset_out, &who);
+            ensure!(balance_in_before >= asset_amount_in, Error::<T>::InsufficientBalance);
+
+            let (route, expected_amount_out) = if pool.scoring_rule == ScoringRule::CPMM {
+                Self::select_route(pool_id, &pool, asset_in, asset_amount_in, asset_out)
+            } else {
+                (SwapRoute::Direct, Zero::zero())
+            };
+            let market_account = T::MarketCommons::market_account(pool.market_id);
+            let outcomes = pool.assets.iter().filter(|a| **a != pool.base_asset);
+            let weight = match route {
+                SwapRoute::Direct => <Self as Swaps<T::AccountId>>::swap_exact_amount_in(
+                    who.clone(),
+                    pool_id,
+                    asset_in,
+                    asset_amount_in,
+                    asset_out,
+                    Some(min_asset_amount_out),
+                    None,
+                )?,
+                SwapRoute::MintSell => {
+                    // We're faking a `buy_complete_sets` operation by transfering to the market
+                    // prize pool.
+                    T::AssetManager::transfer(
+                        pool.base_asset,
+                        &who,
+                        &market_account,
+                        asset_amount_in,
+                    )?;
+                    for asset in outcomes.clone() {
+                        T::AssetManager::deposit(*asset, &who, asset_amount_in)?;
+                    }
+                    for asset in outcomes.filter(|a| **a != asset_out) {
+                        <Self as Swaps<T::AccountId>>::swap_exact_amount_in(
+                            who.clone(),
+                            pool_id,
+                            *asset,
+                            asset_amount_in,
+                            asset_out,
+                            Some(Zero::zero()),
+                            None,
+                        )?;
+                    }
+                    Self::swap_exact_amount_in_routed_weight(
+                        pool.assets.len().saturated_into(),
+                        <DynamicSwapFees<T>>::contains_key(pool_id),
+                    )
+                }
+                SwapRoute::BuyBurn => {
+                    let amount = expected_amount_out;
+                    for asset in outcomes.clone().filter(|a| **a != asset_in) {
+                        <Self as Swaps<T::AccountId>>::swap_exact_amount_out(
+                            who.clone(),
+                            pool_id,
+                            asset_in,
+                            Some(asset_amount_in),
+                            *asset,
+                            amount,
+                            None,
+                        )?;
+                    }
+                    // We're faking a `sell_complete_sets` operation by transfering from the market
+                    // prize pool.
+                    for asset in outcomes {
+                        T::AssetManager::withdraw(*asset, &who, amount)?;
+                    }
+                    T::AssetManager::transfer(pool.base_asset, &market_account, &who, amount)?;
+                    Self::swap_exact_amount_in_routed_weight(
+                        pool.assets.len().saturated_into(),
+                        <DynamicSwapFees<T>>::contains_key(pool_id),
+                    )
+                }
+            };
+
+            let asset_amount_in_spent =
+                balance_in_before.saturating_sub(T::AssetManager::free_balance(asset_in, &who));
+            ensure!(asset_amount_in_spent <= asset_amount_in, Error::<T>::MathApproximation);
+            let asset_amount_out =
+                T::AssetManager::free_balance(asset_out, &who).saturating_sub(balance_out_before);
+            ensure!(asset_amount_out >= min_asset_amount_out, Error::<T>::LimitOut);
+
+            Self::deposit_event(Event::SwapExactAmountInRouted(SwapRoutedEvent {
+                asset_amount_in: asset_amount_in_spent,
+                asset_amount_out,
+                asset_in,
+                asset_out,
+                cpep: CommonPoolEventParams { pool_id, who },
+                route,
+            }));
+            Ok(weight)
+        }
+
+        /// Return the route which yields the largest amount of `asset_out` when swapping
+        /// `asset_amount_in` units of `asset_in` on a CPMM pool, and the expected amount out.
+        ///
+        /// Routes which would fail (for example, due to the maximum in/out ratio) are ignored. If
+        /// no route is feasible, the direct route is returned.
+        fn select_route(
+            pool_id: PoolId,
+            pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
+            asset_in: Asset<MarketIdOf<T>>,
+            asset_amount_in: BalanceOf<T>,
+            asset_out: Asset<MarketIdOf<T>>,
+        ) -> (SwapRoute, BalanceOf<T>) {
+            let pool_account = Self::pool_account_id(&pool_id);
+            let balances = pool
+                .assets
+                .iter()
+                .map(|a| (*a, T::AssetManager::free_balance(*a, &pool_account)))
+                .collect::<BTreeMap<_, _>>();
+            let max_in_ratio = T::MaxInRatio::get().saturated_into();
+            let max_out_ratio = T::MaxOutRatio::get().saturated_into();
+            let direct = pool
+                .calc_route_direct(&balances, asset_in, asset_out, asset_amount_in, max_in_ratio)
+                .unwrap_or_else(|_| Zero::zero());
+            let alternative = if asset_in == pool.base_asset {
+                pool.calc_route_mint_sell(&balances, asset_out, asset_amount_in, max_in_ratio)
+                    .ok()
+                    .map(|amount| (SwapRoute::MintSell, amount))
+            } else if asset_out == pool.base_asset {
+                pool.calc_route_buy_burn(
+                    &balances,
+                    asset_in,
+                    asset_amount_in,
+                    max_out_ratio,
+                    ROUTER_MAX_ITERATIONS,
+                )
+                .ok()
+                .map(|(amount, _)| (SwapRoute::BuyBurn, amount))
+            } else {
+                None
+            };
+            match alternative {
+                Some((route, amount)) if amount > direct => (route, amount),
+                _ => (SwapRoute::Direct, direct),
+            }
+        }
+
+        pub fn get_spot_price(
+            pool_id: &PoolId,
+            asset_in: &Asset<MarketIdOf<T>>,
+            asset_out: &Asset<MarketIdOf<T>>,
+            with_fees: bool,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let pool = Self::pool_by_id(*pool_id)?;
+            ensure!(pool.assets.binary_search(asset_in).is_ok(), Error::<T>::AssetNotInPool);
+            ensure!(pool.assets.binary_search(asset_out).is_ok(), Error::<T>::AssetNotInPool);
+            let pool_account = Self::pool_account_id(pool_id);
+
+            if pool.scoring_rule == ScoringRule::CPMM {
+                let balance_in = T::AssetManager::free_balance(*asset_in, &pool_account);
+                let balance_out = T::AssetManager::free_balance(*asset_out, &pool_account);
+                let in_weight = Self::pool_weight_rslt(&pool, asset_in)?;
+                let out_weight = Self::pool_weight_rslt(&pool, asset_out)?;
+
+                let swap_fee = if with_fees {
+                    pool.swap_fee.ok_or(Error::<T>::SwapFeeMissing)?
+                } else {
+                    BalanceOf::<T>::zero()
+                };
+
+                return Ok(crate::math::calc_spot_price(
+                    balance_in.saturated_into(),
+                    in_weight,
+                    balance_out.saturated_into(),
+                    out_weight,
+                    swap_fee.saturated_into(),
+                )?
+                .saturated_into());
+            }
+
+            if let ScoringRule::LMSR { .. } = pool.scoring_rule {
+                let swap_fee = if with_fees {
+                    pool.swap_fee.ok_or(Error::<T>::SwapFeeMissing)?
+                } else {
+                    BalanceOf::<T>::zero()
+                };
+
+                return Ok(crate::math_lmsr::calc_spot_price(
+                    Self::lmsr_price(pool_id, &pool, asset_in)?,
+                    Self::lmsr_price(pool_id, &pool, asset_out)?,
+                    swap_fee.saturated_into(),
+                )?
+                .saturated_into());
+            }
+
+            // TODO(#880): For now rikiddo does not respect with_fees flag.
             // Price when using Rikiddo.
             ensure!(pool.pool_status == PoolStatus::Active, Error::<T>::PoolIsNotActive);
             let mut balances = Vec::new();
@@ -1469,11 +1980,107 @@ mod pallet {
             }
         }
 
+        #[inline]
+        pub(crate) fn arbitrage_bounty_account_id() -> T::AccountId {
+            T::ArbitrageBountyPalletId::get().into_account_truncating()
+        }
+
         #[inline]
         pub fn pool_account_id(pool_id: &PoolId) -> T::AccountId {
             T::PalletId::get().into_sub_account_truncating((*pool_id).saturated_into::<u128>())
         }
 
+        #[inline]
+        pub(crate) fn treasury_account_id() -> T::AccountId {
+            T::TreasuryPalletId::get().into_account_truncating()
+        }
+
+        // Returns the shares of the market creator and the treasury of the swap fee paid for
+        // swapping `asset_amount_in` in a CPMM pool. The rest of the fee stays in the pool and
+        // accrues to the liquidity providers.
+        pub(crate) fn swap_fee_split(
+            pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
+            asset_amount_in: BalanceOf<T>,
+        ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+            let swap_fee = pool.swap_fee.ok_or(Error::<T>::PoolMissingFee)?;
+            let fee_amount = bmul(asset_amount_in.saturated_into(), swap_fee.saturated_into())?;
+            let creator_fee = T::CreatorSwapFeeShare::get().mul_floor(fee_amount);
+            let protocol_fee = T::ProtocolSwapFeeShare::get()
+                .mul_floor(fee_amount)
+                .min(fee_amount.saturating_sub(creator_fee));
+            Ok((creator_fee.saturated_into(), protocol_fee.saturated_into()))
+        }
+
+        // Returns the shares of the market creator and the treasury of the swap fee paid for
+        // swapping `asset_amount_in` of `asset_in` which are actually paid out. A share which
+        // would leave its recipient with less than the existential deposit of `asset_in` isn't
+        // paid out, but stays in the pool instead, so that small trades don't fail.
+        pub(crate) fn payable_swap_fee_split(
+            pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
+            asset_in: Asset<MarketIdOf<T>>,
+            asset_amount_in: BalanceOf<T>,
+        ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+            let (mut creator_fee, protocol_fee) = Self::swap_fee_split(pool, asset_amount_in)?;
+            if !creator_fee.is_zero() {
+                let creator = T::MarketCommons::market(&pool.market_id)?.creator;
+                creator_fee = Self::payable_swap_fee_share(asset_in, &creator, creator_fee);
+            }
+            let protocol_fee =
+                Self::payable_swap_fee_share(asset_in, &Self::treasury_account_id(), protocol_fee);
+            Ok((creator_fee, protocol_fee))
+        }
+
+        // Returns `amount` if transferring `amount` of `asset` to `who` leaves `who` with at least
+        // the existential deposit of `asset`, and zero otherwise.
+        fn payable_swap_fee_share(
+            asset: Asset<MarketIdOf<T>>,
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            let balance_after = T::AssetManager::total_balance(asset, who).saturating_add(amount);
+            if balance_after < T::AssetManager::minimum_balance(asset) {
+                return Zero::zero();
+            }
+            amount
+        }
+
+        // Transfers the shares of the market creator and the treasury of the swap fee paid for
+        // swapping `asset_amount_in` of `asset_in` out of the pool. Since the fee is not part of
+        // the amount that is used to calculate the trade, the pool retains at least the balance
+        // it would have after a trade without fees, so the invariants of the CPMM still hold.
+        pub(crate) fn distribute_swap_fee(
+            pool_id: PoolId,
+            pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
+            asset_in: Asset<MarketIdOf<T>>,
+            asset_amount_in: BalanceOf<T>,
+        ) -> DispatchResult {
+            let (creator_fee, protocol_fee) =
+                Self::payable_swap_fee_split(pool, asset_in, asset_amount_in)?;
+            if creator_fee.is_zero() && protocol_fee.is_zero() {
+                return Ok(());
+            }
+            let pool_account = Self::pool_account_id(&pool_id);
+            if !creator_fee.is_zero() {
+                let creator = T::MarketCommons::market(&pool.market_id)?.creator;
+                T::AssetManager::transfer(asset_in, &pool_account, &creator, creator_fee)?;
+            }
+            if !protocol_fee.is_zero() {
+                T::AssetManager::transfer(
+                    asset_in,
+                    &pool_account,
+                    &Self::treasury_account_id(),
+                    protocol_fee,
+                )?;
+            }
+            Self::deposit_event(Event::SwapFeeDistributed(
+                pool_id,
+                asset_in,
+                creator_fee,
+                protocol_fee,
+            ));
+            Ok(())
+        }
+
         // The minimum allowed balance in a liquidity pool.
         pub(crate) fn min_balance(asset: Asset<MarketIdOf<T>>) -> BalanceOf<T> {
             T::AssetManager::minimum_balance(asset).max(MIN_BALANCE.saturated_into())
@@ -1567,7 +2174,90 @@ mod pallet {
         where
             T: Config,
         {
-            Self::pools(pool_id).ok_or_else(|| Error::<T>::PoolDoesNotExist.into())
+            let mut pool: Pool<BalanceOf<T>, MarketIdOf<T>> =
+                Self::pools(pool_id).ok_or(Error::<T>::PoolDoesNotExist)?;
+            if <DynamicSwapFees<T>>::contains_key(pool_id) {
+                pool.swap_fee = Some(Self::dynamic_swap_fee(pool_id, &pool)?);
+            }
+            if let Some(schedule) = <WeightSchedules<T>>::get(pool_id) {
+                Self::apply_weight_schedule(&mut pool, &schedule)?;
+            }
+            Ok(pool)
+        }
+
+        // Sets the weights of `pool` to the weights that `schedule` specifies for the current
+        // block. The weights move linearly from the weights set at pool creation (before
+        // `schedule.start`) to `schedule.end_weights` (after `schedule.end`).
+        fn apply_weight_schedule(
+            pool: &mut Pool<BalanceOf<T>, MarketIdOf<T>>,
+            schedule: &WeightSchedule<T::BlockNumber, MarketIdOf<T>>,
+        ) -> DispatchResult {
+            let now = <frame_system::Pallet<T>>::block_number();
+            if now <= schedule.start {
+                return Ok(());
+            }
+            let progress = if now >= schedule.end {
+                Perbill::one()
+            } else {
+                Perbill::from_rational(
+                    now.saturating_sub(schedule.start).saturated_into::<u128>(),
+                    schedule.end.saturating_sub(schedule.start).saturated_into::<u128>(),
+                )
+            };
+            let weights = pool.weights.as_mut().ok_or(Error::<T>::PoolMissingWeight)?;
+            let mut total_weight: u128 = 0;
+            for (asset, weight) in weights.iter_mut() {
+                if let Some(end_weight) = schedule.end_weights.get(asset) {
+                    *weight = if *end_weight >= *weight {
+                        weight
+                            .saturating_add(progress.mul_floor(end_weight.saturating_sub(*weight)))
+                    } else {
+                        weight
+                            .saturating_sub(progress.mul_floor(weight.saturating_sub(*end_weight)))
+                    };
+                }
+                total_weight = total_weight.saturating_add(*weight);
+            }
+            pool.total_weight = Some(total_weight);
+            Ok(())
+        }
+
+        // Returns the swap fee of a CPMM pool in dynamic fee mode. The fee is calculated by the
+        // fee sigmoid of the pool's Rikiddo instance from the short and long EMA of the trade
+        // volume, but never drops below the fee configured at pool creation and never exceeds
+        // `MaxSwapFee`.
+        fn dynamic_swap_fee(
+            pool_id: PoolId,
+            pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let swap_fee = pool.swap_fee.ok_or(Error::<T>::PoolMissingFee)?;
+            let volume_fee = T::RikiddoSigmoidFeeMarketEma::fee(pool_id)?;
+            Ok(swap_fee.max(volume_fee).min(T::MaxSwapFee::get()))
+        }
+
+        // Returns the weight of a routed swap on a pool with `asset_count` assets. The routed
+        // benchmark uses a pool with a static fee, so the overhead of the dynamic fee is added
+        // for each of the swaps of the route if `dynamic_swap_fee` is set.
+        fn swap_exact_amount_in_routed_weight(asset_count: u32, dynamic_swap_fee: bool) -> Weight {
+            let weight = T::WeightInfo::swap_exact_amount_in_routed(asset_count);
+            if !dynamic_swap_fee {
+                return weight;
+            }
+            let overhead = T::WeightInfo::swap_exact_amount_in_cpmm_dynamic_fee()
+                .saturating_sub(T::WeightInfo::swap_exact_amount_in_cpmm())
+                .max(
+                    T::WeightInfo::swap_exact_amount_out_cpmm_dynamic_fee()
+                        .saturating_sub(T::WeightInfo::swap_exact_amount_out_cpmm()),
+                );
+            weight.saturating_add(overhead.saturating_mul(asset_count.into()))
+        }
+
+        // Disables the dynamic fee mode of `pool_id` if it's enabled. Noop otherwise.
+        fn remove_dynamic_swap_fee(pool_id: PoolId) -> DispatchResult {
+            if <DynamicSwapFees<T>>::take(pool_id).is_some() {
+                T::RikiddoSigmoidFeeMarketEma::destroy(pool_id)?;
+            }
+            Ok(())
         }
 
         fn inc_next_pool_id() -> Result<PoolId, DispatchError> {
@@ -1594,6 +2284,370 @@ mod pallet {
             })
         }
 
+        /// Return the spot price of every asset of the pool except the base asset in units of the
+        /// base asset, in the order in which the assets are stored in the pool.
+        pub fn get_all_spot_prices(
+            pool_id: &PoolId,
+            with_fees: bool,
+        ) -> Result<Vec<(Asset<MarketIdOf<T>>, BalanceOf<T>)>, DispatchError> {
+            let pool = Self::pool_by_id(*pool_id)?;
+            let base_asset = pool.base_asset;
+            pool.assets
+                .into_iter()
+                .filter(|asset| *asset != base_asset)
+                .map(|asset| {
+                    let price = Self::get_spot_price(pool_id, &base_asset, &asset, with_fees)?;
+                    Ok((asset, price))
+                })
+                .collect()
+        }
+
+        /// Return the balance of every asset of the pool, in the order in which the assets are
+        /// stored in the pool.
+        pub fn get_pool_balances(
+            pool_id: &PoolId,
+        ) -> Result<Vec<(Asset<MarketIdOf<T>>, BalanceOf<T>)>, DispatchError> {
+            let pool = Self::pool_by_id(*pool_id)?;
+            let pool_account = Self::pool_account_id(pool_id);
+            Ok(pool
+                .assets
+                .into_iter()
+                .map(|asset| (asset, T::AssetManager::free_balance(asset, &pool_account)))
+                .collect())
+        }
+
+        /// Simulate `swap_exact_amount_in` on a CPMM pool.
+        ///
+        /// Returns the amount of `asset_out` received, the part of it lost to fees and the spot
+        /// price of `asset_out` in units of `asset_in` after the trade.
+        pub fn quote_swap_exact_amount_in(
+            pool_id: PoolId,
+            asset_in: Asset<MarketIdOf<T>>,
+            asset_amount_in: BalanceOf<T>,
+            asset_out: Asset<MarketIdOf<T>>,
+        ) -> Result<TradeQuote<BalanceOf<T>>, DispatchError> {
+            let (pool, mut balances, swap_fee) = Self::quote_context(pool_id)?;
+            ensure!(asset_in != asset_out, Error::<T>::UnsupportedTrade);
+            let balance_in = Self::quote_balance(&balances, &asset_in)?;
+            let balance_out = Self::quote_balance(&balances, &asset_out)?;
+            let amount_in: u128 = asset_amount_in.saturated_into();
+            ensure!(
+                amount_in <= bmul(balance_in, T::MaxInRatio::get().saturated_into())?,
+                Error::<T>::MaxInRatio
+            );
+            let calc_amount_out = |fee: u128| -> Result<u128, DispatchError> {
+                crate::math::calc_out_given_in(
+                    balance_in,
+                    Self::pool_weight_rslt(&pool, &asset_in)?,
+                    balance_out,
+                    Self::pool_weight_rslt(&pool, &asset_out)?,
+                    amount_in,
+                    fee,
+                )
+            };
+            let amount_out = calc_amount_out(swap_fee)?;
+            let fee = calc_amount_out(0)?.saturating_sub(amount_out);
+            let distributed_fee = Self::quote_distributed_swap_fee(&pool, asset_in, amount_in)?;
+            balances.insert(
+                asset_in,
+                balance_in.check_add_rslt(&amount_in)?.check_sub_rslt(&distributed_fee)?,
+            );
+            balances.insert(asset_out, balance_out.check_sub_rslt(&amount_out)?);
+            let spot_price_after =
+                Self::quote_spot_price(&pool, &balances, &asset_in, &asset_out, swap_fee)?;
+            Ok(Self::trade_quote(amount_out, fee, spot_price_after))
+        }
+
+        /// Simulate `swap_exact_amount_out` on a CPMM pool.
+        ///
+        /// Returns the amount of `asset_in` required, the part of it due to fees and the spot
+        /// price of `asset_out` in units of `asset_in` after the trade.
+        pub fn quote_swap_exact_amount_out(
+            pool_id: PoolId,
+            asset_in: Asset<MarketIdOf<T>>,
+            asset_out: Asset<MarketIdOf<T>>,
+            asset_amount_out: BalanceOf<T>,
+        ) -> Result<TradeQuote<BalanceOf<T>>, DispatchError> {
+            let (pool, mut balances, swap_fee) = Self::quote_context(pool_id)?;
+            ensure!(asset_in != asset_out, Error::<T>::UnsupportedTrade);
+            let balance_in = Self::quote_balance(&balances, &asset_in)?;
+            let balance_out = Self::quote_balance(&balances, &asset_out)?;
+            let amount_out: u128 = asset_amount_out.saturated_into();
+            ensure!(
+                amount_out <= bmul(balance_out, T::MaxOutRatio::get().saturated_into())?,
+                Error::<T>::MaxOutRatio
+            );
+            let calc_amount_in = |fee: u128| -> Result<u128, DispatchError> {
+                crate::math::calc_in_given_out(
+                    balance_in,
+                    Self::pool_weight_rslt(&pool, &asset_in)?,
+                    balance_out,
+                    Self::pool_weight_rslt(&pool, &asset_out)?,
+                    amount_out,
+                    fee,
+                )
+            };
+            let amount_in = calc_amount_in(swap_fee)?;
+            let fee = amount_in.saturating_sub(calc_amount_in(0)?);
+            let distributed_fee = Self::quote_distributed_swap_fee(&pool, asset_in, amount_in)?;
+            balances.insert(
+                asset_in,
+                balance_in.check_add_rslt(&amount_in)?.check_sub_rslt(&distributed_fee)?,
+            );
+            balances.insert(asset_out, balance_out.check_sub_rslt(&amount_out)?);
+            let spot_price_after =
+                Self::quote_spot_price(&pool, &balances, &asset_in, &asset_out, swap_fee)?;
+            Ok(Self::trade_quote(amount_in, fee, spot_price_after))
+        }
+
+        /// Simulate `pool_join_with_exact_asset_amount` on a CPMM pool.
+        ///
+        /// Returns the amount of pool shares received, the part of it lost to fees and the spot
+        /// price of `asset_in` in units of the base asset after the trade.
+        pub fn quote_pool_join_with_exact_asset_amount(
+            pool_id: PoolId,
+            asset_in: Asset<MarketIdOf<T>>,
+            asset_amount: BalanceOf<T>,
+        ) -> Result<TradeQuote<BalanceOf<T>>, DispatchError> {
+            let (pool, mut balances, swap_fee) = Self::quote_context(pool_id)?;
+            let balance_in = Self::quote_balance(&balances, &asset_in)?;
+            let amount_in: u128 = asset_amount.saturated_into();
+            ensure!(
+                amount_in <= bmul(balance_in, T::MaxInRatio::get().saturated_into())?,
+                Error::<T>::MaxInRatio
+            );
+            let calc_pool_amount = |fee: u128| -> Result<u128, DispatchError> {
+                crate::math::calc_pool_out_given_single_in(
+                    balance_in,
+                    Self::pool_weight_rslt(&pool, &asset_in)?,
+                    Self::quote_pool_supply(pool_id),
+                    pool.total_weight.ok_or(Error::<T>::PoolMissingWeight)?,
+                    amount_in,
+                    fee,
+                )
+            };
+            let pool_amount = calc_pool_amount(swap_fee)?;
+            let fee = calc_pool_amount(0)?.saturating_sub(pool_amount);
+            balances.insert(asset_in, balance_in.check_add_rslt(&amount_in)?);
+            let spot_price_after =
+                Self::quote_spot_price(&pool, &balances, &pool.base_asset, &asset_in, swap_fee)?;
+            Ok(Self::trade_quote(pool_amount, fee, spot_price_after))
+        }
+
+        /// Simulate `pool_join_with_exact_pool_amount` on a CPMM pool.
+        ///
+        /// Returns the amount of `asset` required, the part of it due to fees and the spot price
+        /// of `asset` in units of the base asset after the trade.
+        pub fn quote_pool_join_with_exact_pool_amount(
+            pool_id: PoolId,
+            asset: Asset<MarketIdOf<T>>,
+            pool_amount: BalanceOf<T>,
+        ) -> Result<TradeQuote<BalanceOf<T>>, DispatchError> {
+            let (pool, mut balances, swap_fee) = Self::quote_context(pool_id)?;
+            let balance_in = Self::quote_balance(&balances, &asset)?;
+            let pool_supply = Self::quote_pool_supply(pool_id);
+            let pool_amount: u128 = pool_amount.saturated_into();
+            ensure!(
+                pool_amount <= bmul(pool_supply, T::MaxOutRatio::get().saturated_into())?,
+                Error::<T>::MaxOutRatio
+            );
+            let calc_amount_in = |fee: u128| -> Result<u128, DispatchError> {
+                crate::math::calc_single_in_given_pool_out(
+                    balance_in,
+                    Self::pool_weight_rslt(&pool, &asset)?,
+                    pool_supply,
+                    pool.total_weight.ok_or(Error::<T>::PoolMissingWeight)?,
+                    pool_amount,
+                    fee,
+                )
+            };
+            let amount_in = calc_amount_in(swap_fee)?;
+            let fee = amount_in.saturating_sub(calc_amount_in(0)?);
+            ensure!(
+                amount_in <= bmul(balance_in, T::MaxInRatio::get().saturated_into())?,
+                Error::<T>::MaxInRatio
+            );
+            balances.insert(asset, balance_in.check_add_rslt(&amount_in)?);
+            let spot_price_after =
+                Self::quote_spot_price(&pool, &balances, &pool.base_asset, &asset, swap_fee)?;
+            Ok(Self::trade_quote(amount_in, fee, spot_price_after))
+        }
+
+        /// Simulate `pool_exit_with_exact_asset_amount` on a CPMM pool.
+        ///
+        /// Returns the amount of pool shares required, the part of it due to fees (including the
+        /// exit fee) and the spot price of `asset` in units of the base asset after the trade.
+        pub fn quote_pool_exit_with_exact_asset_amount(
+            pool_id: PoolId,
+            asset: Asset<MarketIdOf<T>>,
+            asset_amount: BalanceOf<T>,
+        ) -> Result<TradeQuote<BalanceOf<T>>, DispatchError> {
+            let (pool, mut balances, swap_fee) = Self::quote_context(pool_id)?;
+            let balance_out = Self::quote_balance(&balances, &asset)?;
+            let amount_out: u128 = asset_amount.saturated_into();
+            ensure!(
+                amount_out <= bmul(balance_out, T::MaxOutRatio::get().saturated_into())?,
+                Error::<T>::MaxOutRatio
+            );
+            let calc_pool_amount =
+                |swap_fee: u128, exit_fee: u128| -> Result<u128, DispatchError> {
+                    crate::math::calc_pool_in_given_single_out(
+                        balance_out,
+                        Self::pool_weight_rslt(&pool, &asset)?,
+                        Self::quote_pool_supply(pool_id),
+                        pool.total_weight.ok_or(Error::<T>::PoolMissingWeight)?,
+                        amount_out,
+                        swap_fee,
+                        exit_fee,
+                    )
+                };
+            let pool_amount = calc_pool_amount(swap_fee, T::ExitFee::get().saturated_into())?;
+            let fee = pool_amount.saturating_sub(calc_pool_amount(0, 0)?);
+            balances.insert(asset, balance_out.check_sub_rslt(&amount_out)?);
+            let spot_price_after =
+                Self::quote_spot_price(&pool, &balances, &pool.base_asset, &asset, swap_fee)?;
+            Ok(Self::trade_quote(pool_amount, fee, spot_price_after))
+        }
+
+        /// Simulate `pool_exit_with_exact_pool_amount` on a CPMM pool.
+        ///
+        /// Returns the amount of `asset` received, the part of it lost to fees (including the
+        /// exit fee) and the spot price of `asset` in units of the base asset after the trade.
+        pub fn quote_pool_exit_with_exact_pool_amount(
+            pool_id: PoolId,
+            asset: Asset<MarketIdOf<T>>,
+            pool_amount: BalanceOf<T>,
+        ) -> Result<TradeQuote<BalanceOf<T>>, DispatchError> {
+            let (pool, mut balances, swap_fee) = Self::quote_context(pool_id)?;
+            let balance_out = Self::quote_balance(&balances, &asset)?;
+            let pool_supply = Self::quote_pool_supply(pool_id);
+            let pool_amount: u128 = pool_amount.saturated_into();
+            ensure!(
+                pool_amount <= bmul(pool_supply, T::MaxInRatio::get().saturated_into())?,
+                Error::<T>::MaxInRatio
+            );
+            let calc_amount_out = |swap_fee: u128, exit_fee: u128| -> Result<u128, DispatchError> {
+                crate::math::calc_single_out_given_pool_in(
+                    balance_out,
+                    Self::pool_weight_rslt(&pool, &asset)?,
+                    pool_supply,
+                    pool.total_weight.ok_or(Error::<T>::PoolMissingWeight)?,
+                    pool_amount,
+                    swap_fee,
+                    exit_fee,
+                )
+            };
+            let amount_out = calc_amount_out(swap_fee, T::ExitFee::get().saturated_into())?;
+            let fee = calc_amount_out(0, 0)?.saturating_sub(amount_out);
+            ensure!(
+                amount_out <= bmul(balance_out, T::MaxOutRatio::get().saturated_into())?,
+                Error::<T>::MaxOutRatio
+            );
+            balances.insert(asset, balance_out.check_sub_rslt(&amount_out)?);
+            let spot_price_after =
+                Self::quote_spot_price(&pool, &balances, &pool.base_asset, &asset, swap_fee)?;
+            Ok(Self::trade_quote(amount_out, fee, spot_price_after))
+        }
+
+        /// Return the pool, the balances of its assets and its swap fee if the pool is an active
+        /// CPMM pool.
+        fn quote_context(
+            pool_id: PoolId,
+        ) -> Result<
+            (Pool<BalanceOf<T>, MarketIdOf<T>>, BTreeMap<Asset<MarketIdOf<T>>, u128>, u128),
+            DispatchError,
+        > {
+            let pool = Self::pool_by_id(pool_id)?;
+            ensure!(pool.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+            Self::check_if_pool_is_active(&pool)?;
+            let pool_account = Self::pool_account_id(&pool_id);
+            let balances = pool
+                .assets
+                .iter()
+                .map(|a| (*a, T::AssetManager::free_balance(*a, &pool_account).saturated_into()))
+                .collect::<BTreeMap<_, _>>();
+            let swap_fee = pool.swap_fee.ok_or(Error::<T>::PoolMissingFee)?.saturated_into();
+            Ok((pool, balances, swap_fee))
+        }
+
+        fn quote_balance(
+            balances: &BTreeMap<Asset<MarketIdOf<T>>, u128>,
+            asset: &Asset<MarketIdOf<T>>,
+        ) -> Result<u128, DispatchError> {
+            Ok(balances.get(asset).copied().ok_or(Error::<T>::AssetNotInPool)?)
+        }
+
+        fn quote_distributed_swap_fee(
+            pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
+            asset_in: Asset<MarketIdOf<T>>,
+            amount_in: u128,
+        ) -> Result<u128, DispatchError> {
+            let (creator_fee, protocol_fee) =
+                Self::payable_swap_fee_split(pool, asset_in, amount_in.saturated_into())?;
+            Ok(creator_fee.saturating_add(protocol_fee).saturated_into())
+        }
+
+        fn quote_pool_supply(pool_id: PoolId) -> u128 {
+            T::AssetManager::total_issuance(Self::pool_shares_id(pool_id)).saturated_into()
+        }
+
+        fn quote_spot_price(
+            pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
+            balances: &BTreeMap<Asset<MarketIdOf<T>>, u128>,
+            asset_in: &Asset<MarketIdOf<T>>,
+            asset_out: &Asset<MarketIdOf<T>>,
+            swap_fee: u128,
+        ) -> Result<u128, DispatchError> {
+            crate::math::calc_spot_price(
+                Self::quote_balance(balances, asset_in)?,
+                Self::pool_weight_rslt(pool, asset_in)?,
+                Self::quote_balance(balances, asset_out)?,
+                Self::pool_weight_rslt(pool, asset_out)?,
+                swap_fee,
+            )
+        }
+
+        fn trade_quote(
+            amount: u128,
+            fee: u128,
+            spot_price_after: u128,
+        ) -> TradeQuote<BalanceOf<T>> {
+            TradeQuote {
+                amount: SerdeWrapper(amount.saturated_into()),
+                fee: SerdeWrapper(fee.saturated_into()),
+                spot_price_after: SerdeWrapper(spot_price_after.saturated_into()),
+            }
+        }
+
+        /// The price of `asset` in units of the base asset in an LMSR pool, or `None` if `asset`
+        /// is the base asset.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)` where `n` is the number of assets in the pool.
+        fn lmsr_price(
+            pool_id: &PoolId,
+            pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
+            asset: &Asset<MarketIdOf<T>>,
+        ) -> Result<Option<u128>, DispatchError> {
+            let liquidity_parameter = match pool.scoring_rule {
+                ScoringRule::LMSR { liquidity_parameter } => liquidity_parameter,
+                _ => return Err(Error::<T>::InvalidScoringRule.into()),
+            };
+            if *asset == pool.base_asset {
+                return Ok(None);
+            }
+            let pool_account = Self::pool_account_id(pool_id);
+            let reserves: Vec<u128> = pool
+                .assets
+                .iter()
+                .filter(|el| **el != pool.base_asset)
+                .map(|el| T::AssetManager::free_balance(*el, &pool_account).saturated_into())
+                .collect();
+            let reserve = T::AssetManager::free_balance(*asset, &pool_account).saturated_into();
+            Ok(Some(crate::math_lmsr::calc_price(reserve, &reserves, liquidity_parameter)?))
+        }
+
         fn pool_weight_rslt(
             pool: &Pool<BalanceOf<T>, MarketIdOf<T>>,
             asset: &Asset<MarketIdOf<T>>,
@@ -1691,11 +2745,12 @@ mod pallet {
         /// * `base_asset`: The base asset in a prediction market swap pool (usually a currency).
         /// * `market_id`: The market id of the market the pool belongs to.
         /// * `scoring_rule`: The scoring rule that's used to determine the asset prices.
-        /// * `swap_fee`: The fee applied to each swap on a CPMM pool, specified as fixed-point
-        ///     ratio (0.1 equals 10% swap fee)
+        /// * `swap_fee`: The fee applied to each swap on a CPMM or LMSR pool, specified as
+        ///     fixed-point ratio (0.1 equals 10% swap fee)
         /// * `amount`: The amount of each asset added to the pool; **may** be `None` only if
         ///     `scoring_rule` is `RikiddoSigmoidFeeMarketEma`.
-        /// * `weights`: These are the raw/denormalized weights (mandatory if scoring rule is CPMM).
+        /// * `weights`: These are the raw/denormalized weights (mandatory if scoring rule is CPMM,
+        ///     must be `None` if scoring rule is LMSR).
         #[frame_support::transactional]
         fn create_pool(
             who: T::AccountId,
@@ -1774,6 +2829,45 @@ mod pallet {
                         let pool_amount = amount_unwrapped;
                         (pool_status, total_subsidy, total_weight, weights, pool_amount)
                     }
+                    ScoringRule::LMSR { liquidity_parameter } => {
+                        ensure!(amount.is_some(), Error::<T>::InvalidAmountArgument);
+                        ensure!(
+                            amount_unwrapped >= T::MinLiquidity::get(),
+                            Error::<T>::InsufficientLiquidity
+                        );
+                        ensure!(
+                            liquidity_parameter >= T::MinLiquidity::get().saturated_into(),
+                            Error::<T>::InvalidLiquidityParameter
+                        );
+                        let swap_fee_unwrapped = swap_fee.ok_or(Error::<T>::InvalidFeeArgument)?;
+                        ensure!(
+                            swap_fee_unwrapped <= T::MaxSwapFee::get(),
+                            Error::<T>::SwapFeeTooHigh
+                        );
+                        ensure!(weights.is_none(), Error::<T>::InvalidWeightArgument);
+
+                        for asset in assets.iter().copied() {
+                            let free_balance = T::AssetManager::free_balance(asset, &who);
+                            ensure!(
+                                free_balance >= amount_unwrapped,
+                                Error::<T>::InsufficientBalance
+                            );
+                            T::AssetManager::transfer(
+                                asset,
+                                &who,
+                                &pool_account,
+                                amount_unwrapped,
+                            )?;
+                        }
+                        T::AssetManager::deposit(pool_shares_id, &who, amount_unwrapped)?;
+
+                        let pool_status = PoolStatus::Initialized;
+                        let total_subsidy = None;
+                        let total_weight = None;
+                        let weights = None;
+                        let pool_amount = amount_unwrapped;
+                        (pool_status, total_subsidy, total_weight, weights, pool_amount)
+                    }
                     ScoringRule::RikiddoSigmoidFeeMarketEma => {
                         let mut rikiddo_instance: RikiddoSigmoidMV<
                             T::FixedTypeU,
@@ -1847,6 +2941,9 @@ mod pallet {
             }
             // NOTE: Currently we don't clean up accounts with pool_share_id.
             // TODO(#792): Remove pool_share_id asset for accounts! It may require storage migration.
+            Self::remove_dynamic_swap_fee(pool_id)?;
+            <WeightSchedules<T>>::remove(pool_id);
+            <ArbitrageConfigs<T>>::remove(pool_id);
             Pools::<T>::remove(pool_id);
             Self::deposit_event(Event::PoolDestroyed(pool_id));
             Ok(T::WeightInfo::destroy_pool(asset_len))
@@ -2048,6 +3145,68 @@ mod pallet {
             Ok(T::WeightInfo::open_pool(asset_len))
         }
 
+        /// Pool - Exit
+        ///
+        /// Retrieves a given set of assets from `pool_id` to `who`.
+        ///
+        /// # Arguments
+        ///
+        /// * `who`: Liquidity Provider (LP). The account whose assets should be received.
+        /// * `pool_id`: Unique pool identifier.
+        /// * `pool_amount`: The amount of LP shares of this pool being burned based on the
+        /// retrieved assets.
+        /// * `min_assets_out`: List of asset lower bounds. No asset should be lower than the
+        /// provided values.
+        fn pool_exit(
+            who: T::AccountId,
+            pool_id: PoolId,
+            pool_amount: BalanceOf<T>,
+            min_assets_out: Vec<BalanceOf<T>>,
+        ) -> Result<Weight, DispatchError> {
+            ensure!(pool_amount != Zero::zero(), Error::<T>::ZeroAmount);
+            let who_clone = who.clone();
+            let pool = Self::pool_by_id(pool_id)?;
+            // The liquidity of LMSR pools determines their prices, so it can only be withdrawn
+            // once trading is over.
+            if let ScoringRule::LMSR { .. } = pool.scoring_rule {
+                ensure!(
+                    matches!(pool.pool_status, PoolStatus::Closed | PoolStatus::Clean),
+                    Error::<T>::InvalidPoolStatus
+                );
+            }
+            // If the pool is still in use, prevent a pool drain.
+            Self::ensure_minimum_liquidity_shares(pool_id, &pool, pool_amount)?;
+            let pool_account_id = Pallet::<T>::pool_account_id(&pool_id);
+            let params = PoolParams {
+                asset_bounds: min_assets_out,
+                event: |evt| Self::deposit_event(Event::PoolExit(evt)),
+                pool_account_id: &pool_account_id,
+                pool_amount,
+                pool_id,
+                pool: &pool,
+                transfer_asset: |amount, amount_bound, asset| {
+                    Self::ensure_minimum_balance(pool_id, &pool, asset, amount)?;
+                    ensure!(amount >= amount_bound, Error::<T>::LimitOut);
+                    T::LiquidityMining::remove_shares(&who, &pool.market_id, amount);
+                    T::AssetManager::transfer(asset, &pool_account_id, &who, amount)?;
+                    Ok(())
+                },
+                transfer_pool: || {
+                    Self::burn_pool_shares(pool_id, &who, pool_amount)?;
+                    Ok(())
+                },
+                fee: |amount: BalanceOf<T>| {
+                    let exit_fee_amount =
+                        bmul(amount.saturated_into(), Self::calc_exit_fee(&pool).saturated_into())?
+                            .saturated_into();
+                    Ok(exit_fee_amount)
+                },
+                who: who_clone,
+            };
+            crate::utils::pool::<_, _, _, _, T>(params)?;
+            Ok(T::WeightInfo::pool_exit(pool.assets.len().saturated_into()))
+        }
+
         /// Pool - Exit with exact pool amount
         ///
         /// Takes an asset from `pool_id` and transfers to `origin`. Differently from `pool_exit`,
@@ -2120,6 +3279,55 @@ mod pallet {
             pool_exit_with_exact_amount::<_, _, _, _, _, T>(params).map(|_| weight)
         }
 
+        /// Pool - Join
+        ///
+        /// Joins a given set of assets provided from `who` to `pool_id`.
+        ///
+        /// # Arguments
+        ///
+        /// * `who`: Liquidity Provider (LP). The account whose assets should be transferred.
+        /// * `pool_id`: Unique pool identifier.
+        /// * `pool_amount`: The amount of LP shares for this pool that should be minted to the
+        /// provider.
+        /// * `max_assets_in`: List of asset upper bounds. No asset should be greater than the
+        /// provided values.
+        fn pool_join(
+            who: T::AccountId,
+            pool_id: PoolId,
+            pool_amount: BalanceOf<T>,
+            max_assets_in: Vec<BalanceOf<T>>,
+        ) -> Result<Weight, DispatchError> {
+            ensure!(pool_amount != Zero::zero(), Error::<T>::ZeroAmount);
+            let pool = Self::pool_by_id(pool_id)?;
+            ensure!(pool.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+            ensure!(
+                matches!(pool.pool_status, PoolStatus::Initialized | PoolStatus::Active),
+                Error::<T>::InvalidPoolStatus,
+            );
+            let pool_account_id = Pallet::<T>::pool_account_id(&pool_id);
+
+            let params = PoolParams {
+                asset_bounds: max_assets_in,
+                event: |evt| Self::deposit_event(Event::PoolJoin(evt)),
+                pool_account_id: &pool_account_id,
+                pool_amount,
+                pool_id,
+                pool: &pool,
+                transfer_asset: |amount, amount_bound, asset| {
+                    ensure!(amount <= amount_bound, Error::<T>::LimitIn);
+                    T::AssetManager::transfer(asset, &who, &pool_account_id, amount)?;
+                    T::LiquidityMining::add_shares(who.clone(), pool.market_id, amount);
+                    Ok(())
+                },
+                transfer_pool: || Self::mint_pool_shares(pool_id, &who, pool_amount),
+                fee: |_| Ok(0u128.saturated_into()),
+                who: who.clone(),
+            };
+
+            crate::utils::pool::<_, _, _, _, T>(params)?;
+            Ok(T::WeightInfo::pool_join(pool.assets.len().saturated_into()))
+        }
+
         /// Pool - Join with exact asset amount
         ///
         /// Joins an asset provided from `origin` to `pool_id`. Differently from `pool_join`,
@@ -2189,6 +3397,31 @@ mod pallet {
             Self::pool_by_id(pool_id)
         }
 
+        fn pool_account_id(pool_id: &PoolId) -> T::AccountId {
+            Pallet::<T>::pool_account_id(pool_id)
+        }
+
+        fn pool_balances(
+            pool_id: PoolId,
+        ) -> Result<Vec<(Asset<MarketIdOf<T>>, Self::Balance)>, DispatchError> {
+            Self::get_pool_balances(&pool_id)
+        }
+
+        fn pool_join_amounts(
+            pool_id: PoolId,
+            pool_amount: Self::Balance,
+        ) -> Result<Vec<(Asset<MarketIdOf<T>>, Self::Balance)>, DispatchError> {
+            // Same calculation as in `utils::pool`.
+            let total_issuance = T::AssetManager::total_issuance(Self::pool_shares_id(pool_id));
+            let ratio = bdiv(pool_amount.saturated_into(), total_issuance.saturated_into())?;
+            Self::get_pool_balances(&pool_id)?
+                .into_iter()
+                .map(|(asset, balance)| {
+                    Ok((asset, bmul(ratio, balance.saturated_into())?.saturated_into()))
+                })
+                .collect()
+        }
+
         /// Remove losing assets and distribute Rikiddo pool share rewards.
         ///
         /// # Arguments
@@ -2218,6 +3451,11 @@ mod pallet {
                 Ok(())
             })?;
             weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1)); // mutate_pool
+            Self::remove_dynamic_swap_fee(pool_id)?;
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+            <WeightSchedules<T>>::remove(pool_id);
+            <ArbitrageConfigs<T>>::remove(pool_id);
+            weight = weight.saturating_add(T::DbWeight::get().writes(2));
             if let MarketType::Categorical(_) = market_type {
                 let extra_weight = Self::clean_up_pool_categorical(
                     pool_id,
@@ -2226,8 +3464,30 @@ mod pallet {
                 )?;
                 weight = weight.saturating_add(extra_weight);
             }
+            // Remove the assets the pool no longer holds (for example, outcome tokens which were
+            // redeemed for the base asset), so that liquidity providers can exit the pool.
+            let pool_account_id = Self::pool_account_id(&pool_id);
+            let mut total_assets = 0usize;
+            Self::mutate_pool(pool_id, |pool| {
+                let base_asset = pool.base_asset;
+                total_assets = pool.assets.len();
+                pool.assets.retain(|asset| {
+                    *asset == base_asset
+                        || T::AssetManager::free_balance(*asset, &pool_account_id) != Zero::zero()
+                });
+                let assets = &pool.assets;
+                if let Some(weights) = pool.weights.as_mut() {
+                    weights.retain(|asset, _| assets.binary_search(asset).is_ok());
+                    pool.total_weight = Some(
+                        weights.values().fold(0u128, |acc, weight| acc.saturating_add(*weight)),
+                    );
+                }
+                Ok(())
+            })?;
+            weight = weight.saturating_add(
+                T::DbWeight::get().reads_writes(total_assets.saturating_add(1) as u64, 1),
+            );
             Self::deposit_event(Event::<T>::PoolCleanedUp(pool_id));
-            // (No extra work required for scalar markets!)
             Ok(weight)
         }
 
@@ -2321,6 +3581,17 @@ mod pallet {
                                 T::RikiddoSigmoidFeeMarketEma::cost(pool_id, &outstanding_after)?;
                             cost_before.checked_sub(&cost_after).ok_or(ArithmeticError::Overflow)?
                         }
+                        ScoringRule::LMSR { liquidity_parameter } => {
+                            ensure!(asset_in != asset_out, Error::<T>::UnsupportedTrade);
+                            crate::math_lmsr::calc_out_given_in(
+                                Self::lmsr_price(&pool_id, &pool, &asset_in)?,
+                                Self::lmsr_price(&pool_id, &pool, &asset_out)?,
+                                liquidity_parameter,
+                                asset_amount_in.saturated_into(),
+                                pool.swap_fee.ok_or(Error::<T>::PoolMissingFee)?.saturated_into(),
+                            )?
+                            .saturated_into()
+                        }
                     };
 
                     if let Some(maao) = min_asset_amount_out {
@@ -2344,10 +3615,16 @@ mod pallet {
             swap_exact_amount::<_, _, _, T>(params)?;
 
             match pool.scoring_rule {
+                ScoringRule::CPMM if <DynamicSwapFees<T>>::contains_key(pool_id) => {
+                    Ok(T::WeightInfo::swap_exact_amount_in_cpmm_dynamic_fee())
+                }
                 ScoringRule::CPMM => Ok(T::WeightInfo::swap_exact_amount_in_cpmm()),
                 ScoringRule::RikiddoSigmoidFeeMarketEma => Ok(
                     T::WeightInfo::swap_exact_amount_in_rikiddo(pool.assets.len().saturated_into()),
                 ),
+                ScoringRule::LMSR { .. } => {
+                    Ok(T::WeightInfo::swap_exact_amount_in_lmsr(pool.assets.len().saturated_into()))
+                }
             }
         }
 
@@ -2421,6 +3698,17 @@ mod pallet {
                                 T::RikiddoSigmoidFeeMarketEma::cost(pool_id, &outstanding_after)?;
                             cost_after.checked_sub(&cost_before).ok_or(ArithmeticError::Overflow)?
                         }
+                        ScoringRule::LMSR { liquidity_parameter } => {
+                            ensure!(asset_in != asset_out, Error::<T>::UnsupportedTrade);
+                            crate::math_lmsr::calc_in_given_out(
+                                Self::lmsr_price(&pool_id, &pool, &asset_in)?,
+                                Self::lmsr_price(&pool_id, &pool, &asset_out)?,
+                                liquidity_parameter,
+                                asset_amount_out.saturated_into(),
+                                pool.swap_fee.ok_or(Error::<T>::PoolMissingFee)?.saturated_into(),
+                            )?
+                            .saturated_into()
+                        }
                     };
 
                     if let Some(maai) = max_asset_amount_in {
@@ -2442,12 +3730,18 @@ mod pallet {
             swap_exact_amount::<_, _, _, T>(params)?;
 
             match pool.scoring_rule {
+                ScoringRule::CPMM if <DynamicSwapFees<T>>::contains_key(pool_id) => {
+                    Ok(T::WeightInfo::swap_exact_amount_out_cpmm_dynamic_fee())
+                }
                 ScoringRule::CPMM => Ok(T::WeightInfo::swap_exact_amount_out_cpmm()),
                 ScoringRule::RikiddoSigmoidFeeMarketEma => {
                     Ok(T::WeightInfo::swap_exact_amount_out_rikiddo(
                         pool.assets.len().saturated_into(),
                     ))
                 }
+                ScoringRule::LMSR { .. } => Ok(T::WeightInfo::swap_exact_amount_out_lmsr(
+                    pool.assets.len().saturated_into(),
+                )),
             }
         }
     }
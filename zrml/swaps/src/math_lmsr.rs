@@ -0,0 +1,362 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! Math of pools using the logarithmic market scoring rule (LMSR) with a fixed liquidity
+//! parameter `b`.
+//!
+//! The pool acts as market maker for the outcome assets it holds. The reserve `r_i` of an outcome
+//! in the pool determines the outstanding quantity `q_i = c - r_i` of the outcome for an arbitrary
+//! constant `c`, so the pool prices trades using the LMSR cost function
+//! `C(q) = b * ln(sum(e ** (q_i / b)))` and the price of an outcome is
+//! `p_i = e ** (-r_i / b) / sum(e ** (-r_j / b))`. The prices of all outcomes sum up to one unit of
+//! the base asset, which is not part of the cost function. The loss of the liquidity provider is
+//! bounded by `b * ln(n)`, where `n` is the number of outcomes.
+//!
+//! All numbers are fixed point numbers with `BASE` as unit. Prices are denoted in units of the
+//! base asset; the base asset itself is denoted by `None`.
+
+use crate::{
+    check_arithm_rslt::CheckArithmRslt,
+    fixed::{bdiv, bfloor, bmul, bpowi, btoi},
+};
+use frame_support::dispatch::DispatchError;
+use zeitgeist_primitives::constants::BASE;
+
+/// Euler's number `e`.
+const EULER: u128 = 27_182_818_285;
+/// The natural logarithm of two.
+const LN_2: u128 = 6_931_471_806;
+/// `e ** -x` is rounded to zero for `x >= EXP_NEG_ZERO`.
+const EXP_NEG_ZERO: u128 = 24 * BASE;
+/// The maximum number of terms used to approximate `exp` and `ln` with power series.
+const SERIES_MAX_ITERATIONS: u128 = 100;
+
+/// Calculate the LMSR cost function `b * ln(sum(e ** (q_i / b)))`.
+///
+/// # Arguments
+///
+/// * `outstanding` - The outstanding quantity `q_i` of each outcome
+/// * `liquidity` - The liquidity parameter `b` of the pool
+pub fn calc_cost(outstanding: &[u128], liquidity: u128) -> Result<u128, DispatchError> {
+    let mut sum: u128 = 0;
+    for quantity in outstanding {
+        sum = sum.check_add_rslt(&exp(bdiv(*quantity, liquidity)?)?)?;
+    }
+    bmul(liquidity, ln(sum)?)
+}
+
+/// Calculate the price of an outcome in units of the base asset.
+///
+/// # Arguments
+///
+/// * `reserve` - The pool balance of the outcome
+/// * `reserves` - The pool balances of all outcomes of the pool, including `reserve`
+/// * `liquidity` - The liquidity parameter `b` of the pool
+pub fn calc_price(
+    reserve: u128,
+    reserves: &[u128],
+    liquidity: u128,
+) -> Result<u128, DispatchError> {
+    // Exponents are shifted by the smallest reserve, so that the sum is at least `BASE`.
+    let min = reserves.iter().min().copied().unwrap_or(reserve);
+    let numer = exp_neg(bdiv(reserve.check_sub_rslt(&min)?, liquidity)?)?;
+    let mut denom: u128 = 0;
+    for other_reserve in reserves {
+        let exponent = bdiv(other_reserve.check_sub_rslt(&min)?, liquidity)?;
+        denom = denom.check_add_rslt(&exp_neg(exponent)?)?;
+    }
+    bdiv(numer, denom)
+}
+
+/// Calculate the spot price of one asset in terms of another, including trading fees.
+///
+/// # Arguments
+///
+/// * `price_in` - The price of the ingoing asset
+/// * `price_out` - The price of the outgoing asset
+/// * `swap_fee` - The swap fee of the pool
+///
+/// # Errors
+///
+/// Returns `DispatchError::Other` if `swap_fee >= BASE`.
+pub fn calc_spot_price(
+    price_in: Option<u128>,
+    price_out: Option<u128>,
+    swap_fee: u128,
+) -> Result<u128, DispatchError> {
+    let ratio = bdiv(price_out.unwrap_or(BASE), price_in.unwrap_or(BASE))?;
+    let scale = bdiv(BASE, BASE.check_sub_rslt(&swap_fee)?)?;
+    bmul(ratio, scale)
+}
+
+/// Calculate the amount of tokens received from the pool for swapping the specified amount of
+/// tokens in, including trading fees.
+///
+/// # Arguments
+///
+/// * `price_in` - The price of the ingoing asset
+/// * `price_out` - The price of the outgoing asset
+/// * `liquidity` - The liquidity parameter `b` of the pool
+/// * `asset_amount_in` - The amount of the ingoing asset to swap in
+/// * `swap_fee` - The swap fee of the pool
+///
+/// # Errors
+///
+/// Returns `DispatchError::Other` if `swap_fee >= BASE` or if neither asset is an outcome.
+pub fn calc_out_given_in(
+    price_in: Option<u128>,
+    price_out: Option<u128>,
+    liquidity: u128,
+    asset_amount_in: u128,
+    swap_fee: u128,
+) -> Result<u128, DispatchError> {
+    let adjusted_in = bmul(asset_amount_in, BASE.check_sub_rslt(&swap_fee)?)?;
+    let ratio_in = bdiv(adjusted_in, liquidity)?;
+    match (price_in, price_out) {
+        // Buying increases the cost by the amount in:
+        // `out = b * ln(1 + (e ** (in / b) - 1) / p_out)`
+        (None, Some(price_out)) => {
+            let growth = bdiv(exp(ratio_in)?.check_sub_rslt(&BASE)?, price_out)?;
+            bmul(liquidity, ln(BASE.check_add_rslt(&growth)?)?)
+        }
+        // Selling decreases the cost by the amount out:
+        // `out = -b * ln(1 - p_in * (1 - e ** (-in / b)))`
+        (Some(price_in), None) => {
+            let shrinkage = bmul(price_in, BASE.check_sub_rslt(&exp_neg(ratio_in)?)?)?;
+            bmul(liquidity, ln(bdiv(BASE, BASE.check_sub_rslt(&shrinkage)?)?)?)
+        }
+        // Swapping outcomes doesn't change the cost:
+        // `out = b * ln(1 + p_in / p_out * (1 - e ** (-in / b)))`
+        (Some(price_in), Some(price_out)) => {
+            let released = BASE.check_sub_rslt(&exp_neg(ratio_in)?)?;
+            let growth = bmul(bdiv(price_in, price_out)?, released)?;
+            bmul(liquidity, ln(BASE.check_add_rslt(&growth)?)?)
+        }
+        (None, None) => Err(DispatchError::Other("[calc_out_given_in]: expected an outcome")),
+    }
+}
+
+/// Calculate the required amount of tokens to swap in to receive a specified amount of tokens
+/// from the pool, including trading fees.
+///
+/// # Arguments
+///
+/// * `price_in` - The price of the ingoing asset
+/// * `price_out` - The price of the outgoing asset
+/// * `liquidity` - The liquidity parameter `b` of the pool
+/// * `asset_amount_out` - The amount of tokens of the outgoing asset to receive
+/// * `swap_fee` - The swap fee of the pool
+///
+/// # Errors
+///
+/// Returns `DispatchError::Other` if `swap_fee >= BASE`, if neither asset is an outcome or if
+/// no amount of the ingoing asset suffices to receive `asset_amount_out`.
+pub fn calc_in_given_out(
+    price_in: Option<u128>,
+    price_out: Option<u128>,
+    liquidity: u128,
+    asset_amount_out: u128,
+    swap_fee: u128,
+) -> Result<u128, DispatchError> {
+    let ratio_out = bdiv(asset_amount_out, liquidity)?;
+    let adjusted_in = match (price_in, price_out) {
+        // `in = b * ln(1 + p_out * (e ** (out / b) - 1))`
+        (None, Some(price_out)) => {
+            let growth = bmul(price_out, exp(ratio_out)?.check_sub_rslt(&BASE)?)?;
+            bmul(liquidity, ln(BASE.check_add_rslt(&growth)?)?)?
+        }
+        // `in = -b * ln(1 - (1 - e ** (-out / b)) / p_in)`
+        (Some(price_in), None) => {
+            let shrinkage = bdiv(BASE.check_sub_rslt(&exp_neg(ratio_out)?)?, price_in)?;
+            bmul(liquidity, ln(bdiv(BASE, BASE.check_sub_rslt(&shrinkage)?)?)?)?
+        }
+        // `in = -b * ln(1 - p_out / p_in * (e ** (out / b) - 1))`
+        (Some(price_in), Some(price_out)) => {
+            let growth = exp(ratio_out)?.check_sub_rslt(&BASE)?;
+            let released = bmul(bdiv(price_out, price_in)?, growth)?;
+            bmul(liquidity, ln(bdiv(BASE, BASE.check_sub_rslt(&released)?)?)?)?
+        }
+        (None, None) => {
+            return Err(DispatchError::Other("[calc_in_given_out]: expected an outcome"));
+        }
+    };
+    bdiv(adjusted_in, BASE.check_sub_rslt(&swap_fee)?)
+}
+
+/// Compute `e ** x`.
+///
+/// # Errors
+///
+/// Returns `DispatchError::Other` if the result overflows.
+fn exp(x: u128) -> Result<u128, DispatchError> {
+    let whole_pow = bpowi(EULER, btoi(x)?)?;
+    let remain = x.check_sub_rslt(&bfloor(x)?)?;
+
+    // Taylor series of `e ** remain`, where `0 <= remain < 1`.
+    let mut term = BASE;
+    let mut sum = BASE;
+    for i in 1..=SERIES_MAX_ITERATIONS {
+        term = bdiv(bmul(term, remain)?, i.check_mul_rslt(&BASE)?)?;
+        if term == 0 {
+            break;
+        }
+        sum = sum.check_add_rslt(&term)?;
+    }
+
+    bmul(whole_pow, sum)
+}
+
+/// Compute `e ** -x`.
+fn exp_neg(x: u128) -> Result<u128, DispatchError> {
+    if x >= EXP_NEG_ZERO {
+        return Ok(0);
+    }
+    bdiv(BASE, exp(x)?)
+}
+
+/// Compute the natural logarithm of `x`.
+///
+/// # Errors
+///
+/// Returns `DispatchError::Other` if `x < BASE`.
+fn ln(x: u128) -> Result<u128, DispatchError> {
+    if x < BASE {
+        return Err(DispatchError::Other("[ln]: expected x >= BASE"));
+    }
+
+    // `ln(x) = k * ln(2) + ln(m)` where `1 <= m = x / 2 ** k < 2`.
+    let mut k: u128 = 0;
+    let mut m = x;
+    while m >= 2 * BASE {
+        m = m.check_div_rslt(&2)?;
+        k = k.check_add_rslt(&1)?;
+    }
+
+    // `ln(m) = 2 * artanh(z) = 2 * (z + z ** 3 / 3 + z ** 5 / 5 + ...)`, where
+    // `0 <= z = (m - 1) / (m + 1) < 1 / 3`.
+    let z = bdiv(m.check_sub_rslt(&BASE)?, m.check_add_rslt(&BASE)?)?;
+    let z_squared = bmul(z, z)?;
+    let mut power = z;
+    let mut sum = z;
+    for i in 1..=SERIES_MAX_ITERATIONS {
+        power = bmul(power, z_squared)?;
+        let term = power.check_div_rslt(&i.check_mul_rslt(&2)?.check_add_rslt(&1)?)?;
+        if term == 0 {
+            break;
+        }
+        sum = sum.check_add_rslt(&term)?;
+    }
+
+    k.check_mul_rslt(&LN_2)?.check_add_rslt(&sum.check_mul_rslt(&2)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use more_asserts::assert_le;
+
+    const _1: u128 = BASE;
+    const _2: u128 = 2 * BASE;
+    const _10: u128 = 10 * BASE;
+    const _100: u128 = 100 * BASE;
+
+    fn assert_close(left: u128, right: u128, precision: u128) {
+        let diff = if left > right { left - right } else { right - left };
+        assert_le!(diff, precision, "{} is not {}-close to {}", left, precision, right);
+    }
+
+    #[test]
+    fn exp_and_ln_are_accurate() {
+        assert_eq!(exp(0), Ok(_1));
+        assert_close(exp(_1).unwrap(), EULER, 10);
+        assert_close(exp(_2 + _1 / 2).unwrap(), 121_824_939_607, 100);
+        assert_close(exp_neg(_1).unwrap(), 3_678_794_412, 10);
+        assert_eq!(exp_neg(EXP_NEG_ZERO), Ok(0));
+        assert_eq!(ln(_1), Ok(0));
+        assert_close(ln(EULER).unwrap(), _1, 10);
+        assert_close(ln(_100).unwrap(), 46_051_701_860, 100);
+        assert!(ln(_1 - 1).is_err());
+    }
+
+    #[test]
+    fn prices_sum_up_to_one() {
+        let reserves = [_100, _100 - _10, _100 + _10];
+        let prices: Vec<_> =
+            reserves.iter().map(|r| calc_price(*r, &reserves, _10).unwrap()).collect();
+        assert_close(prices.iter().sum(), _1, 10);
+        assert_close(prices[0], 2_447_284_711, 10);
+        assert_close(prices[1], 6_652_409_558, 10);
+        assert_close(prices[2], 900_305_732, 10);
+        assert_close(calc_price(_100, &[_100, _100], _10).unwrap(), _1 / 2, 1);
+    }
+
+    #[test]
+    fn buying_increases_the_cost_by_the_amount_in() {
+        let reserves = [_100, _100 - _10];
+        let price = calc_price(_100, &reserves, _10).unwrap();
+        let amount_out = calc_out_given_in(None, Some(price), _10, _2, 0).unwrap();
+        // Outstanding quantities relative to `_100`
+        let cost_before = calc_cost(&[0, _10], _10).unwrap();
+        let cost_after = calc_cost(&[amount_out, _10], _10).unwrap();
+        assert_close(cost_after - cost_before, _2, 100);
+        assert_close(calc_in_given_out(None, Some(price), _10, amount_out, 0).unwrap(), _2, 100);
+    }
+
+    #[test]
+    fn selling_decreases_the_cost_by_the_amount_out() {
+        let reserves = [_100, _100 - _10];
+        let price = calc_price(_100 - _10, &reserves, _10).unwrap();
+        let amount_out = calc_out_given_in(Some(price), None, _10, _2, 0).unwrap();
+        let cost_before = calc_cost(&[0, _10], _10).unwrap();
+        let cost_after = calc_cost(&[0, _10 - _2], _10).unwrap();
+        assert_close(cost_before - cost_after, amount_out, 100);
+        assert_close(calc_in_given_out(Some(price), None, _10, amount_out, 0).unwrap(), _2, 100);
+    }
+
+    #[test]
+    fn swapping_outcomes_keeps_the_cost() {
+        let reserves = [_100, _100 - _10];
+        let price_in = calc_price(_100 - _10, &reserves, _10).unwrap();
+        let price_out = calc_price(_100, &reserves, _10).unwrap();
+        let amount_out = calc_out_given_in(Some(price_in), Some(price_out), _10, _2, 0).unwrap();
+        let cost_before = calc_cost(&[0, _10], _10).unwrap();
+        let cost_after = calc_cost(&[amount_out, _10 - _2], _10).unwrap();
+        assert_close(cost_before, cost_after, 100);
+        assert_close(
+            calc_in_given_out(Some(price_in), Some(price_out), _10, amount_out, 0).unwrap(),
+            _2,
+            100,
+        );
+    }
+
+    #[test]
+    fn fees_are_charged_on_the_amount_in() {
+        let fee = _1 / 10;
+        let price = _1 / 2;
+        let amount_out = calc_out_given_in(None, Some(price), _10, _2, fee).unwrap();
+        assert_eq!(amount_out, calc_out_given_in(None, Some(price), _10, _2 - _2 / 10, 0).unwrap());
+        assert_close(calc_in_given_out(None, Some(price), _10, amount_out, fee).unwrap(), _2, 100);
+        assert_eq!(calc_spot_price(None, Some(price), fee), Ok(_1 / 2 * 10 / 9 + 1));
+    }
+
+    #[test]
+    fn selling_more_than_the_liquidity_allows_fails() {
+        // The pool can't pay out the full price of an outcome for a single share.
+        assert!(calc_in_given_out(Some(_1 / 2), None, _10, _10, 0).is_err());
+        assert!(calc_out_given_in(None, None, _10, _1, 0).is_err());
+    }
+}
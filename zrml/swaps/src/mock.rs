@@ -29,21 +29,26 @@
 
 use crate as zrml_swaps;
 use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use orml_traits::parameter_type_with_key;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
 };
 use substrate_fixed::{types::extra::U33, FixedI128, FixedU128};
 use zeitgeist_primitives::{
     constants::mock::{
-        BalanceFractionalDecimals, BlockHashCount, ExistentialDeposit, ExistentialDeposits,
-        GetNativeCurrencyId, LiquidityMiningPalletId, MaxAssets, MaxInRatio, MaxLocks, MaxOutRatio,
-        MaxReserves, MaxSwapFee, MaxTotalWeight, MaxWeight, MinAssets, MinLiquidity, MinSubsidy,
-        MinWeight, MinimumPeriod, PmPalletId, SwapsPalletId, BASE,
+        ArbitrageBountyPalletId, ArbitrageKeeperShare, BalanceFractionalDecimals, BlockHashCount,
+        ExistentialDeposit, GetNativeCurrencyId, IncentiveProgramClaimPeriod,
+        IncentiveProgramDeposit, LiquidityMiningPalletId, MaxAssets, MaxInRatio,
+        MaxIncentivePrograms, MaxLocks, MaxOutRatio, MaxReserves, MaxSwapFee, MaxTotalWeight,
+        MaxWeight, MinAssets, MinLiquidity, MinSubsidy, MinWeight, MinimumPeriod, PmPalletId,
+        SwapsPalletId, TreasuryPalletId, BASE,
     },
     types::{
         AccountIdTest, Amount, Asset, Balance, BasicCurrencyAdapter, BlockNumber, BlockTest,
-        CurrencyId, Hash, Index, MarketId, Moment, PoolId, SerdeWrapper, UncheckedExtrinsicTest,
+        CurrencyId, Hash, Index, MarketId, Moment, PoolId, SerdeWrapper, TradeQuote,
+        UncheckedExtrinsicTest,
     },
 };
 use zrml_rikiddo::types::{EmaMarketVolume, FeeSigmoid, RikiddoSigmoidMV};
@@ -56,10 +61,25 @@ pub const EVE: AccountIdTest = 4;
 
 pub type UncheckedExtrinsic = UncheckedExtrinsicTest<Runtime>;
 
-// Mocked exit fee for easier calculations
+// Mocked exit fee and swap fee shares for easier calculations
 parameter_types! {
+    pub storage CreatorSwapFeeShareMock: Perbill = Perbill::zero();
     pub storage ExitFeeMock: Balance = BASE / 10;
+    pub storage ProtocolSwapFeeShareMock: Perbill = Perbill::zero();
     pub const MinSubsidyPerAccount: Balance = BASE;
+    pub storage OutcomeExistentialDepositMock: Balance = 0;
+}
+
+parameter_type_with_key! {
+    pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
+        match currency_id {
+            Asset::Ztg => ExistentialDeposit::get(),
+            Asset::CategoricalOutcome(..) | Asset::ScalarOutcome(..) => {
+                OutcomeExistentialDepositMock::get()
+            }
+            _ => 0
+        }
+    };
 }
 
 construct_runtime!(
@@ -84,6 +104,9 @@ construct_runtime!(
 pub type AssetManager = Currencies;
 
 impl crate::Config for Runtime {
+    type ArbitrageBountyPalletId = ArbitrageBountyPalletId;
+    type ArbitrageKeeperShare = ArbitrageKeeperShare;
+    type CreatorSwapFeeShare = CreatorSwapFeeShareMock;
     type Event = Event;
     type ExitFee = ExitFeeMock;
     type FixedTypeU = <Runtime as zrml_rikiddo::Config>::FixedTypeU;
@@ -102,7 +125,9 @@ impl crate::Config for Runtime {
     type MinSubsidyPerAccount = MinSubsidyPerAccount;
     type MinWeight = MinWeight;
     type PalletId = SwapsPalletId;
+    type ProtocolSwapFeeShare = ProtocolSwapFeeShareMock;
     type RikiddoSigmoidFeeMarketEma = RikiddoSigmoidFeeMarketEma;
+    type TreasuryPalletId = TreasuryPalletId;
     type AssetManager = AssetManager;
     type WeightInfo = zrml_swaps::weights::WeightInfo<Runtime>;
 }
@@ -170,9 +195,13 @@ impl pallet_balances::Config for Runtime {
 }
 
 impl zrml_liquidity_mining::Config for Runtime {
+    type AssetManager = AssetManager;
     type Event = Event;
+    type IncentiveProgramClaimPeriod = IncentiveProgramClaimPeriod;
+    type IncentiveProgramDeposit = IncentiveProgramDeposit;
     type MarketCommons = MarketCommons;
     type MarketId = MarketId;
+    type MaxIncentivePrograms = MaxIncentivePrograms;
     type PalletId = LiquidityMiningPalletId;
     type WeightInfo = zrml_liquidity_mining::weights::WeightInfo<Runtime>;
 }
@@ -247,6 +276,73 @@ sp_api::mock_impl_runtime_apis! {
             Swaps::pool_account_id(pool_id)
         }
 
+        fn get_all_spot_prices(
+            pool_id: &PoolId,
+            with_fees: bool,
+        ) -> Option<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>> {
+            Swaps::get_all_spot_prices(pool_id, with_fees)
+                .ok()
+                .map(|prices| prices.into_iter().map(|(asset, price)| (asset, SerdeWrapper(price))).collect())
+        }
+
+        fn get_pool_balances(
+            pool_id: &PoolId,
+        ) -> Option<Vec<(Asset<MarketId>, SerdeWrapper<Balance>)>> {
+            Swaps::get_pool_balances(pool_id)
+                .ok()
+                .map(|balances| balances.into_iter().map(|(asset, balance)| (asset, SerdeWrapper(balance))).collect())
+        }
+
+        fn quote_swap_exact_amount_in(
+            pool_id: &PoolId,
+            asset_in: &Asset<MarketId>,
+            asset_amount_in: Balance,
+            asset_out: &Asset<MarketId>,
+        ) -> Option<TradeQuote<Balance>> {
+            Swaps::quote_swap_exact_amount_in(*pool_id, *asset_in, asset_amount_in, *asset_out).ok()
+        }
+
+        fn quote_swap_exact_amount_out(
+            pool_id: &PoolId,
+            asset_in: &Asset<MarketId>,
+            asset_out: &Asset<MarketId>,
+            asset_amount_out: Balance,
+        ) -> Option<TradeQuote<Balance>> {
+            Swaps::quote_swap_exact_amount_out(*pool_id, *asset_in, *asset_out, asset_amount_out).ok()
+        }
+
+        fn quote_pool_join_with_exact_asset_amount(
+            pool_id: &PoolId,
+            asset_in: &Asset<MarketId>,
+            asset_amount: Balance,
+        ) -> Option<TradeQuote<Balance>> {
+            Swaps::quote_pool_join_with_exact_asset_amount(*pool_id, *asset_in, asset_amount).ok()
+        }
+
+        fn quote_pool_join_with_exact_pool_amount(
+            pool_id: &PoolId,
+            asset: &Asset<MarketId>,
+            pool_amount: Balance,
+        ) -> Option<TradeQuote<Balance>> {
+            Swaps::quote_pool_join_with_exact_pool_amount(*pool_id, *asset, pool_amount).ok()
+        }
+
+        fn quote_pool_exit_with_exact_asset_amount(
+            pool_id: &PoolId,
+            asset: &Asset<MarketId>,
+            asset_amount: Balance,
+        ) -> Option<TradeQuote<Balance>> {
+            Swaps::quote_pool_exit_with_exact_asset_amount(*pool_id, *asset, asset_amount).ok()
+        }
+
+        fn quote_pool_exit_with_exact_pool_amount(
+            pool_id: &PoolId,
+            asset: &Asset<MarketId>,
+            pool_amount: Balance,
+        ) -> Option<TradeQuote<Balance>> {
+            Swaps::quote_pool_exit_with_exact_pool_amount(*pool_id, *asset, pool_amount).ok()
+        }
+
         fn pool_shares_id(pool_id: PoolId) -> Asset<SerdeWrapper<MarketId>> {
             Asset::PoolShare(SerdeWrapper(pool_id))
         }
@@ -0,0 +1,302 @@
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! Traits and implementation for routing trades on CPMM pools through complete sets.
+
+use crate::{
+    fixed::bmul,
+    math::{calc_in_given_out, calc_out_given_in},
+    root::calc_preimage,
+};
+use alloc::collections::btree_map::BTreeMap;
+use parity_scale_codec::MaxEncodedLen;
+use sp_runtime::{
+    traits::{AtLeast32Bit, AtLeast32BitUnsigned},
+    SaturatedConversion,
+};
+use zeitgeist_primitives::{
+    constants::BASE,
+    types::{Asset, Pool},
+};
+
+type Fixed = u128;
+
+const TOLERANCE: Fixed = BASE / 1_000_000; // 0.000001
+
+/// This trait implements the estimates used to route trades on CPMM pools.
+///
+/// All calculations depend on on-chain balances, which are passed using the `balances` parameter.
+/// Swaps are simulated in the order in which they're executed.
+pub(crate) trait RouterForCpmm<Balance, MarketId>
+where
+    MarketId: MaxEncodedLen,
+{
+    /// Calculate the amount of `asset_out` received by swapping `amount_in` units of `asset_in`
+    /// directly.
+    ///
+    /// Arguments:
+    ///
+    /// * `balances`: Maps assets to their current balance.
+    /// * `asset_in`: The asset entering the pool.
+    /// * `asset_out`: The asset leaving the pool.
+    /// * `amount_in`: The amount of `asset_in` to swap.
+    /// * `max_in_ratio`: The maximum ratio of the pool balance that may enter the pool.
+    fn calc_route_direct(
+        &self,
+        balances: &BTreeMap<Asset<MarketId>, Balance>,
+        asset_in: Asset<MarketId>,
+        asset_out: Asset<MarketId>,
+        amount_in: Balance,
+        max_in_ratio: Fixed,
+    ) -> Result<Balance, &'static str>;
+
+    /// Calculate the amount of the outcome `asset_out` received by buying `amount_in` complete
+    /// sets and swapping all other outcomes for `asset_out`.
+    ///
+    /// Arguments:
+    ///
+    /// * `balances`: Maps assets to their current balance.
+    /// * `asset_out`: The outcome to buy.
+    /// * `amount_in`: The amount of the base asset to spend.
+    /// * `max_in_ratio`: The maximum ratio of the pool balance that may enter the pool.
+    fn calc_route_mint_sell(
+        &self,
+        balances: &BTreeMap<Asset<MarketId>, Balance>,
+        asset_out: Asset<MarketId>,
+        amount_in: Balance,
+        max_in_ratio: Fixed,
+    ) -> Result<Balance, &'static str>;
+
+    /// Approximate the number of complete sets that can be sold by swapping at most `amount_in`
+    /// units of the outcome `asset_in` for all other outcomes. The result is the amount of the
+    /// base asset received.
+    ///
+    /// Arguments:
+    ///
+    /// * `balances`: Maps assets to their current balance.
+    /// * `asset_in`: The outcome to sell.
+    /// * `amount_in`: The amount of `asset_in` to spend.
+    /// * `max_out_ratio`: The maximum ratio of the pool balance that may leave the pool.
+    /// * `max_iterations`: Maximum number of iterations allowed in the bisection method.
+    fn calc_route_buy_burn(
+        &self,
+        balances: &BTreeMap<Asset<MarketId>, Balance>,
+        asset_in: Asset<MarketId>,
+        amount_in: Balance,
+        max_out_ratio: Fixed,
+        max_iterations: usize,
+    ) -> Result<(Balance, usize), &'static str>;
+}
+
+impl<Balance, MarketId> RouterForCpmm<Balance, MarketId> for Pool<Balance, MarketId>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    MarketId: MaxEncodedLen + AtLeast32Bit + Copy,
+{
+    fn calc_route_direct(
+        &self,
+        balances: &BTreeMap<Asset<MarketId>, Balance>,
+        asset_in: Asset<MarketId>,
+        asset_out: Asset<MarketId>,
+        amount_in: Balance,
+        max_in_ratio: Fixed,
+    ) -> Result<Balance, &'static str> {
+        let mut balances = to_fixed(balances);
+        let amount_out = simulate_swap_exact_amount_in(
+            self,
+            &mut balances,
+            asset_in,
+            asset_out,
+            amount_in.saturated_into(),
+            max_in_ratio,
+        )?;
+        Ok(amount_out.saturated_into())
+    }
+
+    fn calc_route_mint_sell(
+        &self,
+        balances: &BTreeMap<Asset<MarketId>, Balance>,
+        asset_out: Asset<MarketId>,
+        amount_in: Balance,
+        max_in_ratio: Fixed,
+    ) -> Result<Balance, &'static str> {
+        let mut balances = to_fixed(balances);
+        let amount_in: Fixed = amount_in.saturated_into();
+        let mut amount_out = amount_in;
+        for asset in self.assets.iter().filter(|a| **a != self.base_asset && **a != asset_out) {
+            let received = simulate_swap_exact_amount_in(
+                self,
+                &mut balances,
+                *asset,
+                asset_out,
+                amount_in,
+                max_in_ratio,
+            )?;
+            amount_out = amount_out.checked_add(received).ok_or("Arithmetic overflow")?;
+        }
+        Ok(amount_out.saturated_into())
+    }
+
+    fn calc_route_buy_burn(
+        &self,
+        balances: &BTreeMap<Asset<MarketId>, Balance>,
+        asset_in: Asset<MarketId>,
+        amount_in: Balance,
+        max_out_ratio: Fixed,
+        max_iterations: usize,
+    ) -> Result<(Balance, usize), &'static str> {
+        let balances = to_fixed(balances);
+        let amount_in: Fixed = amount_in.saturated_into();
+        let other_outcomes =
+            || self.assets.iter().filter(move |a| **a != self.base_asset && **a != asset_in);
+        // Returns the total amount of `asset_in` required to sell `amount` complete sets.
+        let calc_total_amount_in = |amount: Fixed| -> Result<Fixed, &'static str> {
+            let mut shifted_balances = balances.clone();
+            let mut total = amount;
+            for asset in other_outcomes() {
+                let required = simulate_swap_exact_amount_out(
+                    self,
+                    &mut shifted_balances,
+                    asset_in,
+                    *asset,
+                    amount,
+                    max_out_ratio,
+                )?;
+                total = total.checked_add(required).ok_or("Arithmetic overflow")?;
+            }
+            Ok(total)
+        };
+        let mut max_amount = amount_in;
+        for asset in other_outcomes() {
+            let balance = balances.get(asset).cloned().ok_or("Asset balance missing")?;
+            max_amount = max_amount.min(bmul(balance, max_out_ratio)?);
+        }
+        let (preimage, iterations) = calc_preimage::<Fixed, _>(
+            &calc_total_amount_in,
+            amount_in,
+            0,
+            max_amount,
+            max_iterations,
+            TOLERANCE,
+        )?;
+        // The approximation may overshoot. Scaling down the amount of complete sets is safe as the
+        // total amount required is a convex function of the amount of complete sets.
+        let required = calc_total_amount_in(preimage)?;
+        let amount = if required > amount_in {
+            preimage.checked_mul(amount_in).ok_or("Arithmetic overflow")? / required
+        } else {
+            preimage
+        };
+        if amount == 0 || calc_total_amount_in(amount)? > amount_in {
+            return Err("calc_route_buy_burn: Failed to find an amount of complete sets");
+        }
+        Ok((amount.saturated_into(), iterations))
+    }
+}
+
+fn to_fixed<Balance, MarketId>(
+    balances: &BTreeMap<Asset<MarketId>, Balance>,
+) -> BTreeMap<Asset<MarketId>, Fixed>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    MarketId: MaxEncodedLen + Ord + Copy,
+{
+    balances.iter().map(|(a, b)| (*a, (*b).saturated_into())).collect()
+}
+
+fn weight_of<Balance, MarketId>(
+    pool: &Pool<Balance, MarketId>,
+    asset: &Asset<MarketId>,
+) -> Result<Fixed, &'static str>
+where
+    MarketId: MaxEncodedLen + Ord,
+{
+    pool.weights
+        .as_ref()
+        .ok_or("Unexpectedly found no weights in pool.")?
+        .get(asset)
+        .cloned()
+        .ok_or("Unexpectedly found no weight for asset.")
+}
+
+/// Simulate `swap_exact_amount_in` and update `balances`. Returns the amount out.
+fn simulate_swap_exact_amount_in<Balance, MarketId>(
+    pool: &Pool<Balance, MarketId>,
+    balances: &mut BTreeMap<Asset<MarketId>, Fixed>,
+    asset_in: Asset<MarketId>,
+    asset_out: Asset<MarketId>,
+    amount_in: Fixed,
+    max_in_ratio: Fixed,
+) -> Result<Fixed, &'static str>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    MarketId: MaxEncodedLen + Ord + Copy,
+{
+    let balance_in = balances.get(&asset_in).cloned().ok_or("Asset balance missing")?;
+    let balance_out = balances.get(&asset_out).cloned().ok_or("Asset balance missing")?;
+    if amount_in > bmul(balance_in, max_in_ratio)? {
+        return Err("Maximum in ratio exceeded");
+    }
+    let amount_out = calc_out_given_in(
+        balance_in,
+        weight_of(pool, &asset_in)?,
+        balance_out,
+        weight_of(pool, &asset_out)?,
+        amount_in,
+        pool.swap_fee.ok_or("Unexpectedly found no swap fee in pool.")?.saturated_into(),
+    )?;
+    balances.insert(asset_in, balance_in.checked_add(amount_in).ok_or("Arithmetic overflow")?);
+    balances.insert(
+        asset_out,
+        balance_out.checked_sub(amount_out).ok_or("Unexpected arithmetic underflow")?,
+    );
+    Ok(amount_out)
+}
+
+/// Simulate `swap_exact_amount_out` and update `balances`. Returns the amount in.
+fn simulate_swap_exact_amount_out<Balance, MarketId>(
+    pool: &Pool<Balance, MarketId>,
+    balances: &mut BTreeMap<Asset<MarketId>, Fixed>,
+    asset_in: Asset<MarketId>,
+    asset_out: Asset<MarketId>,
+    amount_out: Fixed,
+    max_out_ratio: Fixed,
+) -> Result<Fixed, &'static str>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    MarketId: MaxEncodedLen + Ord + Copy,
+{
+    let balance_in = balances.get(&asset_in).cloned().ok_or("Asset balance missing")?;
+    let balance_out = balances.get(&asset_out).cloned().ok_or("Asset balance missing")?;
+    if amount_out > bmul(balance_out, max_out_ratio)? {
+        return Err("Maximum out ratio exceeded");
+    }
+    let amount_in = calc_in_given_out(
+        balance_in,
+        weight_of(pool, &asset_in)?,
+        balance_out,
+        weight_of(pool, &asset_out)?,
+        amount_out,
+        pool.swap_fee.ok_or("Unexpectedly found no swap fee in pool.")?.saturated_into(),
+    )?;
+    balances.insert(asset_in, balance_in.checked_add(amount_in).ok_or("Arithmetic overflow")?);
+    balances.insert(
+        asset_out,
+        balance_out.checked_sub(amount_out).ok_or("Unexpected arithmetic underflow")?,
+    );
+    Ok(amount_in)
+}
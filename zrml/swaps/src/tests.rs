@@ -24,31 +24,36 @@
 #![cfg(all(feature = "mock", test))]
 
 use crate::{
-    events::{CommonPoolEventParams, PoolAssetEvent, PoolAssetsEvent, SwapEvent},
+    events::{
+        CommonPoolEventParams, PoolAssetEvent, PoolAssetsEvent, SwapEvent, SwapRoute,
+        SwapRoutedEvent,
+    },
     mock::*,
-    BalanceOf, Config, Event, MarketIdOf, PoolsCachedForArbitrage, SubsidyProviders,
-    ARBITRAGE_MAX_ITERATIONS,
+    ArbitrageConfigs, BalanceOf, Config, DynamicSwapFees, Event, MarketIdOf,
+    PoolsCachedForArbitrage, SubsidyProviders, WeightSchedules, ARBITRAGE_MAX_ITERATIONS,
+    ARBITRAGE_TOLERANCE,
 };
 use frame_support::{
     assert_err, assert_noop, assert_ok, assert_storage_noop, error::BadOrigin, traits::Hooks,
     weights::Weight,
 };
-use more_asserts::{assert_ge, assert_le};
+use more_asserts::{assert_ge, assert_gt, assert_le};
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{Perbill, SaturatedConversion};
+use substrate_fixed::{types::extra::U33, FixedI128};
 #[allow(unused_imports)]
 use test_case::test_case;
 use zeitgeist_primitives::{
-    constants::BASE,
+    constants::{BASE, CENT},
     traits::Swaps as _,
     types::{
-        AccountIdTest, Asset, Balance, BlockNumber, Deadlines, Market, MarketBonds, MarketCreation,
-        MarketDisputeMechanism, MarketId, MarketPeriod, MarketStatus, MarketType, Moment,
-        OutcomeReport, PoolId, PoolStatus, ScoringRule,
+        AccountIdTest, ArbitrageConfig, Asset, Balance, BlockNumber, Deadlines, Market,
+        MarketBonds, MarketCreation, MarketDisputeMechanism, MarketId, MarketPeriod, MarketStatus,
+        MarketType, Moment, OutcomeReport, PoolId, PoolStatus, ScoringRule,
     },
 };
 use zrml_market_commons::MarketCommonsPalletApi;
-use zrml_rikiddo::traits::RikiddoMVPallet;
+use zrml_rikiddo::{traits::RikiddoMVPallet, types::MarketVolumeState};
 
 pub const ASSET_A: Asset<MarketId> = Asset::CategoricalOutcome(0, 65);
 pub const ASSET_B: Asset<MarketId> = Asset::CategoricalOutcome(0, 66);
@@ -1675,10 +1680,39 @@ fn clean_up_pool_leaves_only_correct_assets() {
         let pool = Swaps::pool(pool_id).unwrap();
         assert_eq!(pool.pool_status, PoolStatus::Clean);
         assert_eq!(Swaps::pool_by_id(pool_id).unwrap().assets, vec![ASSET_A, ASSET_D]);
+        assert_eq!(
+            pool.weights.unwrap().into_iter().collect::<Vec<_>>(),
+            vec![(ASSET_A, _2), (ASSET_D, _2)]
+        );
+        assert_eq!(pool.total_weight, Some(_4));
         System::assert_last_event(Event::PoolCleanedUp(pool_id).into());
     });
 }
 
+#[test]
+fn clean_up_pool_removes_assets_without_balance() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool(ScoringRule::CPMM, Some(0), true);
+        let pool_id = 0;
+        assert_ok!(Swaps::close_pool(pool_id));
+        // Simulate the redemption of the winning outcome tokens held by the pool.
+        let pool_account = Swaps::pool_account_id(&pool_id);
+        let balance = Currencies::free_balance(ASSET_A, &pool_account);
+        assert_ok!(Currencies::withdraw(ASSET_A, &pool_account, balance));
+        let cat_idx = if let Asset::CategoricalOutcome(_, cidx) = ASSET_A { cidx } else { 0 };
+        assert_ok!(Swaps::clean_up_pool(
+            &MarketType::Categorical(4),
+            pool_id,
+            &OutcomeReport::Categorical(cat_idx),
+            &Default::default()
+        ));
+        let pool = Swaps::pool_by_id(pool_id).unwrap();
+        assert_eq!(pool.assets, vec![ASSET_D]);
+        assert_eq!(pool.weights.unwrap().into_iter().collect::<Vec<_>>(), vec![(ASSET_D, _2)]);
+        assert_eq!(pool.total_weight, Some(_2));
+    });
+}
+
 #[test]
 fn clean_up_pool_handles_rikiddo_pools_properly() {
     ExtBuilder::default().build().execute_with(|| {
@@ -3295,7 +3329,7 @@ fn execute_arbitrage_correctly_observes_min_balance_buy_burn() {
             arbitrage_amount + SENTINEL_AMOUNT,
         ));
 
-        assert_ok!(Swaps::execute_arbitrage(pool_id, ARBITRAGE_MAX_ITERATIONS));
+        assert_ok!(Swaps::do_execute_arbitrage(pool_id, None));
 
         assert_eq!(
             Currencies::free_balance(base_asset, &pool_account_id),
@@ -3353,7 +3387,11 @@ fn execute_arbitrage_observes_min_balances_mint_sell() {
         // The arbitrage amount of mint-sell should always be so that min_balances are not
         // violated (provided the pool starts in valid state), _except_ when the approximation
         // overshoots the target. We simulate this by using exactly one iteration.
-        assert_ok!(Swaps::execute_arbitrage(pool_id, 1));
+        ArbitrageConfigs::<Runtime>::insert(
+            pool_id,
+            ArbitrageConfig { tolerance: ARBITRAGE_TOLERANCE, max_iterations: 1 },
+        );
+        assert_ok!(Swaps::do_execute_arbitrage(pool_id, None));
 
         // Using only one iteration means that the arbitrage amount will be slightly more than
         // 9 / 30 * min_balance, but that would result in a violation of the minimum balance of the
@@ -3379,39 +3417,1225 @@ fn execute_arbitrage_observes_min_balances_mint_sell() {
     });
 }
 
-fn alice_signed() -> Origin {
-    Origin::signed(ALICE)
+#[test]
+fn execute_arbitrage_rewards_keeper_with_mint_sell() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let assets = ASSETS;
+        assets.iter().cloned().for_each(|asset| {
+            assert_ok!(Currencies::deposit(asset, &BOB, _10000));
+        });
+        let balance = <Runtime as crate::Config>::MinLiquidity::get();
+        let base_asset = ASSET_A;
+        assert_ok!(Swaps::create_pool(
+            BOB,
+            assets.into(),
+            base_asset,
+            0,
+            ScoringRule::CPMM,
+            Some(0),
+            Some(balance),
+            Some(vec![_3, _1, _1, _1]),
+        ));
+        let pool_id = 0;
+        assert_ok!(Swaps::open_pool(pool_id));
+
+        // Withdraw a certain amount of outcome tokens to push the total spot price to 10 / 9.
+        let pool_account_id = Swaps::pool_account_id(&pool_id);
+        let amount_removed = _25;
+        assert_ok!(Currencies::withdraw(ASSET_B, &pool_account_id, amount_removed));
+        crate::PoolsCachedForArbitrage::<Runtime>::insert(pool_id, ());
+        let bounty_account_id = Swaps::arbitrage_bounty_account_id();
+        assert_ok!(Currencies::deposit(base_asset, &bounty_account_id, _100));
+
+        assert_ok!(Swaps::execute_arbitrage(Origin::signed(CHARLIE), pool_id));
+
+        // The keeper receives 10% of `arbitrage_amount * (10 / 9 - 1) / 2` from the bounty
+        // account, so the pool only loses the arbitrage amount.
+        let arbitrage_amount = 49_537_658_690;
+        let reward = 275_209_214;
+        assert_eq!(
+            Currencies::free_balance(base_asset, &pool_account_id),
+            balance - arbitrage_amount,
+        );
+        assert_eq!(Currencies::free_balance(ASSET_C, &pool_account_id), balance + arbitrage_amount);
+        assert_eq!(Currencies::free_balance(base_asset, &CHARLIE), reward);
+        assert_eq!(Currencies::free_balance(base_asset, &bounty_account_id), _100 - reward);
+        assert!(!crate::PoolsCachedForArbitrage::<Runtime>::contains_key(pool_id));
+        System::assert_has_event(Event::ArbitrageMintSell(pool_id, arbitrage_amount).into());
+        System::assert_last_event(Event::ArbitrageKeeperRewarded(pool_id, CHARLIE, reward).into());
+    });
 }
 
-fn create_initial_pool(
-    scoring_rule: ScoringRule,
+#[test]
+fn execute_arbitrage_rewards_keeper_with_buy_burn() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let assets = ASSETS;
+        assets.iter().cloned().for_each(|asset| {
+            assert_ok!(Currencies::deposit(asset, &BOB, _10000));
+        });
+        let balance = <Runtime as crate::Config>::MinLiquidity::get();
+        let base_asset = ASSET_A;
+        assert_ok!(Swaps::create_pool(
+            BOB,
+            assets.into(),
+            base_asset,
+            0,
+            ScoringRule::CPMM,
+            Some(0),
+            Some(balance),
+            Some(vec![_3, _1, _1, _1]),
+        ));
+        let pool_id = 0;
+        assert_ok!(Swaps::open_pool(pool_id));
+
+        // Withdraw a certain amount of base tokens to push the total spot price to 3 / 4.
+        let pool_account_id = Swaps::pool_account_id(&pool_id);
+        let amount_removed = _25;
+        assert_ok!(Currencies::withdraw(base_asset, &pool_account_id, amount_removed));
+
+        // Deposit funds into the prize pool to ensure that the transfers don't fail.
+        let market_id = 0;
+        let market_account_id = MarketCommons::market_account(market_id);
+        let arbitrage_amount = 125_007_629_394;
+        assert_ok!(Currencies::deposit(
+            base_asset,
+            &market_account_id,
+            arbitrage_amount + SENTINEL_AMOUNT,
+        ));
+        let bounty_account_id = Swaps::arbitrage_bounty_account_id();
+        assert_ok!(Currencies::deposit(base_asset, &bounty_account_id, _100));
+
+        assert_ok!(Swaps::execute_arbitrage(Origin::signed(CHARLIE), pool_id));
+
+        // The keeper receives 10% of `arbitrage_amount * (1 - 3 / 4) / 2` from the bounty
+        // account.
+        let reward = 1_562_595_367;
+        assert_eq!(
+            Currencies::free_balance(base_asset, &pool_account_id),
+            balance + arbitrage_amount - amount_removed,
+        );
+        assert_eq!(Currencies::free_balance(ASSET_B, &pool_account_id), balance - arbitrage_amount);
+        assert_eq!(Currencies::free_balance(base_asset, &CHARLIE), reward);
+        assert_eq!(Currencies::free_balance(base_asset, &bounty_account_id), _100 - reward);
+        System::assert_has_event(Event::ArbitrageBuyBurn(pool_id, arbitrage_amount).into());
+        System::assert_last_event(Event::ArbitrageKeeperRewarded(pool_id, CHARLIE, reward).into());
+    });
+}
+
+#[test]
+fn execute_arbitrage_pays_no_reward_if_bounty_account_is_not_funded() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let assets = ASSETS;
+        assets.iter().cloned().for_each(|asset| {
+            assert_ok!(Currencies::deposit(asset, &BOB, _10000));
+        });
+        let balance = <Runtime as crate::Config>::MinLiquidity::get();
+        let base_asset = ASSET_A;
+        assert_ok!(Swaps::create_pool(
+            BOB,
+            assets.into(),
+            base_asset,
+            0,
+            ScoringRule::CPMM,
+            Some(0),
+            Some(balance),
+            Some(vec![_3, _1, _1, _1]),
+        ));
+        let pool_id = 0;
+        assert_ok!(Swaps::open_pool(pool_id));
+
+        // Withdraw a certain amount of outcome tokens to push the total spot price to 10 / 9.
+        let pool_account_id = Swaps::pool_account_id(&pool_id);
+        assert_ok!(Currencies::withdraw(ASSET_B, &pool_account_id, _25));
+
+        assert_ok!(Swaps::execute_arbitrage(Origin::signed(CHARLIE), pool_id));
+
+        // The arbitrage is executed, but the pool doesn't pay the keeper.
+        let arbitrage_amount = 49_537_658_690;
+        assert_eq!(
+            Currencies::free_balance(base_asset, &pool_account_id),
+            balance - arbitrage_amount,
+        );
+        assert_eq!(Currencies::free_balance(base_asset, &CHARLIE), 0);
+        System::assert_last_event(Event::ArbitrageMintSell(pool_id, arbitrage_amount).into());
+    });
+}
+
+#[test]
+fn execute_arbitrage_fails_if_price_does_not_exceed_threshold() {
+    ExtBuilder::default().build().execute_with(|| {
+        let assets = ASSETS;
+        assets.iter().cloned().for_each(|asset| {
+            assert_ok!(Currencies::deposit(asset, &BOB, _10000));
+        });
+        // Outcome weights sum to the weight of the base asset, and we create no imbalances, so
+        // total spot price is equal to 1.
+        assert_ok!(Swaps::create_pool(
+            BOB,
+            assets.into(),
+            ASSET_A,
+            0,
+            ScoringRule::CPMM,
+            Some(0),
+            Some(<Runtime as crate::Config>::MinLiquidity::get()),
+            Some(vec![_3, _1, _1, _1]),
+        ));
+        assert_ok!(Swaps::open_pool(0));
+        assert_noop!(
+            Swaps::execute_arbitrage(Origin::signed(CHARLIE), 0),
+            crate::Error::<Runtime>::NoArbitrageOpportunity
+        );
+    });
+}
+
+#[test]
+fn execute_arbitrage_fails_on_inactive_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(Swaps::close_pool(0));
+        assert_noop!(
+            Swaps::execute_arbitrage(Origin::signed(CHARLIE), 0),
+            crate::Error::<Runtime>::PoolIsNotActive
+        );
+    });
+}
+
+#[test]
+fn create_pool_generates_a_new_pool_with_correct_parameters_for_lmsr() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let amount = <Runtime as crate::Config>::MinLiquidity::get();
+        create_initial_pool_lmsr(_1_10, true);
+
+        let pool = Swaps::pools(0).unwrap();
+        assert_eq!(pool.assets, ASSETS.to_vec());
+        assert_eq!(pool.base_asset, ASSET_D);
+        assert_eq!(pool.pool_status, PoolStatus::Active);
+        assert_eq!(pool.scoring_rule, ScoringRule::LMSR { liquidity_parameter: _100 });
+        assert_eq!(pool.swap_fee, Some(_1_10));
+        assert_eq!(pool.total_subsidy, None);
+        assert_eq!(pool.total_weight, None);
+        assert_eq!(pool.weights, None);
+
+        let pool_account = Swaps::pool_account_id(&0);
+        for asset in ASSETS.iter() {
+            assert_eq!(Currencies::free_balance(*asset, &pool_account), amount);
+            assert_eq!(Currencies::free_balance(*asset, &BOB), 0);
+        }
+        assert_eq!(Currencies::free_balance(Swaps::pool_shares_id(0), &BOB), amount);
+    });
+}
+
+#[test_case(
+    Some(_1_10),
+    Some(_100),
+    Some(vec![_1; 4]),
+    _100,
+    crate::Error::<Runtime>::InvalidWeightArgument
+)]
+#[test_case(None, Some(_100), None, _100, crate::Error::<Runtime>::InvalidFeeArgument)]
+#[test_case(Some(_1_2), Some(_100), None, _100, crate::Error::<Runtime>::SwapFeeTooHigh)]
+#[test_case(Some(_1_10), Some(_100), None, _99, crate::Error::<Runtime>::InvalidLiquidityParameter)]
+#[test_case(Some(_1_10), Some(_99), None, _100, crate::Error::<Runtime>::InsufficientLiquidity)]
+#[test_case(Some(_1_10), None, None, _100, crate::Error::<Runtime>::InvalidAmountArgument)]
+fn create_pool_fails_on_invalid_lmsr_parameters(
     swap_fee: Option<BalanceOf<Runtime>>,
-    deposit: bool,
+    amount: Option<BalanceOf<Runtime>>,
+    weights: Option<Vec<u128>>,
+    liquidity_parameter: u128,
+    error: crate::Error<Runtime>,
 ) {
-    if deposit {
+    ExtBuilder::default().build().execute_with(|| {
         ASSETS.iter().cloned().for_each(|asset| {
             assert_ok!(Currencies::deposit(asset, &BOB, _100));
         });
-    }
+        assert_noop!(
+            Swaps::create_pool(
+                BOB,
+                ASSETS.to_vec(),
+                ASSET_D,
+                0,
+                ScoringRule::LMSR { liquidity_parameter },
+                swap_fee,
+                amount,
+                weights,
+            ),
+            error
+        );
+    });
+}
 
-    let pool_id = Swaps::next_pool_id();
-    assert_ok!(Swaps::create_pool(
-        BOB,
-        ASSETS.to_vec(),
-        *ASSETS.last().unwrap(),
-        0,
-        scoring_rule,
-        swap_fee,
-        if scoring_rule == ScoringRule::CPMM {
-            Some(<Runtime as crate::Config>::MinLiquidity::get())
-        } else {
-            None
-        },
-        if scoring_rule == ScoringRule::CPMM { Some(vec!(_2, _2, _2, _2)) } else { None },
-    ));
-    if scoring_rule == ScoringRule::CPMM {
-        assert_ok!(Swaps::open_pool(pool_id));
-    }
+#[test_case(ASSET_D, ASSET_A, false, 3_333_333_333)]
+#[test_case(ASSET_D, ASSET_A, true, 3_703_703_703)]
+#[test_case(ASSET_A, ASSET_B, false, _1)]
+#[test_case(ASSET_A, ASSET_B, true, 11_111_111_111)]
+#[test_case(ASSET_A, ASSET_D, false, 30_000_000_003)]
+fn get_spot_price_returns_correct_results_lmsr(
+    asset_in: Asset<MarketId>,
+    asset_out: Asset<MarketId>,
+    with_fees: bool,
+    expected: BalanceOf<Runtime>,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_lmsr(_1_10, true);
+        assert_eq!(Swaps::get_spot_price(&0, &asset_in, &asset_out, with_fees), Ok(expected));
+    });
+}
+
+#[test_case(ASSET_D, ASSET_A, 248_829_115_000; "buy")]
+#[test_case(ASSET_A, ASSET_D, 29_109_196_200; "sell")]
+#[test_case(ASSET_A, ASSET_B, 82_564_584_600; "swap outcomes")]
+fn swap_exact_amount_in_exchanges_correct_values_with_lmsr(
+    asset_in: Asset<MarketId>,
+    asset_out: Asset<MarketId>,
+    asset_amount_out: BalanceOf<Runtime>,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_lmsr(_1_10, true);
+        assert_ok!(Currencies::deposit(asset_in, &ALICE, _10));
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            asset_in,
+            _10,
+            asset_out,
+            Some(asset_amount_out),
+            None,
+        ));
+        let pool_account = Swaps::pool_account_id(&0);
+        assert_eq!(Currencies::free_balance(asset_in, &ALICE), 0);
+        assert_eq!(Currencies::free_balance(asset_out, &ALICE), asset_amount_out);
+        assert_eq!(Currencies::free_balance(asset_in, &pool_account), _100 + _10);
+        assert_eq!(Currencies::free_balance(asset_out, &pool_account), _100 - asset_amount_out);
+    });
+}
+
+#[test_case(ASSET_D, ASSET_A, 38_284_968_000; "buy")]
+#[test_case(ASSET_A, ASSET_D, 373_505_701_111; "sell")]
+fn swap_exact_amount_out_exchanges_correct_values_with_lmsr(
+    asset_in: Asset<MarketId>,
+    asset_out: Asset<MarketId>,
+    asset_amount_in: BalanceOf<Runtime>,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_lmsr(_1_10, true);
+        assert_ok!(Currencies::deposit(asset_in, &ALICE, asset_amount_in));
+        assert_ok!(Swaps::swap_exact_amount_out(
+            alice_signed(),
+            0,
+            asset_in,
+            Some(asset_amount_in),
+            asset_out,
+            _10,
+            None,
+        ));
+        let pool_account = Swaps::pool_account_id(&0);
+        assert_eq!(Currencies::free_balance(asset_in, &ALICE), 0);
+        assert_eq!(Currencies::free_balance(asset_out, &ALICE), _10);
+        assert_eq!(Currencies::free_balance(asset_in, &pool_account), _100 + asset_amount_in);
+        assert_eq!(Currencies::free_balance(asset_out, &pool_account), _100 - _10);
+    });
+}
+
+#[test]
+fn lmsr_prices_sum_up_to_one_after_trade() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_lmsr(_1_10, true);
+        assert_ok!(Currencies::deposit(ASSET_D, &ALICE, _10));
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            ASSET_D,
+            _10,
+            ASSET_A,
+            Some(0),
+            None,
+        ));
+        let prices: Vec<_> = [ASSET_A, ASSET_B, ASSET_C]
+            .iter()
+            .map(|asset| Swaps::get_spot_price(&0, &ASSET_D, asset, false).unwrap())
+            .collect();
+        assert_eq!(prices, vec![3_907_125_431, 3_046_437_285, 3_046_437_285]);
+        assert_le!(prices.iter().sum::<u128>().abs_diff(_1), 1);
+    });
+}
+
+#[test]
+fn swap_exact_amount_in_fails_on_lmsr_trade_of_same_asset() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_lmsr(_1_10, true);
+        assert_ok!(Currencies::deposit(ASSET_A, &ALICE, _10));
+        assert_noop!(
+            Swaps::swap_exact_amount_in(alice_signed(), 0, ASSET_A, _1, ASSET_A, Some(0), None),
+            crate::Error::<Runtime>::UnsupportedTrade
+        );
+    });
+}
+
+#[test]
+fn pool_join_fails_on_lmsr_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_lmsr(_1_10, true);
+        ASSETS.iter().cloned().for_each(|asset| {
+            assert_ok!(Currencies::deposit(asset, &ALICE, _25));
+        });
+        assert_noop!(
+            Swaps::pool_join(alice_signed(), 0, _1, vec![_25; 4]),
+            crate::Error::<Runtime>::InvalidScoringRule
+        );
+    });
+}
+
+#[test]
+fn pool_exit_on_lmsr_pool_is_only_allowed_after_closing() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_lmsr(_1_10, true);
+        assert_noop!(
+            Swaps::pool_exit(Origin::signed(BOB), 0, _50, vec![0; 4]),
+            crate::Error::<Runtime>::InvalidPoolStatus
+        );
+        assert_ok!(Swaps::close_pool(0));
+        assert_ok!(Swaps::pool_exit(Origin::signed(BOB), 0, _50, vec![0; 4]));
+        assert_eq!(Currencies::free_balance(Swaps::pool_shares_id(0), &BOB), _50);
+    });
+}
+
+#[test]
+fn swap_exact_amount_in_routed_buys_complete_sets_if_outcomes_are_expensive() {
+    // Buying complete sets and swapping all other outcomes for `ASSET_A` must be equivalent to
+    // doing the same by hand.
+    let route_by_hand = || {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        let market_account = MarketCommons::market_account(0);
+        assert_ok!(Currencies::transfer(ASSET_D, &ALICE, &market_account, _1));
+        for asset in [ASSET_A, ASSET_B, ASSET_C] {
+            assert_ok!(Currencies::deposit(asset, &ALICE, _1));
+        }
+        for asset in [ASSET_B, ASSET_C] {
+            assert_ok!(Swaps::swap_exact_amount_in(
+                alice_signed(),
+                0,
+                asset,
+                _1,
+                ASSET_A,
+                Some(0),
+                None,
+            ));
+        }
+        ASSETS.map(|asset| Currencies::free_balance(asset, &ALICE))
+    };
+    let expected = ExtBuilder::default().build().execute_with(route_by_hand);
+
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(Swaps::swap_exact_amount_in_routed(alice_signed(), 0, ASSET_D, _1, ASSET_A, _2));
+        assert_eq!(ASSETS.map(|asset| Currencies::free_balance(asset, &ALICE)), expected);
+        assert_eq!(Currencies::free_balance(ASSET_D, &MarketCommons::market_account(0)), _1);
+        System::assert_last_event(
+            Event::SwapExactAmountInRouted(SwapRoutedEvent {
+                asset_amount_in: _1,
+                asset_amount_out: expected[0] - _25,
+                asset_in: ASSET_D,
+                asset_out: ASSET_A,
+                cpep: CommonPoolEventParams { pool_id: 0, who: ALICE },
+                route: SwapRoute::MintSell,
+            })
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn swap_exact_amount_in_routed_sells_complete_sets_if_outcomes_are_cheap() {
+    let create_pool = || {
+        ASSETS.iter().cloned().for_each(|asset| {
+            assert_ok!(Currencies::deposit(asset, &BOB, _100));
+        });
+        assert_ok!(Swaps::create_pool(
+            BOB,
+            ASSETS.to_vec(),
+            ASSET_D,
+            0,
+            ScoringRule::CPMM,
+            Some(0),
+            Some(_100),
+            Some(vec!(_1, _1, _1, _8)),
+        ));
+        assert_ok!(Swaps::open_pool(0));
+        assert_ok!(Currencies::deposit(ASSET_A, &ALICE, _1));
+        assert_ok!(Currencies::deposit(ASSET_D, &MarketCommons::market_account(0), _100));
+    };
+    let amount_out_direct = ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            ASSET_A,
+            _1,
+            ASSET_D,
+            Some(0),
+            None,
+        ));
+        Currencies::free_balance(ASSET_D, &ALICE)
+    });
+
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        create_pool();
+        assert_ok!(Swaps::swap_exact_amount_in_routed(alice_signed(), 0, ASSET_A, _1, ASSET_D, 0));
+        let amount_out = Currencies::free_balance(ASSET_D, &ALICE);
+        assert_gt!(amount_out, amount_out_direct);
+        // All outcomes bought from the pool were burned.
+        assert_eq!(Currencies::free_balance(ASSET_B, &ALICE), 0);
+        assert_eq!(Currencies::free_balance(ASSET_C, &ALICE), 0);
+        let amount_in = _1 - Currencies::free_balance(ASSET_A, &ALICE);
+        assert_le!(amount_in, _1);
+        assert_eq!(
+            Currencies::free_balance(ASSET_D, &MarketCommons::market_account(0)),
+            _100 - amount_out
+        );
+        System::assert_last_event(
+            Event::SwapExactAmountInRouted(SwapRoutedEvent {
+                asset_amount_in: amount_in,
+                asset_amount_out: amount_out,
+                asset_in: ASSET_A,
+                asset_out: ASSET_D,
+                cpep: CommonPoolEventParams { pool_id: 0, who: ALICE },
+                route: SwapRoute::BuyBurn,
+            })
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn swap_exact_amount_in_routed_swaps_directly_if_cheaper() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        let pool_account = Swaps::pool_account_id(&0);
+        assert_ok!(Swaps::swap_exact_amount_in_routed(alice_signed(), 0, ASSET_A, _1, ASSET_D, 0));
+        let amount_out = Currencies::free_balance(ASSET_D, &ALICE) - _25;
+        assert_eq!(Currencies::free_balance(ASSET_A, &pool_account), _101);
+        assert_eq!(Currencies::free_balance(ASSET_D, &pool_account), _100 - amount_out);
+        System::assert_last_event(
+            Event::SwapExactAmountInRouted(SwapRoutedEvent {
+                asset_amount_in: _1,
+                asset_amount_out: amount_out,
+                asset_in: ASSET_A,
+                asset_out: ASSET_D,
+                cpep: CommonPoolEventParams { pool_id: 0, who: ALICE },
+                route: SwapRoute::Direct,
+            })
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn swap_exact_amount_in_routed_fails_if_amount_out_is_below_limit() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_noop!(
+            Swaps::swap_exact_amount_in_routed(alice_signed(), 0, ASSET_D, _1, ASSET_A, _3),
+            crate::Error::<Runtime>::LimitOut
+        );
+    });
+}
+
+#[test]
+fn swap_exact_amount_in_routed_fails_on_same_asset() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_noop!(
+            Swaps::swap_exact_amount_in_routed(alice_signed(), 0, ASSET_A, _1, ASSET_A, 0),
+            crate::Error::<Runtime>::UnsupportedTrade
+        );
+    });
+}
+
+#[test_case(true)]
+#[test_case(false)]
+fn get_all_spot_prices_returns_prices_of_all_non_base_assets(with_fees: bool) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(_1_10), true);
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            ASSET_A,
+            _2,
+            ASSET_B,
+            Some(0),
+            None
+        ));
+        let expected = [ASSET_A, ASSET_B, ASSET_C]
+            .iter()
+            .map(|asset| (*asset, Swaps::get_spot_price(&0, &ASSET_D, asset, with_fees).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(Swaps::get_all_spot_prices(&0, with_fees).unwrap(), expected);
+    });
+}
+
+#[test]
+fn get_all_spot_prices_fails_if_pool_does_not_exist() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Swaps::get_all_spot_prices(&0, true),
+            crate::Error::<Runtime>::PoolDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn get_pool_balances_returns_balances_of_pool_account() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            ASSET_A,
+            _2,
+            ASSET_B,
+            Some(0),
+            None
+        ));
+        let pool_account = Swaps::pool_account_id(&0);
+        let expected = ASSETS
+            .iter()
+            .map(|asset| (*asset, Currencies::free_balance(*asset, &pool_account)))
+            .collect::<Vec<_>>();
+        assert_eq!(expected[0].1, _100 + _2);
+        assert_eq!(Swaps::get_pool_balances(&0).unwrap(), expected);
+    });
+}
+
+#[test_case(0)]
+#[test_case(_1_10)]
+fn quote_swap_exact_amount_in_matches_trade(swap_fee: BalanceOf<Runtime>) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(swap_fee), true);
+        let quote = Swaps::quote_swap_exact_amount_in(0, ASSET_A, _2, ASSET_B).unwrap();
+        let balance_before = Currencies::free_balance(ASSET_B, &ALICE);
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            ASSET_A,
+            _2,
+            ASSET_B,
+            Some(0),
+            None
+        ));
+        assert_eq!(quote.amount.0, Currencies::free_balance(ASSET_B, &ALICE) - balance_before);
+        assert_eq!(
+            quote.spot_price_after.0,
+            Swaps::get_spot_price(&0, &ASSET_A, &ASSET_B, true).unwrap()
+        );
+        if swap_fee == 0 {
+            assert_eq!(quote.fee.0, 0);
+        } else {
+            assert_gt!(quote.fee.0, 0);
+        }
+    });
+}
+
+#[test_case(0)]
+#[test_case(_1_10)]
+fn quote_swap_exact_amount_out_matches_trade(swap_fee: BalanceOf<Runtime>) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(swap_fee), true);
+        let quote = Swaps::quote_swap_exact_amount_out(0, ASSET_A, ASSET_B, _2).unwrap();
+        let balance_before = Currencies::free_balance(ASSET_A, &ALICE);
+        assert_ok!(Swaps::swap_exact_amount_out(
+            alice_signed(),
+            0,
+            ASSET_A,
+            Some(_25),
+            ASSET_B,
+            _2,
+            None
+        ));
+        assert_eq!(quote.amount.0, balance_before - Currencies::free_balance(ASSET_A, &ALICE));
+        assert_eq!(
+            quote.spot_price_after.0,
+            Swaps::get_spot_price(&0, &ASSET_A, &ASSET_B, true).unwrap()
+        );
+        if swap_fee == 0 {
+            assert_eq!(quote.fee.0, 0);
+        } else {
+            assert_gt!(quote.fee.0, 0);
+        }
+    });
+}
+
+#[test]
+fn quote_pool_join_with_exact_asset_amount_matches_trade() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(_1_10), true);
+        let pool_shares_id = Swaps::pool_shares_id(0);
+        let quote = Swaps::quote_pool_join_with_exact_asset_amount(0, ASSET_A, _5).unwrap();
+        assert_ok!(Swaps::pool_join_with_exact_asset_amount(alice_signed(), 0, ASSET_A, _5, 0));
+        assert_eq!(quote.amount.0, Currencies::free_balance(pool_shares_id, &ALICE));
+        assert_eq!(
+            quote.spot_price_after.0,
+            Swaps::get_spot_price(&0, &ASSET_D, &ASSET_A, true).unwrap()
+        );
+        assert_gt!(quote.fee.0, 0);
+    });
+}
+
+#[test]
+fn quote_pool_join_with_exact_pool_amount_matches_trade() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(_1_10), true);
+        let quote = Swaps::quote_pool_join_with_exact_pool_amount(0, ASSET_A, _5).unwrap();
+        assert_ok!(Swaps::pool_join_with_exact_pool_amount(alice_signed(), 0, ASSET_A, _5, _25));
+        assert_eq!(quote.amount.0, _25 - Currencies::free_balance(ASSET_A, &ALICE));
+        assert_eq!(
+            quote.spot_price_after.0,
+            Swaps::get_spot_price(&0, &ASSET_D, &ASSET_A, true).unwrap()
+        );
+        assert_gt!(quote.fee.0, 0);
+    });
+}
+
+#[test]
+fn quote_pool_exit_with_exact_asset_amount_matches_trade() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(_1_10), true);
+        let pool_shares_id = Swaps::pool_shares_id(0);
+        assert_ok!(Swaps::pool_join(alice_signed(), 0, _5, vec![_25; 4]));
+        let shares_before = Currencies::free_balance(pool_shares_id, &ALICE);
+        let quote = Swaps::quote_pool_exit_with_exact_asset_amount(0, ASSET_A, _1).unwrap();
+        assert_ok!(Swaps::pool_exit_with_exact_asset_amount(alice_signed(), 0, ASSET_A, _1, _5));
+        assert_eq!(
+            quote.amount.0,
+            shares_before - Currencies::free_balance(pool_shares_id, &ALICE)
+        );
+        assert_eq!(
+            quote.spot_price_after.0,
+            Swaps::get_spot_price(&0, &ASSET_D, &ASSET_A, true).unwrap()
+        );
+        assert_gt!(quote.fee.0, 0);
+    });
+}
+
+#[test]
+fn quote_pool_exit_with_exact_pool_amount_matches_trade() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(_1_10), true);
+        assert_ok!(Swaps::pool_join(alice_signed(), 0, _5, vec![_25; 4]));
+        let balance_before = Currencies::free_balance(ASSET_A, &ALICE);
+        let quote = Swaps::quote_pool_exit_with_exact_pool_amount(0, ASSET_A, _1).unwrap();
+        assert_ok!(Swaps::pool_exit_with_exact_pool_amount(alice_signed(), 0, ASSET_A, _1, 0));
+        assert_eq!(quote.amount.0, Currencies::free_balance(ASSET_A, &ALICE) - balance_before);
+        assert_eq!(
+            quote.spot_price_after.0,
+            Swaps::get_spot_price(&0, &ASSET_D, &ASSET_A, true).unwrap()
+        );
+        assert_gt!(quote.fee.0, 0);
+    });
+}
+
+#[test]
+fn quotes_fail_on_non_cpmm_pools() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_lmsr(_1_10, true);
+        assert_noop!(
+            Swaps::quote_swap_exact_amount_in(0, ASSET_A, _1, ASSET_B),
+            crate::Error::<Runtime>::InvalidScoringRule
+        );
+        assert_noop!(
+            Swaps::quote_pool_join_with_exact_asset_amount(0, ASSET_A, _1),
+            crate::Error::<Runtime>::InvalidScoringRule
+        );
+    });
+}
+
+#[test]
+fn set_dynamic_swap_fee_enables_dynamic_fee_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::set_dynamic_swap_fee(alice_signed(), 0, true));
+        System::assert_last_event(Event::DynamicSwapFeeEnabled(0).into());
+        assert!(DynamicSwapFees::<Runtime>::contains_key(0));
+        assert!(zrml_rikiddo::RikiddoPerPool::<Runtime>::contains_key(0));
+        let swap_fee = Swaps::pool_by_id(0).unwrap().swap_fee.unwrap();
+        assert_gt!(swap_fee, 0);
+        assert_le!(swap_fee, <Runtime as crate::Config>::MaxSwapFee::get());
+        assert_gt!(
+            Swaps::get_spot_price(&0, &ASSET_A, &ASSET_B, true).unwrap(),
+            Swaps::get_spot_price(&0, &ASSET_A, &ASSET_B, false).unwrap()
+        );
+        assert_noop!(
+            Swaps::set_dynamic_swap_fee(alice_signed(), 0, true),
+            crate::Error::<Runtime>::DynamicSwapFeeAlreadyEnabled
+        );
+    });
+}
+
+#[test]
+fn set_dynamic_swap_fee_disables_dynamic_fee_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_noop!(
+            Swaps::set_dynamic_swap_fee(alice_signed(), 0, false),
+            crate::Error::<Runtime>::DynamicSwapFeeNotEnabled
+        );
+        assert_ok!(Swaps::set_dynamic_swap_fee(alice_signed(), 0, true));
+        assert_ok!(Swaps::set_dynamic_swap_fee(alice_signed(), 0, false));
+        System::assert_last_event(Event::DynamicSwapFeeDisabled(0).into());
+        assert!(!DynamicSwapFees::<Runtime>::contains_key(0));
+        assert!(!zrml_rikiddo::RikiddoPerPool::<Runtime>::contains_key(0));
+        assert_eq!(Swaps::pool_by_id(0).unwrap().swap_fee, Some(0));
+    });
+}
+
+#[test]
+fn set_dynamic_swap_fee_fails_if_sender_is_not_market_creator() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_noop!(
+            Swaps::set_dynamic_swap_fee(Origin::signed(BOB), 0, true),
+            crate::Error::<Runtime>::NotMarketCreator
+        );
+    });
+}
+
+#[test]
+fn set_dynamic_swap_fee_fails_on_non_cpmm_pools() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_lmsr(_1_10, true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_noop!(
+            Swaps::set_dynamic_swap_fee(alice_signed(), 0, true),
+            crate::Error::<Runtime>::InvalidScoringRule
+        );
+    });
+}
+
+#[test_case(0, 1, <Runtime as crate::Config>::MaxSwapFee::get(); "capped by max swap fee")]
+#[test_case(_1_20, 0, _1_20; "bounded below by pool swap fee")]
+fn dynamic_swap_fee_is_bounded(
+    swap_fee: BalanceOf<Runtime>,
+    initial_fee: i32,
+    expected: BalanceOf<Runtime>,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(swap_fee), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::set_dynamic_swap_fee(alice_signed(), 0, true));
+        zrml_rikiddo::RikiddoPerPool::<Runtime>::mutate(0, |rikiddo| {
+            if let Some(rikiddo) = rikiddo {
+                rikiddo.config.initial_fee = FixedI128::<U33>::from_num(initial_fee);
+            }
+        });
+        assert_eq!(Swaps::pool_by_id(0).unwrap().swap_fee, Some(expected));
+    });
+}
+
+#[test]
+fn swaps_update_trade_volume_of_pools_in_dynamic_fee_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::set_dynamic_swap_fee(alice_signed(), 0, true));
+        let ma_short_state =
+            || zrml_rikiddo::RikiddoPerPool::<Runtime>::get(0).unwrap().ma_short.state().clone();
+        assert_eq!(ma_short_state(), MarketVolumeState::Uninitialized);
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            ASSET_A,
+            _1,
+            ASSET_B,
+            Some(0),
+            None
+        ));
+        assert_eq!(ma_short_state(), MarketVolumeState::DataCollectionStarted);
+    });
+}
+
+#[test]
+fn destroy_pool_removes_dynamic_swap_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::set_dynamic_swap_fee(alice_signed(), 0, true));
+        assert_ok!(Swaps::destroy_pool(0));
+        assert!(!DynamicSwapFees::<Runtime>::contains_key(0));
+        assert!(!zrml_rikiddo::RikiddoPerPool::<Runtime>::contains_key(0));
+    });
+}
+
+#[test]
+fn weight_schedule_moves_weights_linearly() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        create_initial_pool_without_opening(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::set_weight_schedule(alice_signed(), 0, 1, 11, vec![_4, _2, _2, _1]));
+        System::assert_last_event(Event::WeightScheduleSet(0, 1, 11).into());
+        assert_ok!(Swaps::open_pool(0));
+        let weights = |block_number| {
+            frame_system::Pallet::<Runtime>::set_block_number(block_number);
+            let pool = Swaps::pool_by_id(0).unwrap();
+            let weights = pool.weights.unwrap();
+            (
+                ASSETS.iter().map(|asset| weights[asset]).collect::<Vec<_>>(),
+                pool.total_weight.unwrap(),
+                Swaps::get_spot_price(&0, &ASSET_D, &ASSET_A, false).unwrap(),
+            )
+        };
+        assert_eq!(weights(1), (vec![_2, _2, _2, _2], _8, _1));
+        assert_eq!(weights(6), (vec![_3, _2, _2, _1 + _1_2], _8 + _1_2, _2));
+        assert_eq!(weights(11), (vec![_4, _2, _2, _1], _9, _4));
+        assert_eq!(weights(100), (vec![_4, _2, _2, _1], _9, _4));
+        // The stored weights remain untouched.
+        assert_eq!(Swaps::pools(0).unwrap().total_weight, Some(_8));
+    });
+}
+
+#[test_case(0, 11; "start in the past")]
+#[test_case(5, 5; "end not after start")]
+fn set_weight_schedule_fails_on_invalid_schedule(start: BlockNumber, end: BlockNumber) {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        create_initial_pool_without_opening(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_noop!(
+            Swaps::set_weight_schedule(alice_signed(), 0, start, end, vec![_2; 4]),
+            crate::Error::<Runtime>::InvalidWeightSchedule
+        );
+    });
+}
+
+#[test_case(vec![_2; 3], crate::Error::<Runtime>::ProvidedValuesLenMustEqualAssetsLen)]
+#[test_case(vec![_2, _2, _2, _1_2], crate::Error::<Runtime>::BelowMinimumWeight)]
+#[test_case(vec![_2, _2, _2, _100], crate::Error::<Runtime>::AboveMaximumWeight)]
+#[test_case(vec![_20, _20, _20, _2], crate::Error::<Runtime>::MaxTotalWeight)]
+fn set_weight_schedule_fails_on_invalid_weights(
+    end_weights: Vec<u128>,
+    error: crate::Error<Runtime>,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_without_opening(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_noop!(Swaps::set_weight_schedule(alice_signed(), 0, 1, 11, end_weights), error);
+    });
+}
+
+#[test_case(PoolStatus::Active)]
+#[test_case(PoolStatus::Closed)]
+fn set_weight_schedule_fails_if_pool_is_not_initialized(pool_status: PoolStatus) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_without_opening(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::mutate_pool(0, |pool| {
+            pool.pool_status = pool_status;
+            Ok(())
+        }));
+        assert_noop!(
+            Swaps::set_weight_schedule(alice_signed(), 0, 1, 11, vec![_2; 4]),
+            crate::Error::<Runtime>::InvalidPoolStatus
+        );
+    });
+}
+
+#[test]
+fn set_weight_schedule_fails_if_schedule_exists() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_without_opening(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::set_weight_schedule(alice_signed(), 0, 1, 11, vec![_1; 4]));
+        assert_noop!(
+            Swaps::set_weight_schedule(alice_signed(), 0, 1, 11, vec![_1; 4]),
+            crate::Error::<Runtime>::WeightScheduleAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn set_weight_schedule_fails_if_sender_is_not_market_creator() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_without_opening(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_noop!(
+            Swaps::set_weight_schedule(Origin::signed(BOB), 0, 1, 11, vec![_1; 4]),
+            crate::Error::<Runtime>::NotMarketCreator
+        );
+    });
+}
+
+#[test]
+fn destroy_pool_removes_weight_schedule() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_without_opening(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::set_weight_schedule(alice_signed(), 0, 1, 11, vec![_1; 4]));
+        assert_ok!(Swaps::destroy_pool(0));
+        assert!(!WeightSchedules::<Runtime>::contains_key(0));
+    });
+}
+
+#[test]
+fn set_arbitrage_config_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::set_arbitrage_config(alice_signed(), 0, CENT, 10));
+        let arbitrage_config = ArbitrageConfig { tolerance: CENT, max_iterations: 10 };
+        assert_eq!(Swaps::arbitrage_configs(0), Some(arbitrage_config));
+        System::assert_last_event(Event::ArbitrageConfigSet(0, arbitrage_config).into());
+    });
+}
+
+#[test_case(0, 10; "zero tolerance")]
+#[test_case(CENT + 1, 10; "tolerance above threshold")]
+#[test_case(CENT, 0; "zero iterations")]
+#[test_case(CENT, ARBITRAGE_MAX_ITERATIONS as u16 + 1; "too many iterations")]
+fn set_arbitrage_config_fails_on_invalid_config(tolerance: u128, max_iterations: u16) {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_noop!(
+            Swaps::set_arbitrage_config(alice_signed(), 0, tolerance, max_iterations),
+            crate::Error::<Runtime>::InvalidArbitrageConfig
+        );
+    });
+}
+
+#[test]
+fn set_arbitrage_config_fails_if_sender_is_not_market_creator() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_noop!(
+            Swaps::set_arbitrage_config(Origin::signed(BOB), 0, CENT, 10),
+            crate::Error::<Runtime>::NotMarketCreator
+        );
+    });
+}
+
+#[test]
+fn destroy_pool_removes_arbitrage_config() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(0), true);
+        assert_ok!(MarketCommons::push_market(mock_market(4)));
+        assert_ok!(Swaps::set_arbitrage_config(alice_signed(), 0, CENT, 10));
+        assert_ok!(Swaps::destroy_pool(0));
+        assert!(!ArbitrageConfigs::<Runtime>::contains_key(0));
+    });
+}
+
+#[test]
+fn swap_exact_amount_in_distributes_swap_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        CreatorSwapFeeShareMock::set(&Perbill::from_percent(10));
+        ProtocolSwapFeeShareMock::set(&Perbill::from_percent(20));
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(_1_10), true);
+        assert_ok!(MarketCommons::push_market(Market { creator: CHARLIE, ..mock_market(4) }));
+        let pool_account = Swaps::pool_account_id(&0);
+        let treasury_account = Swaps::treasury_account_id();
+        let pool_balance_before = Currencies::free_balance(ASSET_A, &pool_account);
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            ASSET_A,
+            _2,
+            ASSET_B,
+            Some(0),
+            None
+        ));
+        // The total fee is 10% of `_2`.
+        let creator_fee = _2 / 100;
+        let protocol_fee = 2 * _2 / 100;
+        assert_eq!(Currencies::free_balance(ASSET_A, &CHARLIE), creator_fee);
+        assert_eq!(Currencies::free_balance(ASSET_A, &treasury_account), protocol_fee);
+        assert_eq!(
+            Currencies::free_balance(ASSET_A, &pool_account),
+            pool_balance_before + _2 - creator_fee - protocol_fee
+        );
+        System::assert_has_event(
+            Event::SwapFeeDistributed(0, ASSET_A, creator_fee, protocol_fee).into(),
+        );
+    });
+}
+
+#[test]
+fn swap_exact_amount_out_distributes_swap_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        CreatorSwapFeeShareMock::set(&Perbill::from_percent(10));
+        ProtocolSwapFeeShareMock::set(&Perbill::from_percent(20));
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(_1_10), true);
+        assert_ok!(MarketCommons::push_market(Market { creator: CHARLIE, ..mock_market(4) }));
+        let balance_before = Currencies::free_balance(ASSET_A, &ALICE);
+        assert_ok!(Swaps::swap_exact_amount_out(
+            alice_signed(),
+            0,
+            ASSET_A,
+            Some(_25),
+            ASSET_B,
+            _1,
+            None
+        ));
+        let asset_amount_in = balance_before - Currencies::free_balance(ASSET_A, &ALICE);
+        let fee_amount = crate::fixed::bmul(asset_amount_in, _1_10).unwrap();
+        assert_eq!(
+            Currencies::free_balance(ASSET_A, &CHARLIE),
+            Perbill::from_percent(10).mul_floor(fee_amount)
+        );
+        assert_eq!(
+            Currencies::free_balance(ASSET_A, &Swaps::treasury_account_id()),
+            Perbill::from_percent(20).mul_floor(fee_amount)
+        );
+    });
+}
+
+#[test]
+fn swap_fee_shares_below_the_existential_deposit_remain_in_the_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        CreatorSwapFeeShareMock::set(&Perbill::from_percent(10));
+        ProtocolSwapFeeShareMock::set(&Perbill::from_percent(20));
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(_1_10), true);
+        assert_ok!(MarketCommons::push_market(Market { creator: CHARLIE, ..mock_market(4) }));
+        let pool_account = Swaps::pool_account_id(&0);
+        let treasury_account = Swaps::treasury_account_id();
+        assert_ok!(Currencies::deposit(ASSET_A, &treasury_account, _1));
+        OutcomeExistentialDepositMock::set(&_1);
+        let pool_balance_before = Currencies::free_balance(ASSET_A, &pool_account);
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            ASSET_A,
+            _2,
+            ASSET_B,
+            Some(0),
+            None
+        ));
+        // The share of the creator is below the existential deposit and stays in the pool. The
+        // treasury already holds `ASSET_A`, so it receives its share.
+        let protocol_fee = 2 * _2 / 100;
+        assert_eq!(Currencies::free_balance(ASSET_A, &CHARLIE), 0);
+        assert_eq!(Currencies::free_balance(ASSET_A, &treasury_account), _1 + protocol_fee);
+        assert_eq!(
+            Currencies::free_balance(ASSET_A, &pool_account),
+            pool_balance_before + _2 - protocol_fee
+        );
+        System::assert_has_event(Event::SwapFeeDistributed(0, ASSET_A, 0, protocol_fee).into());
+    });
+}
+
+#[test]
+fn swap_fee_split_caps_protocol_share() {
+    ExtBuilder::default().build().execute_with(|| {
+        CreatorSwapFeeShareMock::set(&Perbill::from_percent(80));
+        ProtocolSwapFeeShareMock::set(&Perbill::from_percent(50));
+        create_initial_pool(ScoringRule::CPMM, Some(_1_10), true);
+        let pool = Swaps::pool_by_id(0).unwrap();
+        assert_eq!(Swaps::swap_fee_split(&pool, _10).unwrap(), (_1 * 8 / 10, _1 * 2 / 10));
+    });
+}
+
+#[test]
+fn quote_swap_exact_amount_in_accounts_for_swap_fee_split() {
+    ExtBuilder::default().build().execute_with(|| {
+        CreatorSwapFeeShareMock::set(&Perbill::from_percent(10));
+        ProtocolSwapFeeShareMock::set(&Perbill::from_percent(20));
+        create_initial_pool_with_funds_for_alice(ScoringRule::CPMM, Some(_1_10), true);
+        assert_ok!(MarketCommons::push_market(Market { creator: CHARLIE, ..mock_market(4) }));
+        let quote = Swaps::quote_swap_exact_amount_in(0, ASSET_A, _2, ASSET_B).unwrap();
+        assert_ok!(Swaps::swap_exact_amount_in(
+            alice_signed(),
+            0,
+            ASSET_A,
+            _2,
+            ASSET_B,
+            Some(0),
+            None
+        ));
+        assert_eq!(
+            quote.spot_price_after.0,
+            Swaps::get_spot_price(&0, &ASSET_A, &ASSET_B, true).unwrap()
+        );
+    });
+}
+
+fn alice_signed() -> Origin {
+    Origin::signed(ALICE)
+}
+
+fn create_initial_pool(
+    scoring_rule: ScoringRule,
+    swap_fee: Option<BalanceOf<Runtime>>,
+    deposit: bool,
+) {
+    let pool_id = create_initial_pool_without_opening(scoring_rule, swap_fee, deposit);
+    if scoring_rule == ScoringRule::CPMM {
+        assert_ok!(Swaps::open_pool(pool_id));
+    }
+}
+
+fn create_initial_pool_without_opening(
+    scoring_rule: ScoringRule,
+    swap_fee: Option<BalanceOf<Runtime>>,
+    deposit: bool,
+) -> PoolId {
+    if deposit {
+        ASSETS.iter().cloned().for_each(|asset| {
+            assert_ok!(Currencies::deposit(asset, &BOB, _100));
+        });
+    }
+
+    let pool_id = Swaps::next_pool_id();
+    assert_ok!(Swaps::create_pool(
+        BOB,
+        ASSETS.to_vec(),
+        *ASSETS.last().unwrap(),
+        0,
+        scoring_rule,
+        swap_fee,
+        if scoring_rule == ScoringRule::CPMM {
+            Some(<Runtime as crate::Config>::MinLiquidity::get())
+        } else {
+            None
+        },
+        if scoring_rule == ScoringRule::CPMM { Some(vec!(_2, _2, _2, _2)) } else { None },
+    ));
+    pool_id
+}
+
+fn create_initial_pool_lmsr(swap_fee: BalanceOf<Runtime>, deposit: bool) {
+    let amount = <Runtime as crate::Config>::MinLiquidity::get();
+    if deposit {
+        ASSETS.iter().cloned().for_each(|asset| {
+            assert_ok!(Currencies::deposit(asset, &BOB, amount));
+        });
+    }
+
+    let pool_id = Swaps::next_pool_id();
+    assert_ok!(Swaps::create_pool(
+        BOB,
+        ASSETS.to_vec(),
+        ASSET_D,
+        0,
+        ScoringRule::LMSR { liquidity_parameter: _100 },
+        Some(swap_fee),
+        Some(amount),
+        None,
+    ));
+    assert_ok!(Swaps::open_pool(pool_id));
 }
 
 fn create_initial_pool_with_funds_for_alice(
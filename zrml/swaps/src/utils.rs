@@ -25,7 +25,7 @@ use crate::{
     check_arithm_rslt::CheckArithmRslt,
     events::{CommonPoolEventParams, PoolAssetEvent, PoolAssetsEvent, SwapEvent},
     fixed::{bdiv, bmul},
-    BalanceOf, Config, Error, MarketIdOf, Pallet,
+    BalanceOf, Config, DynamicSwapFees, Error, MarketIdOf, Pallet,
 };
 use alloc::vec::Vec;
 use frame_support::{dispatch::DispatchResult, ensure};
@@ -125,7 +125,10 @@ where
     F4: FnMut(BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError>,
     T: Config,
 {
-    ensure!(p.pool.scoring_rule == ScoringRule::CPMM, Error::<T>::InvalidScoringRule);
+    ensure!(
+        p.pool.scoring_rule != ScoringRule::RikiddoSigmoidFeeMarketEma,
+        Error::<T>::InvalidScoringRule
+    );
     let pool_shares_id = Pallet::<T>::pool_shares_id(p.pool_id);
     let total_issuance = T::AssetManager::total_issuance(pool_shares_id);
 
@@ -189,6 +192,7 @@ where
         ScoringRule::CPMM => {
             T::AssetManager::transfer(p.asset_in, &p.who, p.pool_account_id, asset_amount_in)?;
             T::AssetManager::transfer(p.asset_out, p.pool_account_id, &p.who, asset_amount_out)?;
+            Pallet::<T>::distribute_swap_fee(p.pool_id, p.pool, p.asset_in, asset_amount_in)?;
             (p.cache_for_arbitrage)();
         }
         ScoringRule::RikiddoSigmoidFeeMarketEma => {
@@ -211,6 +215,11 @@ where
                 return Err(Error::<T>::UnsupportedTrade.into());
             }
         }
+        // LMSR prices always sum up to one, so there's nothing to arbitrage.
+        ScoringRule::LMSR { .. } => {
+            T::AssetManager::transfer(p.asset_in, &p.who, p.pool_account_id, asset_amount_in)?;
+            T::AssetManager::transfer(p.asset_out, p.pool_account_id, &p.who, asset_amount_out)?;
+        }
     }
 
     let spot_price_after =
@@ -218,7 +227,7 @@ where
 
     // Allow little tolerance
     match p.pool.scoring_rule {
-        ScoringRule::CPMM => {
+        ScoringRule::CPMM | ScoringRule::LMSR { .. } => {
             ensure!(spot_price_after >= spot_price_before, Error::<T>::MathApproximation)
         }
         ScoringRule::RikiddoSigmoidFeeMarketEma => ensure!(
@@ -232,12 +241,29 @@ where
     }
 
     match p.pool.scoring_rule {
-        ScoringRule::CPMM => ensure!(
-            spot_price_before
-                <= bdiv(asset_amount_in.saturated_into(), asset_amount_out.saturated_into())?
-                    .saturated_into(),
-            Error::<T>::MathApproximation
-        ),
+        ScoringRule::CPMM | ScoringRule::LMSR { .. } => {
+            ensure!(
+                spot_price_before
+                    <= bdiv(asset_amount_in.saturated_into(), asset_amount_out.saturated_into())?
+                        .saturated_into(),
+                Error::<T>::MathApproximation
+            );
+            if DynamicSwapFees::<T>::contains_key(p.pool_id) {
+                // We count the volume in base_currency. Trades between two outcome assets are
+                // valued at the spot price of `asset_in` after the trade.
+                let base_asset = p.pool.base_asset;
+                let volume = if p.asset_in == base_asset {
+                    asset_amount_in
+                } else if p.asset_out == base_asset {
+                    asset_amount_out
+                } else {
+                    let price =
+                        Pallet::<T>::get_spot_price(&p.pool_id, &base_asset, &p.asset_in, false)?;
+                    bmul(asset_amount_in.saturated_into(), price.saturated_into())?.saturated_into()
+                };
+                T::RikiddoSigmoidFeeMarketEma::update_volume(p.pool_id, volume)?;
+            }
+        }
         ScoringRule::RikiddoSigmoidFeeMarketEma => {
             // Currently the only allowed trades are base_currency <-> event asset. We count the
             // volume in base_currency.
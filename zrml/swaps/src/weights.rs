@@ -65,12 +65,21 @@ pub trait WeightInfoZeitgeist {
     fn pool_join_with_exact_pool_amount() -> Weight;
     fn clean_up_pool_categorical_without_reward_distribution(a: u32) -> Weight;
     fn swap_exact_amount_in_cpmm() -> Weight;
+    fn swap_exact_amount_in_cpmm_dynamic_fee() -> Weight;
     fn swap_exact_amount_in_rikiddo(a: u32) -> Weight;
+    fn swap_exact_amount_in_lmsr(a: u32) -> Weight;
     fn swap_exact_amount_out_cpmm() -> Weight;
+    fn swap_exact_amount_out_cpmm_dynamic_fee() -> Weight;
     fn swap_exact_amount_out_rikiddo(a: u32) -> Weight;
+    fn swap_exact_amount_out_lmsr(a: u32) -> Weight;
+    fn swap_exact_amount_in_routed(a: u32) -> Weight;
     fn open_pool(a: u32) -> Weight;
     fn close_pool(a: u32) -> Weight;
     fn destroy_pool(a: u32) -> Weight;
+    fn set_dynamic_swap_fee() -> Weight;
+    fn set_weight_schedule(a: u32) -> Weight;
+    fn set_arbitrage_config() -> Weight;
+    fn execute_arbitrage(a: u32) -> Weight;
 }
 
 /// Weight functions for zrml_swaps (automatically generated)
@@ -96,6 +105,9 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     }
     // Storage: Swaps PoolsCachedForArbitrage (r:8 w:7)
     // Storage: Swaps Pools (r:7 w:0)
+    // Storage: Swaps DynamicSwapFees (r:7 w:0)
+    // Storage: Swaps WeightSchedules (r:7 w:0)
+    // Storage: Swaps ArbitrageConfigs (r:7 w:0)
     // Storage: Tokens Accounts (r:462 w:462)
     // Storage: System Account (r:7 w:0)
     // Storage: Tokens TotalIssuance (r:64 w:64)
@@ -104,7 +116,7 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             // Standard Error: 803_000
             .saturating_add(Weight::from_ref_time(2_356_167_000).saturating_mul(a.into()))
             .saturating_add(T::DbWeight::get().reads(43))
-            .saturating_add(T::DbWeight::get().reads((70_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().reads((73_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes(42))
             .saturating_add(T::DbWeight::get().writes((67_u64).saturating_mul(a.into())))
     }
@@ -163,6 +175,9 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(b.into())))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Swaps ArbitrageConfigs (r:1 w:0)
     // Storage: Tokens Accounts (r:3 w:3)
     // Storage: System Account (r:1 w:0)
     // Storage: Tokens TotalIssuance (r:1 w:1)
@@ -170,11 +185,14 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
         Weight::from_ref_time(61_994_000)
             // Standard Error: 34_000
             .saturating_add(Weight::from_ref_time(36_370_000).saturating_mul(a.into()))
-            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().reads(5))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Swaps ArbitrageConfigs (r:1 w:0)
     // Storage: Tokens Accounts (r:3 w:3)
     // Storage: System Account (r:2 w:1)
     // Storage: Tokens TotalIssuance (r:1 w:1)
@@ -182,21 +200,26 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
         Weight::from_ref_time(80_675_000)
             // Standard Error: 31_000
             .saturating_add(Weight::from_ref_time(33_433_000).saturating_mul(a.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(6))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes(1))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Swaps ArbitrageConfigs (r:1 w:0)
     // Storage: Tokens Accounts (r:2 w:0)
     fn execute_arbitrage_skipped(a: u32) -> Weight {
         Weight::from_ref_time(29_826_000)
             // Standard Error: 13_000
             .saturating_add(Weight::from_ref_time(5_199_000).saturating_mul(a.into()))
-            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens TotalIssuance (r:1 w:1)
     // Storage: Tokens Accounts (r:5 w:5)
     // Storage: System Account (r:1 w:0)
@@ -204,7 +227,7 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
         Weight::from_ref_time(64_421_000)
             // Standard Error: 34_000
             .saturating_add(Weight::from_ref_time(27_430_000).saturating_mul(a.into()))
-            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().reads(6))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes(2))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
@@ -218,33 +241,39 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes(3))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens Accounts (r:3 w:3)
     // Storage: Tokens TotalIssuance (r:1 w:1)
     // Storage: System Account (r:1 w:0)
     // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
     fn pool_exit_with_exact_asset_amount() -> Weight {
         Weight::from_ref_time(142_560_000)
-            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().reads(8))
             .saturating_add(T::DbWeight::get().writes(5))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens TotalIssuance (r:1 w:1)
     // Storage: Tokens Accounts (r:3 w:3)
     // Storage: System Account (r:1 w:0)
     // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
     fn pool_exit_with_exact_pool_amount() -> Weight {
         Weight::from_ref_time(143_190_000)
-            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().reads(8))
             .saturating_add(T::DbWeight::get().writes(5))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens TotalIssuance (r:1 w:1)
     // Storage: Tokens Accounts (r:5 w:5)
     fn pool_join(a: u32) -> Weight {
         Weight::from_ref_time(74_055_000)
             // Standard Error: 32_000
             .saturating_add(Weight::from_ref_time(26_488_000).saturating_mul(a.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(5))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes(2))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
@@ -258,21 +287,25 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes(3))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens TotalIssuance (r:1 w:1)
     // Storage: Tokens Accounts (r:3 w:3)
     // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
     fn pool_join_with_exact_asset_amount() -> Weight {
         Weight::from_ref_time(128_600_000)
-            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads(7))
             .saturating_add(T::DbWeight::get().writes(5))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens TotalIssuance (r:1 w:1)
     // Storage: Tokens Accounts (r:3 w:3)
     // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
     fn pool_join_with_exact_pool_amount() -> Weight {
         Weight::from_ref_time(132_720_000)
-            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads(7))
             .saturating_add(T::DbWeight::get().writes(5))
     }
     // Storage: Swaps Pools (r:1 w:1)
@@ -284,15 +317,34 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes(1))
     }
     // Storage: Swaps Pools (r:1 w:0)
-    // Storage: Tokens Accounts (r:4 w:4)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Tokens Accounts (r:6 w:6)
     // Storage: System Account (r:1 w:0)
+    // Storage: MarketCommons Markets (r:1 w:0)
     // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
     fn swap_exact_amount_in_cpmm() -> Weight {
-        Weight::from_ref_time(170_520_000)
-            .saturating_add(T::DbWeight::get().reads(6))
-            .saturating_add(T::DbWeight::get().writes(5))
+        Weight::from_ref_time(214_862_000)
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: RikiddoSigmoidFeeMarketEma RikiddoPerPool (r:1 w:1)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Tokens Accounts (r:6 w:6)
+    // Storage: System Account (r:1 w:0)
+    // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
+    fn swap_exact_amount_in_cpmm_dynamic_fee() -> Weight {
+        Weight::from_ref_time(291_534_000)
+            .saturating_add(T::DbWeight::get().reads(13))
+            .saturating_add(T::DbWeight::get().writes(8))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens Accounts (r:3 w:3)
     // Storage: Tokens TotalIssuance (r:2 w:1)
     // Storage: RikiddoSigmoidFeeMarketEma RikiddoPerPool (r:1 w:1)
@@ -302,20 +354,52 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
         Weight::from_ref_time(159_946_000)
             // Standard Error: 28_000
             .saturating_add(Weight::from_ref_time(19_270_000).saturating_mul(a.into()))
-            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().reads(8))
             .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes(5))
     }
     // Storage: Swaps Pools (r:1 w:0)
-    // Storage: Tokens Accounts (r:4 w:4)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Tokens Accounts (r:3 w:4)
+    // Storage: System Account (r:1 w:0)
+    fn swap_exact_amount_in_lmsr(a: u32) -> Weight {
+        Weight::from_ref_time(146_312_000)
+            // Standard Error: 31_000
+            .saturating_add(Weight::from_ref_time(24_716_000).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Tokens Accounts (r:6 w:6)
     // Storage: System Account (r:1 w:0)
+    // Storage: MarketCommons Markets (r:1 w:0)
     // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
     fn swap_exact_amount_out_cpmm() -> Weight {
-        Weight::from_ref_time(169_581_000)
-            .saturating_add(T::DbWeight::get().reads(6))
-            .saturating_add(T::DbWeight::get().writes(5))
+        Weight::from_ref_time(213_407_000)
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: RikiddoSigmoidFeeMarketEma RikiddoPerPool (r:1 w:1)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Tokens Accounts (r:6 w:6)
+    // Storage: System Account (r:1 w:0)
+    // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
+    fn swap_exact_amount_out_cpmm_dynamic_fee() -> Weight {
+        Weight::from_ref_time(290_116_000)
+            .saturating_add(T::DbWeight::get().reads(13))
+            .saturating_add(T::DbWeight::get().writes(8))
     }
     // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens Accounts (r:4 w:3)
     // Storage: Tokens TotalIssuance (r:2 w:1)
     // Storage: RikiddoSigmoidFeeMarketEma RikiddoPerPool (r:1 w:1)
@@ -324,10 +408,39 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
         Weight::from_ref_time(101_410_000)
             // Standard Error: 42_000
             .saturating_add(Weight::from_ref_time(35_542_000).saturating_mul(a.into()))
-            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().reads(8))
             .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes(5))
     }
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Tokens Accounts (r:3 w:4)
+    // Storage: System Account (r:1 w:0)
+    fn swap_exact_amount_out_lmsr(a: u32) -> Weight {
+        Weight::from_ref_time(149_087_000)
+            // Standard Error: 33_000
+            .saturating_add(Weight::from_ref_time(25_104_000).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Tokens Accounts (r:3 w:3)
+    // Storage: Tokens TotalIssuance (r:1 w:1)
+    // Storage: System Account (r:1 w:0)
+    // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
+    fn swap_exact_amount_in_routed(a: u32) -> Weight {
+        Weight::from_ref_time(71_245_000)
+            // Standard Error: 94_000
+            .saturating_add(Weight::from_ref_time(193_817_000).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(a.into())))
+    }
     // Storage: Swaps Pools (r:1 w:1)
     fn open_pool(a: u32) -> Weight {
         Weight::from_ref_time(28_613_000)
@@ -345,6 +458,8 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes(1))
     }
     // Storage: Swaps Pools (r:1 w:1)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
     // Storage: Tokens Accounts (r:2 w:2)
     // Storage: System Account (r:1 w:1)
     // Storage: Tokens TotalIssuance (r:2 w:2)
@@ -352,9 +467,57 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
         Weight::from_ref_time(38_770_000)
             // Standard Error: 30_000
             .saturating_add(Weight::from_ref_time(26_154_000).saturating_mul(a.into()))
-            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes(2))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
     }
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:1)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: RikiddoSigmoidFeeMarketEma RikiddoPerPool (r:2 w:1)
+    // Storage: MarketCommons Markets (r:1 w:0)
+    fn set_dynamic_swap_fee() -> Weight {
+        Weight::from_ref_time(45_370_000)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:1)
+    // Storage: MarketCommons Markets (r:1 w:0)
+    fn set_weight_schedule(a: u32) -> Weight {
+        Weight::from_ref_time(31_482_000)
+            // Standard Error: 3_000
+            .saturating_add(Weight::from_ref_time(1_093_000).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: MarketCommons Markets (r:1 w:0)
+    // Storage: Swaps ArbitrageConfigs (r:0 w:1)
+    fn set_arbitrage_config() -> Weight {
+        Weight::from_ref_time(29_614_000)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    // Storage: Swaps Pools (r:1 w:0)
+    // Storage: Swaps DynamicSwapFees (r:1 w:0)
+    // Storage: Swaps WeightSchedules (r:1 w:0)
+    // Storage: Swaps ArbitrageConfigs (r:1 w:0)
+    // Storage: Tokens Accounts (r:5 w:5)
+    // Storage: System Account (r:2 w:1)
+    // Storage: Tokens TotalIssuance (r:1 w:1)
+    // Storage: Swaps PoolsCachedForArbitrage (r:0 w:1)
+    fn execute_arbitrage(a: u32) -> Weight {
+        Weight::from_ref_time(112_742_000)
+            // Standard Error: 35_000
+            .saturating_add(Weight::from_ref_time(36_912_000).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
+    }
 }